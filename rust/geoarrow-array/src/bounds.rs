@@ -1,22 +1,19 @@
+//! Planar bounds of geometries.
+
 use std::ops::Add;
 
 use geo_traits::{
-    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
     MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
-    UnimplementedGeometryCollection, UnimplementedLine, UnimplementedLineString,
+    TriangleTrait, UnimplementedGeometryCollection, UnimplementedLine, UnimplementedLineString,
     UnimplementedMultiLineString, UnimplementedMultiPoint, UnimplementedMultiPolygon,
     UnimplementedPoint, UnimplementedPolygon, UnimplementedTriangle,
 };
-use geo_types::Coord;
-use geoarrow_array::array::RectArray;
-use geoarrow_array::builder::RectBuilder;
-use geoarrow_array::cast::AsGeoArrowArray;
-use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor};
-use geoarrow_schema::error::GeoArrowResult;
-use geoarrow_schema::{BoxType, Dimension, GeoArrowType};
+use wkt::types::Coord;
 
+/// The planar bounds of geometries, accumulated one coordinate at a time.
 #[derive(Debug, Clone, Copy)]
-pub struct BoundingRect {
+pub struct BoundsAccumulator {
     minx: f64,
     miny: f64,
     minz: f64,
@@ -25,10 +22,10 @@ pub struct BoundingRect {
     maxz: f64,
 }
 
-impl BoundingRect {
-    /// New
+impl BoundsAccumulator {
+    /// Create an empty accumulator, to which no coordinate has been added.
     pub fn new() -> Self {
-        BoundingRect {
+        BoundsAccumulator {
             minx: f64::INFINITY,
             miny: f64::INFINITY,
             minz: f64::INFINITY,
@@ -38,14 +35,31 @@ impl BoundingRect {
         }
     }
 
+    /// Construct a 2D bounding rect from its extents.
+    ///
+    /// `minx` may be greater than `maxx` for a box that wraps across the antimeridian.
+    pub fn from_xy(minx: f64, miny: f64, maxx: f64, maxy: f64) -> Self {
+        BoundsAccumulator {
+            minx,
+            miny,
+            minz: f64::INFINITY,
+            maxx,
+            maxy,
+            maxz: -f64::INFINITY,
+        }
+    }
+
+    /// The minimum x value.
     pub fn minx(&self) -> f64 {
         self.minx
     }
 
+    /// The minimum y value.
     pub fn miny(&self) -> f64 {
         self.miny
     }
 
+    /// The minimum z value, or `None` if no coordinate had a z value.
     pub fn minz(&self) -> Option<f64> {
         if self.minz == f64::INFINITY {
             None
@@ -54,14 +68,17 @@ impl BoundingRect {
         }
     }
 
+    /// The maximum x value.
     pub fn maxx(&self) -> f64 {
         self.maxx
     }
 
+    /// The maximum y value.
     pub fn maxy(&self) -> f64 {
         self.maxy
     }
 
+    /// The maximum z value, or `None` if no coordinate had a z value.
     pub fn maxz(&self) -> Option<f64> {
         if self.maxz == -f64::INFINITY {
             None
@@ -70,6 +87,7 @@ impl BoundingRect {
         }
     }
 
+    /// Add a coordinate.
     pub fn add_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
         let x = coord.x();
         let y = coord.y();
@@ -100,18 +118,27 @@ impl BoundingRect {
         }
     }
 
+    /// Extend the z range to contain `z`.
+    pub fn add_z(&mut self, z: f64) {
+        self.minz = self.minz.min(z);
+        self.maxz = self.maxz.max(z);
+    }
+
+    /// Add a point. Empty points are ignored.
     pub fn add_point(&mut self, point: &impl PointTrait<T = f64>) {
         if let Some(coord) = point.coord() {
             self.add_coord(&coord);
         }
     }
 
+    /// Add the coordinates of a line string.
     pub fn add_line_string(&mut self, line_string: &impl LineStringTrait<T = f64>) {
         for coord in line_string.coords() {
             self.add_coord(&coord);
         }
     }
 
+    /// Add the coordinates of all rings of a polygon.
     pub fn add_polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) {
         if let Some(exterior_ring) = polygon.exterior() {
             self.add_line_string(&exterior_ring);
@@ -122,12 +149,14 @@ impl BoundingRect {
         }
     }
 
+    /// Add the points of a multi point.
     pub fn add_multi_point(&mut self, multi_point: &impl MultiPointTrait<T = f64>) {
         for point in multi_point.points() {
             self.add_point(&point);
         }
     }
 
+    /// Add the line strings of a multi line string.
     pub fn add_multi_line_string(
         &mut self,
         multi_line_string: &impl MultiLineStringTrait<T = f64>,
@@ -137,12 +166,14 @@ impl BoundingRect {
         }
     }
 
+    /// Add the polygons of a multi polygon.
     pub fn add_multi_polygon(&mut self, multi_polygon: &impl MultiPolygonTrait<T = f64>) {
         for polygon in multi_polygon.polygons() {
             self.add_polygon(&polygon);
         }
     }
 
+    /// Add a geometry of any type.
     pub fn add_geometry(&mut self, geometry: &impl GeometryTrait<T = f64>) {
         use GeometryType::*;
 
@@ -155,10 +186,12 @@ impl BoundingRect {
             MultiPolygon(g) => self.add_multi_polygon(g),
             GeometryCollection(g) => self.add_geometry_collection(g),
             Rect(g) => self.add_rect(g),
-            Triangle(_) | Line(_) => todo!(),
+            Triangle(g) => self.add_triangle(g),
+            Line(g) => self.add_line(g),
         }
     }
 
+    /// Add the geometries of a geometry collection.
     pub fn add_geometry_collection(
         &mut self,
         geometry_collection: &impl GeometryCollectionTrait<T = f64>,
@@ -168,27 +201,47 @@ impl BoundingRect {
         }
     }
 
+    /// Add the corners of a rect.
     pub fn add_rect(&mut self, rect: &impl RectTrait<T = f64>) {
         self.add_coord(&rect.min());
         self.add_coord(&rect.max());
     }
 
-    pub fn update(&mut self, other: &BoundingRect) {
+    /// Add the vertices of a triangle.
+    pub fn add_triangle(&mut self, triangle: &impl TriangleTrait<T = f64>) {
+        for coord in triangle.coords() {
+            self.add_coord(&coord);
+        }
+    }
+
+    /// Add the start and end of a line.
+    pub fn add_line(&mut self, line: &impl LineTrait<T = f64>) {
+        self.add_coord(&line.start());
+        self.add_coord(&line.end());
+    }
+
+    /// Extend these bounds to contain `other`.
+    pub fn update(&mut self, other: &BoundsAccumulator) {
         self.add_rect(other)
     }
+
+    /// Whether no coordinate has been added.
+    pub fn is_empty(&self) -> bool {
+        self.minx == f64::INFINITY
+    }
 }
 
-impl Default for BoundingRect {
+impl Default for BoundsAccumulator {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Add for BoundingRect {
+impl Add for BoundsAccumulator {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        BoundingRect {
+        BoundsAccumulator {
             minx: self.minx.min(rhs.minx),
             miny: self.miny.min(rhs.miny),
             minz: self.minz.min(rhs.minz),
@@ -199,13 +252,15 @@ impl Add for BoundingRect {
     }
 }
 
-impl RectTrait for BoundingRect {
-    type CoordType<'a> = Coord;
+impl RectTrait for BoundsAccumulator {
+    type CoordType<'a> = Coord<f64>;
 
     fn min(&self) -> Self::CoordType<'_> {
         Coord {
             x: self.minx,
             y: self.miny,
+            z: None,
+            m: None,
         }
     }
 
@@ -213,11 +268,13 @@ impl RectTrait for BoundingRect {
         Coord {
             x: self.maxx,
             y: self.maxy,
+            z: None,
+            m: None,
         }
     }
 }
 
-impl GeometryTrait for BoundingRect {
+impl GeometryTrait for BoundsAccumulator {
     type T = f64;
     type PointType<'a>
         = UnimplementedPoint<f64>
@@ -287,82 +344,17 @@ impl GeometryTrait for BoundingRect {
     }
 }
 
-/// Create a new RectArray using the bounding box of each geometry.
-///
-/// Note that this **does not** currently correctly handle the antimeridian
-pub(crate) fn bounding_rect(arr: &dyn GeoArrowArray) -> GeoArrowResult<RectArray> {
-    use GeoArrowType::*;
-    match arr.data_type() {
-        Point(_) => impl_array_accessor(arr.as_point()),
-        LineString(_) => impl_array_accessor(arr.as_line_string()),
-        Polygon(_) => impl_array_accessor(arr.as_polygon()),
-        MultiPoint(_) => impl_array_accessor(arr.as_multi_point()),
-        MultiLineString(_) => impl_array_accessor(arr.as_multi_line_string()),
-        MultiPolygon(_) => impl_array_accessor(arr.as_multi_polygon()),
-        Geometry(_) => impl_array_accessor(arr.as_geometry()),
-        GeometryCollection(_) => impl_array_accessor(arr.as_geometry_collection()),
-        Rect(_) => Ok(arr.as_rect().clone()),
-        Wkb(_) => impl_array_accessor(arr.as_wkb::<i32>()),
-        LargeWkb(_) => impl_array_accessor(arr.as_wkb::<i64>()),
-        WkbView(_) => impl_array_accessor(arr.as_wkb_view()),
-        Wkt(_) => impl_array_accessor(arr.as_wkt::<i32>()),
-        LargeWkt(_) => impl_array_accessor(arr.as_wkt::<i64>()),
-        WktView(_) => impl_array_accessor(arr.as_wkt_view()),
-    }
-}
-
-/// The actual implementation of computing the bounding rect
-fn impl_array_accessor<'a>(arr: &'a impl GeoArrowArrayAccessor<'a>) -> GeoArrowResult<RectArray> {
-    match arr.data_type() {
-        GeoArrowType::Rect(_) => unreachable!(),
-        _ => {
-            let mut builder = RectBuilder::with_capacity(
-                BoxType::new(Dimension::XY, arr.data_type().metadata().clone()),
-                arr.len(),
-            );
-            for item in arr.iter() {
-                if let Some(item) = item {
-                    let mut rect = BoundingRect::new();
-                    rect.add_geometry(&item?);
-                    builder.push_rect(Some(&rect));
-                } else {
-                    builder.push_null();
-                }
-            }
-            Ok(builder.finish())
-        }
-    }
-}
-
-/// Get the total bounds (i.e. minx, miny, maxx, maxy) of the entire geoarrow array.
-pub(crate) fn total_bounds(arr: &dyn GeoArrowArray) -> GeoArrowResult<BoundingRect> {
-    use GeoArrowType::*;
-    match arr.data_type() {
-        Point(_) => impl_total_bounds(arr.as_point()),
-        LineString(_) => impl_total_bounds(arr.as_line_string()),
-        Polygon(_) => impl_total_bounds(arr.as_polygon()),
-        MultiPoint(_) => impl_total_bounds(arr.as_multi_point()),
-        MultiLineString(_) => impl_total_bounds(arr.as_multi_line_string()),
-        MultiPolygon(_) => impl_total_bounds(arr.as_multi_polygon()),
-        Geometry(_) => impl_total_bounds(arr.as_geometry()),
-        GeometryCollection(_) => impl_total_bounds(arr.as_geometry_collection()),
-        Rect(_) => impl_total_bounds(arr.as_rect()),
-        Wkb(_) => impl_total_bounds(arr.as_wkb::<i32>()),
-        LargeWkb(_) => impl_total_bounds(arr.as_wkb::<i64>()),
-        WkbView(_) => impl_total_bounds(arr.as_wkb_view()),
-        Wkt(_) => impl_total_bounds(arr.as_wkt::<i32>()),
-        LargeWkt(_) => impl_total_bounds(arr.as_wkt::<i64>()),
-        WktView(_) => impl_total_bounds(arr.as_wkt_view()),
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn triangle_and_line() {
+        let mut rect = BoundsAccumulator::new();
+        rect.add_geometry(&geo_types::Triangle::from([(0., 0.), (4., 1.), (1., 3.)]));
+        rect.add_geometry(&geo_types::Line::new((-2., 1.), (1., -5.)));
+        assert_eq!((rect.minx(), rect.miny()), (-2., -5.));
+        assert_eq!((rect.maxx(), rect.maxy()), (4., 3.));
+        assert!(rect.minz().is_none());
     }
 }
-
-/// The actual implementation of computing the total bounds
-fn impl_total_bounds<'a>(arr: &'a impl GeoArrowArrayAccessor<'a>) -> GeoArrowResult<BoundingRect> {
-    let mut rect = BoundingRect::new();
-
-    for item in arr.iter().flatten() {
-        rect.add_geometry(&item?);
-    }
-
-    Ok(rect)
-}
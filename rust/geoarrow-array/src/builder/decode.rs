@@ -0,0 +1,467 @@
+//! Decoding serialized (WKB or WKT) geometries directly into native builders.
+//!
+//! Collecting every parsed geometry into a `Vec` before building the output array doubles peak
+//! memory for large columns. The functions in this module instead allocate the output builder
+//! up front and push parsed geometries straight into it.
+
+use std::ops::Range;
+
+use geo_traits::{GeometryTrait, MultiPointTrait};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{
+    GeometryCollectionType, GeometryType, LineStringType, MultiLineStringType, MultiPointType,
+    MultiPolygonType, PointType, PolygonType,
+};
+
+use crate::GeoArrowArrayAccessor;
+use crate::array::{
+    GeometryArray, GeometryCollectionArray, LineStringArray, MultiLineStringArray, MultiPointArray,
+    MultiPolygonArray, PointArray, PolygonArray,
+};
+use crate::builder::{
+    GeometryBuilder, GeometryCollectionBuilder, LineStringBuilder, MultiLineStringBuilder,
+    MultiPointBuilder, MultiPolygonBuilder, PointBuilder, PolygonBuilder,
+};
+use crate::capacity::{
+    GeometryCapacity, GeometryCollectionCapacity, LineStringCapacity, MultiLineStringCapacity,
+    MultiPointCapacity, MultiPolygonCapacity, PointCapacity, PolygonCapacity,
+};
+use crate::util::GeometryTypeName;
+
+/// The number of rows parsed at a time by [`decode_chunked`].
+const DECODE_CHUNK_SIZE: usize = 1024;
+
+/// The number of rows decoded by each task in [`decode_parallel`].
+#[cfg(feature = "rayon")]
+pub(crate) const PARALLEL_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A native builder that can be allocated from a capacity counter and then filled one geometry
+/// at a time.
+pub(crate) trait DecodeBuilder: Sized + Send {
+    /// The GeoArrow type of the built array.
+    type DataType: Clone + Send + Sync;
+
+    /// The capacity counter for this builder.
+    type Capacity: Default + Copy + Send;
+
+    /// The array produced by this builder.
+    type Array: for<'a> GeoArrowArrayAccessor<'a> + 'static;
+
+    /// Add the buffer sizes of `geom` to `capacity`.
+    fn add_capacity(
+        capacity: &mut Self::Capacity,
+        geom: Option<&impl GeometryTrait<T = f64>>,
+    ) -> GeoArrowResult<()>;
+
+    /// Sum two capacity counters.
+    fn merge_capacity(left: Self::Capacity, right: Self::Capacity) -> Self::Capacity;
+
+    /// Create a new builder with the given capacity.
+    fn with_capacity(typ: Self::DataType, capacity: Self::Capacity) -> Self;
+
+    /// Reserve capacity for at least `additional` more geometries.
+    fn reserve(&mut self, additional: Self::Capacity);
+
+    /// Push a geometry onto the end of this builder.
+    fn push_geometry(&mut self, geom: Option<&impl GeometryTrait<T = f64>>) -> GeoArrowResult<()>;
+
+    /// Consume the builder and return the finished array.
+    fn finish(self) -> Self::Array;
+}
+
+macro_rules! impl_decode_builder {
+    ($builder:ty, $data_type:ty, $capacity:ty, $array:ty) => {
+        impl DecodeBuilder for $builder {
+            type DataType = $data_type;
+            type Capacity = $capacity;
+            type Array = $array;
+
+            fn add_capacity(
+                capacity: &mut Self::Capacity,
+                geom: Option<&impl GeometryTrait<T = f64>>,
+            ) -> GeoArrowResult<()> {
+                capacity.add_geometry(geom)
+            }
+
+            fn merge_capacity(left: Self::Capacity, right: Self::Capacity) -> Self::Capacity {
+                left + right
+            }
+
+            fn with_capacity(typ: Self::DataType, capacity: Self::Capacity) -> Self {
+                <$builder>::with_capacity(typ, capacity)
+            }
+
+            fn reserve(&mut self, additional: Self::Capacity) {
+                <$builder>::reserve(self, additional)
+            }
+
+            fn push_geometry(
+                &mut self,
+                geom: Option<&impl GeometryTrait<T = f64>>,
+            ) -> GeoArrowResult<()> {
+                <$builder>::push_geometry(self, geom)
+            }
+
+            fn finish(self) -> Self::Array {
+                <$builder>::finish(self)
+            }
+        }
+    };
+}
+
+impl_decode_builder!(
+    LineStringBuilder,
+    LineStringType,
+    LineStringCapacity,
+    LineStringArray
+);
+impl_decode_builder!(PolygonBuilder, PolygonType, PolygonCapacity, PolygonArray);
+impl_decode_builder!(
+    MultiPointBuilder,
+    MultiPointType,
+    MultiPointCapacity,
+    MultiPointArray
+);
+impl_decode_builder!(
+    MultiLineStringBuilder,
+    MultiLineStringType,
+    MultiLineStringCapacity,
+    MultiLineStringArray
+);
+impl_decode_builder!(
+    MultiPolygonBuilder,
+    MultiPolygonType,
+    MultiPolygonCapacity,
+    MultiPolygonArray
+);
+impl_decode_builder!(
+    GeometryCollectionBuilder,
+    GeometryCollectionType,
+    GeometryCollectionCapacity,
+    GeometryCollectionArray
+);
+
+impl DecodeBuilder for PointBuilder {
+    type DataType = PointType;
+    type Capacity = PointCapacity;
+    type Array = PointArray;
+
+    fn add_capacity(
+        capacity: &mut Self::Capacity,
+        geom: Option<&impl GeometryTrait<T = f64>>,
+    ) -> GeoArrowResult<()> {
+        // Mirror the geometry types accepted by `PointBuilder::push_geometry`, so that invalid
+        // rows are caught before anything is pushed.
+        if let Some(geom) = geom {
+            match geom.as_type() {
+                geo_traits::GeometryType::Point(_) => {}
+                geo_traits::GeometryType::MultiPoint(mp) if mp.num_points() <= 1 => {}
+                gt => {
+                    return Err(GeoArrowError::IncorrectGeometryType(format!(
+                        "Expected point, got {}",
+                        gt.name()
+                    )));
+                }
+            }
+        }
+        capacity.geom_capacity += 1;
+        Ok(())
+    }
+
+    fn merge_capacity(left: Self::Capacity, right: Self::Capacity) -> Self::Capacity {
+        left + right
+    }
+
+    fn with_capacity(typ: Self::DataType, capacity: Self::Capacity) -> Self {
+        PointBuilder::with_capacity(typ, capacity.geom_capacity)
+    }
+
+    fn reserve(&mut self, additional: Self::Capacity) {
+        PointBuilder::reserve(self, additional.geom_capacity)
+    }
+
+    fn push_geometry(&mut self, geom: Option<&impl GeometryTrait<T = f64>>) -> GeoArrowResult<()> {
+        PointBuilder::push_geometry(self, geom)
+    }
+
+    fn finish(self) -> Self::Array {
+        PointBuilder::finish(self)
+    }
+}
+
+impl DecodeBuilder for GeometryBuilder {
+    type DataType = GeometryType;
+    type Capacity = GeometryCapacity;
+    type Array = GeometryArray;
+
+    fn add_capacity(
+        capacity: &mut Self::Capacity,
+        geom: Option<&impl GeometryTrait<T = f64>>,
+    ) -> GeoArrowResult<()> {
+        capacity.add_geometry(geom)
+    }
+
+    fn merge_capacity(mut left: Self::Capacity, right: Self::Capacity) -> Self::Capacity {
+        left += right;
+        left
+    }
+
+    fn with_capacity(typ: Self::DataType, capacity: Self::Capacity) -> Self {
+        GeometryBuilder::with_capacity(typ, capacity)
+    }
+
+    fn reserve(&mut self, additional: Self::Capacity) {
+        GeometryBuilder::reserve(self, additional)
+    }
+
+    fn push_geometry(&mut self, geom: Option<&impl GeometryTrait<T = f64>>) -> GeoArrowResult<()> {
+        GeometryBuilder::push_geometry(self, geom)
+    }
+
+    fn finish(self) -> Self::Array {
+        GeometryBuilder::finish(self)
+    }
+}
+
+/// Rows that failed to decode, as `(row index, error)` pairs in row order.
+pub(crate) type InvalidRows = Vec<(usize, GeoArrowError)>;
+
+/// Decode the geometries in `range` of `arr` in two passes.
+///
+/// The first pass fully parses each value to count its buffer sizes; a WKB header alone does not
+/// hold the number of coordinates. The builder is then allocated once, and the second pass parses
+/// each value again to push it. This trades parsing every value twice for never reallocating the
+/// output, which pays off for WKB, where parsing only reads the coordinate buffer in place.
+///
+/// If `invalid` is `None`, the first value that can't be parsed or doesn't match the builder's
+/// geometry type is returned as an error. Otherwise such values are recorded in `invalid` and
+/// pushed as nulls, including values that were counted but that the builder then refused.
+pub(crate) fn decode_two_pass<'a, A, B>(
+    arr: &'a A,
+    range: Range<usize>,
+    typ: B::DataType,
+    mut invalid: Option<&mut InvalidRows>,
+) -> GeoArrowResult<B>
+where
+    A: GeoArrowArrayAccessor<'a>,
+    B: DecodeBuilder,
+{
+    let first_invalid = invalid.as_ref().map_or(0, |rows| rows.len());
+
+    let mut capacity = B::Capacity::default();
+    for i in range.clone() {
+        let result = arr
+            .get(i)
+            .and_then(|geom| B::add_capacity(&mut capacity, geom.as_ref()));
+        match (result, invalid.as_deref_mut()) {
+            (Ok(()), _) => {}
+            (Err(err), Some(rows)) => rows.push((i, err)),
+            (Err(err), None) => return Err(err),
+        }
+    }
+
+    let counted_invalid = invalid
+        .as_deref()
+        .map(|rows| {
+            rows[first_invalid..]
+                .iter()
+                .map(|(i, _)| *i)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let mut counted_invalid = counted_invalid.into_iter().peekable();
+    let mut builder = B::with_capacity(typ, capacity);
+    for i in range {
+        if counted_invalid.next_if_eq(&i).is_some() {
+            builder.push_geometry(None::<&wkt::Wkt>)?;
+        } else {
+            let result = arr
+                .get(i)
+                .and_then(|geom| builder.push_geometry(geom.as_ref()));
+            push_null_if_invalid(&mut builder, i, result, invalid.as_deref_mut())?;
+        }
+    }
+    sort_invalid_rows(invalid, first_invalid);
+    Ok(builder)
+}
+
+/// Decode the geometries in `range` of `arr` in a single pass, [`DECODE_CHUNK_SIZE`] rows at a
+/// time.
+///
+/// Each chunk is parsed once and its capacity reserved on the builder before its geometries are
+/// pushed, so at most one chunk of parsed geometries is alive at a time. This is used for WKT,
+/// where parsing a value is too expensive to do twice.
+///
+/// Invalid values are handled as in [`decode_two_pass`].
+pub(crate) fn decode_chunked<'a, A, B>(
+    arr: &'a A,
+    range: Range<usize>,
+    typ: B::DataType,
+    mut invalid: Option<&mut InvalidRows>,
+) -> GeoArrowResult<B>
+where
+    A: GeoArrowArrayAccessor<'a>,
+    B: DecodeBuilder,
+{
+    let first_invalid = invalid.as_ref().map_or(0, |rows| rows.len());
+    let mut builder = B::with_capacity(typ, Default::default());
+    let mut geoms = Vec::with_capacity(DECODE_CHUNK_SIZE.min(range.len()));
+    for chunk_start in range.clone().step_by(DECODE_CHUNK_SIZE) {
+        let chunk_end = (chunk_start + DECODE_CHUNK_SIZE).min(range.end);
+
+        let mut capacity = B::Capacity::default();
+        for i in chunk_start..chunk_end {
+            let result = arr.get(i).and_then(|geom| {
+                B::add_capacity(&mut capacity, geom.as_ref())?;
+                Ok(geom)
+            });
+            match (result, invalid.as_deref_mut()) {
+                (Ok(geom), _) => geoms.push(geom),
+                (Err(err), Some(rows)) => {
+                    rows.push((i, err));
+                    geoms.push(None);
+                }
+                (Err(err), None) => return Err(err),
+            }
+        }
+
+        builder.reserve(capacity);
+        for (i, geom) in (chunk_start..chunk_end).zip(geoms.drain(..)) {
+            let result = builder.push_geometry(geom.as_ref());
+            push_null_if_invalid(&mut builder, i, result, invalid.as_deref_mut())?;
+        }
+    }
+    sort_invalid_rows(invalid, first_invalid);
+    Ok(builder)
+}
+
+/// Handle the `result` of pushing row `i` onto `builder`.
+///
+/// If the push failed and `invalid` is `Some`, the error is recorded there and a null is pushed in
+/// its place. Builders check a geometry's type before pushing any of it, so a refused value leaves
+/// the builder as it was.
+fn push_null_if_invalid<B: DecodeBuilder>(
+    builder: &mut B,
+    i: usize,
+    result: GeoArrowResult<()>,
+    invalid: Option<&mut InvalidRows>,
+) -> GeoArrowResult<()> {
+    match (result, invalid) {
+        (Ok(()), _) => Ok(()),
+        (Err(err), Some(rows)) => {
+            rows.push((i, err));
+            builder.push_geometry(None::<&wkt::Wkt>)
+        }
+        (Err(err), None) => Err(err),
+    }
+}
+
+/// Restore row order in the rows recorded in `invalid` after `first_invalid`.
+///
+/// Rows refused by the builder are recorded after the rows that failed to parse in the same range.
+fn sort_invalid_rows(invalid: Option<&mut InvalidRows>, first_invalid: usize) {
+    if let Some(rows) = invalid {
+        rows[first_invalid..].sort_by_key(|(i, _)| *i);
+    }
+}
+
+/// A function that decodes a range of rows of an array, as passed to [`decode_parallel`].
+#[cfg(feature = "rayon")]
+pub(crate) type DecodeFn<'a, A, B> = fn(
+    &'a A,
+    Range<usize>,
+    <B as DecodeBuilder>::DataType,
+    Option<&mut InvalidRows>,
+) -> GeoArrowResult<B>;
+
+/// Decode `arr` in parallel, [`PARALLEL_CHUNK_SIZE`] rows per task, using `decode` for each
+/// chunk.
+///
+/// Chunks are decoded in waves of one chunk per rayon thread. Each wave's chunk arrays are
+/// appended to a single output builder and dropped before the next wave starts, so peak memory
+/// is the output plus one wave of chunks, rather than twice the output.
+///
+/// If `presize` is true, the output builder is first allocated for the whole array from a
+/// parallel counting pass over `arr`, so it is never reallocated. Otherwise it grows by each
+/// wave's size, which avoids parsing values an extra time.
+#[cfg(feature = "rayon")]
+pub(crate) fn decode_parallel<'a, A, B>(
+    arr: &'a A,
+    typ: B::DataType,
+    decode: DecodeFn<'a, A, B>,
+    presize: bool,
+    mut invalid: Option<&mut InvalidRows>,
+) -> GeoArrowResult<B>
+where
+    A: GeoArrowArrayAccessor<'a>,
+    B: DecodeBuilder,
+{
+    use rayon::prelude::*;
+
+    let lenient = invalid.is_some();
+    let len = arr.len();
+    let chunks = (0..len)
+        .step_by(PARALLEL_CHUNK_SIZE)
+        .map(|start| start..(start + PARALLEL_CHUNK_SIZE).min(len))
+        .collect::<Vec<_>>();
+
+    let capacity = if presize {
+        // Invalid values are skipped here; they are reported by `decode` below.
+        chunks
+            .par_iter()
+            .map(|range| {
+                let mut capacity = B::Capacity::default();
+                for i in range.clone() {
+                    if let Ok(geom) = arr.get(i) {
+                        let _ = B::add_capacity(&mut capacity, geom.as_ref());
+                    }
+                }
+                capacity
+            })
+            .reduce(B::Capacity::default, B::merge_capacity)
+    } else {
+        B::Capacity::default()
+    };
+    let mut builder = B::with_capacity(typ.clone(), capacity);
+
+    for wave in chunks.chunks(rayon::current_num_threads().max(1)) {
+        let results = wave
+            .par_iter()
+            .map(|range| {
+                let mut chunk_invalid = InvalidRows::new();
+                let array = decode(
+                    arr,
+                    range.clone(),
+                    typ.clone(),
+                    lenient.then_some(&mut chunk_invalid),
+                )?
+                .finish();
+                let mut chunk_capacity = B::Capacity::default();
+                if !presize {
+                    for geom in array.iter() {
+                        B::add_capacity(&mut chunk_capacity, geom.transpose()?.as_ref())?;
+                    }
+                }
+                Ok((array, chunk_capacity, chunk_invalid))
+            })
+            .collect::<GeoArrowResult<Vec<_>>>()?;
+
+        if !presize {
+            let wave_capacity = results
+                .iter()
+                .fold(B::Capacity::default(), |acc, (_, capacity, _)| {
+                    B::merge_capacity(acc, *capacity)
+                });
+            builder.reserve(wave_capacity);
+        }
+        for (array, _, chunk_invalid) in results {
+            for geom in array.iter() {
+                builder.push_geometry(geom.transpose()?.as_ref())?;
+            }
+            if let Some(invalid) = invalid.as_deref_mut() {
+                invalid.extend(chunk_invalid);
+            }
+        }
+    }
+    Ok(builder)
+}
@@ -10,13 +10,14 @@ use geoarrow_schema::{
 
 use crate::GeoArrowArray;
 use crate::array::{DimensionIndex, GenericWkbArray, GeometryArray};
+use crate::builder::decode::decode_two_pass;
 use crate::builder::geo_trait_wrappers::{LineWrapper, RectWrapper, TriangleWrapper};
 use crate::builder::{
     GeometryCollectionBuilder, LineStringBuilder, MultiLineStringBuilder, MultiPointBuilder,
     MultiPolygonBuilder, PointBuilder, PolygonBuilder,
 };
 use crate::capacity::GeometryCapacity;
-use crate::trait_::{GeoArrowArrayAccessor, GeoArrowArrayBuilder};
+use crate::trait_::GeoArrowArrayBuilder;
 
 pub(crate) const DEFAULT_PREFER_MULTI: bool = false;
 
@@ -780,11 +781,7 @@ impl<O: OffsetSizeTrait> TryFrom<(GenericWkbArray<O>, GeometryType)> for Geometr
     type Error = GeoArrowError;
 
     fn try_from((value, typ): (GenericWkbArray<O>, GeometryType)) -> GeoArrowResult<Self> {
-        let wkb_objects = value
-            .iter()
-            .map(|x| x.transpose())
-            .collect::<GeoArrowResult<Vec<_>>>()?;
-        Self::from_nullable_geometries(&wkb_objects, typ)
+        decode_two_pass(&value, 0..value.len(), typ, None)
     }
 }
 
@@ -848,7 +845,7 @@ mod test {
     use wkt::wkt;
 
     use super::*;
-    use crate::GeoArrowArray;
+    use crate::{GeoArrowArray, GeoArrowArrayAccessor};
 
     #[test]
     fn all_items_null() {
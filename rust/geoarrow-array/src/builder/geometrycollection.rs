@@ -11,10 +11,11 @@ use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 
 use crate::GeoArrowArray;
 use crate::array::{GenericWkbArray, GeometryCollectionArray};
+use crate::builder::decode::decode_two_pass;
 use crate::builder::geo_trait_wrappers::{LineWrapper, RectWrapper, TriangleWrapper};
 use crate::builder::{MixedGeometryBuilder, OffsetsBuilder};
 use crate::capacity::GeometryCollectionCapacity;
-use crate::trait_::{GeoArrowArrayAccessor, GeoArrowArrayBuilder};
+use crate::trait_::GeoArrowArrayBuilder;
 
 /// The GeoArrow equivalent to `Vec<Option<GeometryCollection>>`: a mutable collection of
 /// GeometryCollections.
@@ -319,11 +320,7 @@ impl<O: OffsetSizeTrait> TryFrom<(GenericWkbArray<O>, GeometryCollectionType)>
     fn try_from(
         (value, typ): (GenericWkbArray<O>, GeometryCollectionType),
     ) -> GeoArrowResult<Self> {
-        let wkb_objects = value
-            .iter()
-            .map(|x| x.transpose())
-            .collect::<GeoArrowResult<Vec<_>>>()?;
-        Self::from_nullable_geometries(&wkb_objects, typ)
+        decode_two_pass(&value, 0..value.len(), typ, None)
     }
 }
 
@@ -8,10 +8,11 @@ use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 
 use crate::GeoArrowArray;
 use crate::array::{GenericWkbArray, LineStringArray};
+use crate::builder::decode::decode_two_pass;
 use crate::builder::geo_trait_wrappers::LineWrapper;
 use crate::builder::{CoordBufferBuilder, OffsetsBuilder};
 use crate::capacity::LineStringCapacity;
-use crate::trait_::{GeoArrowArrayAccessor, GeoArrowArrayBuilder};
+use crate::trait_::GeoArrowArrayBuilder;
 use crate::util::GeometryTypeName;
 
 /// The GeoArrow equivalent to `Vec<Option<LineString>>`: a mutable collection of LineStrings.
@@ -236,11 +237,7 @@ impl<O: OffsetSizeTrait> TryFrom<(GenericWkbArray<O>, LineStringType)> for LineS
     type Error = GeoArrowError;
 
     fn try_from((value, typ): (GenericWkbArray<O>, LineStringType)) -> GeoArrowResult<Self> {
-        let wkb_objects = value
-            .iter()
-            .map(|x| x.transpose())
-            .collect::<GeoArrowResult<Vec<_>>>()?;
-        Self::from_nullable_geometries(&wkb_objects, typ)
+        decode_two_pass(&value, 0..value.len(), typ, None)
     }
 }
 
@@ -1,6 +1,7 @@
 //! Push-based APIs for constructing arrays.
 
 mod coord;
+pub(crate) mod decode;
 pub(crate) mod geo_trait_wrappers;
 mod geometry;
 mod geometrycollection;
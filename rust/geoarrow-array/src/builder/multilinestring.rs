@@ -8,9 +8,10 @@ use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 
 use crate::GeoArrowArray;
 use crate::array::{GenericWkbArray, MultiLineStringArray};
+use crate::builder::decode::decode_two_pass;
 use crate::builder::{CoordBufferBuilder, OffsetsBuilder};
 use crate::capacity::MultiLineStringCapacity;
-use crate::trait_::{GeoArrowArrayAccessor, GeoArrowArrayBuilder};
+use crate::trait_::GeoArrowArrayBuilder;
 use crate::util::GeometryTypeName;
 
 /// The GeoArrow equivalent to `Vec<Option<MultiLineString>>`: a mutable collection of
@@ -334,11 +335,7 @@ impl<O: OffsetSizeTrait> TryFrom<(GenericWkbArray<O>, MultiLineStringType)>
     type Error = GeoArrowError;
 
     fn try_from((value, typ): (GenericWkbArray<O>, MultiLineStringType)) -> GeoArrowResult<Self> {
-        let wkb_objects = value
-            .iter()
-            .map(|x| x.transpose())
-            .collect::<GeoArrowResult<Vec<_>>>()?;
-        Self::from_nullable_geometries(&wkb_objects, typ)
+        decode_two_pass(&value, 0..value.len(), typ, None)
     }
 }
 
@@ -8,9 +8,10 @@ use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 
 use crate::GeoArrowArray;
 use crate::array::{GenericWkbArray, MultiPointArray};
+use crate::builder::decode::decode_two_pass;
 use crate::builder::{CoordBufferBuilder, OffsetsBuilder};
 use crate::capacity::MultiPointCapacity;
-use crate::trait_::{GeoArrowArrayAccessor, GeoArrowArrayBuilder};
+use crate::trait_::GeoArrowArrayBuilder;
 use crate::util::GeometryTypeName;
 
 /// The GeoArrow equivalent to `Vec<Option<MultiPoint>>`: a mutable collection of MultiPoints.
@@ -236,11 +237,7 @@ impl<O: OffsetSizeTrait> TryFrom<(GenericWkbArray<O>, MultiPointType)> for Multi
     type Error = GeoArrowError;
 
     fn try_from((value, typ): (GenericWkbArray<O>, MultiPointType)) -> GeoArrowResult<Self> {
-        let wkb_objects = value
-            .iter()
-            .map(|x| x.transpose())
-            .collect::<GeoArrowResult<Vec<_>>>()?;
-        Self::from_nullable_geometries(&wkb_objects, typ)
+        decode_two_pass(&value, 0..value.len(), typ, None)
     }
 }
 
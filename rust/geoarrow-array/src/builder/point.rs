@@ -9,7 +9,8 @@ use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 use crate::GeoArrowArray;
 use crate::array::{GenericWkbArray, PointArray};
 use crate::builder::CoordBufferBuilder;
-use crate::trait_::{GeoArrowArrayAccessor, GeoArrowArrayBuilder};
+use crate::builder::decode::decode_two_pass;
+use crate::trait_::GeoArrowArrayBuilder;
 use crate::util::GeometryTypeName;
 
 /// The GeoArrow equivalent to `Vec<Option<Point>>`: a mutable collection of Points.
@@ -239,11 +240,7 @@ impl<O: OffsetSizeTrait> TryFrom<(GenericWkbArray<O>, PointType)> for PointBuild
     type Error = GeoArrowError;
 
     fn try_from((value, typ): (GenericWkbArray<O>, PointType)) -> GeoArrowResult<Self> {
-        let wkb_objects = value
-            .iter()
-            .map(|x| x.transpose())
-            .collect::<GeoArrowResult<Vec<_>>>()?;
-        Self::from_nullable_geometries(&wkb_objects, typ)
+        decode_two_pass(&value, 0..value.len(), typ, None)
     }
 }
 
@@ -2,6 +2,7 @@ use arrow_array::OffsetSizeTrait;
 use arrow_array::builder::GenericBinaryBuilder;
 use geo_traits::GeometryTrait;
 use geoarrow_schema::WkbType;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 use wkb::Endianness;
 use wkb::writer::{WriteOptions, write_geometry};
 
@@ -37,17 +38,39 @@ impl<O: OffsetSizeTrait> WkbBuilder<O> {
     // }
 
     /// Push a Geometry onto the end of this builder
+    ///
+    /// ## Panics
+    ///
+    /// If the geometry can't be written as WKB. Use
+    /// [`try_push_geometry`][Self::try_push_geometry] to handle the error instead.
     #[inline]
     pub fn push_geometry(&mut self, geom: Option<&impl GeometryTrait<T = f64>>) {
+        self.try_push_geometry(geom).unwrap()
+    }
+
+    /// Push a Geometry onto the end of this builder, returning an error if it can't be written as
+    /// WKB.
+    ///
+    /// On error a null is pushed in place of the geometry, so the builder stays usable.
+    #[inline]
+    pub fn try_push_geometry(
+        &mut self,
+        geom: Option<&impl GeometryTrait<T = f64>>,
+    ) -> GeoArrowResult<()> {
         if let Some(geom) = geom {
             let wkb_options = WriteOptions {
                 endianness: Endianness::LittleEndian,
             };
-            write_geometry(&mut self.0, geom, &wkb_options).unwrap();
+            if let Err(err) = write_geometry(&mut self.0, geom, &wkb_options) {
+                // Close the slot so that partially written bytes don't leak into the next value.
+                self.0.append_null();
+                return Err(GeoArrowError::Wkb(err.to_string()));
+            }
             self.0.append_value("")
         } else {
             self.0.append_null()
         }
+        Ok(())
     }
 
     /// Extend this builder from an iterator of Geometries.
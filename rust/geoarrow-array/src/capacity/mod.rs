@@ -23,5 +23,6 @@ pub(crate) use mixed::MixedCapacity;
 pub use multilinestring::MultiLineStringCapacity;
 pub use multipoint::MultiPointCapacity;
 pub use multipolygon::MultiPolygonCapacity;
+pub(crate) use point::PointCapacity;
 pub use polygon::PolygonCapacity;
 pub use wkb::WkbCapacity;
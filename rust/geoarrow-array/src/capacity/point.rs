@@ -60,6 +60,12 @@ impl PointCapacity {
     }
 }
 
+impl Default for PointCapacity {
+    fn default() -> Self {
+        Self::new_empty()
+    }
+}
+
 impl Add for PointCapacity {
     type Output = Self;
 
@@ -3,17 +3,26 @@
 
 use std::sync::Arc;
 
-use arrow_array::OffsetSizeTrait;
 use arrow_array::builder::{
-    BinaryViewBuilder, GenericByteBuilder, GenericStringBuilder, StringViewBuilder,
+    BinaryViewBuilder, BooleanBufferBuilder, GenericByteBuilder, GenericStringBuilder,
+    StringViewBuilder,
 };
 use arrow_array::cast::AsArray;
+use arrow_array::{
+    ArrayRef, BinaryArray, BinaryViewArray, LargeBinaryArray, LargeStringArray, OffsetSizeTrait,
+    StringArray, StringViewArray,
+};
+use arrow_buffer::NullBuffer;
+use arrow_schema::DataType;
 use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
-use geoarrow_schema::{GeoArrowType, WkbType};
+use geoarrow_schema::{GeoArrowType, GeometryType, WkbType};
 use wkb::Endianness;
 use wkb::writer::WriteOptions;
 
 use crate::array::*;
+use crate::builder::decode::{DecodeBuilder, InvalidRows, decode_chunked, decode_two_pass};
+#[cfg(feature = "rayon")]
+use crate::builder::decode::{PARALLEL_CHUNK_SIZE, decode_parallel};
 use crate::builder::{
     GeometryBuilder, GeometryCollectionBuilder, LineStringBuilder, MultiLineStringBuilder,
     MultiPointBuilder, MultiPolygonBuilder, PointBuilder, PolygonBuilder, WkbBuilder,
@@ -433,40 +442,92 @@ fn impl_to_wkb_view<'a>(
 ///
 /// Note that this will be slow if converting from a WKB array to another WKB-typed array. If
 /// possible, use the `From` impls on WKB-typed arrays.
+///
+/// This errors on the first value that can't be parsed or doesn't match `to_type`. Use
+/// [`from_wkb_with_options`] to null out such values instead.
 pub fn from_wkb<'a, A: GenericWkbArrayType<'a>>(
     arr: &'a A,
     to_type: GeoArrowType,
 ) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
-    let geoms = arr
-        .iter()
-        .map(|g| g.transpose())
-        .collect::<GeoArrowResult<Vec<_>>>()?;
+    impl_from_wkb(arr, to_type, None)
+}
+
+/// Parse a [`GenericWkbArray`] or [`WkbViewArray`] to a [`GeoArrowArray`] with the designated
+/// [`GeoArrowType`], with control over how invalid values are handled.
+///
+/// See [`from_wkb`] for details on the output type, and [`ParseOptions`] for the available
+/// options.
+pub fn from_wkb_with_options<'a, A: GenericWkbArrayType<'a>>(
+    arr: &'a A,
+    to_type: GeoArrowType,
+    options: &ParseOptions,
+) -> GeoArrowResult<ParsedArray> {
+    let mut invalid = InvalidRows::new();
+    let array = impl_from_wkb(arr, to_type, options.is_lenient().then_some(&mut invalid))?;
+    ParsedArray::try_new(arr, array, invalid, options)
+}
 
+fn impl_from_wkb<'a, A: GenericWkbArrayType<'a>>(
+    arr: &'a A,
+    to_type: GeoArrowType,
+    invalid: Option<&mut InvalidRows>,
+) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
     use GeoArrowType::*;
     let result: Arc<dyn GeoArrowArray> = match to_type {
-        Point(typ) => Arc::new(PointBuilder::from_nullable_geometries(&geoms, typ)?.finish()),
-        LineString(typ) => {
-            Arc::new(LineStringBuilder::from_nullable_geometries(&geoms, typ)?.finish())
-        }
-        Polygon(typ) => Arc::new(PolygonBuilder::from_nullable_geometries(&geoms, typ)?.finish()),
-        MultiPoint(typ) => {
-            Arc::new(MultiPointBuilder::from_nullable_geometries(&geoms, typ)?.finish())
-        }
+        Point(typ) => Arc::new(decode_wkb::<_, PointBuilder>(arr, typ, invalid)?),
+        LineString(typ) => Arc::new(decode_wkb::<_, LineStringBuilder>(arr, typ, invalid)?),
+        Polygon(typ) => Arc::new(decode_wkb::<_, PolygonBuilder>(arr, typ, invalid)?),
+        MultiPoint(typ) => Arc::new(decode_wkb::<_, MultiPointBuilder>(arr, typ, invalid)?),
         MultiLineString(typ) => {
-            Arc::new(MultiLineStringBuilder::from_nullable_geometries(&geoms, typ)?.finish())
-        }
-        MultiPolygon(typ) => {
-            Arc::new(MultiPolygonBuilder::from_nullable_geometries(&geoms, typ)?.finish())
-        }
-        GeometryCollection(typ) => {
-            Arc::new(GeometryCollectionBuilder::from_nullable_geometries(&geoms, typ)?.finish())
+            Arc::new(decode_wkb::<_, MultiLineStringBuilder>(arr, typ, invalid)?)
         }
+        MultiPolygon(typ) => Arc::new(decode_wkb::<_, MultiPolygonBuilder>(arr, typ, invalid)?),
+        GeometryCollection(typ) => Arc::new(decode_wkb::<_, GeometryCollectionBuilder>(
+            arr, typ, invalid,
+        )?),
         Rect(_) => {
             return Err(GeoArrowError::IncorrectGeometryType(format!(
                 "Cannot decode WKB geometries to Rect geometry type in from_wkb {to_type:?}",
             )));
         }
-        Geometry(typ) => Arc::new(GeometryBuilder::from_nullable_geometries(&geoms, typ)?.finish()),
+        Geometry(typ) => Arc::new(decode_wkb::<_, GeometryBuilder>(arr, typ, invalid)?),
+        Wkb(_) | LargeWkb(_) | WkbView(_) | Wkt(_) | LargeWkt(_) | WktView(_) => match invalid {
+            None => to_serialized(arr, to_type)?,
+            Some(invalid) => {
+                // Validate every value by decoding it before serializing again
+                let typ = GeometryType::new(to_type.metadata().clone());
+                let geoms = decode_wkb::<_, GeometryBuilder>(arr, typ, Some(invalid))?;
+                to_serialized(&geoms, to_type)?
+            }
+        },
+    };
+    Ok(result)
+}
+
+/// Decode a WKB array into a native array.
+///
+/// Each value is fully parsed twice, once to count buffer sizes and again to push it, so that the
+/// output builder is allocated only once. With the `rayon` feature, large arrays are decoded in parallel
+/// chunks.
+fn decode_wkb<'a, A: GenericWkbArrayType<'a>, B: DecodeBuilder>(
+    arr: &'a A,
+    typ: B::DataType,
+    invalid: Option<&mut InvalidRows>,
+) -> GeoArrowResult<B::Array> {
+    #[cfg(feature = "rayon")]
+    if arr.len() > PARALLEL_CHUNK_SIZE {
+        return Ok(decode_parallel(arr, typ, decode_two_pass::<A, B>, true, invalid)?.finish());
+    }
+    Ok(decode_two_pass::<A, B>(arr, 0..arr.len(), typ, invalid)?.finish())
+}
+
+/// Convert `arr` to a WKB or WKT array of `to_type`.
+fn to_serialized(
+    arr: &dyn GeoArrowArray,
+    to_type: GeoArrowType,
+) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
+    use GeoArrowType::*;
+    let result: Arc<dyn GeoArrowArray> = match to_type {
         Wkb(typ) => {
             let mut wkb_arr = to_wkb::<i32>(arr)?;
             wkb_arr.data_type = typ;
@@ -497,6 +558,11 @@ pub fn from_wkb<'a, A: GenericWkbArrayType<'a>>(
             wkt_view_arr.data_type = typ;
             Arc::new(wkt_view_arr)
         }
+        _ => {
+            return Err(GeoArrowError::IncorrectGeometryType(format!(
+                "Expected a WKB or WKT type, got {to_type:?}",
+            )));
+        }
     };
     Ok(result)
 }
@@ -615,69 +681,213 @@ fn impl_to_wkt_view<'a>(
 ///
 /// Note that the GeoArrow metadata on the new array is taken from `to_type` **not** the original
 /// array. Ensure you construct the [GeoArrowType] with the correct metadata.
+///
+/// This errors on the first value that can't be parsed or doesn't match `to_type`. Use
+/// [`from_wkt_with_options`] to null out such values instead.
 pub fn from_wkt<A: GenericWktArrayType>(
     arr: &A,
     to_type: GeoArrowType,
 ) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
-    let geoms = arr
-        .iter()
-        .map(|g| g.transpose())
-        .collect::<GeoArrowResult<Vec<_>>>()?;
+    impl_from_wkt(arr, to_type, None)
+}
 
+/// Parse a [`GenericWktArray`] or [`WktViewArray`] to a [`GeoArrowArray`] with the designated
+/// [`GeoArrowType`], with control over how invalid values are handled.
+///
+/// See [`from_wkt`] for details on the output type, and [`ParseOptions`] for the available
+/// options.
+pub fn from_wkt_with_options<A: GenericWktArrayType>(
+    arr: &A,
+    to_type: GeoArrowType,
+    options: &ParseOptions,
+) -> GeoArrowResult<ParsedArray> {
+    let mut invalid = InvalidRows::new();
+    let array = impl_from_wkt(arr, to_type, options.is_lenient().then_some(&mut invalid))?;
+    ParsedArray::try_new(arr, array, invalid, options)
+}
+
+fn impl_from_wkt<A: GenericWktArrayType>(
+    arr: &A,
+    to_type: GeoArrowType,
+    invalid: Option<&mut InvalidRows>,
+) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
     use GeoArrowType::*;
     let result: Arc<dyn GeoArrowArray> = match to_type {
-        Point(typ) => Arc::new(PointBuilder::from_nullable_geometries(&geoms, typ)?.finish()),
-        LineString(typ) => {
-            Arc::new(LineStringBuilder::from_nullable_geometries(&geoms, typ)?.finish())
-        }
-        Polygon(typ) => Arc::new(PolygonBuilder::from_nullable_geometries(&geoms, typ)?.finish()),
-        MultiPoint(typ) => {
-            Arc::new(MultiPointBuilder::from_nullable_geometries(&geoms, typ)?.finish())
-        }
+        Point(typ) => Arc::new(decode_wkt::<_, PointBuilder>(arr, typ, invalid)?),
+        LineString(typ) => Arc::new(decode_wkt::<_, LineStringBuilder>(arr, typ, invalid)?),
+        Polygon(typ) => Arc::new(decode_wkt::<_, PolygonBuilder>(arr, typ, invalid)?),
+        MultiPoint(typ) => Arc::new(decode_wkt::<_, MultiPointBuilder>(arr, typ, invalid)?),
         MultiLineString(typ) => {
-            Arc::new(MultiLineStringBuilder::from_nullable_geometries(&geoms, typ)?.finish())
-        }
-        MultiPolygon(typ) => {
-            Arc::new(MultiPolygonBuilder::from_nullable_geometries(&geoms, typ)?.finish())
-        }
-        GeometryCollection(typ) => {
-            Arc::new(GeometryCollectionBuilder::from_nullable_geometries(&geoms, typ)?.finish())
+            Arc::new(decode_wkt::<_, MultiLineStringBuilder>(arr, typ, invalid)?)
         }
+        MultiPolygon(typ) => Arc::new(decode_wkt::<_, MultiPolygonBuilder>(arr, typ, invalid)?),
+        GeometryCollection(typ) => Arc::new(decode_wkt::<_, GeometryCollectionBuilder>(
+            arr, typ, invalid,
+        )?),
         Rect(_) => {
             return Err(GeoArrowError::IncorrectGeometryType(format!(
                 "Cannot decode WKT geometries to Rect geometry type in from_wkt {to_type:?}",
             )));
         }
-        Geometry(typ) => Arc::new(GeometryBuilder::from_nullable_geometries(&geoms, typ)?.finish()),
-        Wkb(typ) => {
-            let mut wkb_arr = to_wkb::<i32>(arr)?;
-            wkb_arr.data_type = typ;
-            Arc::new(wkb_arr)
+        Geometry(typ) => Arc::new(decode_wkt::<_, GeometryBuilder>(arr, typ, invalid)?),
+        Wkb(_) | LargeWkb(_) | WkbView(_) | Wkt(_) | LargeWkt(_) | WktView(_) => match invalid {
+            None => to_serialized(arr, to_type)?,
+            Some(invalid) => {
+                // Validate every value by decoding it before serializing again
+                let typ = GeometryType::new(to_type.metadata().clone());
+                let geoms = decode_wkt::<_, GeometryBuilder>(arr, typ, Some(invalid))?;
+                to_serialized(&geoms, to_type)?
+            }
+        },
+    };
+    Ok(result)
+}
+
+/// Decode a WKT array into a native array.
+///
+/// Each value is parsed only once, a chunk of rows at a time, and pushed straight into the output
+/// builder. With the `rayon` feature, large arrays are decoded in parallel chunks.
+fn decode_wkt<A: GenericWktArrayType, B: DecodeBuilder>(
+    arr: &A,
+    typ: B::DataType,
+    invalid: Option<&mut InvalidRows>,
+) -> GeoArrowResult<B::Array> {
+    #[cfg(feature = "rayon")]
+    if arr.len() > PARALLEL_CHUNK_SIZE {
+        return Ok(decode_parallel(arr, typ, decode_chunked::<A, B>, false, invalid)?.finish());
+    }
+    Ok(decode_chunked::<A, B>(arr, 0..arr.len(), typ, invalid)?.finish())
+}
+
+/// How [`from_wkb_with_options`] and [`from_wkt_with_options`] handle values that can't be parsed
+/// or don't match the target geometry type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InvalidGeometryHandling {
+    /// Fail the whole array on the first invalid value. This matches [`from_wkb`] and
+    /// [`from_wkt`].
+    #[default]
+    Error,
+
+    /// Replace invalid values with nulls.
+    Null,
+
+    /// Replace invalid values with nulls, and keep the original values in
+    /// [`ParsedArray::side_car`].
+    SideCar,
+}
+
+/// Options for [`from_wkb_with_options`] and [`from_wkt_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// How to handle values that can't be parsed or don't match the target geometry type.
+    pub invalid: InvalidGeometryHandling,
+}
+
+impl ParseOptions {
+    /// Set how to handle values that can't be parsed or don't match the target geometry type.
+    pub fn with_invalid(self, invalid: InvalidGeometryHandling) -> Self {
+        Self { invalid }
+    }
+
+    fn is_lenient(&self) -> bool {
+        self.invalid != InvalidGeometryHandling::Error
+    }
+}
+
+/// A row that couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidGeometry {
+    /// The index of the row in the input array.
+    pub index: usize,
+
+    /// The error message for this row.
+    pub message: String,
+}
+
+/// The output of [`from_wkb_with_options`] and [`from_wkt_with_options`].
+#[derive(Debug, Clone)]
+pub struct ParsedArray {
+    /// The parsed array, with a null in place of each invalid value.
+    pub array: Arc<dyn GeoArrowArray>,
+
+    /// With [`InvalidGeometryHandling::SideCar`], an array with the same type and length as the
+    /// input that holds the original value of each invalid row, and is null everywhere else.
+    pub side_car: Option<Arc<dyn GeoArrowArray>>,
+
+    /// The rows that couldn't be parsed, in row order.
+    pub invalid: Vec<InvalidGeometry>,
+}
+
+impl ParsedArray {
+    fn try_new(
+        input: &dyn GeoArrowArray,
+        array: Arc<dyn GeoArrowArray>,
+        invalid: InvalidRows,
+        options: &ParseOptions,
+    ) -> GeoArrowResult<Self> {
+        let side_car = if options.invalid == InvalidGeometryHandling::SideCar {
+            let rows = invalid.iter().map(|(i, _)| *i).collect::<Vec<_>>();
+            let side_car = retain_rows(&input.to_array_ref(), &rows)?;
+            let field = input.data_type().to_field("", true);
+            Some(from_arrow_array(side_car.as_ref(), &field)?)
+        } else {
+            None
+        };
+        let invalid = invalid
+            .into_iter()
+            .map(|(index, err)| InvalidGeometry {
+                index,
+                message: err.to_string(),
+            })
+            .collect();
+        Ok(Self {
+            array,
+            side_car,
+            invalid,
+        })
+    }
+}
+
+/// Null out every value of a binary or string array except those at `rows`.
+///
+/// The value buffers are shared with the input; only a new validity buffer is allocated.
+fn retain_rows(array: &ArrayRef, rows: &[usize]) -> GeoArrowResult<ArrayRef> {
+    let mut validity = BooleanBufferBuilder::new(array.len());
+    validity.append_n(array.len(), false);
+    for row in rows {
+        validity.set_bit(*row, true);
+    }
+    let nulls = Some(NullBuffer::new(validity.finish()));
+
+    let result: ArrayRef = match array.data_type() {
+        DataType::Binary => {
+            let (offsets, values, _) = array.as_binary::<i32>().clone().into_parts();
+            Arc::new(BinaryArray::try_new(offsets, values, nulls)?)
         }
-        LargeWkb(typ) => {
-            let mut wkb_arr = to_wkb::<i64>(arr)?;
-            wkb_arr.data_type = typ;
-            Arc::new(wkb_arr)
+        DataType::LargeBinary => {
+            let (offsets, values, _) = array.as_binary::<i64>().clone().into_parts();
+            Arc::new(LargeBinaryArray::try_new(offsets, values, nulls)?)
         }
-        WkbView(typ) => {
-            let mut wkb_view_arr = to_wkb_view(arr)?;
-            wkb_view_arr.data_type = typ;
-            Arc::new(wkb_view_arr)
+        DataType::BinaryView => {
+            let (views, buffers, _) = array.as_binary_view().clone().into_parts();
+            Arc::new(BinaryViewArray::try_new(views, buffers, nulls)?)
         }
-        Wkt(typ) => {
-            let mut wkt_arr = to_wkt::<i32>(arr)?;
-            wkt_arr.data_type = typ;
-            Arc::new(wkt_arr)
+        DataType::Utf8 => {
+            let (offsets, values, _) = array.as_string::<i32>().clone().into_parts();
+            Arc::new(StringArray::try_new(offsets, values, nulls)?)
         }
-        LargeWkt(typ) => {
-            let mut wkt_arr = to_wkt::<i64>(arr)?;
-            wkt_arr.data_type = typ;
-            Arc::new(wkt_arr)
+        DataType::LargeUtf8 => {
+            let (offsets, values, _) = array.as_string::<i64>().clone().into_parts();
+            Arc::new(LargeStringArray::try_new(offsets, values, nulls)?)
         }
-        WktView(typ) => {
-            let mut wkt_view_arr = to_wkt_view(arr)?;
-            wkt_view_arr.data_type = typ;
-            Arc::new(wkt_view_arr)
+        DataType::Utf8View => {
+            let (views, buffers, _) = array.as_string_view().clone().into_parts();
+            Arc::new(StringViewArray::try_new(views, buffers, nulls)?)
+        }
+        dt => {
+            return Err(GeoArrowError::InvalidGeoArrow(format!(
+                "Expected a binary or string array, got {dt}",
+            )));
         }
     };
     Ok(result)
@@ -824,7 +1034,7 @@ macro_rules! downcast_geoarrow_array {
 mod test {
     use std::sync::Arc;
 
-    use geoarrow_schema::{CoordType, Dimension, WkbType};
+    use geoarrow_schema::{CoordType, Dimension, LineStringType, PointType, PolygonType, WkbType};
 
     use super::*;
     use crate::test;
@@ -1191,6 +1401,83 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_from_wkt_multiple_chunks() {
+        let values = (0..2500)
+            .map(|i| (i % 7 != 0).then(|| format!("LINESTRING ({i} 0, {i} 1)")))
+            .collect::<Vec<_>>();
+        let wkt_arr = GenericWktArray::<i32>::new(StringArray::from(values), Default::default());
+        let typ = GeoArrowType::LineString(LineStringType::new(Dimension::XY, Default::default()));
+
+        let arr = from_wkt(&wkt_arr, typ.clone()).unwrap();
+        assert_eq!(arr.len(), 2500);
+        assert_eq!(arr.logical_null_count(), 358);
+
+        let wkb_arr = to_wkb::<i32>(&wkt_arr).unwrap();
+        let arr2 = from_wkb(&wkb_arr, typ).unwrap();
+        assert_eq!(arr.as_line_string(), arr2.as_line_string());
+    }
+
+    #[test]
+    fn test_from_wkt_with_options() {
+        let wkt_arr = GenericWktArray::<i32>::new(
+            StringArray::from(vec![
+                Some("POINT (1 2)"),
+                Some("POINT (1"),
+                None,
+                Some("LINESTRING (0 0, 1 1)"),
+                Some("POINT (3 4)"),
+            ]),
+            Default::default(),
+        );
+        let typ = GeoArrowType::Point(PointType::new(Dimension::XY, Default::default()));
+        assert!(from_wkt(&wkt_arr, typ.clone()).is_err());
+
+        let options = ParseOptions::default().with_invalid(InvalidGeometryHandling::Null);
+        let parsed = from_wkt_with_options(&wkt_arr, typ.clone(), &options).unwrap();
+        assert_eq!(parsed.array.len(), 5);
+        assert_eq!(parsed.array.logical_null_count(), 3);
+        assert!(parsed.side_car.is_none());
+        let invalid_rows = parsed
+            .invalid
+            .iter()
+            .map(|row| row.index)
+            .collect::<Vec<_>>();
+        assert_eq!(invalid_rows, vec![1, 3]);
+
+        let options = ParseOptions::default().with_invalid(InvalidGeometryHandling::SideCar);
+        let parsed = from_wkt_with_options(&wkt_arr, typ, &options).unwrap();
+        let side_car = parsed.side_car.unwrap().to_array_ref();
+        let side_car = side_car.as_string::<i32>();
+        assert_eq!(side_car.len(), 5);
+        assert_eq!(side_car.null_count(), 3);
+        assert_eq!(side_car.value(1), "POINT (1");
+        assert_eq!(side_car.value(3), "LINESTRING (0 0, 1 1)");
+    }
+
+    #[test]
+    fn test_from_wkb_with_options() {
+        let wkb_arr =
+            to_wkb::<i32>(&test::polygon::array(CoordType::Separated, Dimension::XY)).unwrap();
+        let mut values = wkb_arr
+            .to_array_ref()
+            .as_binary::<i32>()
+            .iter()
+            .map(|value| value.map(|v| v.to_vec()))
+            .collect::<Vec<_>>();
+        // Corrupt the geometry type of the first value so that it can't be parsed
+        values[0].as_mut().unwrap()[1] = 99;
+        let wkb_arr = GenericWkbArray::new(BinaryArray::from_iter(values), Default::default());
+
+        let typ = GeoArrowType::Polygon(PolygonType::new(Dimension::XY, Default::default()));
+        let options = ParseOptions::default().with_invalid(InvalidGeometryHandling::Null);
+        let parsed = from_wkb_with_options(&wkb_arr, typ, &options).unwrap();
+        assert_eq!(parsed.invalid.len(), 1);
+        assert_eq!(parsed.invalid[0].index, 0);
+        assert!(parsed.array.is_null(0));
+        assert!(!parsed.array.is_null(1));
+    }
+
     // Verify that this compiles with the macro
     #[allow(dead_code)]
     fn _to_wkb_test_downcast_macro(
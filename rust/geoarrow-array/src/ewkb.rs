@@ -0,0 +1,538 @@
+//! Read and write Extended WKB (EWKB).
+//!
+//! EWKB is the WKB dialect used by PostGIS. It differs from ISO WKB in two ways:
+//!
+//! - Z and M dimensions are signalled by the high bits `0x80000000` and `0x40000000` of the
+//!   geometry type, instead of by adding 1000, 2000 or 3000 to it.
+//! - The outermost geometry may carry an SRID, signalled by the bit `0x20000000` and stored as a
+//!   4-byte integer directly after the geometry type.
+//!
+//! GeoArrow WKB arrays always store ISO WKB and keep the CRS in the field metadata. Use
+//! [`from_ewkb`] to import EWKB, moving the SRID into the array's [`Crs`], and
+//! [`to_wkb_with_options`] to export EWKB as a plain binary array.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::builder::GenericBinaryBuilder;
+use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{Crs, Metadata};
+use wkb::Endianness;
+use wkb::writer::write_geometry;
+
+use crate::array::GenericWkbArray;
+use crate::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// The dialect to use for the geometry type codes when writing WKB.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WkbFlavor {
+    /// ISO WKB, where Z and M dimensions add 1000, 2000 or 3000 to the geometry type.
+    ///
+    /// ISO WKB cannot store an SRID.
+    #[default]
+    Iso,
+
+    /// Extended WKB as used by PostGIS, where Z and M dimensions are signalled by flag bits.
+    Extended {
+        /// The SRID to write into each geometry.
+        ///
+        /// If `None`, the SRID is taken from the array's [`Crs`] when [`Crs::srid`] can
+        /// determine one. Otherwise no SRID is written.
+        srid: Option<i32>,
+    },
+}
+
+/// Options for writing WKB.
+#[derive(Debug, Clone, Copy)]
+pub struct WkbWriteOptions {
+    /// The byte order of the output.
+    pub endianness: Endianness,
+
+    /// Whether to write ISO WKB or EWKB.
+    pub flavor: WkbFlavor,
+}
+
+impl WkbWriteOptions {
+    /// Set the byte order of the output.
+    pub fn with_endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
+    /// Set the WKB dialect of the output.
+    pub fn with_flavor(mut self, flavor: WkbFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Resolve a missing EWKB SRID from the provided CRS.
+    pub(crate) fn resolve_srid(mut self, crs: &Crs) -> Self {
+        if let WkbFlavor::Extended { srid: None } = self.flavor {
+            self.flavor = WkbFlavor::Extended { srid: crs.srid() };
+        }
+        self
+    }
+
+    pub(crate) fn wkb_options(&self) -> wkb::writer::WriteOptions {
+        wkb::writer::WriteOptions {
+            endianness: self.endianness,
+        }
+    }
+}
+
+impl Default for WkbWriteOptions {
+    fn default() -> Self {
+        Self {
+            endianness: Endianness::LittleEndian,
+            flavor: WkbFlavor::Iso,
+        }
+    }
+}
+
+/// Options for reading EWKB.
+#[derive(Debug, Clone, Default)]
+pub struct EwkbReadOptions {
+    /// The authority to interpret the SRID against, such as `"EPSG"`.
+    ///
+    /// If set, the SRID is stored as a [`Crs::from_authority_code`] of `"<authority>:<srid>"`.
+    /// Otherwise it is stored as an opaque [`Crs::from_srid`].
+    pub authority: Option<String>,
+}
+
+impl EwkbReadOptions {
+    /// Set the authority to interpret the SRID against.
+    pub fn with_authority(mut self, authority: impl Into<String>) -> Self {
+        self.authority = Some(authority.into());
+        self
+    }
+
+    fn crs(&self, srid: i32) -> Crs {
+        match &self.authority {
+            Some(authority) => Crs::from_authority_code(format!("{authority}:{srid}")),
+            None => Crs::from_srid(srid.to_string()),
+        }
+    }
+}
+
+/// Import a binary array of EWKB (or ISO WKB) geometries as a [`GenericWkbArray`].
+///
+/// Each geometry is rewritten to ISO WKB, keeping its byte order. An SRID of `0` is treated as
+/// unknown, as in PostGIS. All other SRIDs must agree across rows, and must agree with the SRID
+/// of the CRS in `metadata` if it has one.
+///
+/// If `metadata` has no CRS, the SRID is stored on the returned array according to `options`. If
+/// `metadata` already has a CRS that doesn't conflict with the SRID, that CRS is kept.
+pub fn from_ewkb<O: OffsetSizeTrait>(
+    array: &GenericBinaryArray<O>,
+    metadata: Arc<Metadata>,
+    options: &EwkbReadOptions,
+) -> GeoArrowResult<GenericWkbArray<O>> {
+    let mut builder = GenericBinaryBuilder::<O>::with_capacity(array.len(), array.values().len());
+    let mut srid: Option<(usize, i32)> = None;
+
+    for (row, value) in array.iter().enumerate() {
+        let Some(value) = value else {
+            builder.append_null();
+            continue;
+        };
+
+        let row_srid = convert_wkb(value, &mut builder, WkbFlavor::Iso)?;
+        builder.append_value("");
+
+        match (srid, row_srid.filter(|s| *s != 0)) {
+            (None, Some(row_srid)) => srid = Some((row, row_srid)),
+            (Some((first_row, first_srid)), Some(row_srid)) if first_srid != row_srid => {
+                return Err(GeoArrowError::Wkb(format!(
+                    "Mixed SRIDs in EWKB array: row {first_row} has SRID {first_srid} but row {row} has SRID {row_srid}"
+                )));
+            }
+            _ => {}
+        }
+    }
+
+    let metadata = match srid {
+        None => metadata,
+        Some((_, srid)) if metadata.crs() == &Crs::default() => {
+            Arc::new(Metadata::new(options.crs(srid), metadata.edges()))
+        }
+        Some((_, srid)) => match metadata.crs().srid() {
+            Some(existing) if existing != srid => {
+                return Err(GeoArrowError::Crs(format!(
+                    "EWKB SRID {srid} does not match the SRID {existing} of the array's CRS"
+                )));
+            }
+            _ => metadata,
+        },
+    };
+
+    Ok(GenericWkbArray::new(builder.finish(), metadata))
+}
+
+/// Serialize any GeoArrow array to WKB with the provided [`WkbWriteOptions`].
+///
+/// This is the counterpart of [`to_wkb`][crate::cast::to_wkb] that allows choosing the byte order
+/// and writing EWKB. The output is a plain binary array without GeoArrow metadata, because a
+/// GeoArrow WKB array can't hold EWKB.
+pub fn to_wkb_with_options<O: OffsetSizeTrait>(
+    arr: &dyn GeoArrowArray,
+    options: WkbWriteOptions,
+) -> GeoArrowResult<GenericBinaryArray<O>> {
+    downcast_geoarrow_array!(arr, impl_to_wkb_with_options, options)
+}
+
+fn impl_to_wkb_with_options<'a, O: OffsetSizeTrait>(
+    geo_arr: &'a impl GeoArrowArrayAccessor<'a>,
+    options: WkbWriteOptions,
+) -> GeoArrowResult<GenericBinaryArray<O>> {
+    let options = options.resolve_srid(geo_arr.data_type().metadata().crs());
+    let wkb_options = options.wkb_options();
+    let mut builder = GenericBinaryBuilder::<O>::with_capacity(geo_arr.len(), 0);
+    // ISO WKB is written here first before being rewritten as EWKB
+    let mut scratch = Vec::new();
+
+    for geom in geo_arr.iter() {
+        let Some(geom) = geom.transpose()? else {
+            builder.append_null();
+            continue;
+        };
+
+        match options.flavor {
+            WkbFlavor::Iso => write_geometry(&mut builder, &geom, &wkb_options)
+                .map_err(|err| GeoArrowError::Wkb(err.to_string()))?,
+            flavor @ WkbFlavor::Extended { .. } => {
+                scratch.clear();
+                write_geometry(&mut scratch, &geom, &wkb_options)
+                    .map_err(|err| GeoArrowError::Wkb(err.to_string()))?;
+                convert_wkb(&scratch, &mut builder, flavor)?;
+            }
+        }
+        builder.append_value("");
+    }
+
+    Ok(builder.finish())
+}
+
+/// Rewrite a single WKB or EWKB geometry into the given flavor, returning the SRID of the input,
+/// if any.
+///
+/// The output keeps the byte order of the input. Only the outermost geometry carries an SRID.
+pub(crate) fn convert_wkb(
+    buf: &[u8],
+    out: &mut impl Write,
+    flavor: WkbFlavor,
+) -> GeoArrowResult<Option<i32>> {
+    let mut reader = Reader { buf, pos: 0 };
+    let srid = match flavor {
+        WkbFlavor::Iso => None,
+        WkbFlavor::Extended { srid } => srid,
+    };
+    let header = rewrite_geometry(&mut reader, out, flavor, srid)?;
+    Ok(header.srid)
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> GeoArrowResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(|| {
+                GeoArrowError::Wkb(format!(
+                    "Unexpected end of WKB buffer at byte {} of {}",
+                    self.pos,
+                    self.buf.len()
+                ))
+            })?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> GeoArrowResult<u32> {
+        let bytes: [u8; 4] = self.take(4)?.try_into().unwrap();
+        if little_endian {
+            Ok(u32::from_le_bytes(bytes))
+        } else {
+            Ok(u32::from_be_bytes(bytes))
+        }
+    }
+}
+
+struct Header {
+    little_endian: bool,
+    geometry_type: u32,
+    has_z: bool,
+    has_m: bool,
+    srid: Option<i32>,
+}
+
+impl Header {
+    fn read(reader: &mut Reader) -> GeoArrowResult<Self> {
+        let little_endian = match reader.take(1)?[0] {
+            0 => false,
+            1 => true,
+            other => {
+                return Err(GeoArrowError::Wkb(format!(
+                    "Invalid WKB byte order {other}"
+                )));
+            }
+        };
+        let code = reader.read_u32(little_endian)?;
+        let iso_code = code & !(EWKB_Z_FLAG | EWKB_M_FLAG | EWKB_SRID_FLAG);
+        let geometry_type = iso_code % 1000;
+        let iso_dim = iso_code / 1000;
+        if !(1..=7).contains(&geometry_type) || iso_dim > 3 {
+            return Err(GeoArrowError::Wkb(format!(
+                "Unsupported WKB geometry type {code:#x}"
+            )));
+        }
+
+        let srid = if code & EWKB_SRID_FLAG != 0 {
+            Some(reader.read_u32(little_endian)? as i32)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            little_endian,
+            geometry_type,
+            has_z: code & EWKB_Z_FLAG != 0 || iso_dim == 1 || iso_dim == 3,
+            has_m: code & EWKB_M_FLAG != 0 || iso_dim == 2 || iso_dim == 3,
+            srid,
+        })
+    }
+
+    fn write(
+        &self,
+        out: &mut impl Write,
+        flavor: WkbFlavor,
+        srid: Option<i32>,
+    ) -> GeoArrowResult<()> {
+        let code = match flavor {
+            WkbFlavor::Iso => {
+                let dim = match (self.has_z, self.has_m) {
+                    (false, false) => 0,
+                    (true, false) => 1,
+                    (false, true) => 2,
+                    (true, true) => 3,
+                };
+                self.geometry_type + dim * 1000
+            }
+            WkbFlavor::Extended { .. } => {
+                let mut code = self.geometry_type;
+                if self.has_z {
+                    code |= EWKB_Z_FLAG;
+                }
+                if self.has_m {
+                    code |= EWKB_M_FLAG;
+                }
+                if srid.is_some() {
+                    code |= EWKB_SRID_FLAG;
+                }
+                code
+            }
+        };
+
+        out.write_all(&[self.little_endian as u8])?;
+        self.write_u32(out, code)?;
+        if let Some(srid) = srid {
+            self.write_u32(out, srid as u32)?;
+        }
+        Ok(())
+    }
+
+    fn write_u32(&self, out: &mut (impl Write + ?Sized), value: u32) -> GeoArrowResult<()> {
+        if self.little_endian {
+            out.write_all(&value.to_le_bytes())?;
+        } else {
+            out.write_all(&value.to_be_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn coord_size(&self) -> usize {
+        8 * (2 + self.has_z as usize + self.has_m as usize)
+    }
+}
+
+fn rewrite_geometry(
+    reader: &mut Reader,
+    out: &mut impl Write,
+    flavor: WkbFlavor,
+    srid: Option<i32>,
+) -> GeoArrowResult<Header> {
+    let header = Header::read(reader)?;
+    header.write(out, flavor, srid)?;
+
+    // Counts are copied verbatim, so they keep the byte order of the input.
+    let mut copy_count = |reader: &mut Reader, out: &mut dyn Write| -> GeoArrowResult<usize> {
+        let count = reader.read_u32(header.little_endian)?;
+        header.write_u32(out, count)?;
+        Ok(count as usize)
+    };
+
+    let coord_size = header.coord_size();
+    match header.geometry_type {
+        1 => out.write_all(reader.take(coord_size)?)?,
+        2 => {
+            let num_coords = copy_count(reader, out)?;
+            out.write_all(reader.take(num_coords.saturating_mul(coord_size))?)?;
+        }
+        3 => {
+            let num_rings = copy_count(reader, out)?;
+            for _ in 0..num_rings {
+                let num_coords = copy_count(reader, out)?;
+                out.write_all(reader.take(num_coords.saturating_mul(coord_size))?)?;
+            }
+        }
+        _ => {
+            let num_geometries = copy_count(reader, out)?;
+            for _ in 0..num_geometries {
+                rewrite_geometry(reader, out, flavor, None)?;
+            }
+        }
+    }
+
+    Ok(header)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::BinaryArray;
+    use geo_traits::to_geo::ToGeoGeometry;
+    use geoarrow_schema::{CoordType, Dimension};
+
+    use super::*;
+    use crate::test::point;
+
+    fn ewkb_point(srid: Option<i32>, little_endian: bool) -> Vec<u8> {
+        let write_u32 = |buf: &mut Vec<u8>, v: u32| {
+            if little_endian {
+                buf.extend_from_slice(&v.to_le_bytes())
+            } else {
+                buf.extend_from_slice(&v.to_be_bytes())
+            }
+        };
+        let write_f64 = |buf: &mut Vec<u8>, v: f64| {
+            if little_endian {
+                buf.extend_from_slice(&v.to_le_bytes())
+            } else {
+                buf.extend_from_slice(&v.to_be_bytes())
+            }
+        };
+
+        let mut buf = vec![little_endian as u8];
+        // PointZ
+        let mut code = 1 | EWKB_Z_FLAG;
+        if srid.is_some() {
+            code |= EWKB_SRID_FLAG;
+        }
+        write_u32(&mut buf, code);
+        if let Some(srid) = srid {
+            write_u32(&mut buf, srid as u32);
+        }
+        write_f64(&mut buf, 1.0);
+        write_f64(&mut buf, 2.0);
+        write_f64(&mut buf, 3.0);
+        buf
+    }
+
+    #[test]
+    fn test_from_ewkb() {
+        let rows = vec![
+            Some(ewkb_point(Some(4326), true)),
+            None,
+            Some(ewkb_point(None, false)),
+            Some(ewkb_point(Some(4326), false)),
+        ];
+        let binary = BinaryArray::from_iter(rows.iter().map(|x| x.as_deref()));
+        let options = EwkbReadOptions::default().with_authority("EPSG");
+        let wkb_arr = from_ewkb(&binary, Default::default(), &options).unwrap();
+
+        assert_eq!(
+            wkb_arr.data_type().metadata().crs(),
+            &Crs::from_authority_code("EPSG:4326".to_string())
+        );
+        assert!(wkb_arr.is_null(1));
+        for i in [0, 2, 3] {
+            // PointZ in ISO WKB
+            let value = wkb_arr.array.value(i);
+            let code = if value[0] == 1 {
+                u32::from_le_bytes(value[1..5].try_into().unwrap())
+            } else {
+                u32::from_be_bytes(value[1..5].try_into().unwrap())
+            };
+            assert_eq!(code, 1001);
+            assert_eq!(value.len(), 1 + 4 + 24);
+            let geom = wkb_arr.value(i).unwrap().to_geometry();
+            assert_eq!(geom, geo::Point::new(1.0, 2.0).into());
+        }
+    }
+
+    #[test]
+    fn test_from_ewkb_mixed_srid() {
+        let rows = [ewkb_point(Some(4326), true), ewkb_point(Some(3857), true)];
+        let binary = BinaryArray::from_iter_values(rows.iter());
+        let err = from_ewkb(&binary, Default::default(), &Default::default()).unwrap_err();
+        assert!(err.to_string().contains("Mixed SRIDs"));
+    }
+
+    #[test]
+    fn test_from_ewkb_crs_mismatch() {
+        let binary = BinaryArray::from_iter_values([ewkb_point(Some(4326), true)]);
+        let metadata = Arc::new(Metadata::new(
+            Crs::from_srid("3857".to_string()),
+            Default::default(),
+        ));
+        assert!(from_ewkb(&binary, metadata, &Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_ewkb_round_trip() {
+        let metadata = Arc::new(Metadata::new(
+            Crs::from_srid("4326".to_string()),
+            Default::default(),
+        ));
+        let arr =
+            point::array(CoordType::Interleaved, Dimension::XYZ).with_metadata(metadata.clone());
+
+        let options = WkbWriteOptions::default()
+            .with_endianness(Endianness::BigEndian)
+            .with_flavor(WkbFlavor::Extended { srid: None });
+        let ewkb = to_wkb_with_options::<i32>(&arr, options).unwrap();
+        let first = ewkb.value(0);
+        assert_eq!(first[0], 0);
+        assert_eq!(
+            u32::from_be_bytes(first[1..5].try_into().unwrap()),
+            1 | EWKB_Z_FLAG | EWKB_SRID_FLAG
+        );
+        assert_eq!(u32::from_be_bytes(first[5..9].try_into().unwrap()), 4326);
+
+        let round_trip = from_ewkb(
+            &ewkb,
+            Arc::new(Metadata::new(Crs::default(), Default::default())),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_eq!(round_trip.data_type().metadata(), &metadata);
+
+        // The ISO output of the import matches the default writer byte for byte, apart from
+        // the byte order.
+        let iso = to_wkb_with_options::<i32>(
+            &arr,
+            WkbWriteOptions::default().with_endianness(Endianness::BigEndian),
+        )
+        .unwrap();
+        assert_eq!(round_trip.array, iso);
+    }
+}
@@ -3,10 +3,12 @@
 #![cfg_attr(not(test), deny(unused_crate_dependencies))]
 
 pub mod array;
+pub mod bounds;
 pub mod builder;
 pub mod capacity;
 pub mod cast;
 mod eq;
+pub mod ewkb;
 #[cfg(feature = "geozero")]
 pub mod geozero;
 pub mod scalar;
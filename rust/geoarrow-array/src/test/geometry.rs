@@ -1,4 +1,7 @@
-use geoarrow_schema::{CoordType, GeometryType};
+use std::sync::Arc;
+
+use geo_types::Geometry;
+use geoarrow_schema::{CoordType, GeometryType, Metadata};
 use geoarrow_test::raw;
 
 use crate::array::GeometryArray;
@@ -10,3 +13,10 @@ pub fn array(coord_type: CoordType, _prefer_multi: bool) -> GeometryArray {
         .unwrap()
         .finish()
 }
+
+/// A [`GeometryArray`] of the provided geometries.
+pub fn from_geoms(geoms: &[Option<Geometry>], metadata: Arc<Metadata>) -> GeometryArray {
+    GeometryBuilder::from_nullable_geometries(geoms, GeometryType::new(metadata))
+        .unwrap()
+        .finish()
+}
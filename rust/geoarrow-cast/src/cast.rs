@@ -12,7 +12,8 @@ use geoarrow_array::builder::{
 };
 use geoarrow_array::capacity::{LineStringCapacity, PolygonCapacity};
 use geoarrow_array::cast::{
-    AsGeoArrowArray, from_wkb, from_wkt, to_wkb, to_wkb_view, to_wkt, to_wkt_view,
+    AsGeoArrowArray, ParseOptions, ParsedArray, from_wkb, from_wkb_with_options, from_wkt,
+    from_wkt_with_options, to_wkb, to_wkb_view, to_wkt, to_wkt_view,
 };
 use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor};
 use geoarrow_schema::GeoArrowType;
@@ -57,26 +58,7 @@ pub fn cast(
     array: &dyn GeoArrowArray,
     to_type: &GeoArrowType,
 ) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
-    // We want to error if the dimensions aren't compatible, but allow conversions to
-    // `GeometryArray`, `WKB`, etc where the target array isn't parameterized by a specific
-    // dimension.
-    if let (Some(from_dim), Some(to_dim)) = (array.data_type().dimension(), to_type.dimension()) {
-        if from_dim != to_dim {
-            return Err(ArrowError::CastError(format!(
-                "Cannot cast from {from_dim:?} to {to_dim:?}: incompatible dimensions",
-            ))
-            .into());
-        }
-    }
-
-    if array.data_type().metadata() != to_type.metadata() {
-        return Err(ArrowError::CastError(format!(
-            "Cannot cast from {:?} to {:?}: incompatible metadata",
-            array.data_type().metadata(),
-            to_type.metadata(),
-        ))
-        .into());
-    }
+    check_compatible(&array.data_type(), to_type)?;
 
     use GeoArrowType::*;
     let out: Arc<dyn GeoArrowArray> = match (array.data_type(), to_type) {
@@ -276,6 +258,60 @@ pub fn cast(
     Ok(out)
 }
 
+/// Cast a [`GeoArrowArray`] to another [`GeoArrowType`], with control over how WKB or WKT values
+/// that can't be parsed are handled.
+///
+/// The same criteria as in [`cast`] apply. The [`ParseOptions`] only affect casts from WKB and
+/// WKT arrays; other casts never report invalid rows.
+pub fn cast_with_options(
+    array: &dyn GeoArrowArray,
+    to_type: &GeoArrowType,
+    options: &ParseOptions,
+) -> GeoArrowResult<ParsedArray> {
+    check_compatible(&array.data_type(), to_type)?;
+
+    use GeoArrowType::*;
+    let to_type = to_type.clone();
+    match array.data_type() {
+        Wkb(_) => from_wkb_with_options(array.as_wkb::<i32>(), to_type, options),
+        LargeWkb(_) => from_wkb_with_options(array.as_wkb::<i64>(), to_type, options),
+        WkbView(_) => from_wkb_with_options(array.as_wkb_view(), to_type, options),
+        Wkt(_) => from_wkt_with_options(array.as_wkt::<i32>(), to_type, options),
+        LargeWkt(_) => from_wkt_with_options(array.as_wkt::<i64>(), to_type, options),
+        WktView(_) => from_wkt_with_options(array.as_wkt_view(), to_type, options),
+        _ => Ok(ParsedArray {
+            array: cast(array, &to_type)?,
+            side_car: None,
+            invalid: vec![],
+        }),
+    }
+}
+
+fn check_compatible(from_type: &GeoArrowType, to_type: &GeoArrowType) -> GeoArrowResult<()> {
+    // We want to error if the dimensions aren't compatible, but allow conversions to
+    // `GeometryArray`, `WKB`, etc where the target array isn't parameterized by a specific
+    // dimension.
+    if let (Some(from_dim), Some(to_dim)) = (from_type.dimension(), to_type.dimension()) {
+        if from_dim != to_dim {
+            return Err(ArrowError::CastError(format!(
+                "Cannot cast from {from_dim:?} to {to_dim:?}: incompatible dimensions",
+            ))
+            .into());
+        }
+    }
+
+    if from_type.metadata() != to_type.metadata() {
+        return Err(ArrowError::CastError(format!(
+            "Cannot cast from {:?} to {:?}: incompatible metadata",
+            from_type.metadata(),
+            to_type.metadata(),
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use geoarrow_array::builder::MultiPointBuilder;
@@ -0,0 +1,10 @@
+//! Read from and write to CSV files with geometries.
+//!
+//! Geometries are read from a WKT column, a column of hex-encoded WKB or a pair of x/y coordinate
+//! columns, and are written as WKT.
+
+#![warn(missing_docs)]
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+pub mod reader;
+pub mod writer;
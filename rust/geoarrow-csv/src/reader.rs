@@ -0,0 +1,587 @@
+//! Read CSV files into record batches with a GeoArrow geometry column.
+
+use std::io::{Read, Seek};
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{BinaryArray, RecordBatch, RecordBatchReader, StringArray};
+use arrow_csv::ReaderBuilder;
+use arrow_csv::reader::Format;
+use arrow_schema::{ArrowError, DataType, Schema, SchemaRef};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::{WkbArray, WktArray};
+use geoarrow_array::builder::PointBuilder;
+use geoarrow_array::cast::{from_wkb, from_wkt};
+use geoarrow_array::ewkb::from_ewkb;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{CoordType, Crs, Dimension, GeoArrowType, GeometryType, Metadata, PointType};
+
+/// Column names that are recognized as a WKT or hex-encoded WKB geometry column, compared
+/// case-insensitively.
+const GEOMETRY_COLUMN_NAMES: [&str; 5] = ["geometry", "geom", "the_geom", "wkt", "wkb"];
+
+/// Pairs of column names that are recognized as x/y coordinate columns, compared
+/// case-insensitively.
+const XY_COLUMN_NAMES: [(&str, &str); 5] = [
+    ("x", "y"),
+    ("lon", "lat"),
+    ("lng", "lat"),
+    ("long", "lat"),
+    ("longitude", "latitude"),
+];
+
+/// Where the geometries of a CSV file are stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeometrySource {
+    /// A column of WKT strings.
+    Wkt(String),
+
+    /// A column of hex-encoded WKB, as written by PostGIS.
+    ///
+    /// An optional `\x` or `0x` prefix is allowed, and EWKB is accepted as well.
+    WkbHex(String),
+
+    /// A pair of numeric columns holding the x and y coordinates of points.
+    ///
+    /// The two columns are replaced by a single point column named `"geometry"`.
+    XY {
+        /// The name of the x (longitude) column
+        x: String,
+        /// The name of the y (latitude) column
+        y: String,
+    },
+}
+
+/// Options for the CSV reader.
+#[derive(Debug, Clone)]
+pub struct CsvReaderOptions {
+    /// The GeoArrow coordinate type to use in the geometry arrays.
+    pub coord_type: CoordType,
+
+    /// The number of rows in each batch.
+    pub batch_size: usize,
+
+    /// Where to read geometries from.
+    ///
+    /// When `None`, the geometry source is detected from the schema: a string column named one of
+    /// `geometry`, `geom`, `the_geom`, `wkt` or `wkb` is used first, with WKT and hex-encoded WKB
+    /// told apart by the first non-null value. Otherwise a pair of numeric `x`/`y`, `lon`/`lat`,
+    /// `lng`/`lat`, `long`/`lat` or `longitude`/`latitude` columns is used.
+    pub geometry_source: Option<GeometrySource>,
+
+    /// The coordinate reference system of the geometries.
+    ///
+    /// CSV files carry no CRS information, so this is written to the metadata of the geometry
+    /// column as given. EWKB SRIDs must agree with this CRS.
+    pub crs: Option<Crs>,
+
+    /// Specify whether the CSV file has a header, defaults to `true`
+    ///
+    /// When `true`, the first row of the CSV file is treated as a header row
+    pub has_header: Option<bool>,
+
+    /// The maximum number of records to read for schema inference.
+    ///
+    /// See [`arrow_csv::reader::Format::infer_schema`].
+    ///
+    /// **By default, all rows are read to infer the CSV schema.**
+    pub max_records: Option<usize>,
+
+    /// Specify a custom delimiter character, defaults to comma `','`
+    pub delimiter: Option<char>,
+
+    /// Specify an escape character, defaults to `None`
+    pub escape: Option<char>,
+
+    /// Specify a custom quote character, defaults to double quote `'"'`
+    pub quote: Option<char>,
+
+    /// Specify a custom terminator character, defaults to CRLF
+    pub terminator: Option<char>,
+
+    /// Specify a comment character, defaults to `None`
+    ///
+    /// Lines starting with this character will be ignored
+    pub comment: Option<char>,
+}
+
+impl CsvReaderOptions {
+    /// Set where to read geometries from.
+    pub fn with_geometry_source(mut self, geometry_source: GeometrySource) -> Self {
+        self.geometry_source = Some(geometry_source);
+        self
+    }
+
+    /// Set the coordinate reference system of the geometries.
+    pub fn with_crs(mut self, crs: Crs) -> Self {
+        self.crs = Some(crs);
+        self
+    }
+
+    /// Set the GeoArrow coordinate type to use in the geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    fn to_format(&self) -> Format {
+        // Default to having a header
+        let mut format = Format::default().with_header(true);
+
+        if let Some(has_header) = self.has_header {
+            format = format.with_header(has_header);
+        }
+        if let Some(delimiter) = self.delimiter {
+            format = format.with_delimiter(delimiter as u8);
+        }
+        if let Some(escape) = self.escape {
+            format = format.with_escape(escape as u8);
+        }
+        if let Some(quote) = self.quote {
+            format = format.with_quote(quote as u8);
+        }
+        if let Some(terminator) = self.terminator {
+            format = format.with_terminator(terminator as u8);
+        }
+        if let Some(comment) = self.comment {
+            format = format.with_comment(comment as u8);
+        }
+
+        format
+    }
+
+    fn metadata(&self) -> Arc<Metadata> {
+        Arc::new(Metadata::new(self.crs.clone().unwrap_or_default(), None))
+    }
+}
+
+impl Default for CsvReaderOptions {
+    fn default() -> Self {
+        Self {
+            coord_type: Default::default(),
+            batch_size: 65_536,
+            geometry_source: Default::default(),
+            crs: Default::default(),
+            has_header: Default::default(),
+            max_records: Default::default(),
+            delimiter: Default::default(),
+            escape: Default::default(),
+            quote: Default::default(),
+            terminator: Default::default(),
+            comment: Default::default(),
+        }
+    }
+}
+
+/// The encoding of a string geometry column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextEncoding {
+    Wkt,
+    WkbHex,
+}
+
+/// How to build the geometry column of each batch.
+#[derive(Debug, Clone)]
+enum GeometryParser {
+    /// A string column. An encoding of `None` is detected from the first non-null value.
+    Text {
+        index: usize,
+        encoding: Option<TextEncoding>,
+        geometry_type: GeoArrowType,
+    },
+    XY {
+        x: usize,
+        y: usize,
+        point_type: PointType,
+    },
+}
+
+impl GeometryParser {
+    fn try_new(schema: &Schema, options: &CsvReaderOptions) -> GeoArrowResult<Self> {
+        // A geometry column found by name has its encoding detected from the data
+        let (source, detect_encoding) = match &options.geometry_source {
+            Some(source) => (source.clone(), false),
+            None => (detect_geometry_source(schema)?, true),
+        };
+        let text_encoding = |encoding: TextEncoding| (!detect_encoding).then_some(encoding);
+
+        let metadata = options.metadata();
+        let geometry_type = GeoArrowType::Geometry(
+            GeometryType::new(metadata.clone()).with_coord_type(options.coord_type),
+        );
+        let parser = match source {
+            GeometrySource::Wkt(name) => Self::Text {
+                index: schema.index_of(&name)?,
+                encoding: text_encoding(TextEncoding::Wkt),
+                geometry_type,
+            },
+            GeometrySource::WkbHex(name) => Self::Text {
+                index: schema.index_of(&name)?,
+                encoding: text_encoding(TextEncoding::WkbHex),
+                geometry_type,
+            },
+            GeometrySource::XY { x, y } => Self::XY {
+                x: schema.index_of(&x)?,
+                y: schema.index_of(&y)?,
+                point_type: PointType::new(Dimension::XY, metadata)
+                    .with_coord_type(options.coord_type),
+            },
+        };
+        Ok(parser)
+    }
+
+    /// The schema to pass to the underlying `arrow_csv` reader, forcing the type of the geometry
+    /// source columns.
+    fn input_schema(&self, schema: &Schema) -> SchemaRef {
+        let mut fields = schema.fields().to_vec();
+        let mut set_type = |idx: usize, data_type: DataType| {
+            fields[idx] = Arc::new(fields[idx].as_ref().clone().with_data_type(data_type));
+        };
+        match self {
+            Self::Text { index, .. } => set_type(*index, DataType::Utf8),
+            Self::XY { x, y, .. } => {
+                set_type(*x, DataType::Float64);
+                set_type(*y, DataType::Float64);
+            }
+        }
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+    }
+
+    fn output_schema(&self, schema: &Schema) -> SchemaRef {
+        let mut fields = schema.fields().to_vec();
+        match self {
+            Self::Text {
+                index,
+                geometry_type,
+                ..
+            } => {
+                fields[*index] = Arc::new(geometry_type.to_field(fields[*index].name(), true));
+            }
+            Self::XY { x, y, point_type } => {
+                fields[*x] = Arc::new(point_type.to_field("geometry", true));
+                fields.remove(*y);
+            }
+        }
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+    }
+
+    fn parse(
+        &mut self,
+        batch: RecordBatch,
+        output_schema: SchemaRef,
+    ) -> GeoArrowResult<RecordBatch> {
+        let mut columns = batch.columns().to_vec();
+        match self {
+            Self::Text {
+                index,
+                encoding,
+                geometry_type,
+            } => {
+                let strings = columns[*index].as_string::<i32>().clone();
+                let encoding = *encoding.get_or_insert_with(|| detect_encoding(&strings));
+                let geometry = match encoding {
+                    TextEncoding::Wkt => {
+                        let wkt = WktArray::new(strings, geometry_type.metadata().clone());
+                        from_wkt(&wkt, geometry_type.clone())?
+                    }
+                    TextEncoding::WkbHex => {
+                        let binary = decode_hex_column(&strings)?;
+                        let wkb: WkbArray = from_ewkb(
+                            &binary,
+                            geometry_type.metadata().clone(),
+                            &Default::default(),
+                        )?;
+                        from_wkb(&wkb, geometry_type.clone())?
+                    }
+                };
+                columns[*index] = geometry.to_array_ref();
+            }
+            Self::XY { x, y, point_type } => {
+                let xs = columns[*x].as_primitive::<Float64Type>();
+                let ys = columns[*y].as_primitive::<Float64Type>();
+                let mut builder = PointBuilder::with_capacity(point_type.clone(), batch.num_rows());
+                for (x, y) in xs.iter().zip(ys.iter()) {
+                    match (x, y) {
+                        (Some(x), Some(y)) => builder.push_coord(Some(&(x, y))),
+                        _ => builder.push_null(),
+                    }
+                }
+                columns[*x] = builder.finish().to_array_ref();
+                columns.remove(*y);
+            }
+        }
+        Ok(RecordBatch::try_new(output_schema, columns)?)
+    }
+}
+
+/// Find the geometry column(s) of a CSV file from its inferred schema.
+fn detect_geometry_source(schema: &Schema) -> GeoArrowResult<GeometrySource> {
+    let find_field = |name: &str, numeric: bool| {
+        schema.fields().iter().find(|field| {
+            field.name().eq_ignore_ascii_case(name)
+                && if numeric {
+                    field.data_type().is_numeric()
+                } else {
+                    field.data_type() == &DataType::Utf8
+                }
+        })
+    };
+
+    for name in GEOMETRY_COLUMN_NAMES {
+        if let Some(field) = find_field(name, false) {
+            // The encoding is detected from the data
+            return Ok(GeometrySource::Wkt(field.name().clone()));
+        }
+    }
+
+    for (x, y) in XY_COLUMN_NAMES {
+        if let (Some(x), Some(y)) = (find_field(x, true), find_field(y, true)) {
+            return Ok(GeometrySource::XY {
+                x: x.name().clone(),
+                y: y.name().clone(),
+            });
+        }
+    }
+
+    Err(GeoArrowError::InvalidGeoArrow(
+        "Could not find a WKT, hex-encoded WKB or x/y coordinate column in the CSV file"
+            .to_string(),
+    ))
+}
+
+/// Tell WKT and hex-encoded WKB apart from the first non-null value.
+fn detect_encoding(strings: &StringArray) -> TextEncoding {
+    match strings.iter().flatten().next() {
+        Some(value) if is_hex_wkb(value) => TextEncoding::WkbHex,
+        _ => TextEncoding::Wkt,
+    }
+}
+
+fn strip_hex_prefix(value: &str) -> &str {
+    let value = value.trim();
+    value
+        .strip_prefix("\\x")
+        .or_else(|| value.strip_prefix("0x"))
+        .unwrap_or(value)
+}
+
+fn is_hex_wkb(value: &str) -> bool {
+    let value = strip_hex_prefix(value);
+    // A WKB geometry starts with a byte order marker of 00 or 01 followed by a 4-byte type
+    value.len() >= 10
+        && value.len() % 2 == 0
+        && (value.starts_with("00") || value.starts_with("01"))
+        && value.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn decode_hex_column(strings: &StringArray) -> GeoArrowResult<BinaryArray> {
+    let mut buf = vec![];
+    strings
+        .iter()
+        .map(|value| {
+            value
+                .map(|value| {
+                    buf.clear();
+                    decode_hex(value, &mut buf)?;
+                    Ok(buf.clone())
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+fn decode_hex(value: &str, out: &mut Vec<u8>) -> GeoArrowResult<()> {
+    fn nibble(b: u8) -> Option<u8> {
+        match b {
+            b'0'..=b'9' => Some(b - b'0'),
+            b'a'..=b'f' => Some(b - b'a' + 10),
+            b'A'..=b'F' => Some(b - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let value = strip_hex_prefix(value);
+    let invalid = || GeoArrowError::Wkb(format!("Invalid hex-encoded WKB: '{value}'"));
+    if value.len() % 2 != 0 {
+        return Err(invalid());
+    }
+    for pair in value.as_bytes().chunks_exact(2) {
+        let high = nibble(pair[0]).ok_or_else(invalid)?;
+        let low = nibble(pair[1]).ok_or_else(invalid)?;
+        out.push((high << 4) | low);
+    }
+    Ok(())
+}
+
+/// A CSV reader that parses a WKT, hex-encoded WKB or x/y geometry source into a GeoArrow
+/// geometry column.
+pub struct CsvReader<R> {
+    reader: arrow_csv::Reader<R>,
+    output_schema: SchemaRef,
+    parser: GeometryParser,
+}
+
+impl<R> CsvReader<R> {
+    /// Access the schema of this reader
+    pub fn schema(&self) -> SchemaRef {
+        self.output_schema.clone()
+    }
+}
+
+impl<R: Read + Seek> CsvReader<R> {
+    /// Create a new CSV reader, automatically inferring a CSV file's schema.
+    ///
+    /// By default, the reader will **scan the entire CSV file** to infer the data's
+    /// schema. If your data is large, you can limit the number of records scanned
+    /// with the [CsvReaderOptions].
+    pub fn try_new(mut reader: R, options: CsvReaderOptions) -> GeoArrowResult<Self> {
+        let (schema, _records_read) = options
+            .to_format()
+            .infer_schema(&mut reader, options.max_records)?;
+        reader.rewind()?;
+
+        Self::try_new_with_schema(reader, Arc::new(schema), options)
+    }
+}
+
+impl<R: Read> CsvReader<R> {
+    /// Create a new CSV reader with a known schema.
+    ///
+    /// Note that the input required here is [`Read`] and not [`Read`] + [`Seek`]. This
+    /// means that you must infer the schema yourself before calling this function. This allows using
+    /// with objects that are only `Read` in the case when you already know the file's schema.
+    ///
+    /// This schema is expected to describe the CSV file itself, as inferred by `arrow-csv`'s
+    /// [`infer_schema`][Format::infer_schema]. That means the geometry source columns should be
+    /// strings or numbers in the schema.
+    pub fn try_new_with_schema(
+        reader: R,
+        schema: SchemaRef,
+        options: CsvReaderOptions,
+    ) -> GeoArrowResult<Self> {
+        let parser = GeometryParser::try_new(&schema, &options)?;
+        let output_schema = parser.output_schema(&schema);
+
+        let reader = ReaderBuilder::new(parser.input_schema(&schema))
+            .with_format(options.to_format())
+            .with_batch_size(options.batch_size)
+            .build(reader)?;
+        Ok(Self {
+            reader,
+            output_schema,
+            parser,
+        })
+    }
+}
+
+impl<R: Read> Iterator for CsvReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = self.reader.next()?;
+        Some(batch.and_then(|batch| Ok(self.parser.parse(batch, self.output_schema.clone())?)))
+    }
+}
+
+impl<R: Read> RecordBatchReader for CsvReader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.schema()
+    }
+}
+
+/// Read a CSV file into record batches with a GeoArrow geometry column.
+pub fn read_csv<R: Read + Seek>(
+    reader: R,
+    options: CsvReaderOptions,
+) -> GeoArrowResult<Vec<RecordBatch>> {
+    CsvReader::try_new(reader, options)?
+        .map(|batch| Ok(batch?))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use geo_traits::{CoordTrait, PointTrait};
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::from_arrow_array;
+    use geoarrow_array::cast::AsGeoArrowArray;
+    use geoarrow_schema::CrsType;
+
+    use super::*;
+
+    fn geometry(batch: &RecordBatch, name: &str) -> Arc<dyn GeoArrowArray> {
+        let schema = batch.schema();
+        let idx = schema.index_of(name).unwrap();
+        from_arrow_array(batch.column(idx).as_ref(), schema.field(idx)).unwrap()
+    }
+
+    #[test]
+    fn read_wkt() {
+        let data = "id,geometry\n1,POINT (1 2)\n2,\"LINESTRING (0 0, 1 1)\"\n3,\n";
+        let batches = read_csv(Cursor::new(data), Default::default()).unwrap();
+        let geometry = geometry(&batches[0], "geometry");
+        assert!(matches!(geometry.data_type(), GeoArrowType::Geometry(_)));
+        assert_eq!(geometry.len(), 3);
+        assert_eq!(geometry.logical_null_count(), 1);
+    }
+
+    #[test]
+    fn read_wkb_hex() {
+        // POINT (1 2) as little-endian EWKB with SRID 4326, as printed by PostGIS
+        let data = "name,geom\na,0101000020E6100000000000000000F03F0000000000000040\n";
+        let crs = Crs::from_authority_code("EPSG:4326".to_string());
+        let options = CsvReaderOptions::default().with_crs(crs.clone());
+        let batches = read_csv(Cursor::new(data), options).unwrap();
+        let geometry = geometry(&batches[0], "geom");
+        assert_eq!(geometry.data_type().metadata().crs(), &crs);
+        assert_eq!(geometry.len(), 1);
+
+        // A mismatching CRS is an error
+        let options =
+            CsvReaderOptions::default().with_crs(Crs::from_authority_code("EPSG:3857".to_string()));
+        assert!(read_csv(Cursor::new(data), options).is_err());
+    }
+
+    #[test]
+    fn read_xy() {
+        let data = "name,lon,lat\na,1,2\nb,3.5,\n";
+        let options =
+            CsvReaderOptions::default().with_crs(Crs::from_authority_code("EPSG:4326".to_string()));
+        let batches = read_csv(Cursor::new(data), options).unwrap();
+        let schema = batches[0].schema();
+        assert_eq!(schema.fields().len(), 2);
+        assert_eq!(
+            schema.field(1).extension_type_name(),
+            Some("geoarrow.point")
+        );
+        assert_eq!(
+            geometry(&batches[0], "geometry")
+                .data_type()
+                .metadata()
+                .crs()
+                .crs_type(),
+            Some(CrsType::AuthorityCode)
+        );
+
+        let points = geometry(&batches[0], "geometry");
+        let points = points.as_point();
+        let coord = points.value(0).unwrap().coord().unwrap();
+        assert_eq!((coord.x(), coord.y()), (1.0, 2.0));
+        assert!(points.is_null(1));
+    }
+
+    #[test]
+    fn missing_geometry() {
+        let data = "a,b\n1,2\n";
+        assert!(read_csv(Cursor::new(data), Default::default()).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+//! Write record batches with GeoArrow columns to CSV, serializing geometries as WKT.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_csv::WriterBuilder;
+use arrow_schema::{DataType, Field, Schema};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::cast::to_wkt;
+use geoarrow_schema::GeoArrowType;
+use geoarrow_schema::error::GeoArrowResult;
+
+/// Options for the CSV writer
+#[derive(Debug, Clone)]
+pub struct CsvWriterOptions {
+    /// Whether to write a header row, defaults to `true`
+    pub has_header: bool,
+
+    /// The delimiter character, defaults to comma `','`
+    pub delimiter: char,
+
+    /// The quote character, defaults to double quote `'"'`
+    pub quote: char,
+}
+
+impl CsvWriterOptions {
+    /// Set whether to write a header row.
+    pub fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Set the delimiter character.
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the quote character.
+    pub fn with_quote(mut self, quote: char) -> Self {
+        self.quote = quote;
+        self
+    }
+}
+
+impl Default for CsvWriterOptions {
+    fn default() -> Self {
+        Self {
+            has_header: true,
+            delimiter: ',',
+            quote: '"',
+        }
+    }
+}
+
+/// Write a stream of record batches to CSV, serializing geometry columns as WKT.
+pub fn write_csv<W: Write>(reader: impl RecordBatchReader, writer: W) -> GeoArrowResult<()> {
+    write_csv_with_options(reader, writer, &Default::default())
+}
+
+/// Write a stream of record batches to CSV with specific writer options.
+///
+/// Every GeoArrow extension column is serialized as WKT. Other columns are written as by
+/// [`arrow_csv::Writer`].
+pub fn write_csv_with_options<W: Write>(
+    reader: impl RecordBatchReader,
+    writer: W,
+    options: &CsvWriterOptions,
+) -> GeoArrowResult<()> {
+    let mut writer = WriterBuilder::new()
+        .with_header(options.has_header)
+        .with_delimiter(options.delimiter as u8)
+        .with_quote(options.quote as u8)
+        .build(writer);
+    for batch in reader {
+        writer.write(&encode_batch(batch?)?)?;
+    }
+    Ok(())
+}
+
+fn encode_batch(batch: RecordBatch) -> GeoArrowResult<RecordBatch> {
+    let schema = batch.schema();
+
+    let mut fields = Vec::with_capacity(schema.fields().len());
+    let mut columns = Vec::with_capacity(schema.fields().len());
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        // Only convert columns that are tagged with a GeoArrow extension type
+        if field.extension_type_name().is_some() && GeoArrowType::try_from(field.as_ref()).is_ok() {
+            let array = from_arrow_array(column.as_ref(), field)?;
+            let wkt = to_wkt::<i32>(array.as_ref())?;
+            fields.push(Arc::new(Field::new(
+                field.name(),
+                DataType::Utf8,
+                field.is_nullable(),
+            )));
+            columns.push(wkt.to_array_ref());
+        } else {
+            fields.push(field.clone());
+            columns.push(column.clone());
+        }
+    }
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        columns,
+    )?)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use arrow_array::{Int32Array, RecordBatchIterator};
+    use geoarrow_array::test::point;
+    use geoarrow_schema::{CoordType, Dimension};
+
+    use super::*;
+    use crate::reader::{CsvReaderOptions, GeometrySource, read_csv};
+
+    #[test]
+    fn round_trip() {
+        let points = point::array(CoordType::Separated, Dimension::XY);
+        let values = Int32Array::from_iter_values(0..points.len() as i32);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            points.data_type().to_field("geometry", true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(values), points.to_array_ref()],
+        )
+        .unwrap();
+
+        let mut buf = vec![];
+        write_csv(RecordBatchIterator::new(vec![Ok(batch)], schema), &mut buf).unwrap();
+        let text = String::from_utf8(buf.clone()).unwrap();
+        assert!(text.starts_with("id,geometry\n"));
+
+        let options = CsvReaderOptions::default()
+            .with_geometry_source(GeometrySource::Wkt("geometry".to_string()));
+        let batches = read_csv(Cursor::new(buf), options).unwrap();
+        let schema = batches[0].schema();
+        let geometry = from_arrow_array(batches[0].column(1).as_ref(), schema.field(1)).unwrap();
+        assert_eq!(geometry.len(), points.len());
+        assert_eq!(geometry.logical_null_count(), points.logical_null_count());
+    }
+}
@@ -0,0 +1,548 @@
+//! Planar and spherical bounding boxes of geometries.
+
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+use geoarrow_array::array::RectArray;
+use geoarrow_array::bounds::BoundsAccumulator;
+use geoarrow_array::builder::RectBuilder;
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{BoxType, Dimension, Edges, GeoArrowType};
+
+/// Create a new RectArray using the bounding box of each geometry.
+///
+/// When `edges` is [`Edges::Spherical`], edges are interpreted as great circle arcs: latitudes
+/// account for arcs bulging towards the poles, and boxes that cross the antimeridian are emitted
+/// with `xmin > xmax`. All other edge types are treated as planar.
+pub fn bounding_rect(arr: &dyn GeoArrowArray, edges: Option<Edges>) -> GeoArrowResult<RectArray> {
+    use GeoArrowType::*;
+    let spherical = matches!(edges, Some(Edges::Spherical));
+    match arr.data_type() {
+        Point(_) => impl_array_accessor(arr.as_point(), spherical),
+        LineString(_) => impl_array_accessor(arr.as_line_string(), spherical),
+        Polygon(_) => impl_array_accessor(arr.as_polygon(), spherical),
+        MultiPoint(_) => impl_array_accessor(arr.as_multi_point(), spherical),
+        MultiLineString(_) => impl_array_accessor(arr.as_multi_line_string(), spherical),
+        MultiPolygon(_) => impl_array_accessor(arr.as_multi_polygon(), spherical),
+        Geometry(_) => impl_array_accessor(arr.as_geometry(), spherical),
+        GeometryCollection(_) => impl_array_accessor(arr.as_geometry_collection(), spherical),
+        Rect(_) => Ok(arr.as_rect().clone()),
+        Wkb(_) => impl_array_accessor(arr.as_wkb::<i32>(), spherical),
+        LargeWkb(_) => impl_array_accessor(arr.as_wkb::<i64>(), spherical),
+        WkbView(_) => impl_array_accessor(arr.as_wkb_view(), spherical),
+        Wkt(_) => impl_array_accessor(arr.as_wkt::<i32>(), spherical),
+        LargeWkt(_) => impl_array_accessor(arr.as_wkt::<i64>(), spherical),
+        WktView(_) => impl_array_accessor(arr.as_wkt_view(), spherical),
+    }
+}
+
+/// The actual implementation of computing the bounding rect
+fn impl_array_accessor<'a>(
+    arr: &'a impl GeoArrowArrayAccessor<'a>,
+    spherical: bool,
+) -> GeoArrowResult<RectArray> {
+    match arr.data_type() {
+        GeoArrowType::Rect(_) => unreachable!(),
+        _ => {
+            let mut builder = RectBuilder::with_capacity(
+                BoxType::new(Dimension::XY, arr.data_type().metadata().clone()),
+                arr.len(),
+            );
+            for item in arr.iter() {
+                if let Some(item) = item {
+                    let rect = if spherical {
+                        let mut rect = SphericalBoundsAccumulator::new();
+                        rect.add_geometry(&item?);
+                        rect.finish()
+                    } else {
+                        let mut rect = BoundsAccumulator::new();
+                        rect.add_geometry(&item?);
+                        rect
+                    };
+                    builder.push_rect(Some(&rect));
+                } else {
+                    builder.push_null();
+                }
+            }
+            Ok(builder.finish())
+        }
+    }
+}
+
+/// Get the total bounds (i.e. minx, miny, maxx, maxy) of the entire geoarrow array.
+///
+/// See [`bounding_rect`] for how `edges` is interpreted.
+pub fn total_bounds(
+    arr: &dyn GeoArrowArray,
+    edges: Option<Edges>,
+) -> GeoArrowResult<BoundsAccumulator> {
+    use GeoArrowType::*;
+    let spherical = matches!(edges, Some(Edges::Spherical));
+    match arr.data_type() {
+        Point(_) => impl_total_bounds(arr.as_point(), spherical),
+        LineString(_) => impl_total_bounds(arr.as_line_string(), spherical),
+        Polygon(_) => impl_total_bounds(arr.as_polygon(), spherical),
+        MultiPoint(_) => impl_total_bounds(arr.as_multi_point(), spherical),
+        MultiLineString(_) => impl_total_bounds(arr.as_multi_line_string(), spherical),
+        MultiPolygon(_) => impl_total_bounds(arr.as_multi_polygon(), spherical),
+        Geometry(_) => impl_total_bounds(arr.as_geometry(), spherical),
+        GeometryCollection(_) => impl_total_bounds(arr.as_geometry_collection(), spherical),
+        Rect(_) => impl_total_bounds(arr.as_rect(), spherical),
+        Wkb(_) => impl_total_bounds(arr.as_wkb::<i32>(), spherical),
+        LargeWkb(_) => impl_total_bounds(arr.as_wkb::<i64>(), spherical),
+        WkbView(_) => impl_total_bounds(arr.as_wkb_view(), spherical),
+        Wkt(_) => impl_total_bounds(arr.as_wkt::<i32>(), spherical),
+        LargeWkt(_) => impl_total_bounds(arr.as_wkt::<i64>(), spherical),
+        WktView(_) => impl_total_bounds(arr.as_wkt_view(), spherical),
+    }
+}
+
+/// The actual implementation of computing the total bounds
+fn impl_total_bounds<'a>(
+    arr: &'a impl GeoArrowArrayAccessor<'a>,
+    spherical: bool,
+) -> GeoArrowResult<BoundsAccumulator> {
+    if spherical {
+        let mut rect = SphericalBoundsAccumulator::new();
+        for item in arr.iter().flatten() {
+            rect.add_geometry(&item?);
+        }
+        Ok(rect.finish())
+    } else {
+        let mut rect = BoundsAccumulator::new();
+        for item in arr.iter().flatten() {
+            rect.add_geometry(&item?);
+        }
+        Ok(rect)
+    }
+}
+
+/// A longitude interval in degrees.
+///
+/// When `lo > hi` the interval wraps across the antimeridian, covering `[lo, 180]` and
+/// `[-180, hi]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LonInterval {
+    lo: f64,
+    hi: f64,
+}
+
+impl LonInterval {
+    const FULL: Self = Self {
+        lo: -180.0,
+        hi: 180.0,
+    };
+
+    fn point(lon: f64) -> Self {
+        let lon = normalize_lon(lon);
+        Self { lo: lon, hi: lon }
+    }
+
+    /// The interval swept by the shorter path between two longitudes.
+    fn edge(a: f64, b: f64) -> Self {
+        let a = normalize_lon(a);
+        let b = normalize_lon(b);
+        let (west, east) = if (b - a).abs() <= 180.0 {
+            (a.min(b), a.max(b))
+        } else {
+            // The shorter path crosses the antimeridian, starting from the eastern-most point
+            (a.max(b), a.min(b))
+        };
+        Self { lo: west, hi: east }
+    }
+
+    fn width(&self) -> f64 {
+        if self.lo <= self.hi {
+            self.hi - self.lo
+        } else {
+            self.hi - self.lo + 360.0
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.width() >= 360.0
+    }
+
+    fn contains(&self, other: &Self) -> bool {
+        if self.is_full() {
+            return true;
+        }
+        let offset = (other.lo - self.lo).rem_euclid(360.0);
+        offset + other.width() <= self.width()
+    }
+
+    /// The smallest interval containing both `self` and `other`.
+    fn union(&self, other: &Self) -> Self {
+        if self.contains(other) {
+            return *self;
+        }
+        if other.contains(self) {
+            return *other;
+        }
+
+        [
+            Self {
+                lo: self.lo,
+                hi: other.hi,
+            },
+            Self {
+                lo: other.lo,
+                hi: self.hi,
+            },
+        ]
+        .into_iter()
+        .filter(|candidate| candidate.contains(self) && candidate.contains(other))
+        .min_by(|a, b| a.width().total_cmp(&b.width()))
+        .unwrap_or(Self::FULL)
+    }
+}
+
+/// Normalize a longitude into `[-180, 180]`.
+fn normalize_lon(lon: f64) -> f64 {
+    if (-180.0..=180.0).contains(&lon) {
+        lon
+    } else {
+        (lon + 180.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+/// Compute bounding boxes of geometries whose edges are great circle arcs on a sphere.
+///
+/// Coordinates are interpreted as longitude/latitude in degrees. In contrast to
+/// [`BoundsAccumulator`], the latitude range includes the poleward bulge of each arc and the
+/// longitude range is the smallest interval covering every vertex and arc, which may cross the
+/// antimeridian.
+#[derive(Debug, Clone, Copy)]
+pub struct SphericalBoundsAccumulator {
+    lon: Option<LonInterval>,
+    miny: f64,
+    minz: f64,
+    maxy: f64,
+    maxz: f64,
+}
+
+impl SphericalBoundsAccumulator {
+    pub fn new() -> Self {
+        SphericalBoundsAccumulator {
+            lon: None,
+            miny: f64::INFINITY,
+            minz: f64::INFINITY,
+            maxy: -f64::INFINITY,
+            maxz: -f64::INFINITY,
+        }
+    }
+
+    fn add_lon_interval(&mut self, interval: LonInterval) {
+        self.lon = Some(match self.lon {
+            Some(existing) => existing.union(&interval),
+            None => interval,
+        });
+    }
+
+    fn add_lat(&mut self, lat: f64) {
+        self.miny = self.miny.min(lat);
+        self.maxy = self.maxy.max(lat);
+    }
+
+    fn add_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
+        self.add_lon_interval(LonInterval::point(coord.x()));
+        self.add_lat(coord.y());
+        if let Some(z) = coord.nth(2) {
+            self.minz = self.minz.min(z);
+            self.maxz = self.maxz.max(z);
+        }
+    }
+
+    /// Add the great circle arc between two coordinates.
+    fn add_edge(&mut self, start: &impl CoordTrait<T = f64>, end: &impl CoordTrait<T = f64>) {
+        self.add_coord(start);
+        self.add_coord(end);
+        self.add_lon_interval(LonInterval::edge(start.x(), end.x()));
+
+        let (max_lat, min_lat) = arc_latitude_extrema((start.x(), start.y()), (end.x(), end.y()));
+        if let Some(max_lat) = max_lat {
+            self.add_lat(max_lat);
+        }
+        if let Some(min_lat) = min_lat {
+            self.add_lat(min_lat);
+        }
+    }
+
+    fn add_point(&mut self, point: &impl PointTrait<T = f64>) {
+        if let Some(coord) = point.coord() {
+            self.add_coord(&coord);
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &impl LineStringTrait<T = f64>) {
+        let mut coords = line_string.coords();
+        let Some(mut prev) = coords.next() else {
+            return;
+        };
+        self.add_coord(&prev);
+        for coord in coords {
+            self.add_edge(&prev, &coord);
+            prev = coord;
+        }
+    }
+
+    /// Add a polygon ring, which may additionally enclose a pole.
+    fn add_ring(&mut self, ring: &impl LineStringTrait<T = f64>) {
+        self.add_line_string(ring);
+
+        // A ring that winds all the way around the globe encloses one of the poles, so it covers
+        // every longitude. We assume the pole on the side of the ring's vertices.
+        let mut winding = 0.0;
+        let mut lat_sum = 0.0;
+        let mut num_coords = 0;
+        let mut prev: Option<(f64, f64)> = None;
+        for coord in ring.coords() {
+            if let Some((prev_lon, _)) = prev {
+                let delta = normalize_lon(coord.x() - prev_lon);
+                winding += if delta == 180.0 { 0.0 } else { delta };
+            }
+            lat_sum += coord.y();
+            num_coords += 1;
+            prev = Some((coord.x(), coord.y()));
+        }
+        if winding.abs() > 180.0 {
+            self.add_lon_interval(LonInterval::FULL);
+            self.add_lat(if lat_sum / num_coords as f64 >= 0.0 {
+                90.0
+            } else {
+                -90.0
+            });
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) {
+        if let Some(exterior_ring) = polygon.exterior() {
+            self.add_ring(&exterior_ring);
+        }
+
+        for interior in polygon.interiors() {
+            self.add_ring(&interior);
+        }
+    }
+
+    fn add_multi_point(&mut self, multi_point: &impl MultiPointTrait<T = f64>) {
+        for point in multi_point.points() {
+            self.add_point(&point);
+        }
+    }
+
+    fn add_multi_line_string(&mut self, multi_line_string: &impl MultiLineStringTrait<T = f64>) {
+        for linestring in multi_line_string.line_strings() {
+            self.add_line_string(&linestring);
+        }
+    }
+
+    fn add_multi_polygon(&mut self, multi_polygon: &impl MultiPolygonTrait<T = f64>) {
+        for polygon in multi_polygon.polygons() {
+            self.add_polygon(&polygon);
+        }
+    }
+
+    fn add_geometry_collection(
+        &mut self,
+        geometry_collection: &impl GeometryCollectionTrait<T = f64>,
+    ) {
+        for geometry in geometry_collection.geometries() {
+            self.add_geometry(&geometry);
+        }
+    }
+
+    /// Add a rect, whose longitude range is taken as-is and may wrap across the antimeridian.
+    fn add_rect(&mut self, rect: &impl RectTrait<T = f64>) {
+        self.add_lon_interval(LonInterval {
+            lo: normalize_lon(rect.min().x()),
+            hi: normalize_lon(rect.max().x()),
+        });
+        self.add_lat(rect.min().y());
+        self.add_lat(rect.max().y());
+    }
+
+    fn add_triangle(&mut self, triangle: &impl TriangleTrait<T = f64>) {
+        let [a, b, c] = triangle.coords();
+        self.add_edge(&a, &b);
+        self.add_edge(&b, &c);
+        self.add_edge(&c, &a);
+    }
+
+    pub fn add_geometry(&mut self, geometry: &impl GeometryTrait<T = f64>) {
+        use GeometryType::*;
+
+        match geometry.as_type() {
+            Point(g) => self.add_point(g),
+            LineString(g) => self.add_line_string(g),
+            Polygon(g) => self.add_polygon(g),
+            MultiPoint(g) => self.add_multi_point(g),
+            MultiLineString(g) => self.add_multi_line_string(g),
+            MultiPolygon(g) => self.add_multi_polygon(g),
+            GeometryCollection(g) => self.add_geometry_collection(g),
+            Rect(g) => self.add_rect(g),
+            Triangle(g) => self.add_triangle(g),
+            Line(g) => self.add_edge(&g.start(), &g.end()),
+        }
+    }
+
+    /// Add an existing, possibly wrapping, bounding rect.
+    fn add_bounding_rect(&mut self, rect: &BoundsAccumulator) {
+        if rect.is_empty() {
+            return;
+        }
+        self.add_rect(rect);
+        if let (Some(minz), Some(maxz)) = (rect.minz(), rect.maxz()) {
+            self.minz = self.minz.min(minz);
+            self.maxz = self.maxz.max(maxz);
+        }
+    }
+
+    /// Convert to a [`BoundsAccumulator`], where `minx > maxx` if the bounds cross the antimeridian.
+    pub fn finish(&self) -> BoundsAccumulator {
+        let (minx, maxx) = match self.lon {
+            Some(lon) if lon.is_full() => (-180.0, 180.0),
+            Some(lon) => (lon.lo, lon.hi),
+            None => (f64::INFINITY, -f64::INFINITY),
+        };
+        let mut rect = BoundsAccumulator::from_xy(minx, self.miny, maxx, self.maxy);
+        if self.minz <= self.maxz {
+            rect.add_z(self.minz);
+            rect.add_z(self.maxz);
+        }
+        rect
+    }
+
+    /// The smallest bounds containing both `a` and `b`, treating longitudes as circular.
+    ///
+    /// Either rect may wrap across the antimeridian (i.e. have `minx > maxx`), and the result is
+    /// the smallest longitude interval containing both.
+    pub fn union(a: &BoundsAccumulator, b: &BoundsAccumulator) -> BoundsAccumulator {
+        let mut bounds = Self::new();
+        bounds.add_bounding_rect(a);
+        bounds.add_bounding_rect(b);
+        bounds.finish()
+    }
+}
+
+impl Default for SphericalBoundsAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn to_unit_vector(lon: f64, lat: f64) -> [f64; 3] {
+    let (lon, lat) = (lon.to_radians(), lat.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn normalize(v: [f64; 3]) -> Option<[f64; 3]> {
+    let norm = dot(v, v).sqrt();
+    if norm < 1e-12 {
+        None
+    } else {
+        Some([v[0] / norm, v[1] / norm, v[2] / norm])
+    }
+}
+
+/// The maximum and minimum latitudes reached in the interior of the great circle arc between two
+/// `(lon, lat)` points, if the arc reaches its circle's northern- or southern-most point between
+/// its endpoints.
+fn arc_latitude_extrema(start: (f64, f64), end: (f64, f64)) -> (Option<f64>, Option<f64>) {
+    let a = to_unit_vector(start.0, start.1);
+    let b = to_unit_vector(end.0, end.1);
+
+    // Coincident or antipodal endpoints don't define a unique arc
+    let Some(n) = normalize(cross(a, b)) else {
+        return (None, None);
+    };
+
+    // The northern-most point of the great circle is the north pole projected onto the circle's
+    // plane. This is undefined when the great circle is the equator.
+    let Some(north) = normalize([-n[2] * n[0], -n[2] * n[1], 1.0 - n[2] * n[2]]) else {
+        return (None, None);
+    };
+    let south = [-north[0], -north[1], -north[2]];
+
+    // A point lies on the (minor) arc from a to b if it's reached by rotating from a towards b
+    let on_arc = |p: [f64; 3]| dot(cross(a, p), n) > 0.0 && dot(cross(p, b), n) > 0.0;
+    let lat = |p: [f64; 3]| p[2].clamp(-1.0, 1.0).asin().to_degrees();
+
+    (
+        on_arc(north).then(|| lat(north)),
+        on_arc(south).then(|| lat(south)),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use geo::{line_string, polygon};
+
+    use super::*;
+
+    #[test]
+    fn spherical_line_bulges_poleward() {
+        let line = line_string![(x: -90., y: 45.), (x: 90., y: 45.)];
+        let mut planar = BoundsAccumulator::new();
+        planar.add_line_string(&line);
+        assert!(planar.maxy() < 46.0);
+
+        // The great circle path between these points passes over the north pole
+        let mut spherical = SphericalBoundsAccumulator::new();
+        spherical.add_line_string(&line);
+        assert!(spherical.finish().maxy() > 89.0);
+    }
+
+    #[test]
+    fn spherical_bounds_cross_antimeridian() {
+        let poly = polygon![
+            (x: 170., y: -10.),
+            (x: -170., y: -10.),
+            (x: -170., y: 10.),
+            (x: 170., y: 10.),
+            (x: 170., y: -10.),
+        ];
+        let mut rect = SphericalBoundsAccumulator::new();
+        rect.add_polygon(&poly);
+        let rect = rect.finish();
+        assert_eq!(rect.minx(), 170.);
+        assert_eq!(rect.maxx(), -170.);
+        assert!(rect.maxy() > 10.);
+        assert!(rect.miny() < -10.);
+    }
+
+    #[test]
+    fn spherical_union_merges_wrapping_boxes() {
+        let rect = SphericalBoundsAccumulator::union(
+            &BoundsAccumulator::from_xy(170., 0., -170., 10.),
+            &BoundsAccumulator::from_xy(-175., -5., -160., 5.),
+        );
+        assert_eq!(rect.minx(), 170.);
+        assert_eq!(rect.maxx(), -160.);
+        assert_eq!(rect.miny(), -5.);
+        assert_eq!(rect.maxy(), 10.);
+
+        let rect = SphericalBoundsAccumulator::union(
+            &BoundsAccumulator::from_xy(0., 0., 10., 10.),
+            &BoundsAccumulator::from_xy(20., 0., 30., 1.),
+        );
+        assert_eq!(rect.minx(), 0.);
+        assert_eq!(rect.maxx(), 30.);
+    }
+}
@@ -1,20 +1,31 @@
 #![warn(unused_crate_dependencies)]
 
 mod area;
+mod bounds;
 mod centroid;
 mod contains;
 mod convex_hull;
 mod distance;
 mod intersects;
+mod nearest;
+mod orient;
 mod relate;
 mod simplify;
+mod sjoin;
+pub mod tiling;
 pub mod util;
+pub mod web_mercator;
 
 pub use area::{signed_area, unsigned_area};
+pub use bounds::{SphericalBoundsAccumulator, bounding_rect, total_bounds};
 pub use centroid::centroid;
 pub use contains::contains;
 pub use convex_hull::convex_hull;
 pub use distance::euclidean_distance;
+pub use geoarrow_array::bounds::BoundsAccumulator;
 pub use intersects::intersects;
+pub use nearest::{DistanceMetric, nearest};
+pub use orient::orient_polygons;
 pub use relate::relate_boolean;
 pub use simplify::simplify;
+pub use sjoin::{JoinType, SpatialPredicate, sjoin_indices, sjoin_indices_with_index};
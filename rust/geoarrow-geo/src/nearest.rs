@@ -0,0 +1,234 @@
+use arrow_array::builder::{Float64Builder, UInt32Builder};
+use arrow_array::{Float64Array, UInt32Array};
+use geo::{BoundingRect, Distance, Euclidean, Geometry, Haversine, Rect};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_index::GeoArrowRTree;
+use geoarrow_schema::Edges;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+use crate::util::to_geo::array_to_geo;
+
+/// The metric used to measure distances in [`nearest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceMetric {
+    /// Planar distance, in the units of the coordinates.
+    Euclidean,
+
+    /// Great circle distance on a sphere in meters, between points in longitude and latitude
+    /// degrees.
+    Haversine,
+}
+
+impl DistanceMetric {
+    /// The metric for geometries with the given edges.
+    ///
+    /// Planar edges use Euclidean distance. All non-planar edges are approximated with the
+    /// Haversine distance on a sphere.
+    pub fn from_edges(edges: Option<Edges>) -> Self {
+        match edges {
+            None => Self::Euclidean,
+            Some(_) => Self::Haversine,
+        }
+    }
+
+    fn distance(&self, left: &Geometry, right: &Geometry) -> GeoArrowResult<f64> {
+        match (self, left, right) {
+            (Self::Euclidean, _, _) => Ok(Euclidean.distance(left, right)),
+            (Self::Haversine, Geometry::Point(left), Geometry::Point(right)) => {
+                Ok(Haversine.distance(*left, *right))
+            }
+            (Self::Haversine, _, _) => Err(GeoArrowError::IncorrectGeometryType(
+                "Haversine distance is only supported between points".to_string(),
+            )),
+        }
+    }
+
+    /// The rows of the index whose bounding boxes may be within `radius` of `rect`.
+    fn search(&self, index: &GeoArrowRTree, rect: Rect, radius: f64) -> Vec<u32> {
+        let (min, max) = (rect.min(), rect.max());
+        match self {
+            Self::Euclidean => index.search(
+                min.x - radius,
+                min.y - radius,
+                max.x + radius,
+                max.y + radius,
+            ),
+            Self::Haversine => {
+                let angle = radius / Haversine.radius();
+                let min_y = (min.y - angle.to_degrees()).max(-90.0);
+                let max_y = (max.y + angle.to_degrees()).min(90.0);
+                let max_lat = min_y.abs().max(max_y.abs()).to_radians();
+                // The longitude span of a circle of `angle` radius at latitude `max_lat`
+                let sin_lon = angle.sin() / max_lat.cos();
+                if angle >= std::f64::consts::FRAC_PI_2 || sin_lon >= 1.0 {
+                    return index.search(-180.0, min_y, 180.0, max_y);
+                }
+                let delta_lon = sin_lon.asin().to_degrees();
+                let (min_x, max_x) = (min.x - delta_lon, max.x + delta_lon);
+                if max_x - min_x >= 360.0 {
+                    return index.search(-180.0, min_y, 180.0, max_y);
+                }
+                let mut rows = index.search(min_x, min_y, max_x, max_y);
+                if min_x < -180.0 {
+                    rows.extend(index.search(min_x + 360.0, min_y, 180.0, max_y));
+                }
+                if max_x > 180.0 {
+                    rows.extend(index.search(-180.0, min_y, max_x - 360.0, max_y));
+                }
+                rows.sort_unstable();
+                rows.dedup();
+                rows
+            }
+        }
+    }
+}
+
+/// Find the `k` nearest geometries of the right array to each geometry of the left array.
+///
+/// Returns the left row index, right row index and distance of each pair, ordered by left row
+/// and then by distance. Rows of the left array with no geometry of the right array within
+/// `max_distance` have no pairs, and null geometries never match.
+///
+/// The distance metric is chosen from the edges of the arrays' metadata with
+/// [`DistanceMetric::from_edges`], and both arrays must have the same edges. The Haversine
+/// metric is only supported between points.
+pub fn nearest(
+    left_array: &dyn GeoArrowArray,
+    right_array: &dyn GeoArrowArray,
+    k: usize,
+    max_distance: Option<f64>,
+) -> GeoArrowResult<(UInt32Array, UInt32Array, Float64Array)> {
+    let edges = right_array.data_type().metadata().edges();
+    if left_array.data_type().metadata().edges() != edges {
+        return Err(GeoArrowError::InvalidGeoArrow(
+            "Input arrays must have the same edges".to_string(),
+        ));
+    }
+    if u32::try_from(left_array.len()).is_err() {
+        return Err(GeoArrowError::Overflow);
+    }
+    let metric = DistanceMetric::from_edges(edges);
+    let right_index = GeoArrowRTree::try_new(right_array)?;
+    let left = array_to_geo(left_array)?;
+    let right = array_to_geo(right_array)?;
+
+    let mut left_indices = UInt32Builder::new();
+    let mut right_indices = UInt32Builder::new();
+    let mut distances = Float64Builder::new();
+    if k == 0 {
+        return Ok((
+            left_indices.finish(),
+            right_indices.finish(),
+            distances.finish(),
+        ));
+    }
+
+    let distance_to = |left_geom: &Geometry, right_idx: u32| -> GeoArrowResult<Option<f64>> {
+        right[right_idx as usize]
+            .as_ref()
+            .map(|right_geom| metric.distance(left_geom, right_geom))
+            .transpose()
+    };
+
+    for (left_idx, left_geom) in left.iter().enumerate() {
+        let Some((left_geom, rect)) = left_geom
+            .as_ref()
+            .and_then(|geom| Some((geom, geom.bounding_rect()?)))
+        else {
+            continue;
+        };
+
+        // The k geometries with the nearest bounding boxes to the center bound the distance of
+        // the k nearest geometries, so every match is among the candidates within that bound.
+        let center = rect.center();
+        let mut radius = f64::NEG_INFINITY;
+        for right_idx in right_index.nearest(center.x, center.y, Some(k), None) {
+            if let Some(distance) = distance_to(left_geom, right_idx)? {
+                radius = radius.max(distance);
+            }
+        }
+        if let Some(max_distance) = max_distance {
+            radius = radius.min(max_distance);
+        }
+        if radius < 0.0 {
+            continue;
+        }
+
+        let mut matches = vec![];
+        for right_idx in metric.search(&right_index, rect, radius) {
+            if let Some(distance) = distance_to(left_geom, right_idx)? {
+                if distance <= radius {
+                    matches.push((distance, right_idx));
+                }
+            }
+        }
+        matches.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        for (distance, right_idx) in matches.into_iter().take(k) {
+            left_indices.append_value(left_idx as u32);
+            right_indices.append_value(right_idx);
+            distances.append_value(distance);
+        }
+    }
+
+    Ok((
+        left_indices.finish(),
+        right_indices.finish(),
+        distances.finish(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use geo::point;
+    use geoarrow_array::builder::PointBuilder;
+    use geoarrow_schema::{Crs, Dimension, Metadata, PointType};
+
+    use super::*;
+
+    fn points(coords: &[(f64, f64)], edges: Option<Edges>) -> impl GeoArrowArray {
+        let geoms = coords
+            .iter()
+            .map(|(x, y)| Some(point!(x: *x, y: *y)))
+            .collect::<Vec<_>>();
+        let metadata = Arc::new(Metadata::new(Crs::default(), edges));
+        PointBuilder::from_nullable_geometries(&geoms, PointType::new(Dimension::XY, metadata))
+            .unwrap()
+            .finish()
+    }
+
+    #[test]
+    fn euclidean() {
+        let left = points(&[(0.0, 0.0), (10.0, 10.0)], None);
+        let right = points(&[(1.0, 0.0), (0.0, 3.0), (11.0, 10.0), (-2.0, 0.0)], None);
+        let (left_idx, right_idx, distance) = nearest(&left, &right, 2, None).unwrap();
+        assert_eq!(left_idx.values().to_vec(), vec![0, 0, 1, 1]);
+        assert_eq!(right_idx.values().to_vec(), vec![0, 3, 2, 1]);
+        assert_eq!(distance.value(0), 1.0);
+        assert_eq!(distance.value(1), 2.0);
+        assert_eq!(distance.value(2), 1.0);
+
+        let (left_idx, right_idx, _) = nearest(&left, &right, 2, Some(1.5)).unwrap();
+        assert_eq!(left_idx.values().to_vec(), vec![0, 1]);
+        assert_eq!(right_idx.values().to_vec(), vec![0, 2]);
+    }
+
+    #[test]
+    fn haversine_across_antimeridian() {
+        let edges = Some(Edges::Spherical);
+        let left = points(&[(179.9, 0.0)], edges);
+        let right = points(&[(178.0, 0.0), (-179.9, 0.0)], edges);
+        let (_, right_idx, distance) = nearest(&left, &right, 1, None).unwrap();
+        assert_eq!(right_idx.values().to_vec(), vec![1]);
+        let expected = Haversine.distance(point!(x: 179.9, y: 0.0), point!(x: -179.9, y: 0.0));
+        assert!((distance.value(0) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn mismatched_edges() {
+        let left = points(&[(0.0, 0.0)], None);
+        let right = points(&[(0.0, 0.0)], Some(Edges::Spherical));
+        assert!(nearest(&left, &right, 1, None).is_err());
+    }
+}
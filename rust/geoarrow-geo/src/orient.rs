@@ -0,0 +1,736 @@
+//! Rewind polygon rings to a consistent orientation.
+//!
+//! Rings are reversed lazily through `repr(transparent)` views over the [geo_traits] geometry
+//! types, so all coordinate dimensions are preserved and no intermediate geometries are built.
+
+use std::sync::Arc;
+
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiPolygonTrait, PolygonTrait, RectTrait, UnimplementedGeometryCollection, UnimplementedLine,
+    UnimplementedLineString, UnimplementedMultiLineString, UnimplementedMultiPoint,
+    UnimplementedMultiPolygon, UnimplementedPoint, UnimplementedPolygon, UnimplementedRect,
+    UnimplementedTriangle,
+};
+use geoarrow_array::array::GeometryArray;
+use geoarrow_array::builder::{GeometryBuilder, MultiPolygonBuilder, PolygonBuilder};
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{Dimension, GeoArrowType, Metadata, PolygonType};
+
+/// Rewind all polygons so that exterior rings are counterclockwise and interior rings are
+/// clockwise.
+///
+/// Polygon and MultiPolygon arrays keep their type, including their dimension. Serialized,
+/// mixed and collection arrays are returned as a [`GeometryArray`], with polygons nested in
+/// GeometryCollections rewound as well. Rect arrays are returned as polygon arrays of the same
+/// dimension. Arrays that cannot contain polygons are returned unchanged.
+pub fn orient_polygons(array: &dyn GeoArrowArray) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
+    use GeoArrowType::*;
+    let oriented: Arc<dyn GeoArrowArray> = match array.data_type() {
+        Polygon(typ) => {
+            let mut builder = PolygonBuilder::new(typ);
+            for item in array.as_polygon().iter() {
+                if let Some(polygon) = item {
+                    builder.push_polygon(Some(OrientedPolygon::from_ref(&polygon?)))?;
+                } else {
+                    builder.push_polygon(None::<&geo::Polygon>)?;
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        MultiPolygon(typ) => {
+            let mut builder = MultiPolygonBuilder::new(typ);
+            for item in array.as_multi_polygon().iter() {
+                if let Some(multi_polygon) = item {
+                    builder.push_multi_polygon(Some(OrientedMultiPolygon::from_ref(
+                        &multi_polygon?,
+                    )))?;
+                } else {
+                    builder.push_multi_polygon(None::<&geo::MultiPolygon>)?;
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        Rect(typ) => {
+            let dim = typ.dimension();
+            let polygon_type = PolygonType::new(dim, typ.metadata().clone());
+            let mut builder = PolygonBuilder::new(polygon_type);
+            for item in array.as_rect().iter() {
+                if let Some(rect) = item {
+                    let polygon = rect_to_polygon(&rect?, dim);
+                    builder.push_polygon(Some(OrientedPolygon::from_ref(&polygon)))?;
+                } else {
+                    builder.push_polygon(None::<&geo::Polygon>)?;
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        Geometry(typ) => Arc::new(orient_geometries(array.as_geometry(), typ)?),
+        GeometryCollection(typ) => Arc::new(orient_geometries(
+            array.as_geometry_collection(),
+            geometry_type(typ.metadata().clone()),
+        )?),
+        Wkb(typ) => Arc::new(orient_geometries(
+            array.as_wkb::<i32>(),
+            geometry_type(typ.metadata().clone()),
+        )?),
+        LargeWkb(typ) => Arc::new(orient_geometries(
+            array.as_wkb::<i64>(),
+            geometry_type(typ.metadata().clone()),
+        )?),
+        WkbView(typ) => Arc::new(orient_geometries(
+            array.as_wkb_view(),
+            geometry_type(typ.metadata().clone()),
+        )?),
+        Wkt(typ) => Arc::new(orient_geometries(
+            array.as_wkt::<i32>(),
+            geometry_type(typ.metadata().clone()),
+        )?),
+        LargeWkt(typ) => Arc::new(orient_geometries(
+            array.as_wkt::<i64>(),
+            geometry_type(typ.metadata().clone()),
+        )?),
+        WktView(typ) => Arc::new(orient_geometries(
+            array.as_wkt_view(),
+            geometry_type(typ.metadata().clone()),
+        )?),
+        Point(_) | LineString(_) | MultiPoint(_) | MultiLineString(_) => {
+            array.slice(0, array.len())
+        }
+    };
+    Ok(oriented)
+}
+
+fn geometry_type(metadata: Arc<Metadata>) -> geoarrow_schema::GeometryType {
+    geoarrow_schema::GeometryType::new(metadata)
+}
+
+/// The outline of a rect as a polygon of the given dimension.
+///
+/// The Z and M values of every vertex are taken from the minimum corner of the rect.
+fn rect_to_polygon(rect: &impl RectTrait<T = f64>, dim: Dimension) -> wkt::types::Polygon<f64> {
+    let (min, max) = (rect.min(), rect.max());
+    let (z, m, wkt_dim) = match dim {
+        Dimension::XY => (None, None, wkt::types::Dimension::XY),
+        Dimension::XYZ => (min.nth(2), None, wkt::types::Dimension::XYZ),
+        Dimension::XYM => (None, min.nth(2), wkt::types::Dimension::XYM),
+        Dimension::XYZM => (min.nth(2), min.nth(3), wkt::types::Dimension::XYZM),
+    };
+    let coord = |x, y| wkt::types::Coord { x, y, z, m };
+    let ring = vec![
+        coord(min.x(), min.y()),
+        coord(max.x(), min.y()),
+        coord(max.x(), max.y()),
+        coord(min.x(), max.y()),
+        coord(min.x(), min.y()),
+    ];
+    wkt::types::Polygon::new(vec![wkt::types::LineString::new(ring, wkt_dim)], wkt_dim)
+}
+
+fn orient_geometries<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    typ: geoarrow_schema::GeometryType,
+) -> GeoArrowResult<GeometryArray> {
+    let mut builder = GeometryBuilder::new(typ);
+    for item in array.iter() {
+        if let Some(geom) = item {
+            builder.push_geometry(Some(OrientedGeometry::from_ref(&geom?)))?;
+        } else {
+            builder.push_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Twice the signed area of a ring, considering only the x and y dimensions.
+///
+/// This is positive for counterclockwise rings and negative for clockwise rings. The ring does
+/// not need to be explicitly closed.
+fn ring_signed_area(ring: &impl LineStringTrait<T = f64>) -> f64 {
+    ring.coords()
+        .zip(ring.coords().skip(1).chain(ring.coord(0)))
+        .map(|(a, b)| a.x() * b.y() - b.x() * a.y())
+        .sum()
+}
+
+/// A ring whose coordinates are optionally iterated in reverse order.
+struct OrientedRing<L: LineStringTrait<T = f64>> {
+    ring: L,
+    reverse: bool,
+}
+
+impl<L: LineStringTrait<T = f64>> OrientedRing<L> {
+    /// Wrap a ring, reversing it if its orientation doesn't match `counterclockwise`.
+    fn new(ring: L, counterclockwise: bool) -> Self {
+        let signed_area = ring_signed_area(&ring);
+        let reverse = if counterclockwise {
+            signed_area < 0.0
+        } else {
+            signed_area > 0.0
+        };
+        Self { ring, reverse }
+    }
+}
+
+impl<L: LineStringTrait<T = f64>> LineStringTrait for OrientedRing<L> {
+    type CoordType<'a>
+        = L::CoordType<'a>
+    where
+        Self: 'a;
+
+    fn num_coords(&self) -> usize {
+        self.ring.num_coords()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        if self.reverse {
+            unsafe { self.ring.coord_unchecked(self.ring.num_coords() - 1 - i) }
+        } else {
+            unsafe { self.ring.coord_unchecked(i) }
+        }
+    }
+}
+
+impl<L: LineStringTrait<T = f64>> GeometryTrait for OrientedRing<L> {
+    type T = f64;
+    type PointType<'a>
+        = UnimplementedPoint<f64>
+    where
+        Self: 'a;
+    type LineStringType<'a>
+        = Self
+    where
+        Self: 'a;
+    type PolygonType<'a>
+        = UnimplementedPolygon<f64>
+    where
+        Self: 'a;
+    type MultiPointType<'a>
+        = UnimplementedMultiPoint<f64>
+    where
+        Self: 'a;
+    type MultiLineStringType<'a>
+        = UnimplementedMultiLineString<f64>
+    where
+        Self: 'a;
+    type MultiPolygonType<'a>
+        = UnimplementedMultiPolygon<f64>
+    where
+        Self: 'a;
+    type GeometryCollectionType<'a>
+        = UnimplementedGeometryCollection<f64>
+    where
+        Self: 'a;
+    type RectType<'a>
+        = UnimplementedRect<f64>
+    where
+        Self: 'a;
+    type TriangleType<'a>
+        = UnimplementedTriangle<f64>
+    where
+        Self: 'a;
+    type LineType<'a>
+        = UnimplementedLine<f64>
+    where
+        Self: 'a;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.ring.dim()
+    }
+
+    fn as_type(
+        &self,
+    ) -> GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        GeometryType::LineString(self)
+    }
+}
+
+/// A view of a polygon with a counterclockwise exterior ring and clockwise interior rings.
+#[repr(transparent)]
+struct OrientedPolygon<P: PolygonTrait<T = f64>>(P);
+
+impl<P: PolygonTrait<T = f64>> OrientedPolygon<P> {
+    fn from_ref(polygon: &P) -> &Self {
+        // SAFETY: `OrientedPolygon` is a `repr(transparent)` wrapper around `P`.
+        unsafe { &*(polygon as *const P as *const Self) }
+    }
+}
+
+impl<P: PolygonTrait<T = f64>> PolygonTrait for OrientedPolygon<P> {
+    type RingType<'a>
+        = OrientedRing<P::RingType<'a>>
+    where
+        Self: 'a;
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        self.0.exterior().map(|ring| OrientedRing::new(ring, true))
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.0.num_interiors()
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        let ring = unsafe { self.0.interior_unchecked(i) };
+        OrientedRing::new(ring, false)
+    }
+}
+
+impl<P: PolygonTrait<T = f64>> GeometryTrait for OrientedPolygon<P> {
+    type T = f64;
+    type PointType<'a>
+        = UnimplementedPoint<f64>
+    where
+        Self: 'a;
+    type LineStringType<'a>
+        = UnimplementedLineString<f64>
+    where
+        Self: 'a;
+    type PolygonType<'a>
+        = Self
+    where
+        Self: 'a;
+    type MultiPointType<'a>
+        = UnimplementedMultiPoint<f64>
+    where
+        Self: 'a;
+    type MultiLineStringType<'a>
+        = UnimplementedMultiLineString<f64>
+    where
+        Self: 'a;
+    type MultiPolygonType<'a>
+        = UnimplementedMultiPolygon<f64>
+    where
+        Self: 'a;
+    type GeometryCollectionType<'a>
+        = UnimplementedGeometryCollection<f64>
+    where
+        Self: 'a;
+    type RectType<'a>
+        = UnimplementedRect<f64>
+    where
+        Self: 'a;
+    type TriangleType<'a>
+        = UnimplementedTriangle<f64>
+    where
+        Self: 'a;
+    type LineType<'a>
+        = UnimplementedLine<f64>
+    where
+        Self: 'a;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.0.dim()
+    }
+
+    fn as_type(
+        &self,
+    ) -> GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        GeometryType::Polygon(self)
+    }
+}
+
+/// A view of a multi polygon whose polygons are each an [`OrientedPolygon`].
+#[repr(transparent)]
+struct OrientedMultiPolygon<M: MultiPolygonTrait<T = f64>>(M);
+
+impl<M: MultiPolygonTrait<T = f64>> OrientedMultiPolygon<M> {
+    fn from_ref(multi_polygon: &M) -> &Self {
+        // SAFETY: `OrientedMultiPolygon` is a `repr(transparent)` wrapper around `M`.
+        unsafe { &*(multi_polygon as *const M as *const Self) }
+    }
+}
+
+impl<M: MultiPolygonTrait<T = f64>> MultiPolygonTrait for OrientedMultiPolygon<M> {
+    type InnerPolygonType<'a>
+        = OrientedPolygon<M::InnerPolygonType<'a>>
+    where
+        Self: 'a;
+
+    fn num_polygons(&self) -> usize {
+        self.0.num_polygons()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::InnerPolygonType<'_> {
+        OrientedPolygon(unsafe { self.0.polygon_unchecked(i) })
+    }
+}
+
+impl<M: MultiPolygonTrait<T = f64>> GeometryTrait for OrientedMultiPolygon<M> {
+    type T = f64;
+    type PointType<'a>
+        = UnimplementedPoint<f64>
+    where
+        Self: 'a;
+    type LineStringType<'a>
+        = UnimplementedLineString<f64>
+    where
+        Self: 'a;
+    type PolygonType<'a>
+        = UnimplementedPolygon<f64>
+    where
+        Self: 'a;
+    type MultiPointType<'a>
+        = UnimplementedMultiPoint<f64>
+    where
+        Self: 'a;
+    type MultiLineStringType<'a>
+        = UnimplementedMultiLineString<f64>
+    where
+        Self: 'a;
+    type MultiPolygonType<'a>
+        = Self
+    where
+        Self: 'a;
+    type GeometryCollectionType<'a>
+        = UnimplementedGeometryCollection<f64>
+    where
+        Self: 'a;
+    type RectType<'a>
+        = UnimplementedRect<f64>
+    where
+        Self: 'a;
+    type TriangleType<'a>
+        = UnimplementedTriangle<f64>
+    where
+        Self: 'a;
+    type LineType<'a>
+        = UnimplementedLine<f64>
+    where
+        Self: 'a;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.0.dim()
+    }
+
+    fn as_type(
+        &self,
+    ) -> GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        GeometryType::MultiPolygon(self)
+    }
+}
+
+/// A view of a geometry collection whose members are each an [`OrientedGeometry`].
+#[repr(transparent)]
+struct OrientedGeometryCollection<C: GeometryCollectionTrait<T = f64>>(C);
+
+impl<C: GeometryCollectionTrait<T = f64>> OrientedGeometryCollection<C> {
+    fn from_ref(collection: &C) -> &Self {
+        // SAFETY: `OrientedGeometryCollection` is a `repr(transparent)` wrapper around `C`.
+        unsafe { &*(collection as *const C as *const Self) }
+    }
+}
+
+impl<C: GeometryCollectionTrait<T = f64>> GeometryCollectionTrait
+    for OrientedGeometryCollection<C>
+{
+    type GeometryType<'a>
+        = OrientedGeometry<C::GeometryType<'a>>
+    where
+        Self: 'a;
+
+    fn num_geometries(&self) -> usize {
+        self.0.num_geometries()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        OrientedGeometry(unsafe { self.0.geometry_unchecked(i) })
+    }
+}
+
+impl<C: GeometryCollectionTrait<T = f64>> GeometryTrait for OrientedGeometryCollection<C> {
+    type T = f64;
+    type PointType<'a>
+        = UnimplementedPoint<f64>
+    where
+        Self: 'a;
+    type LineStringType<'a>
+        = UnimplementedLineString<f64>
+    where
+        Self: 'a;
+    type PolygonType<'a>
+        = UnimplementedPolygon<f64>
+    where
+        Self: 'a;
+    type MultiPointType<'a>
+        = UnimplementedMultiPoint<f64>
+    where
+        Self: 'a;
+    type MultiLineStringType<'a>
+        = UnimplementedMultiLineString<f64>
+    where
+        Self: 'a;
+    type MultiPolygonType<'a>
+        = UnimplementedMultiPolygon<f64>
+    where
+        Self: 'a;
+    type GeometryCollectionType<'a>
+        = Self
+    where
+        Self: 'a;
+    type RectType<'a>
+        = UnimplementedRect<f64>
+    where
+        Self: 'a;
+    type TriangleType<'a>
+        = UnimplementedTriangle<f64>
+    where
+        Self: 'a;
+    type LineType<'a>
+        = UnimplementedLine<f64>
+    where
+        Self: 'a;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.0.dim()
+    }
+
+    fn as_type(
+        &self,
+    ) -> GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        GeometryType::GeometryCollection(self)
+    }
+}
+
+/// A view of any geometry in which polygons, including those nested in collections, are
+/// oriented.
+#[repr(transparent)]
+struct OrientedGeometry<G: GeometryTrait<T = f64>>(G);
+
+impl<G: GeometryTrait<T = f64>> OrientedGeometry<G> {
+    fn from_ref(geometry: &G) -> &Self {
+        // SAFETY: `OrientedGeometry` is a `repr(transparent)` wrapper around `G`.
+        unsafe { &*(geometry as *const G as *const Self) }
+    }
+}
+
+impl<G: GeometryTrait<T = f64>> GeometryTrait for OrientedGeometry<G> {
+    type T = f64;
+    type PointType<'a>
+        = G::PointType<'a>
+    where
+        Self: 'a;
+    type LineStringType<'a>
+        = G::LineStringType<'a>
+    where
+        Self: 'a;
+    type PolygonType<'a>
+        = OrientedPolygon<G::PolygonType<'a>>
+    where
+        Self: 'a;
+    type MultiPointType<'a>
+        = G::MultiPointType<'a>
+    where
+        Self: 'a;
+    type MultiLineStringType<'a>
+        = G::MultiLineStringType<'a>
+    where
+        Self: 'a;
+    type MultiPolygonType<'a>
+        = OrientedMultiPolygon<G::MultiPolygonType<'a>>
+    where
+        Self: 'a;
+    type GeometryCollectionType<'a>
+        = OrientedGeometryCollection<G::GeometryCollectionType<'a>>
+    where
+        Self: 'a;
+    type RectType<'a>
+        = G::RectType<'a>
+    where
+        Self: 'a;
+    type TriangleType<'a>
+        = G::TriangleType<'a>
+    where
+        Self: 'a;
+    type LineType<'a>
+        = G::LineType<'a>
+    where
+        Self: 'a;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.0.dim()
+    }
+
+    fn as_type(
+        &self,
+    ) -> GeometryType<
+        '_,
+        Self::PointType<'_>,
+        Self::LineStringType<'_>,
+        Self::PolygonType<'_>,
+        Self::MultiPointType<'_>,
+        Self::MultiLineStringType<'_>,
+        Self::MultiPolygonType<'_>,
+        Self::GeometryCollectionType<'_>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        match self.0.as_type() {
+            GeometryType::Point(g) => GeometryType::Point(g),
+            GeometryType::LineString(g) => GeometryType::LineString(g),
+            GeometryType::Polygon(g) => GeometryType::Polygon(OrientedPolygon::from_ref(g)),
+            GeometryType::MultiPoint(g) => GeometryType::MultiPoint(g),
+            GeometryType::MultiLineString(g) => GeometryType::MultiLineString(g),
+            GeometryType::MultiPolygon(g) => {
+                GeometryType::MultiPolygon(OrientedMultiPolygon::from_ref(g))
+            }
+            GeometryType::GeometryCollection(g) => {
+                GeometryType::GeometryCollection(OrientedGeometryCollection::from_ref(g))
+            }
+            GeometryType::Rect(g) => GeometryType::Rect(g),
+            GeometryType::Triangle(g) => GeometryType::Triangle(g),
+            GeometryType::Line(g) => GeometryType::Line(g),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::{ArrayRef, Float64Array, StructArray};
+    use arrow_schema::DataType;
+    use geo::{Geometry, GeometryCollection, polygon};
+    use geo_traits::to_geo::ToGeoPolygon;
+    use geoarrow_array::array::RectArray;
+    use geoarrow_schema::{BoxType, CoordType};
+
+    use super::*;
+
+    #[test]
+    fn orient_polygon_array() {
+        let clockwise = polygon![
+            exterior: [(x: 0., y: 0.), (x: 0., y: 10.), (x: 10., y: 10.), (x: 10., y: 0.), (x: 0., y: 0.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.), (x: 2., y: 2.)]],
+        ];
+        let typ = PolygonType::new(Dimension::XY, Default::default())
+            .with_coord_type(CoordType::Separated);
+        let array = PolygonBuilder::from_polygons(&[clockwise], typ).finish();
+
+        let oriented = orient_polygons(&array).unwrap();
+        let oriented = oriented.as_polygon().value(0).unwrap().to_polygon();
+        let expected = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.), (x: 2., y: 2.)]],
+        ];
+        assert_eq!(oriented, expected);
+    }
+
+    #[test]
+    fn orient_keeps_dimension() {
+        let geo_arr = geoarrow_array::test::polygon::array(CoordType::Separated, Dimension::XYZ);
+        let oriented = orient_polygons(&geo_arr).unwrap();
+        assert_eq!(oriented.data_type().dimension(), Some(Dimension::XYZ));
+
+        for polygon in oriented.as_polygon().iter().flatten() {
+            let exterior = polygon.unwrap().exterior().unwrap();
+            assert!(ring_signed_area(&exterior) > 0.0);
+            assert!(exterior.coords().all(|coord| coord.nth(2).is_some()));
+        }
+    }
+
+    #[test]
+    fn orient_nested_in_collection() {
+        let clockwise = polygon![
+            (x: 0., y: 0.), (x: 0., y: 10.), (x: 10., y: 10.), (x: 10., y: 0.), (x: 0., y: 0.)
+        ];
+        let collection =
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![Geometry::Polygon(
+                clockwise,
+            )]));
+        let typ = geoarrow_schema::GeometryType::new(Default::default());
+        let array = GeometryBuilder::from_nullable_geometries(&[Some(collection)], typ)
+            .unwrap()
+            .finish();
+
+        let oriented = orient_polygons(&array).unwrap();
+        let geometry = oriented.as_geometry().value(0).unwrap();
+        let GeometryType::GeometryCollection(collection) = geometry.as_type() else {
+            panic!("expected a geometry collection");
+        };
+        let member = collection.geometry(0).unwrap();
+        let GeometryType::Polygon(polygon) = member.as_type() else {
+            panic!("expected a polygon");
+        };
+        assert!(ring_signed_area(&polygon.exterior().unwrap()) > 0.0);
+    }
+
+    #[test]
+    fn orient_rect_keeps_dimension() {
+        let typ = BoxType::new(Dimension::XYZ, Default::default());
+        let DataType::Struct(fields) = typ.data_type() else {
+            unreachable!()
+        };
+        let columns = [0., 0., 5., 10., 20., 8.]
+            .into_iter()
+            .map(|value| Arc::new(Float64Array::from(vec![value])) as ArrayRef)
+            .collect();
+        let struct_array = StructArray::new(fields, columns, None);
+        let array = RectArray::try_from((&struct_array, typ)).unwrap();
+
+        let oriented = orient_polygons(&array).unwrap();
+        assert_eq!(oriented.data_type().dimension(), Some(Dimension::XYZ));
+        let polygon = oriented.as_polygon().value(0).unwrap();
+        let exterior = polygon.exterior().unwrap();
+        assert!(ring_signed_area(&exterior) > 0.0);
+        assert_eq!(exterior.num_coords(), 5);
+        assert!(exterior.coords().all(|coord| coord.nth(2) == Some(5.)));
+    }
+
+    #[test]
+    fn orient_point_array() {
+        let geo_arr = geoarrow_array::test::point::array(CoordType::Interleaved, Dimension::XY);
+        let oriented = orient_polygons(&geo_arr).unwrap();
+        assert_eq!(oriented.len(), geo_arr.len());
+        assert_eq!(oriented.data_type(), geo_arr.data_type());
+    }
+}
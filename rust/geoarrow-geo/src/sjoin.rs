@@ -0,0 +1,266 @@
+use arrow_array::UInt32Array;
+use arrow_array::builder::UInt32Builder;
+use geo::{BoundingRect, Distance, Euclidean, Geometry, Relate};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_index::GeoArrowRTree;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+use crate::util::to_geo::array_to_geo;
+
+/// The predicate a pair of geometries must satisfy to match in [`sjoin_indices`].
+///
+/// Candidates are found by intersecting bounding boxes, so only predicates that imply the pair
+/// intersects, or lies within a distance, are supported. Predicates like disjoint would miss every
+/// pair whose bounding boxes don't intersect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpatialPredicate {
+    /// The pair shares at least one point.
+    Intersects,
+
+    /// The left geometry contains the right geometry.
+    Contains,
+
+    /// The left geometry is within the right geometry.
+    Within,
+
+    /// Every point of the right geometry is a point of the left geometry.
+    Covers,
+
+    /// Every point of the left geometry is a point of the right geometry.
+    CoveredBy,
+
+    /// The pair shares boundary points but no interior points.
+    Touches,
+
+    /// The pair shares some but not all interior points, and has the same dimension.
+    Overlaps,
+
+    /// The pair shares some but not all interior points, and has different dimensions.
+    Crosses,
+
+    /// The pair is topologically equal.
+    Equals,
+
+    /// The planar Euclidean distance between the pair is at most the given distance, in the units
+    /// of the coordinates.
+    ///
+    /// Coordinates are not treated as longitude and latitude, so this is not a geodesic distance.
+    WithinDistance(f64),
+}
+
+impl SpatialPredicate {
+    /// How far to expand the bounding box of a left geometry when searching for candidates.
+    fn expansion(&self) -> f64 {
+        match self {
+            Self::WithinDistance(distance) => *distance,
+            _ => 0.0,
+        }
+    }
+
+    fn evaluate(&self, left: &Geometry, right: &Geometry) -> bool {
+        let matrix = match self {
+            Self::WithinDistance(distance) => return Euclidean.distance(left, right) <= *distance,
+            _ => left.relate(right),
+        };
+        match self {
+            Self::Intersects => matrix.is_intersects(),
+            Self::Contains => matrix.is_contains(),
+            Self::Within => matrix.is_within(),
+            Self::Covers => matrix.is_covers(),
+            Self::CoveredBy => matrix.is_coveredby(),
+            Self::Touches => matrix.is_touches(),
+            Self::Overlaps => matrix.is_overlaps(),
+            Self::Crosses => matrix.is_crosses(),
+            Self::Equals => matrix.is_equal_topo(),
+            Self::WithinDistance(_) => unreachable!(),
+        }
+    }
+}
+
+/// Which rows of the left array appear in the output of [`sjoin_indices`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JoinType {
+    /// Only rows of the left array with a match.
+    #[default]
+    Inner,
+
+    /// Every row of the left array, with a null right index if it has no match.
+    Left,
+}
+
+/// Find the pairs of rows of two arrays whose geometries satisfy a predicate.
+///
+/// Returns the left and right row indices of each matching pair, ordered by left row and then
+/// right row. Candidates are found with an R-tree over the right array, and then refined with the
+/// exact predicate. Null geometries never match.
+pub fn sjoin_indices(
+    left_array: &dyn GeoArrowArray,
+    right_array: &dyn GeoArrowArray,
+    predicate: &SpatialPredicate,
+    join_type: JoinType,
+) -> GeoArrowResult<(UInt32Array, UInt32Array)> {
+    let right_index = GeoArrowRTree::try_new(right_array)?;
+    sjoin_indices_with_index(left_array, right_array, &right_index, predicate, join_type)
+}
+
+/// Find the pairs of rows of two arrays whose geometries satisfy a predicate, using an existing
+/// R-tree over the right array.
+///
+/// See [`sjoin_indices`].
+pub fn sjoin_indices_with_index(
+    left_array: &dyn GeoArrowArray,
+    right_array: &dyn GeoArrowArray,
+    right_index: &GeoArrowRTree,
+    predicate: &SpatialPredicate,
+    join_type: JoinType,
+) -> GeoArrowResult<(UInt32Array, UInt32Array)> {
+    if u32::try_from(left_array.len()).is_err() {
+        return Err(GeoArrowError::Overflow);
+    }
+    let left = array_to_geo(left_array)?;
+    let right = array_to_geo(right_array)?;
+    let expansion = predicate.expansion();
+
+    let mut left_indices = UInt32Builder::new();
+    let mut right_indices = UInt32Builder::new();
+    for (left_idx, left_geom) in left.iter().enumerate() {
+        let mut matched = false;
+        let left_bounds = left_geom
+            .as_ref()
+            .and_then(|geom| Some((geom, geom.bounding_rect()?)));
+        if let Some((left_geom, rect)) = left_bounds {
+            let candidates = right_index.search(
+                rect.min().x - expansion,
+                rect.min().y - expansion,
+                rect.max().x + expansion,
+                rect.max().y + expansion,
+            );
+            for right_idx in candidates {
+                let right_geom = right.get(right_idx as usize).ok_or_else(|| {
+                    GeoArrowError::InvalidGeoArrow(
+                        "R-tree index was not built over the right array".to_string(),
+                    )
+                })?;
+                if right_geom
+                    .as_ref()
+                    .is_some_and(|right_geom| predicate.evaluate(left_geom, right_geom))
+                {
+                    left_indices.append_value(left_idx as u32);
+                    right_indices.append_value(right_idx);
+                    matched = true;
+                }
+            }
+        }
+        if !matched && join_type == JoinType::Left {
+            left_indices.append_value(left_idx as u32);
+            right_indices.append_null();
+        }
+    }
+
+    Ok((left_indices.finish(), right_indices.finish()))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use geo::{point, polygon};
+    use geoarrow_array::test::geometry::from_geoms;
+
+    use super::*;
+
+    fn squares() -> impl GeoArrowArray {
+        from_geoms(
+            &[
+                Some(Geometry::from(polygon![
+                    (x: 0.0, y: 0.0),
+                    (x: 2.0, y: 0.0),
+                    (x: 2.0, y: 2.0),
+                    (x: 0.0, y: 2.0),
+                ])),
+                None,
+                Some(Geometry::from(polygon![
+                    (x: 10.0, y: 10.0),
+                    (x: 12.0, y: 10.0),
+                    (x: 12.0, y: 12.0),
+                    (x: 10.0, y: 12.0),
+                ])),
+            ],
+            Default::default(),
+        )
+    }
+
+    fn points() -> impl GeoArrowArray {
+        from_geoms(
+            &[
+                Some(Geometry::from(point!(x: 1.0, y: 1.0))),
+                Some(Geometry::from(point!(x: 3.0, y: 1.0))),
+                Some(Geometry::from(point!(x: 1.5, y: 0.5))),
+                None,
+            ],
+            Default::default(),
+        )
+    }
+
+    #[test]
+    fn inner_contains() {
+        let (left, right) = sjoin_indices(
+            &squares(),
+            &points(),
+            &SpatialPredicate::Contains,
+            JoinType::Inner,
+        )
+        .unwrap();
+        assert_eq!(left.values().to_vec(), vec![0, 0]);
+        assert_eq!(right.values().to_vec(), vec![0, 2]);
+    }
+
+    #[test]
+    fn left_join() {
+        let (left, right) = sjoin_indices(
+            &squares(),
+            &points(),
+            &SpatialPredicate::Intersects,
+            JoinType::Left,
+        )
+        .unwrap();
+        assert_eq!(left.values().to_vec(), vec![0, 0, 1, 2]);
+        assert_eq!(
+            right.iter().collect::<Vec<_>>(),
+            vec![Some(0), Some(2), None, None]
+        );
+    }
+
+    #[test]
+    fn touches() {
+        let boundary = from_geoms(
+            &[
+                Some(Geometry::from(point!(x: 2.0, y: 1.0))),
+                Some(Geometry::from(point!(x: 1.0, y: 1.0))),
+            ],
+            Default::default(),
+        );
+        let (left, right) = sjoin_indices(
+            &squares(),
+            &boundary,
+            &SpatialPredicate::Touches,
+            JoinType::Inner,
+        )
+        .unwrap();
+        assert_eq!(left.values().to_vec(), vec![0]);
+        assert_eq!(right.values().to_vec(), vec![0]);
+    }
+
+    #[test]
+    fn within_distance() {
+        let (left, right) = sjoin_indices(
+            &points(),
+            &squares(),
+            &SpatialPredicate::WithinDistance(1.0),
+            JoinType::Inner,
+        )
+        .unwrap();
+        assert_eq!(left.values().to_vec(), vec![0, 1, 2]);
+        assert_eq!(right.values().to_vec(), vec![0, 0, 0]);
+        assert_eq!(right.null_count(), 0);
+    }
+}
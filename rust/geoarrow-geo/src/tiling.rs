@@ -0,0 +1,799 @@
+//! Web Mercator tiling kernels: the tiles of points and geometries, and the quadkeys and
+//! envelopes of tiles.
+
+use std::collections::BTreeSet;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use arrow_array::builder::{StringBuilder, UInt32Builder};
+use arrow_array::{Array, StringArray, UInt8Array, UInt32Array};
+use geo::{Coord, coord};
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+use geoarrow_array::array::{PointArray, RectArray};
+use geoarrow_array::builder::RectBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{BoxType, Crs, Dimension, Metadata};
+
+use crate::web_mercator::{EARTH_RADIUS, lon_lat_to_web_mercator, web_mercator_to_lon_lat};
+
+/// The circumference of the Web Mercator world, in meters.
+const CIRCUMFERENCE: f64 = 2.0 * PI * EARTH_RADIUS;
+
+/// A default for the maximum number of tiles covering one geometry in [`tiles_covering`].
+pub const DEFAULT_MAX_TILES: usize = 1 << 20;
+
+/// The address of a tile in the XYZ tiling scheme, with the origin in the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileCoord {
+    /// The zoom level
+    pub z: u8,
+    /// The column, from west to east
+    pub x: u32,
+    /// The row, from north to south
+    pub y: u32,
+}
+
+impl TileCoord {
+    /// Create a new tile address.
+    pub fn new(z: u8, x: u32, y: u32) -> Self {
+        Self { z, x, y }
+    }
+
+    /// Check that the tile exists at its zoom level.
+    pub fn validate(&self) -> GeoArrowResult<()> {
+        if self.z > 31 {
+            return Err(GeoArrowError::Tile(format!(
+                "Zoom level {} is larger than the maximum of 31",
+                self.z
+            )));
+        }
+        let num_tiles = 1_u64 << self.z;
+        if u64::from(self.x) >= num_tiles || u64::from(self.y) >= num_tiles {
+            return Err(GeoArrowError::Tile(format!(
+                "Tile {}/{}/{} is out of range",
+                self.z, self.x, self.y
+            )));
+        }
+        Ok(())
+    }
+
+    /// The [quadkey](https://learn.microsoft.com/en-us/bingmaps/articles/bing-maps-tile-system)
+    /// of the tile, with one digit per zoom level.
+    pub fn quadkey(&self) -> String {
+        (1..=self.z)
+            .rev()
+            .map(|level| {
+                let mask = 1_u32 << (level - 1);
+                let digit = u8::from(self.x & mask != 0) + 2 * u8::from(self.y & mask != 0);
+                char::from(b'0' + digit)
+            })
+            .collect()
+    }
+
+    /// Parse a tile from its quadkey.
+    pub fn from_quadkey(quadkey: &str) -> GeoArrowResult<Self> {
+        let z = u8::try_from(quadkey.len())
+            .ok()
+            .filter(|z| *z <= 31)
+            .ok_or_else(|| GeoArrowError::Tile(format!("Quadkey {quadkey} is too long")))?;
+        let (mut x, mut y) = (0, 0);
+        for digit in quadkey.bytes() {
+            let digit = match digit {
+                b'0'..=b'3' => u32::from(digit - b'0'),
+                _ => {
+                    return Err(GeoArrowError::Tile(format!("Invalid quadkey {quadkey}")));
+                }
+            };
+            x = (x << 1) | (digit & 1);
+            y = (y << 1) | (digit >> 1);
+        }
+        Ok(Self { z, x, y })
+    }
+
+    /// The bounds of the tile in the given projection, as `[min_x, min_y, max_x, max_y]`.
+    pub fn bounds(&self, projection: SourceProjection) -> [f64; 4] {
+        let num_tiles = (1_u64 << self.z) as f64;
+        let (min_x, max_y) =
+            projection.from_world(f64::from(self.x) / num_tiles, f64::from(self.y) / num_tiles);
+        let (max_x, min_y) = projection.from_world(
+            f64::from(self.x + 1) / num_tiles,
+            f64::from(self.y + 1) / num_tiles,
+        );
+        [min_x, min_y, max_x, max_y]
+    }
+}
+
+/// The coordinate reference system of the source geometries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SourceProjection {
+    /// Longitude and latitude in degrees (EPSG:4326). Latitudes are clamped to the Web Mercator
+    /// range.
+    #[default]
+    LonLat,
+
+    /// Web Mercator in meters (EPSG:3857)
+    WebMercator,
+}
+
+impl SourceProjection {
+    /// The projection of geometries with the given CRS.
+    ///
+    /// Geometries without a CRS are assumed to be in longitude and latitude. Any CRS other than
+    /// EPSG:4326 or EPSG:3857 is an error.
+    pub fn from_crs(crs: &Crs) -> GeoArrowResult<Self> {
+        if crs == &Crs::default() {
+            return Ok(Self::LonLat);
+        }
+        match crs.srid() {
+            Some(4326) => Ok(Self::LonLat),
+            Some(3857) => Ok(Self::WebMercator),
+            _ => Err(GeoArrowError::Crs(
+                "Web Mercator tiles require geometries in EPSG:4326 or EPSG:3857".to_string(),
+            )),
+        }
+    }
+
+    /// Project to normalized world coordinates in `[0, 1]`, with the origin in the north-west
+    /// corner.
+    pub fn to_world(self, x: f64, y: f64) -> (f64, f64) {
+        (self.world_x(x), self.world_y(y))
+    }
+
+    fn world_x(self, x: f64) -> f64 {
+        match self {
+            Self::LonLat => x / 360.0 + 0.5,
+            Self::WebMercator => x / CIRCUMFERENCE + 0.5,
+        }
+    }
+
+    fn world_y(self, y: f64) -> f64 {
+        let y = match self {
+            Self::LonLat => lon_lat_to_web_mercator(Coord { x: 0.0, y }).y,
+            Self::WebMercator => y,
+        };
+        0.5 - y / CIRCUMFERENCE
+    }
+
+    /// The inverse of [`Self::to_world`].
+    pub fn from_world(self, wx: f64, wy: f64) -> (f64, f64) {
+        let coord = Coord {
+            x: (wx - 0.5) * CIRCUMFERENCE,
+            y: (0.5 - wy) * CIRCUMFERENCE,
+        };
+        let coord = match self {
+            Self::LonLat => web_mercator_to_lon_lat(coord),
+            Self::WebMercator => coord,
+        };
+        (coord.x, coord.y)
+    }
+
+    /// The CRS of this projection as an EPSG authority code.
+    pub fn crs(self) -> Crs {
+        let code = match self {
+            Self::LonLat => "EPSG:4326",
+            Self::WebMercator => "EPSG:3857",
+        };
+        Crs::from_authority_code(code.to_string())
+    }
+}
+
+/// The column of the tile containing each point at a zoom level.
+///
+/// Null and empty points have a null column. Points outside the Web Mercator bounds are assigned
+/// to the nearest tile.
+pub fn point_to_tile_x(
+    array: &PointArray,
+    zoom: u8,
+    projection: SourceProjection,
+) -> GeoArrowResult<UInt32Array> {
+    let grid = TileGrid::try_new(zoom, projection)?;
+    point_to_tile_axis(array, |x, _| grid.column(x))
+}
+
+/// The row, from north to south, of the tile containing each point at a zoom level.
+///
+/// Null and empty points have a null row. Points outside the Web Mercator bounds are assigned to
+/// the nearest tile.
+pub fn point_to_tile_y(
+    array: &PointArray,
+    zoom: u8,
+    projection: SourceProjection,
+) -> GeoArrowResult<UInt32Array> {
+    let grid = TileGrid::try_new(zoom, projection)?;
+    point_to_tile_axis(array, |_, y| grid.row(y))
+}
+
+fn point_to_tile_axis(
+    array: &PointArray,
+    tile_index: impl Fn(f64, f64) -> GeoArrowResult<u32>,
+) -> GeoArrowResult<UInt32Array> {
+    let mut builder = UInt32Builder::with_capacity(array.len());
+    for point in array.iter() {
+        match point.transpose()?.and_then(|point| point.coord()) {
+            Some(coord) => builder.append_value(tile_index(coord.x(), coord.y())?),
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// The quadkey of each tile, or null if any of its zoom, column or row is null.
+pub fn tile_quadkey(
+    z: &UInt8Array,
+    x: &UInt32Array,
+    y: &UInt32Array,
+) -> GeoArrowResult<StringArray> {
+    let mut builder = StringBuilder::with_capacity(z.len(), z.len() * 8);
+    for tile in tiles(z, x, y)? {
+        builder.append_option(tile.map(|tile| tile.quadkey()));
+    }
+    Ok(builder.finish())
+}
+
+/// The envelope of each tile in the given projection, or null if any of its zoom, column or row
+/// is null.
+///
+/// The envelopes have the CRS of the projection.
+pub fn tile_envelope(
+    z: &UInt8Array,
+    x: &UInt32Array,
+    y: &UInt32Array,
+    projection: SourceProjection,
+) -> GeoArrowResult<RectArray> {
+    let metadata = Arc::new(Metadata::new(projection.crs(), None));
+    let mut builder = RectBuilder::with_capacity(BoxType::new(Dimension::XY, metadata), z.len());
+    for tile in tiles(z, x, y)? {
+        match tile {
+            Some(tile) => {
+                let [min_x, min_y, max_x, max_y] = tile.bounds(projection);
+                builder.push_min_max(
+                    &coord! { x: min_x, y: min_y },
+                    &coord! { x: max_x, y: max_y },
+                );
+            }
+            None => builder.push_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// The tiles at a zoom level that intersect each geometry.
+///
+/// Returns the row index, column and row of every covering tile, ordered by row index, then
+/// column, then row. Null and empty geometries have no tiles. A geometry covered by more than
+/// `max_tiles` tiles is an error, as the number of tiles grows fourfold with each zoom level.
+pub fn tiles_covering(
+    array: &dyn GeoArrowArray,
+    zoom: u8,
+    projection: SourceProjection,
+    max_tiles: usize,
+) -> GeoArrowResult<(UInt32Array, UInt32Array, UInt32Array)> {
+    if u32::try_from(array.len()).is_err() {
+        return Err(GeoArrowError::Overflow);
+    }
+    let grid = TileGrid::try_new(zoom, projection)?;
+    downcast_geoarrow_array!(array, tiles_covering_impl, &grid, max_tiles)
+}
+
+fn tiles_covering_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    grid: &TileGrid,
+    max_tiles: usize,
+) -> GeoArrowResult<(UInt32Array, UInt32Array, UInt32Array)> {
+    let mut rows = UInt32Builder::new();
+    let mut xs = UInt32Builder::new();
+    let mut ys = UInt32Builder::new();
+    for (row, geometry) in array.iter().enumerate() {
+        let Some(geometry) = geometry else {
+            continue;
+        };
+        let mut cover = TileCover::new(grid, max_tiles);
+        cover.add_geometry(&geometry?)?;
+        for [x, y] in cover.finish() {
+            rows.append_value(row as u32);
+            xs.append_value(x);
+            ys.append_value(y);
+        }
+    }
+    Ok((rows.finish(), xs.finish(), ys.finish()))
+}
+
+/// Zip tile coordinate arrays, validating each tile.
+fn tiles(
+    z: &UInt8Array,
+    x: &UInt32Array,
+    y: &UInt32Array,
+) -> GeoArrowResult<Vec<Option<TileCoord>>> {
+    if z.len() != x.len() || z.len() != y.len() {
+        return Err(GeoArrowError::Tile(
+            "Tile zoom, column and row arrays must have the same length".to_string(),
+        ));
+    }
+    z.iter()
+        .zip(x.iter())
+        .zip(y.iter())
+        .map(|((z, x), y)| {
+            let (Some(z), Some(x), Some(y)) = (z, x, y) else {
+                return Ok(None);
+            };
+            let tile = TileCoord::new(z, x, y);
+            tile.validate()?;
+            Ok(Some(tile))
+        })
+        .collect()
+}
+
+fn finite(value: f64) -> GeoArrowResult<f64> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(GeoArrowError::Tile(
+            "Coordinates must be finite to compute tiles".to_string(),
+        ))
+    }
+}
+
+/// The tiles of a zoom level, in tile units where each tile is a unit square.
+struct TileGrid {
+    projection: SourceProjection,
+    zoom: u8,
+    num_tiles: u32,
+}
+
+impl TileGrid {
+    fn try_new(zoom: u8, projection: SourceProjection) -> GeoArrowResult<Self> {
+        TileCoord::new(zoom, 0, 0).validate()?;
+        Ok(Self {
+            projection,
+            zoom,
+            num_tiles: (1_u64 << zoom) as u32,
+        })
+    }
+
+    /// Scale a normalized world coordinate to tile units, clamped to the bounds of the grid.
+    fn to_tile_units(&self, world: f64) -> f64 {
+        let size = f64::from(self.num_tiles);
+        (world * size).clamp(0.0, size)
+    }
+
+    /// Project a coordinate to tile units.
+    fn project(&self, coord: &impl CoordTrait<T = f64>) -> GeoArrowResult<[f64; 2]> {
+        let (wx, wy) = self
+            .projection
+            .to_world(finite(coord.x())?, finite(coord.y())?);
+        Ok([self.to_tile_units(wx), self.to_tile_units(wy)])
+    }
+
+    /// The column of the tile containing a source x coordinate.
+    fn column(&self, x: f64) -> GeoArrowResult<u32> {
+        let wx = self.projection.world_x(finite(x)?);
+        Ok(self.tile_index(self.to_tile_units(wx)))
+    }
+
+    /// The row of the tile containing a source y coordinate.
+    fn row(&self, y: f64) -> GeoArrowResult<u32> {
+        let wy = self.projection.world_y(finite(y)?);
+        Ok(self.tile_index(self.to_tile_units(wy)))
+    }
+
+    /// The index of the column or row containing a position in tile units.
+    fn tile_index(&self, position: f64) -> u32 {
+        (position.floor() as u32).min(self.num_tiles - 1)
+    }
+
+    /// The tile containing a position in tile units.
+    fn tile_of(&self, [x, y]: [f64; 2]) -> [u32; 2] {
+        [self.tile_index(x), self.tile_index(y)]
+    }
+}
+
+/// Accumulates the tiles intersecting a geometry, up to a maximum number of tiles.
+///
+/// The tiles crossed by every point and edge are found by walking the grid. A tile that doesn't
+/// touch the boundary of a polygon intersects it only if it lies entirely within, so the
+/// interiors of polygons are filled by scanning the center line of each row of tiles.
+struct TileCover<'a> {
+    grid: &'a TileGrid,
+    max_tiles: usize,
+    tiles: BTreeSet<[u32; 2]>,
+}
+
+impl<'a> TileCover<'a> {
+    fn new(grid: &'a TileGrid, max_tiles: usize) -> Self {
+        Self {
+            grid,
+            max_tiles,
+            tiles: BTreeSet::new(),
+        }
+    }
+
+    fn insert(&mut self, tile: [u32; 2]) -> GeoArrowResult<()> {
+        if self.tiles.insert(tile) && self.tiles.len() > self.max_tiles {
+            return Err(GeoArrowError::Tile(format!(
+                "Geometry covers more than {} tiles at zoom level {}",
+                self.max_tiles, self.grid.zoom
+            )));
+        }
+        Ok(())
+    }
+
+    fn add_point(&mut self, point: [f64; 2]) -> GeoArrowResult<()> {
+        self.insert(self.grid.tile_of(point))
+    }
+
+    /// Add the tiles crossed by a segment, walking from tile to tile along it.
+    fn add_segment(&mut self, a: [f64; 2], b: [f64; 2]) -> GeoArrowResult<()> {
+        let [mut x, mut y] = self.grid.tile_of(a);
+        let [end_x, end_y] = self.grid.tile_of(b);
+        self.insert([x, y])?;
+
+        let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+        let (step_x, step_y) = (dx.signum() as i64, dy.signum() as i64);
+        // The parameter along the segment of the next vertical and horizontal tile boundary
+        let boundary = |start: f64, tile: u32, step: i64, delta: f64| {
+            if delta == 0.0 {
+                f64::INFINITY
+            } else {
+                let next = f64::from(tile) + if step > 0 { 1.0 } else { 0.0 };
+                (next - start) / delta
+            }
+        };
+        let mut t_x = boundary(a[0], x, step_x, dx);
+        let mut t_y = boundary(a[1], y, step_y, dy);
+        let (t_delta_x, t_delta_y) = (1.0 / dx.abs(), 1.0 / dy.abs());
+
+        let steps = end_x.abs_diff(x) + end_y.abs_diff(y);
+        for _ in 0..steps {
+            if t_x < t_y {
+                x = x.saturating_add_signed(step_x as i32);
+                t_x += t_delta_x;
+            } else {
+                y = y.saturating_add_signed(step_y as i32);
+                t_y += t_delta_y;
+            }
+            self.insert(self.grid.tile_of([f64::from(x), f64::from(y)]))?;
+        }
+        Ok(())
+    }
+
+    fn add_line(&mut self, coords: &[[f64; 2]]) -> GeoArrowResult<()> {
+        if let Some(first) = coords.first() {
+            self.add_point(*first)?;
+        }
+        for segment in coords.windows(2) {
+            self.add_segment(segment[0], segment[1])?;
+        }
+        Ok(())
+    }
+
+    /// Add the tiles intersecting a polygon given by its rings, which needn't be closed.
+    fn add_rings(&mut self, rings: &[Vec<[f64; 2]>]) -> GeoArrowResult<()> {
+        let rings = rings
+            .iter()
+            .filter(|ring| !ring.is_empty())
+            .map(|ring| {
+                let mut ring = ring.clone();
+                if ring.first() != ring.last() {
+                    ring.push(ring[0]);
+                }
+                ring
+            })
+            .collect::<Vec<_>>();
+        // The boundary crosses every row of tiles the polygon spans, so the limit on the number
+        // of tiles also bounds the rows scanned below
+        for ring in &rings {
+            self.add_line(ring)?;
+        }
+
+        // Fill the tiles whose center lies within the polygon, by the even-odd rule
+        let (min_y, max_y) = rings
+            .iter()
+            .flatten()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), c| {
+                (min.min(c[1]), max.max(c[1]))
+            });
+        if min_y > max_y {
+            return Ok(());
+        }
+        let first_row = self.grid.tile_index(min_y);
+        let last_row = self.grid.tile_index(max_y);
+        for row in first_row..=last_row {
+            let center_y = f64::from(row) + 0.5;
+            let mut crossings = rings
+                .iter()
+                .flat_map(|ring| ring.windows(2))
+                .filter(|edge| (edge[0][1] > center_y) != (edge[1][1] > center_y))
+                .map(|edge| {
+                    let t = (center_y - edge[0][1]) / (edge[1][1] - edge[0][1]);
+                    edge[0][0] + t * (edge[1][0] - edge[0][0])
+                })
+                .collect::<Vec<_>>();
+            crossings.sort_by(f64::total_cmp);
+            for span in crossings.chunks_exact(2) {
+                // The columns whose center lies within the span
+                let first_col = (span[0] - 0.5).ceil().max(0.0) as u32;
+                let last_col = (span[1] - 0.5).floor();
+                if last_col < 0.0 {
+                    continue;
+                }
+                let last_col = (last_col as u32).min(self.grid.num_tiles - 1);
+                for col in first_col..=last_col {
+                    self.insert([col, row])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn project_line(&self, line: &impl LineStringTrait<T = f64>) -> GeoArrowResult<Vec<[f64; 2]>> {
+        line.coords()
+            .map(|coord| self.grid.project(&coord))
+            .collect()
+    }
+
+    fn add_polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) -> GeoArrowResult<()> {
+        let mut rings = vec![];
+        if let Some(exterior) = polygon.exterior() {
+            rings.push(self.project_line(&exterior)?);
+        }
+        for interior in polygon.interiors() {
+            rings.push(self.project_line(&interior)?);
+        }
+        self.add_rings(&rings)
+    }
+
+    fn add_geometry(&mut self, geometry: &impl GeometryTrait<T = f64>) -> GeoArrowResult<()> {
+        match geometry.as_type() {
+            GeometryType::Point(point) => {
+                if let Some(coord) = point.coord() {
+                    self.add_point(self.grid.project(&coord)?)?;
+                }
+            }
+            GeometryType::LineString(line_string) => {
+                let coords = self.project_line(line_string)?;
+                self.add_line(&coords)?;
+            }
+            GeometryType::Polygon(polygon) => self.add_polygon(polygon)?,
+            GeometryType::MultiPoint(multi_point) => {
+                for point in multi_point.points() {
+                    if let Some(coord) = point.coord() {
+                        self.add_point(self.grid.project(&coord)?)?;
+                    }
+                }
+            }
+            GeometryType::MultiLineString(multi_line_string) => {
+                for line_string in multi_line_string.line_strings() {
+                    let coords = self.project_line(&line_string)?;
+                    self.add_line(&coords)?;
+                }
+            }
+            GeometryType::MultiPolygon(multi_polygon) => {
+                for polygon in multi_polygon.polygons() {
+                    self.add_polygon(&polygon)?;
+                }
+            }
+            GeometryType::GeometryCollection(collection) => {
+                for geometry in collection.geometries() {
+                    self.add_geometry(&geometry)?;
+                }
+            }
+            GeometryType::Rect(rect) => {
+                let [min_x, min_y] = self.grid.project(&rect.min())?;
+                let [max_x, max_y] = self.grid.project(&rect.max())?;
+                self.add_rings(&[vec![
+                    [min_x, min_y],
+                    [max_x, min_y],
+                    [max_x, max_y],
+                    [min_x, max_y],
+                ]])?;
+            }
+            GeometryType::Line(line) => {
+                let start = self.grid.project(&line.start())?;
+                let end = self.grid.project(&line.end())?;
+                self.add_line(&[start, end])?;
+            }
+            GeometryType::Triangle(triangle) => {
+                let ring = triangle
+                    .coords()
+                    .iter()
+                    .map(|coord| self.grid.project(coord))
+                    .collect::<GeoArrowResult<Vec<_>>>()?;
+                self.add_rings(&[ring])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The covering tiles as `[column, row]`, ordered by column and then row.
+    fn finish(self) -> BTreeSet<[u32; 2]> {
+        self.tiles
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use geo::{line_string, point, polygon};
+    use geoarrow_array::builder::PointBuilder;
+    use geoarrow_array::test::geometry::from_geoms;
+    use geoarrow_schema::PointType;
+
+    use super::*;
+    use crate::web_mercator::MAX_LATITUDE;
+
+    #[test]
+    fn quadkey() {
+        let tile = TileCoord::new(3, 3, 5);
+        assert_eq!(tile.quadkey(), "213");
+        assert_eq!(TileCoord::from_quadkey("213").unwrap(), tile);
+        assert_eq!(TileCoord::new(0, 0, 0).quadkey(), "");
+        assert!(TileCoord::from_quadkey("124").is_err());
+    }
+
+    #[test]
+    fn tile_bounds() {
+        let [min_x, min_y, max_x, max_y] = TileCoord::new(1, 1, 0).bounds(SourceProjection::LonLat);
+        assert!(min_x.abs() < 1e-9);
+        assert!(min_y.abs() < 1e-9);
+        assert!((max_x - 180.0).abs() < 1e-9);
+        assert!((max_y - MAX_LATITUDE).abs() < 1e-9);
+    }
+
+    #[test]
+    fn invalid_tile() {
+        assert!(TileCoord::new(1, 2, 0).validate().is_err());
+        assert!(TileCoord::new(1, 1, 1).validate().is_ok());
+    }
+
+    #[test]
+    fn projection_from_crs() {
+        let projection = |crs: Crs| SourceProjection::from_crs(&crs);
+        assert_eq!(
+            projection(Crs::default()).unwrap(),
+            SourceProjection::LonLat
+        );
+        assert_eq!(
+            projection(Crs::from_srid("3857".to_string())).unwrap(),
+            SourceProjection::WebMercator
+        );
+        assert!(matches!(
+            projection(Crs::from_srid("27700".to_string())),
+            Err(GeoArrowError::Crs(_))
+        ));
+    }
+
+    #[test]
+    fn points_to_tiles() {
+        let points = vec![
+            Some(point!(x: -122.4194, y: 37.7749)),
+            None,
+            Some(point!(x: 180.0, y: -90.0)),
+        ];
+        let typ = PointType::new(Dimension::XY, Default::default());
+        let array = PointBuilder::from_nullable_geometries(&points, typ)
+            .unwrap()
+            .finish();
+        let x = point_to_tile_x(&array, 10, SourceProjection::LonLat).unwrap();
+        let y = point_to_tile_y(&array, 10, SourceProjection::LonLat).unwrap();
+        assert_eq!((x.value(0), y.value(0)), (163, 395));
+        assert!(x.is_null(1) && y.is_null(1));
+        assert_eq!((x.value(2), y.value(2)), (1023, 1023));
+
+        assert!(point_to_tile_x(&array, 32, SourceProjection::LonLat).is_err());
+    }
+
+    #[test]
+    fn quadkeys_and_envelopes() {
+        let z = UInt8Array::from(vec![Some(3), Some(1), None]);
+        let x = UInt32Array::from(vec![3, 1, 0]);
+        let y = UInt32Array::from(vec![5, 0, 0]);
+        let quadkeys = tile_quadkey(&z, &x, &y).unwrap();
+        assert_eq!(quadkeys.value(0), "213");
+        assert_eq!(quadkeys.value(1), "1");
+        assert!(quadkeys.is_null(2));
+
+        let envelopes = tile_envelope(&z, &x, &y, SourceProjection::WebMercator).unwrap();
+        let envelope = envelopes.value(1).unwrap();
+        assert!(envelope.min().x().abs() < 1e-6);
+        assert!(envelope.min().y().abs() < 1e-6);
+        assert!((envelope.max().x() - 20_037_508.342_789_244).abs() < 1e-6);
+        assert!(envelopes.is_null(2));
+
+        let out_of_range = UInt32Array::from(vec![8, 0, 0]);
+        assert!(tile_quadkey(&z, &out_of_range, &y).is_err());
+    }
+
+    #[test]
+    fn cover_geometries() {
+        let array = from_geoms(
+            &[
+                Some(point!(x: 10.0, y: 10.0).into()),
+                None,
+                // A diagonal line covers the three tiles it passes through, but not the fourth
+                Some(line_string![(x: -10.0, y: -20.0), (x: 10.0, y: 10.0)].into()),
+                Some(
+                    polygon![
+                        (x: -170.0, y: -80.0),
+                        (x: 170.0, y: -80.0),
+                        (x: 170.0, y: 80.0),
+                        (x: -170.0, y: 80.0),
+                    ]
+                    .into(),
+                ),
+            ],
+            Default::default(),
+        );
+        let (rows, x, y) =
+            tiles_covering(&array, 1, SourceProjection::LonLat, DEFAULT_MAX_TILES).unwrap();
+        let tiles = rows
+            .values()
+            .iter()
+            .zip(x.values())
+            .zip(y.values())
+            .map(|((row, x), y)| (*row, *x, *y))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tiles,
+            vec![
+                (0, 1, 0),
+                (2, 0, 1),
+                (2, 1, 0),
+                (2, 1, 1),
+                (3, 0, 0),
+                (3, 0, 1),
+                (3, 1, 0),
+                (3, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn cover_polygon_interior() {
+        // A polygon much larger than a tile covers the tiles within it, not only its boundary
+        let array = from_geoms(
+            &[Some(
+                polygon![
+                    (x: 0.0, y: 0.0),
+                    (x: 10.0, y: 0.0),
+                    (x: 10.0, y: 10.0),
+                    (x: 0.0, y: 10.0),
+                ]
+                .into(),
+            )],
+            Default::default(),
+        );
+        let (_, x, y) =
+            tiles_covering(&array, 8, SourceProjection::LonLat, DEFAULT_MAX_TILES).unwrap();
+        let (x_range, y_range) = (128..=135, 120..=128);
+        assert_eq!(x.len(), x_range.clone().count() * y_range.clone().count());
+        for (x, y) in x.values().iter().zip(y.values()) {
+            assert!(x_range.contains(x) && y_range.contains(y));
+        }
+    }
+
+    #[test]
+    fn cover_too_many_tiles() {
+        let array = from_geoms(
+            &[Some(
+                polygon![
+                    (x: -170.0, y: -80.0),
+                    (x: 170.0, y: -80.0),
+                    (x: 170.0, y: 80.0),
+                    (x: -170.0, y: 80.0),
+                ]
+                .into(),
+            )],
+            Default::default(),
+        );
+        assert!(matches!(
+            tiles_covering(&array, 31, SourceProjection::LonLat, DEFAULT_MAX_TILES),
+            Err(GeoArrowError::Tile(_))
+        ));
+        assert!(tiles_covering(&array, 1, SourceProjection::LonLat, 3).is_err());
+        assert!(tiles_covering(&array, 1, SourceProjection::LonLat, 4).is_ok());
+    }
+}
@@ -17,6 +17,7 @@ use geo_traits::to_geo::{
     ToGeoPoint, ToGeoPolygon, ToGeoRect, ToGeoTriangle,
 };
 use geo_traits::{GeometryCollectionTrait, GeometryTrait, GeometryType};
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
 use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 
 /// Convert any [geo_traits] Geometry to a [`geo::Geometry`].
@@ -64,3 +65,17 @@ fn geometry_collection_to_geo<T: CoordNum>(
             .collect::<GeoArrowResult<_>>()?,
     ))
 }
+
+/// Convert every geometry of an array to a [`geo::Geometry`], keeping nulls.
+pub fn array_to_geo(array: &dyn GeoArrowArray) -> GeoArrowResult<Vec<Option<Geometry>>> {
+    downcast_geoarrow_array!(array, _array_to_geo_impl)
+}
+
+fn _array_to_geo_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+) -> GeoArrowResult<Vec<Option<Geometry>>> {
+    array
+        .iter()
+        .map(|geom| geom.map(|geom| geometry_to_geo(&geom?)).transpose())
+        .collect()
+}
@@ -0,0 +1,56 @@
+//! The spherical Web Mercator projection (EPSG:3857) of longitude and latitude (EPSG:4326).
+
+use std::f64::consts::PI;
+
+use geo::Coord;
+
+/// The equatorial radius of the WGS84 ellipsoid, in meters, as used by Web Mercator.
+pub const EARTH_RADIUS: f64 = 6_378_137.0;
+
+/// The largest latitude representable in Web Mercator.
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Project longitude and latitude in degrees to Web Mercator in meters.
+///
+/// Latitudes are clamped to [`MAX_LATITUDE`], as the poles are infinitely far away.
+pub fn lon_lat_to_web_mercator(coord: Coord) -> Coord {
+    let lat = coord.y.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+    Coord {
+        x: coord.x.to_radians() * EARTH_RADIUS,
+        y: (PI / 4.0 + lat / 2.0).tan().ln() * EARTH_RADIUS,
+    }
+}
+
+/// The inverse of [`lon_lat_to_web_mercator`].
+pub fn web_mercator_to_lon_lat(coord: Coord) -> Coord {
+    Coord {
+        x: (coord.x / EARTH_RADIUS).to_degrees(),
+        y: (coord.y / EARTH_RADIUS).sinh().atan().to_degrees(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let coord = Coord {
+            x: -71.104,
+            y: 42.315,
+        };
+        let projected = lon_lat_to_web_mercator(coord);
+        assert!((projected.x - -7_915_261.07).abs() < 1.0);
+        assert!((projected.y - 5_208_282.33).abs() < 1.0);
+        let round_trip = web_mercator_to_lon_lat(projected);
+        assert!((round_trip.x - coord.x).abs() < 1e-9);
+        assert!((round_trip.y - coord.y).abs() < 1e-9);
+    }
+
+    #[test]
+    fn clamps_poles() {
+        let projected = lon_lat_to_web_mercator(Coord { x: 180.0, y: 90.0 });
+        assert!((projected.x - projected.y).abs() < 1e-6);
+        assert!((web_mercator_to_lon_lat(projected).y - MAX_LATITUDE).abs() < 1e-9);
+    }
+}
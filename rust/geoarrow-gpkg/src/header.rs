@@ -0,0 +1,275 @@
+//! Encoding and decoding of the GeoPackage binary geometry header.
+//!
+//! Every geometry blob in a GeoPackage feature table is a small header followed by ISO WKB. See
+//! <https://www.geopackage.org/spec140/index.html#gpb_format>.
+
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+const MAGIC: &[u8; 2] = b"GP";
+const VERSION: u8 = 0;
+
+const FLAG_LITTLE_ENDIAN: u8 = 0b0000_0001;
+const FLAG_ENVELOPE_MASK: u8 = 0b0000_1110;
+const FLAG_EMPTY: u8 = 0b0001_0000;
+const FLAG_EXTENDED: u8 = 0b0010_0000;
+
+/// A 2D envelope of a geometry.
+///
+/// An envelope that has not seen any coordinates is empty.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Envelope {
+    pub(crate) minx: f64,
+    pub(crate) miny: f64,
+    pub(crate) maxx: f64,
+    pub(crate) maxy: f64,
+}
+
+impl Envelope {
+    pub(crate) fn new() -> Self {
+        Self {
+            minx: f64::INFINITY,
+            miny: f64::INFINITY,
+            maxx: f64::NEG_INFINITY,
+            maxy: f64::NEG_INFINITY,
+        }
+    }
+
+    pub(crate) fn from_geometry(geometry: &impl GeometryTrait<T = f64>) -> Self {
+        let mut envelope = Self::new();
+        envelope.add_geometry(geometry);
+        envelope
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.minx > self.maxx || self.miny > self.maxy
+    }
+
+    /// Whether this envelope intersects the box `(minx, miny, maxx, maxy)`.
+    pub(crate) fn intersects(&self, bbox: (f64, f64, f64, f64)) -> bool {
+        let (minx, miny, maxx, maxy) = bbox;
+        !self.is_empty()
+            && self.minx <= maxx
+            && self.maxx >= minx
+            && self.miny <= maxy
+            && self.maxy >= miny
+    }
+
+    pub(crate) fn union(&mut self, other: &Envelope) {
+        self.minx = self.minx.min(other.minx);
+        self.miny = self.miny.min(other.miny);
+        self.maxx = self.maxx.max(other.maxx);
+        self.maxy = self.maxy.max(other.maxy);
+    }
+
+    fn add_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
+        // NaN coordinates, as used for empty points, never compare as smaller or larger
+        let (x, y) = (coord.x(), coord.y());
+        if x < self.minx {
+            self.minx = x;
+        }
+        if x > self.maxx {
+            self.maxx = x;
+        }
+        if y < self.miny {
+            self.miny = y;
+        }
+        if y > self.maxy {
+            self.maxy = y;
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &impl LineStringTrait<T = f64>) {
+        line_string.coords().for_each(|c| self.add_coord(&c));
+    }
+
+    fn add_polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) {
+        // Interior rings lie within the exterior ring
+        if let Some(exterior) = polygon.exterior() {
+            self.add_line_string(&exterior);
+        }
+    }
+
+    fn add_geometry(&mut self, geometry: &impl GeometryTrait<T = f64>) {
+        match geometry.as_type() {
+            GeometryType::Point(g) => {
+                if let Some(c) = g.coord() {
+                    self.add_coord(&c)
+                }
+            }
+            GeometryType::LineString(g) => self.add_line_string(g),
+            GeometryType::Polygon(g) => self.add_polygon(g),
+            GeometryType::MultiPoint(g) => g.points().for_each(|p| {
+                if let Some(c) = p.coord() {
+                    self.add_coord(&c)
+                }
+            }),
+            GeometryType::MultiLineString(g) => {
+                g.line_strings().for_each(|l| self.add_line_string(&l))
+            }
+            GeometryType::MultiPolygon(g) => g.polygons().for_each(|p| self.add_polygon(&p)),
+            GeometryType::GeometryCollection(g) => {
+                g.geometries().for_each(|g| self.add_geometry(&g))
+            }
+            GeometryType::Rect(g) => {
+                self.add_coord(&g.min());
+                self.add_coord(&g.max());
+            }
+            GeometryType::Triangle(g) => g.coords().iter().for_each(|c| self.add_coord(c)),
+            GeometryType::Line(g) => g.coords().iter().for_each(|c| self.add_coord(c)),
+        }
+    }
+}
+
+/// A decoded GeoPackage geometry blob.
+#[derive(Debug)]
+pub(crate) struct GpkgGeometry<'a> {
+    pub(crate) srs_id: i32,
+    pub(crate) envelope: Option<Envelope>,
+    pub(crate) empty: bool,
+    pub(crate) wkb: &'a [u8],
+}
+
+/// Parse the header of a GeoPackage geometry blob.
+pub(crate) fn parse_blob(buf: &[u8]) -> GeoArrowResult<GpkgGeometry<'_>> {
+    if buf.len() < 8 || &buf[0..2] != MAGIC {
+        return Err(GeoArrowError::GeoPackage(
+            "Geometry blob is missing the 'GP' magic bytes".to_string(),
+        ));
+    }
+    if buf[2] != VERSION {
+        return Err(GeoArrowError::GeoPackage(format!(
+            "Unsupported GeoPackage binary version {}",
+            buf[2]
+        )));
+    }
+
+    let flags = buf[3];
+    if flags & FLAG_EXTENDED != 0 {
+        return Err(GeoArrowError::GeoPackage(
+            "Extended GeoPackage geometry types are not supported".to_string(),
+        ));
+    }
+    let little_endian = flags & FLAG_LITTLE_ENDIAN != 0;
+    let read_f64 = |bytes: &[u8]| {
+        let bytes: [u8; 8] = bytes.try_into().unwrap();
+        if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        }
+    };
+
+    let srs_id_bytes: [u8; 4] = buf[4..8].try_into().unwrap();
+    let srs_id = if little_endian {
+        i32::from_le_bytes(srs_id_bytes)
+    } else {
+        i32::from_be_bytes(srs_id_bytes)
+    };
+
+    let num_doubles = match (flags & FLAG_ENVELOPE_MASK) >> 1 {
+        0 => 0,
+        1 => 4,
+        2 | 3 => 6,
+        4 => 8,
+        other => {
+            return Err(GeoArrowError::GeoPackage(format!(
+                "Invalid GeoPackage envelope indicator {other}"
+            )));
+        }
+    };
+    let wkb_start = 8 + num_doubles * 8;
+    if buf.len() < wkb_start {
+        return Err(GeoArrowError::GeoPackage(
+            "Geometry blob is shorter than its header".to_string(),
+        ));
+    }
+
+    // The envelope is ordered minx, maxx, miny, maxy, followed by the optional z or m range
+    let envelope = (num_doubles > 0).then(|| Envelope {
+        minx: read_f64(&buf[8..16]),
+        maxx: read_f64(&buf[16..24]),
+        miny: read_f64(&buf[24..32]),
+        maxy: read_f64(&buf[32..40]),
+    });
+
+    Ok(GpkgGeometry {
+        srs_id,
+        envelope,
+        empty: flags & FLAG_EMPTY != 0,
+        wkb: &buf[wkb_start..],
+    })
+}
+
+/// Write a little-endian GeoPackage geometry header followed by `wkb`.
+///
+/// An empty `envelope` flags the geometry as empty. The envelope is only written when
+/// `write_envelope` is `true`.
+pub(crate) fn write_blob(
+    out: &mut Vec<u8>,
+    srs_id: i32,
+    envelope: &Envelope,
+    write_envelope: bool,
+    wkb: &[u8],
+) {
+    let empty = envelope.is_empty();
+    let write_envelope = write_envelope && !empty;
+
+    let mut flags = FLAG_LITTLE_ENDIAN;
+    if empty {
+        flags |= FLAG_EMPTY;
+    }
+    if write_envelope {
+        flags |= 1 << 1;
+    }
+
+    out.extend_from_slice(MAGIC);
+    out.push(VERSION);
+    out.push(flags);
+    out.extend_from_slice(&srs_id.to_le_bytes());
+    if write_envelope {
+        for value in [envelope.minx, envelope.maxx, envelope.miny, envelope.maxy] {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    out.extend_from_slice(wkb);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip_header() {
+        let envelope = Envelope {
+            minx: 1.0,
+            miny: 2.0,
+            maxx: 3.0,
+            maxy: 4.0,
+        };
+        let wkb = [1, 2, 3];
+
+        let mut buf = vec![];
+        write_blob(&mut buf, 4326, &envelope, true, &wkb);
+        let geom = parse_blob(&buf).unwrap();
+        assert_eq!(geom.srs_id, 4326);
+        assert_eq!(geom.envelope, Some(envelope));
+        assert!(!geom.empty);
+        assert_eq!(geom.wkb, &wkb);
+
+        buf.clear();
+        write_blob(&mut buf, 4326, &Envelope::new(), true, &wkb);
+        let geom = parse_blob(&buf).unwrap();
+        assert_eq!(geom.envelope, None);
+        assert!(geom.empty);
+        assert_eq!(geom.wkb, &wkb);
+    }
+
+    #[test]
+    fn invalid_magic() {
+        assert!(parse_blob(b"XX\0\x01\0\0\0\0").is_err());
+    }
+}
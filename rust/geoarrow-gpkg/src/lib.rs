@@ -0,0 +1,18 @@
+//! Read from and write to [GeoPackage](https://www.geopackage.org/) files.
+//!
+//! SQLite is bundled through [`rusqlite`], which is re-exported so that callers can pass their
+//! own connections.
+
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+mod header;
+mod metadata;
+pub mod reader;
+pub mod writer;
+
+use geoarrow_schema::error::GeoArrowError;
+pub use rusqlite;
+
+pub(crate) fn sqlite_error(err: rusqlite::Error) -> GeoArrowError {
+    GeoArrowError::External(Box::new(err))
+}
@@ -0,0 +1,341 @@
+//! GeoPackage metadata tables: spatial reference systems, contents and geometry columns.
+
+use std::sync::Arc;
+
+use geoarrow_schema::crs::CrsTransform;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{
+    CoordType, Crs, Dimension, GeoArrowType, GeometryCollectionType, GeometryType, LineStringType,
+    Metadata, MultiLineStringType, MultiPointType, MultiPolygonType, PointType, PolygonType,
+};
+use rusqlite::{Connection, OptionalExtension, params};
+
+use crate::sqlite_error;
+
+/// The `application_id` of a GeoPackage database, `"GPKG"` as a big-endian integer.
+const APPLICATION_ID: i32 = 0x4750_4B47;
+
+/// The `user_version` of a GeoPackage 1.4.0 database.
+const USER_VERSION: i32 = 10400;
+
+/// The SRS id of the undefined Cartesian SRS that every GeoPackage contains.
+pub(crate) const UNDEFINED_CARTESIAN_SRS_ID: i32 = -1;
+
+/// The extension name registered in `gpkg_extensions` for RTree spatial indexes.
+pub(crate) const RTREE_EXTENSION: &str = "gpkg_rtree_index";
+
+const CORE_TABLES: &str = r#"
+CREATE TABLE IF NOT EXISTS gpkg_spatial_ref_sys (
+    srs_name TEXT NOT NULL,
+    srs_id INTEGER PRIMARY KEY,
+    organization TEXT NOT NULL,
+    organization_coordsys_id INTEGER NOT NULL,
+    definition TEXT NOT NULL,
+    description TEXT
+);
+CREATE TABLE IF NOT EXISTS gpkg_contents (
+    table_name TEXT NOT NULL PRIMARY KEY,
+    data_type TEXT NOT NULL,
+    identifier TEXT UNIQUE,
+    description TEXT DEFAULT '',
+    last_change DATETIME NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    min_x DOUBLE,
+    min_y DOUBLE,
+    max_x DOUBLE,
+    max_y DOUBLE,
+    srs_id INTEGER,
+    CONSTRAINT fk_gc_r_srs_id FOREIGN KEY (srs_id) REFERENCES gpkg_spatial_ref_sys(srs_id)
+);
+CREATE TABLE IF NOT EXISTS gpkg_geometry_columns (
+    table_name TEXT NOT NULL,
+    column_name TEXT NOT NULL,
+    geometry_type_name TEXT NOT NULL,
+    srs_id INTEGER NOT NULL,
+    z TINYINT NOT NULL,
+    m TINYINT NOT NULL,
+    CONSTRAINT pk_geom_cols PRIMARY KEY (table_name, column_name),
+    CONSTRAINT uk_gc_table_name UNIQUE (table_name),
+    CONSTRAINT fk_gc_tn FOREIGN KEY (table_name) REFERENCES gpkg_contents(table_name),
+    CONSTRAINT fk_gc_srs FOREIGN KEY (srs_id) REFERENCES gpkg_spatial_ref_sys (srs_id)
+);
+CREATE TABLE IF NOT EXISTS gpkg_extensions (
+    table_name TEXT,
+    column_name TEXT,
+    extension_name TEXT NOT NULL,
+    definition TEXT NOT NULL,
+    scope TEXT NOT NULL,
+    CONSTRAINT ge_tce UNIQUE (table_name, column_name, extension_name)
+);
+INSERT OR IGNORE INTO gpkg_spatial_ref_sys VALUES
+    ('Undefined cartesian SRS', -1, 'NONE', -1, 'undefined', 'undefined cartesian coordinate reference system'),
+    ('Undefined geographic SRS', 0, 'NONE', 0, 'undefined', 'undefined geographic coordinate reference system'),
+    ('WGS 84 geodetic', 4326, 'EPSG', 4326, 'GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563,AUTHORITY["EPSG","7030"]],AUTHORITY["EPSG","6326"]],PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],UNIT["degree",0.0174532925199433,AUTHORITY["EPSG","9122"]],AXIS["Latitude",NORTH],AXIS["Longitude",EAST],AUTHORITY["EPSG","4326"]]', 'longitude/latitude coordinates in decimal degrees on the WGS 84 spheroid');
+"#;
+
+/// Quote an SQLite identifier.
+pub(crate) fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// The name of the RTree virtual table indexing `table`.`column`.
+pub(crate) fn rtree_table_name(table: &str, column: &str) -> String {
+    format!("rtree_{table}_{column}")
+}
+
+/// Create the GeoPackage core tables if they don't yet exist.
+pub(crate) fn initialize(conn: &Connection) -> GeoArrowResult<()> {
+    let application_id: i32 = conn
+        .query_row("PRAGMA application_id", [], |row| row.get(0))
+        .map_err(sqlite_error)?;
+    if application_id == 0 {
+        conn.pragma_update(None, "application_id", APPLICATION_ID)
+            .map_err(sqlite_error)?;
+        conn.pragma_update(None, "user_version", USER_VERSION)
+            .map_err(sqlite_error)?;
+    }
+    conn.execute_batch(CORE_TABLES).map_err(sqlite_error)
+}
+
+/// The registration of a geometry column in `gpkg_geometry_columns`.
+#[derive(Debug, Clone)]
+pub(crate) struct GeometryColumn {
+    pub(crate) column_name: String,
+    pub(crate) geometry_type_name: String,
+    pub(crate) srs_id: i32,
+    pub(crate) z: u8,
+    pub(crate) m: u8,
+}
+
+impl GeometryColumn {
+    pub(crate) fn read(conn: &Connection, table: &str) -> GeoArrowResult<Self> {
+        conn.query_row(
+            "SELECT column_name, geometry_type_name, srs_id, z, m FROM gpkg_geometry_columns WHERE table_name = ?1",
+            [table],
+            |row| {
+                Ok(Self {
+                    column_name: row.get(0)?,
+                    geometry_type_name: row.get(1)?,
+                    srs_id: row.get(2)?,
+                    z: row.get(3)?,
+                    m: row.get(4)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(sqlite_error)?
+        .ok_or_else(|| {
+            GeoArrowError::GeoPackage(format!("'{table}' is not a GeoPackage feature table"))
+        })
+    }
+
+    pub(crate) fn write(&self, conn: &Connection, table: &str) -> GeoArrowResult<()> {
+        conn.execute(
+            "INSERT INTO gpkg_geometry_columns (table_name, column_name, geometry_type_name, srs_id, z, m) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                table,
+                self.column_name,
+                self.geometry_type_name,
+                self.srs_id,
+                self.z,
+                self.m
+            ],
+        )
+        .map_err(sqlite_error)?;
+        Ok(())
+    }
+
+    /// Describe a GeoArrow geometry column for `gpkg_geometry_columns`.
+    pub(crate) fn from_data_type(column_name: &str, data_type: &GeoArrowType, srs_id: i32) -> Self {
+        use GeoArrowType::*;
+
+        let geometry_type_name = match data_type {
+            Point(_) => "POINT",
+            LineString(_) => "LINESTRING",
+            Polygon(_) | Rect(_) => "POLYGON",
+            MultiPoint(_) => "MULTIPOINT",
+            MultiLineString(_) => "MULTILINESTRING",
+            MultiPolygon(_) => "MULTIPOLYGON",
+            GeometryCollection(_) => "GEOMETRYCOLLECTION",
+            _ => "GEOMETRY",
+        };
+        // 0: prohibited, 1: mandatory, 2: optional
+        let (z, m) = match data_type.dimension() {
+            Some(Dimension::XY) => (0, 0),
+            Some(Dimension::XYZ) => (1, 0),
+            Some(Dimension::XYM) => (0, 1),
+            Some(Dimension::XYZM) => (1, 1),
+            None => (2, 2),
+        };
+
+        Self {
+            column_name: column_name.to_string(),
+            geometry_type_name: geometry_type_name.to_string(),
+            srs_id,
+            z,
+            m,
+        }
+    }
+
+    /// The GeoArrow type to decode this column into.
+    ///
+    /// Columns where Z or M values are optional, or whose geometry type is `GEOMETRY`, are
+    /// decoded into a [`GeometryType`] array.
+    pub(crate) fn data_type(&self, coord_type: CoordType, metadata: Arc<Metadata>) -> GeoArrowType {
+        let dim = match (self.z, self.m) {
+            (0, 0) => Some(Dimension::XY),
+            (1, 0) => Some(Dimension::XYZ),
+            (0, 1) => Some(Dimension::XYM),
+            (1, 1) => Some(Dimension::XYZM),
+            _ => None,
+        };
+
+        let Some(dim) = dim else {
+            return GeoArrowType::Geometry(GeometryType::new(metadata).with_coord_type(coord_type));
+        };
+        match self.geometry_type_name.to_ascii_uppercase().as_str() {
+            "POINT" => {
+                GeoArrowType::Point(PointType::new(dim, metadata).with_coord_type(coord_type))
+            }
+            "LINESTRING" => GeoArrowType::LineString(
+                LineStringType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+            "POLYGON" => {
+                GeoArrowType::Polygon(PolygonType::new(dim, metadata).with_coord_type(coord_type))
+            }
+            "MULTIPOINT" => GeoArrowType::MultiPoint(
+                MultiPointType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+            "MULTILINESTRING" => GeoArrowType::MultiLineString(
+                MultiLineStringType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+            "MULTIPOLYGON" => GeoArrowType::MultiPolygon(
+                MultiPolygonType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+            "GEOMETRYCOLLECTION" => GeoArrowType::GeometryCollection(
+                GeometryCollectionType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+            _ => GeoArrowType::Geometry(GeometryType::new(metadata).with_coord_type(coord_type)),
+        }
+    }
+}
+
+/// Whether the database has a column `column` in `table`.
+fn has_column(conn: &Connection, table: &str, column: &str) -> GeoArrowResult<bool> {
+    let mut stmt = conn
+        .prepare("SELECT 1 FROM pragma_table_info(?1) WHERE name = ?2")
+        .map_err(sqlite_error)?;
+    stmt.exists([table, column]).map_err(sqlite_error)
+}
+
+/// Look up the CRS of a `gpkg_spatial_ref_sys` entry.
+///
+/// This prefers, in order, a WKT2 definition from the `gpkg_crs_wkt` extension, an
+/// `authority:code` identifier, and the WKT1 `definition`. The undefined SRSs map to an empty
+/// [`Crs`].
+pub(crate) fn read_crs(conn: &Connection, srs_id: i32) -> GeoArrowResult<Crs> {
+    let wkt2_column = if has_column(conn, "gpkg_spatial_ref_sys", "definition_12_063")? {
+        "definition_12_063"
+    } else {
+        "NULL"
+    };
+    let sql = format!(
+        "SELECT organization, organization_coordsys_id, definition, {wkt2_column} FROM gpkg_spatial_ref_sys WHERE srs_id = ?1"
+    );
+    let row = conn
+        .query_row(&sql, [srs_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+            ))
+        })
+        .optional()
+        .map_err(sqlite_error)?;
+
+    let Some((organization, coordsys_id, definition, wkt2)) = row else {
+        return Err(GeoArrowError::GeoPackage(format!(
+            "srs_id {srs_id} is not defined in gpkg_spatial_ref_sys"
+        )));
+    };
+
+    let is_defined = |s: &str| !s.is_empty() && !s.eq_ignore_ascii_case("undefined");
+    if let Some(wkt2) = wkt2.filter(|s| is_defined(s)) {
+        Ok(Crs::from_wkt2_2019(wkt2))
+    } else if is_defined(&organization) && !organization.eq_ignore_ascii_case("NONE") {
+        Ok(Crs::from_authority_code(format!(
+            "{}:{coordsys_id}",
+            organization.to_ascii_uppercase()
+        )))
+    } else if is_defined(&definition) {
+        Ok(Crs::from_unknown_crs_type(definition))
+    } else {
+        Ok(Crs::default())
+    }
+}
+
+/// Find or create the `gpkg_spatial_ref_sys` entry for a CRS, returning its `srs_id`.
+///
+/// A CRS with an EPSG code (see [`Crs::srid`]) is registered under that code. Any other CRS is
+/// converted to WKT with `crs_transform` and registered under a new id. If the CRS is missing or
+/// can't be converted, the undefined Cartesian SRS is used.
+pub(crate) fn write_crs(
+    conn: &Connection,
+    crs: &Crs,
+    crs_transform: &dyn CrsTransform,
+) -> GeoArrowResult<i32> {
+    if crs.crs_value().is_none() {
+        return Ok(UNDEFINED_CARTESIAN_SRS_ID);
+    }
+
+    if let Some(srid) = crs.srid() {
+        let definition = crs_transform
+            .extract_wkt(crs)?
+            .unwrap_or_else(|| "undefined".to_string());
+        conn.execute(
+            "INSERT OR IGNORE INTO gpkg_spatial_ref_sys (srs_name, srs_id, organization, organization_coordsys_id, definition) VALUES (?1, ?2, 'EPSG', ?2, ?3)",
+            params![format!("EPSG:{srid}"), srid, definition],
+        )
+        .map_err(sqlite_error)?;
+        return Ok(srid);
+    }
+
+    let Some(definition) = crs_transform.extract_wkt(crs)? else {
+        return Ok(UNDEFINED_CARTESIAN_SRS_ID);
+    };
+    let existing = conn
+        .query_row(
+            "SELECT srs_id FROM gpkg_spatial_ref_sys WHERE definition = ?1",
+            [&definition],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(sqlite_error)?;
+    if let Some(srs_id) = existing {
+        return Ok(srs_id);
+    }
+
+    // Custom definitions are numbered from 100000 to stay clear of EPSG codes
+    let srs_id: i32 = conn
+        .query_row(
+            "SELECT MAX(MAX(srs_id) + 1, 100000) FROM gpkg_spatial_ref_sys",
+            [],
+            |row| row.get(0),
+        )
+        .map_err(sqlite_error)?;
+    conn.execute(
+        "INSERT INTO gpkg_spatial_ref_sys (srs_name, srs_id, organization, organization_coordsys_id, definition) VALUES (?1, ?2, 'NONE', ?2, ?3)",
+        params![format!("srs_{srs_id}"), srs_id, definition],
+    )
+    .map_err(sqlite_error)?;
+    Ok(srs_id)
+}
+
+/// Whether `table`.`column` has an RTree spatial index.
+pub(crate) fn has_rtree(conn: &Connection, table: &str, column: &str) -> GeoArrowResult<bool> {
+    let mut stmt = conn
+        .prepare("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1")
+        .map_err(sqlite_error)?;
+    stmt.exists([rtree_table_name(table, column)])
+        .map_err(sqlite_error)
+}
@@ -0,0 +1,446 @@
+//! Read GeoPackage feature tables into GeoArrow record batches.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::builder::{
+    BinaryBuilder, BooleanBuilder, Float64Builder, Int64Builder, StringBuilder,
+};
+use arrow_array::{ArrayRef, RecordBatch, RecordBatchReader};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::WkbArray;
+use geoarrow_array::cast::from_wkb;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{CoordType, GeoArrowType, Metadata};
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::{Connection, OpenFlags, params_from_iter};
+use wkb::reader::Wkb;
+
+use crate::header::{Envelope, parse_blob};
+use crate::metadata::{GeometryColumn, has_rtree, quote_identifier, read_crs, rtree_table_name};
+use crate::sqlite_error;
+
+/// Options for the GeoPackage reader
+#[derive(Debug, Clone)]
+pub struct GeoPackageReaderOptions {
+    /// The GeoArrow coordinate type to use in the geometry arrays.
+    pub coord_type: CoordType,
+
+    /// The number of rows in each batch.
+    ///
+    /// Must not be zero. If set to `None`, batches of 65,536 rows are read.
+    pub batch_size: Option<usize>,
+
+    /// A spatial filter for reading rows, as `(minx, miny, maxx, maxy)`.
+    ///
+    /// The table's RTree spatial index is used when it exists. Otherwise each geometry's envelope
+    /// is checked as it is read. If set to `None`, no spatial filtering will be performed.
+    pub bbox: Option<(f64, f64, f64, f64)>,
+}
+
+impl Default for GeoPackageReaderOptions {
+    fn default() -> Self {
+        Self {
+            coord_type: Default::default(),
+            batch_size: Some(65_536),
+            bbox: None,
+        }
+    }
+}
+
+/// A builder for [GeoPackageRecordBatchIterator]
+pub struct GeoPackageReaderBuilder {
+    conn: Connection,
+}
+
+impl GeoPackageReaderBuilder {
+    /// Open a GeoPackage file read-only.
+    pub fn open(path: impl AsRef<Path>) -> GeoArrowResult<Self> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .map_err(sqlite_error)?;
+        Ok(Self::new(conn))
+    }
+
+    /// Read from an existing SQLite connection.
+    pub fn new(conn: Connection) -> Self {
+        Self { conn }
+    }
+
+    /// The names of the feature tables in this GeoPackage.
+    pub fn layers(&self) -> GeoArrowResult<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT table_name FROM gpkg_contents WHERE data_type = 'features' ORDER BY table_name",
+            )
+            .map_err(sqlite_error)?;
+        stmt.query_map([], |row| row.get(0))
+            .map_err(sqlite_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(sqlite_error)
+    }
+
+    /// Read the feature table `table`.
+    pub fn read(
+        self,
+        table: &str,
+        options: GeoPackageReaderOptions,
+    ) -> GeoArrowResult<GeoPackageRecordBatchIterator> {
+        if options.batch_size == Some(0) {
+            return Err(GeoArrowError::GeoPackage(
+                "batch_size must be greater than zero".to_string(),
+            ));
+        }
+
+        let geometry_column = GeometryColumn::read(&self.conn, table)?;
+        let crs = read_crs(&self.conn, geometry_column.srs_id)?;
+        let geometry_type = geometry_column.data_type(
+            options.coord_type,
+            Arc::new(Metadata::new(crs, Default::default())),
+        );
+
+        let mut columns = vec![];
+        {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT name, type, \"notnull\", pk FROM pragma_table_info(?1)")
+                .map_err(sqlite_error)?;
+            let mut rows = stmt.query([table]).map_err(sqlite_error)?;
+            while let Some(row) = rows.next().map_err(sqlite_error)? {
+                let name: String = row.get(0).map_err(sqlite_error)?;
+                let declared_type: String = row.get(1).map_err(sqlite_error)?;
+                let not_null: bool = row.get(2).map_err(sqlite_error)?;
+                let pk: i64 = row.get(3).map_err(sqlite_error)?;
+
+                if name == geometry_column.column_name {
+                    columns.push(Column::Geometry);
+                    continue;
+                }
+                let (kind, data_type) = parse_declared_type(&declared_type);
+                let field = Field::new(name, data_type, !(not_null || pk == 1));
+                columns.push(Column::Property(kind, field));
+            }
+        }
+
+        let select_list = columns
+            .iter()
+            .map(|c| match c {
+                Column::Property(_, field) => quote_identifier(field.name()),
+                Column::Geometry => quote_identifier(&geometry_column.column_name),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let use_rtree =
+            options.bbox.is_some() && has_rtree(&self.conn, table, &geometry_column.column_name)?;
+        let rtree_filter = if use_rtree {
+            format!(
+                " AND rowid IN (SELECT id FROM {} WHERE minx <= ?5 AND maxx >= ?3 AND miny <= ?6 AND maxy >= ?4)",
+                quote_identifier(&rtree_table_name(table, &geometry_column.column_name))
+            )
+        } else {
+            String::new()
+        };
+        // The rowid is selected first to page through the table. For a GeoPackage feature table it
+        // is the same as the integer primary key, which is also the id of the RTree index.
+        let sql = format!(
+            "SELECT rowid, {select_list} FROM {} WHERE rowid > ?1{rtree_filter} ORDER BY rowid LIMIT ?2",
+            quote_identifier(table)
+        );
+
+        let fields = columns
+            .iter()
+            .map(|c| match c {
+                Column::Property(_, field) => Arc::new(field.clone()),
+                Column::Geometry => {
+                    Arc::new(geometry_type.to_field(&geometry_column.column_name, true))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok(GeoPackageRecordBatchIterator {
+            conn: self.conn,
+            sql,
+            columns,
+            srs_id: geometry_column.srs_id,
+            geometry_type,
+            schema: Arc::new(Schema::new(fields)),
+            bbox: options.bbox,
+            use_rtree,
+            batch_size: options.batch_size.unwrap_or(65_536),
+            last_rowid: i64::MIN,
+            finished: false,
+        })
+    }
+}
+
+/// A column of a feature table.
+#[derive(Debug)]
+enum Column {
+    /// A property column with the storage class it is read as and its Arrow field.
+    Property(ValueKind, Field),
+    Geometry,
+}
+
+/// The SQLite storage class a property column is read as.
+#[derive(Debug, Clone, Copy)]
+enum ValueKind {
+    Boolean,
+    Integer,
+    Real,
+    Text,
+    Blob,
+}
+
+/// Map a GeoPackage column type to the storage class to read and the Arrow type to return.
+///
+/// Dates and datetimes are stored as ISO 8601 text and parsed after reading. Columns with types
+/// outside the GeoPackage spec are read as text.
+fn parse_declared_type(declared_type: &str) -> (ValueKind, DataType) {
+    let base_type = declared_type
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_uppercase();
+    match base_type.as_str() {
+        "BOOLEAN" => (ValueKind::Boolean, DataType::Boolean),
+        "TINYINT" => (ValueKind::Integer, DataType::Int8),
+        "SMALLINT" => (ValueKind::Integer, DataType::Int16),
+        "MEDIUMINT" => (ValueKind::Integer, DataType::Int32),
+        "INT" | "INTEGER" => (ValueKind::Integer, DataType::Int64),
+        "FLOAT" => (ValueKind::Real, DataType::Float32),
+        "DOUBLE" | "REAL" => (ValueKind::Real, DataType::Float64),
+        "BLOB" => (ValueKind::Blob, DataType::Binary),
+        "DATE" => (ValueKind::Text, DataType::Date32),
+        "DATETIME" => (
+            ValueKind::Text,
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+        ),
+        _ => (ValueKind::Text, DataType::Utf8),
+    }
+}
+
+enum ColumnBuilder {
+    Boolean(BooleanBuilder),
+    Integer(Int64Builder),
+    Real(Float64Builder),
+    Text(StringBuilder),
+    Blob(BinaryBuilder),
+}
+
+impl ColumnBuilder {
+    fn new(kind: ValueKind, capacity: usize) -> Self {
+        match kind {
+            ValueKind::Boolean => Self::Boolean(BooleanBuilder::with_capacity(capacity)),
+            ValueKind::Integer => Self::Integer(Int64Builder::with_capacity(capacity)),
+            ValueKind::Real => Self::Real(Float64Builder::with_capacity(capacity)),
+            ValueKind::Text => Self::Text(StringBuilder::with_capacity(capacity, 0)),
+            ValueKind::Blob => Self::Blob(BinaryBuilder::with_capacity(capacity, 0)),
+        }
+    }
+
+    /// Append a value, coercing between SQLite storage classes where that is lossless.
+    fn append(&mut self, value: ValueRef<'_>, field: &Field) -> GeoArrowResult<()> {
+        match (self, value) {
+            (Self::Boolean(b), ValueRef::Null) => b.append_null(),
+            (Self::Integer(b), ValueRef::Null) => b.append_null(),
+            (Self::Real(b), ValueRef::Null) => b.append_null(),
+            (Self::Text(b), ValueRef::Null) => b.append_null(),
+            (Self::Blob(b), ValueRef::Null) => b.append_null(),
+            (Self::Boolean(b), ValueRef::Integer(v)) => b.append_value(v != 0),
+            (Self::Integer(b), ValueRef::Integer(v)) => b.append_value(v),
+            (Self::Integer(b), ValueRef::Real(v)) if v.fract() == 0.0 => b.append_value(v as i64),
+            (Self::Real(b), ValueRef::Integer(v)) => b.append_value(v as f64),
+            (Self::Real(b), ValueRef::Real(v)) => b.append_value(v),
+            (Self::Text(b), ValueRef::Text(v)) => {
+                let v = std::str::from_utf8(v).map_err(|err| {
+                    GeoArrowError::GeoPackage(format!(
+                        "Invalid UTF-8 in column '{}': {err}",
+                        field.name()
+                    ))
+                })?;
+                b.append_value(v)
+            }
+            (Self::Text(b), ValueRef::Integer(v)) => b.append_value(v.to_string()),
+            (Self::Text(b), ValueRef::Real(v)) => b.append_value(v.to_string()),
+            (Self::Blob(b), ValueRef::Blob(v) | ValueRef::Text(v)) => b.append_value(v),
+            (_, value) => {
+                return Err(GeoArrowError::GeoPackage(format!(
+                    "Unexpected {} value in column '{}' of type {}",
+                    value.data_type(),
+                    field.name(),
+                    field.data_type()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self, data_type: &DataType) -> GeoArrowResult<ArrayRef> {
+        let array: ArrayRef = match self {
+            Self::Boolean(mut b) => Arc::new(b.finish()),
+            Self::Integer(mut b) => Arc::new(b.finish()),
+            Self::Real(mut b) => Arc::new(b.finish()),
+            Self::Text(mut b) => Arc::new(b.finish()),
+            Self::Blob(mut b) => Arc::new(b.finish()),
+        };
+        if array.data_type() == data_type {
+            Ok(array)
+        } else {
+            Ok(arrow_cast::cast(&array, data_type)?)
+        }
+    }
+}
+
+/// An iterator over record batches from a GeoPackage feature table.
+///
+/// Rows are read in `rowid` order, which for a GeoPackage feature table is the order of its integer
+/// primary key. Tables declared `WITHOUT ROWID` are not supported. This implements
+/// [arrow_array::RecordBatchReader], which you can use to access data.
+pub struct GeoPackageRecordBatchIterator {
+    conn: Connection,
+    sql: String,
+    columns: Vec<Column>,
+    /// The `srs_id` of the geometry column, which every geometry blob must match
+    srs_id: i32,
+    geometry_type: GeoArrowType,
+    schema: SchemaRef,
+    bbox: Option<(f64, f64, f64, f64)>,
+    use_rtree: bool,
+    batch_size: usize,
+    /// Batches are paged by rowid rather than held open as a cursor
+    last_rowid: i64,
+    finished: bool,
+}
+
+impl GeoPackageRecordBatchIterator {
+    fn process_batch(&mut self) -> GeoArrowResult<Option<RecordBatch>> {
+        while !self.finished {
+            let mut params = vec![
+                Value::Integer(self.last_rowid),
+                Value::Integer(self.batch_size as i64),
+            ];
+            if let (Some((minx, miny, maxx, maxy)), true) = (self.bbox, self.use_rtree) {
+                params.extend([minx, miny, maxx, maxy].map(Value::Real));
+            }
+
+            let mut builders = self
+                .columns
+                .iter()
+                .map(|c| match c {
+                    Column::Property(kind, _) => Some(ColumnBuilder::new(*kind, self.batch_size)),
+                    Column::Geometry => None,
+                })
+                .collect::<Vec<_>>();
+            let mut geometry_builder = BinaryBuilder::new();
+
+            let mut stmt = self.conn.prepare_cached(&self.sql).map_err(sqlite_error)?;
+            let mut rows = stmt.query(params_from_iter(params)).map_err(sqlite_error)?;
+            let mut num_scanned = 0;
+            let mut num_rows = 0;
+            'rows: while let Some(row) = rows.next().map_err(sqlite_error)? {
+                num_scanned += 1;
+                self.last_rowid = row.get(0).map_err(sqlite_error)?;
+
+                let mut wkb = None;
+                for (i, column) in self.columns.iter().enumerate() {
+                    if !matches!(column, Column::Geometry) {
+                        continue;
+                    }
+                    wkb = match row.get_ref(i + 1).map_err(sqlite_error)? {
+                        ValueRef::Null => None,
+                        ValueRef::Blob(blob) => {
+                            let geometry = parse_blob(blob)?;
+                            if geometry.srs_id != self.srs_id {
+                                return Err(GeoArrowError::GeoPackage(format!(
+                                    "Geometry blob has srs_id {} but its column has srs_id {}",
+                                    geometry.srs_id, self.srs_id
+                                )));
+                            }
+                            if let (Some(bbox), false) = (self.bbox, self.use_rtree) {
+                                let envelope = match geometry.envelope {
+                                    Some(envelope) => envelope,
+                                    None if geometry.empty => Envelope::new(),
+                                    None => Envelope::from_geometry(
+                                        &Wkb::try_new(geometry.wkb).map_err(|err| {
+                                            GeoArrowError::External(Box::new(err))
+                                        })?,
+                                    ),
+                                };
+                                if !envelope.intersects(bbox) {
+                                    continue 'rows;
+                                }
+                            }
+                            Some(geometry.wkb)
+                        }
+                        _ => {
+                            return Err(GeoArrowError::GeoPackage(
+                                "Geometry values must be stored as blobs".to_string(),
+                            ));
+                        }
+                    };
+                }
+                if self.bbox.is_some() && !self.use_rtree && wkb.is_none() {
+                    continue;
+                }
+
+                for (i, (column, builder)) in self.columns.iter().zip(&mut builders).enumerate() {
+                    if let (Column::Property(_, field), Some(builder)) = (column, builder) {
+                        builder.append(row.get_ref(i + 1).map_err(sqlite_error)?, field)?;
+                    }
+                }
+                geometry_builder.append_option(wkb);
+                num_rows += 1;
+            }
+
+            if num_scanned < self.batch_size {
+                self.finished = true;
+            }
+            if num_rows == 0 {
+                continue;
+            }
+
+            let mut geometry_builder = Some(geometry_builder);
+            let arrays = self
+                .columns
+                .iter()
+                .zip(builders)
+                .map(|(column, builder)| match (column, builder) {
+                    (Column::Property(_, field), Some(builder)) => {
+                        builder.finish(field.data_type())
+                    }
+                    _ => {
+                        let wkb_arr = WkbArray::new(
+                            geometry_builder.take().unwrap().finish(),
+                            self.geometry_type.metadata().clone(),
+                        );
+                        Ok(from_wkb(&wkb_arr, self.geometry_type.clone())?.to_array_ref())
+                    }
+                })
+                .collect::<GeoArrowResult<Vec<_>>>()?;
+            return Ok(Some(RecordBatch::try_new(self.schema.clone(), arrays)?));
+        }
+
+        Ok(None)
+    }
+}
+
+impl Iterator for GeoPackageRecordBatchIterator {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.process_batch()
+            .map_err(|err| ArrowError::ExternalError(Box::new(err)))
+            .transpose()
+    }
+}
+
+impl RecordBatchReader for GeoPackageRecordBatchIterator {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
@@ -0,0 +1,584 @@
+//! Write GeoArrow record batches to GeoPackage feature tables.
+
+use std::path::Path;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::TimestampMillisecondType;
+use arrow_array::{
+    Array, ArrayRef, BinaryArray, Float64Array, Int64Array, PrimitiveArray, RecordBatchReader,
+    StringArray,
+};
+use arrow_cast::{CastOptions, cast_with_options};
+use arrow_schema::{DataType, Schema, TimeUnit};
+use geo_traits::GeometryTrait;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::cast::to_wkb;
+use geoarrow_array::{GeoArrowArrayAccessor, IntoArrow};
+use geoarrow_schema::GeoArrowType;
+use geoarrow_schema::crs::{CrsTransform, DefaultCrsTransform};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use rusqlite::types::{ToSqlOutput, ValueRef};
+use rusqlite::{Connection, params, params_from_iter};
+
+use crate::header::{Envelope, write_blob};
+use crate::metadata::{
+    GeometryColumn, RTREE_EXTENSION, initialize, quote_identifier, rtree_table_name, write_crs,
+};
+use crate::sqlite_error;
+
+/// Options for the GeoPackage writer
+#[derive(Debug)]
+pub struct GeoPackageWriterOptions {
+    /// Create an RTree spatial index on the geometry column.
+    pub spatial_index: bool,
+    /// Store the envelope of each non-point geometry in its GeoPackage geometry header.
+    pub write_envelopes: bool,
+    /// Description of the table, stored in `gpkg_contents`.
+    pub description: Option<String>,
+    /// A method for transforming CRS to WKT
+    ///
+    /// This is implemented as an external trait so that external libraries can inject the method
+    /// for CRS conversions. CRSs with an EPSG code are registered under that code even without a
+    /// WKT definition.
+    pub crs_transform: Option<Box<dyn CrsTransform>>,
+}
+
+impl Default for GeoPackageWriterOptions {
+    fn default() -> Self {
+        Self {
+            spatial_index: true,
+            write_envelopes: true,
+            description: None,
+            crs_transform: Some(Box::new(DefaultCrsTransform::default())),
+        }
+    }
+}
+
+/// Write a stream of GeoArrow RecordBatches to a new feature table in a GeoPackage file.
+///
+/// The file is created if it doesn't exist. The table must not already exist.
+pub fn write_geopackage(
+    reader: impl RecordBatchReader,
+    path: impl AsRef<Path>,
+    table_name: &str,
+) -> GeoArrowResult<()> {
+    write_geopackage_with_options(reader, path, table_name, Default::default())
+}
+
+/// Write a stream of GeoArrow RecordBatches to a GeoPackage file with specific writer options.
+pub fn write_geopackage_with_options(
+    reader: impl RecordBatchReader,
+    path: impl AsRef<Path>,
+    table_name: &str,
+    options: GeoPackageWriterOptions,
+) -> GeoArrowResult<()> {
+    let mut conn = Connection::open(path).map_err(sqlite_error)?;
+    write_geopackage_to_connection(reader, &mut conn, table_name, options)
+}
+
+/// Write a stream of GeoArrow RecordBatches to a new feature table in an open SQLite connection.
+///
+/// The GeoPackage core tables are created if they don't exist. The whole table is written in a
+/// single transaction.
+///
+/// The input must have exactly one GeoArrow geometry column. An integer column named `fid` is used
+/// as the table's primary key; otherwise an `fid` primary key is generated.
+pub fn write_geopackage_to_connection(
+    reader: impl RecordBatchReader,
+    conn: &mut Connection,
+    table_name: &str,
+    options: GeoPackageWriterOptions,
+) -> GeoArrowResult<()> {
+    let schema = reader.schema();
+    let geometry_index = geometry_column(&schema)?;
+    let geometry_field = schema.field(geometry_index);
+    let geometry_type = GeoArrowType::try_from(geometry_field)?;
+    let fid_index = schema
+        .fields()
+        .iter()
+        .position(|f| f.name().eq_ignore_ascii_case("fid") && f.data_type().is_integer());
+    let fid_name = fid_index
+        .map(|i| schema.field(i).name().as_str())
+        .unwrap_or("fid");
+
+    let tx = conn.transaction().map_err(sqlite_error)?;
+    initialize(&tx)?;
+
+    let default_transform = DefaultCrsTransform::default();
+    let crs_transform = options
+        .crs_transform
+        .as_deref()
+        .unwrap_or(&default_transform);
+    let srs_id = write_crs(&tx, geometry_type.metadata().crs(), crs_transform)?;
+    let geometry_column =
+        GeometryColumn::from_data_type(geometry_field.name(), &geometry_type, srs_id);
+
+    // Create the table and register it
+    let mut column_defs = vec![format!(
+        "{} INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL",
+        quote_identifier(fid_name)
+    )];
+    for (i, field) in schema.fields().iter().enumerate() {
+        if Some(i) == fid_index {
+            continue;
+        }
+        let sql_type = if i == geometry_index {
+            geometry_column.geometry_type_name.as_str()
+        } else {
+            sql_type(field.data_type())?
+        };
+        let not_null = if field.is_nullable() { "" } else { " NOT NULL" };
+        column_defs.push(format!(
+            "{} {sql_type}{not_null}",
+            quote_identifier(field.name())
+        ));
+    }
+    let table = quote_identifier(table_name);
+    tx.execute_batch(&format!(
+        "CREATE TABLE {table} ({})",
+        column_defs.join(", ")
+    ))
+    .map_err(sqlite_error)?;
+    tx.execute(
+        "INSERT INTO gpkg_contents (table_name, data_type, identifier, description, srs_id) VALUES (?1, 'features', ?1, ?2, ?3)",
+        params![table_name, options.description.as_deref().unwrap_or(""), srs_id],
+    )
+    .map_err(sqlite_error)?;
+    geometry_column.write(&tx, table_name)?;
+
+    let rtree = quote_identifier(&rtree_table_name(table_name, geometry_field.name()));
+    if options.spatial_index {
+        tx.execute_batch(&format!(
+            "CREATE VIRTUAL TABLE {rtree} USING rtree(id, minx, maxx, miny, maxy)"
+        ))
+        .map_err(sqlite_error)?;
+    }
+
+    // Insert the features, populating the index directly. The index triggers are only created
+    // afterwards, as they rely on ST_* SQL functions that plain SQLite doesn't provide.
+    let insert_columns = schema
+        .fields()
+        .iter()
+        .map(|f| quote_identifier(f.name()))
+        .collect::<Vec<_>>();
+    let placeholders = (1..=insert_columns.len())
+        .map(|i| format!("?{i}"))
+        .collect::<Vec<_>>();
+    let mut insert = tx
+        .prepare(&format!(
+            "INSERT INTO {table} ({}) VALUES ({})",
+            insert_columns.join(", "),
+            placeholders.join(", ")
+        ))
+        .map_err(sqlite_error)?;
+    let mut insert_rtree = if options.spatial_index {
+        Some(
+            tx.prepare(&format!("INSERT INTO {rtree} VALUES (?1, ?2, ?3, ?4, ?5)"))
+                .map_err(sqlite_error)?,
+        )
+    } else {
+        None
+    };
+
+    let mut extent = Envelope::new();
+    let mut blob = vec![];
+    for batch in reader {
+        let batch = batch?;
+        let geometry_array =
+            from_arrow_array(batch.column(geometry_index).as_ref(), geometry_field)?;
+        let wkb_array = to_wkb::<i32>(geometry_array.as_ref())?;
+        let wkb_binary = wkb_array.clone().into_arrow();
+        let columns = batch
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(i, array)| {
+                if i == geometry_index {
+                    Ok(SqlColumn::Blob(wkb_binary.clone()))
+                } else {
+                    SqlColumn::try_new(array)
+                }
+            })
+            .collect::<GeoArrowResult<Vec<_>>>()?;
+
+        for row in 0..batch.num_rows() {
+            let mut envelope = Envelope::new();
+            let has_geometry = wkb_binary.is_valid(row);
+            if has_geometry {
+                let wkb = wkb_array.value(row)?;
+                envelope = Envelope::from_geometry(&wkb);
+                let is_point = matches!(wkb.as_type(), geo_traits::GeometryType::Point(_));
+                blob.clear();
+                write_blob(
+                    &mut blob,
+                    srs_id,
+                    &envelope,
+                    options.write_envelopes && !is_point,
+                    wkb_binary.value(row),
+                );
+            }
+
+            let values = columns
+                .iter()
+                .enumerate()
+                .map(|(i, column)| {
+                    if i == geometry_index && has_geometry {
+                        ToSqlOutput::Borrowed(ValueRef::Blob(&blob))
+                    } else {
+                        column.value(row)
+                    }
+                })
+                .collect::<Vec<_>>();
+            insert
+                .execute(params_from_iter(values.iter()))
+                .map_err(sqlite_error)?;
+
+            if !envelope.is_empty() {
+                extent.union(&envelope);
+                if let Some(insert_rtree) = &mut insert_rtree {
+                    insert_rtree
+                        .execute(params![
+                            tx.last_insert_rowid(),
+                            envelope.minx,
+                            envelope.maxx,
+                            envelope.miny,
+                            envelope.maxy
+                        ])
+                        .map_err(sqlite_error)?;
+                }
+            }
+        }
+    }
+    drop(insert);
+    drop(insert_rtree);
+
+    if !extent.is_empty() {
+        tx.execute(
+            "UPDATE gpkg_contents SET min_x = ?2, min_y = ?3, max_x = ?4, max_y = ?5 WHERE table_name = ?1",
+            params![table_name, extent.minx, extent.miny, extent.maxx, extent.maxy],
+        )
+        .map_err(sqlite_error)?;
+    }
+
+    if options.spatial_index {
+        tx.execute_batch(&rtree_triggers(table_name, geometry_field.name(), fid_name))
+            .map_err(sqlite_error)?;
+        tx.execute(
+            "INSERT INTO gpkg_extensions (table_name, column_name, extension_name, definition, scope) VALUES (?1, ?2, ?3, 'http://www.geopackage.org/spec120/#extension_rtree', 'write-only')",
+            params![table_name, geometry_field.name(), RTREE_EXTENSION],
+        )
+        .map_err(sqlite_error)?;
+    }
+
+    tx.commit().map_err(sqlite_error)
+}
+
+fn geometry_column(schema: &Schema) -> GeoArrowResult<usize> {
+    let geometry_columns = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| {
+            field.extension_type_name().is_some() && GeoArrowType::try_from(field.as_ref()).is_ok()
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    if geometry_columns.len() != 1 {
+        return Err(GeoArrowError::GeoPackage(
+            "Exactly one geometry column is required in the GeoPackage writer".to_string(),
+        ));
+    }
+    Ok(geometry_columns[0])
+}
+
+/// The GeoPackage column type used to store an Arrow type.
+fn sql_type(data_type: &DataType) -> GeoArrowResult<&'static str> {
+    use DataType::*;
+
+    let sql_type = match data_type {
+        Boolean => "BOOLEAN",
+        Int8 | UInt8 => "TINYINT",
+        Int16 | UInt16 => "SMALLINT",
+        Int32 => "MEDIUMINT",
+        UInt32 | Int64 | UInt64 => "INTEGER",
+        Float16 | Float32 => "FLOAT",
+        Float64 | Decimal128(_, _) | Decimal256(_, _) => "DOUBLE",
+        Utf8 | LargeUtf8 | Utf8View => "TEXT",
+        Binary | LargeBinary | BinaryView | FixedSizeBinary(_) => "BLOB",
+        Date32 | Date64 => "DATE",
+        Timestamp(_, _) => "DATETIME",
+        other => {
+            return Err(GeoArrowError::GeoPackage(format!(
+                "Unsupported data type for GeoPackage column: {other}"
+            )));
+        }
+    };
+    Ok(sql_type)
+}
+
+/// A property column normalized to the SQLite storage class it is written as.
+enum SqlColumn {
+    Integer(Int64Array),
+    Real(Float64Array),
+    Text(StringArray),
+    Blob(BinaryArray),
+    /// Milliseconds since the epoch, written as GeoPackage `DATETIME` text in UTC
+    DateTime(PrimitiveArray<TimestampMillisecondType>),
+}
+
+impl SqlColumn {
+    fn try_new(array: &ArrayRef) -> GeoArrowResult<Self> {
+        use DataType::*;
+
+        // Fail on integer overflow instead of writing nulls
+        let cast_options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let cast = |to_type: &DataType| cast_with_options(array, to_type, &cast_options);
+
+        let column = match array.data_type() {
+            Boolean | Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 => {
+                Self::Integer(cast(&Int64)?.as_primitive().clone())
+            }
+            Float16 | Float32 | Float64 | Decimal128(_, _) | Decimal256(_, _) => {
+                Self::Real(cast(&Float64)?.as_primitive().clone())
+            }
+            Utf8 | LargeUtf8 | Utf8View | Date32 | Date64 => {
+                Self::Text(cast(&Utf8)?.as_string().clone())
+            }
+            Binary | LargeBinary | BinaryView | FixedSizeBinary(_) => {
+                Self::Blob(cast(&Binary)?.as_binary().clone())
+            }
+            Timestamp(_, tz) => Self::DateTime(
+                cast(&Timestamp(TimeUnit::Millisecond, tz.clone()))?
+                    .as_primitive()
+                    .clone(),
+            ),
+            other => {
+                return Err(GeoArrowError::GeoPackage(format!(
+                    "Unsupported data type for GeoPackage column: {other}"
+                )));
+            }
+        };
+        Ok(column)
+    }
+
+    fn value(&self, row: usize) -> ToSqlOutput<'_> {
+        let value = match self {
+            _ if self.is_null(row) => ValueRef::Null,
+            Self::Integer(arr) => ValueRef::Integer(arr.value(row)),
+            Self::Real(arr) => ValueRef::Real(arr.value(row)),
+            Self::Text(arr) => ValueRef::Text(arr.value(row).as_bytes()),
+            Self::Blob(arr) => ValueRef::Blob(arr.value(row)),
+            Self::DateTime(arr) => {
+                return match arr.value_as_datetime(row) {
+                    Some(datetime) => {
+                        ToSqlOutput::from(datetime.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string())
+                    }
+                    None => ToSqlOutput::Borrowed(ValueRef::Null),
+                };
+            }
+        };
+        ToSqlOutput::Borrowed(value)
+    }
+
+    fn is_null(&self, row: usize) -> bool {
+        match self {
+            Self::Integer(arr) => arr.is_null(row),
+            Self::Real(arr) => arr.is_null(row),
+            Self::Text(arr) => arr.is_null(row),
+            Self::Blob(arr) => arr.is_null(row),
+            Self::DateTime(arr) => arr.is_null(row),
+        }
+    }
+}
+
+/// The triggers that keep an RTree spatial index in sync with its feature table.
+///
+/// See <https://www.geopackage.org/spec140/index.html#extension_rtree>.
+fn rtree_triggers(table_name: &str, column_name: &str, id_name: &str) -> String {
+    let t = quote_identifier(table_name);
+    let c = quote_identifier(column_name);
+    let i = quote_identifier(id_name);
+    let rtree_name = rtree_table_name(table_name, column_name);
+    let rtree = quote_identifier(&rtree_name);
+    let trigger = |suffix: &str| quote_identifier(&format!("{rtree_name}_{suffix}"));
+    let insert_new = format!(
+        "INSERT OR REPLACE INTO {rtree} VALUES (NEW.{i}, ST_MinX(NEW.{c}), ST_MaxX(NEW.{c}), ST_MinY(NEW.{c}), ST_MaxY(NEW.{c}));"
+    );
+
+    format!(
+        r#"
+CREATE TRIGGER {insert} AFTER INSERT ON {t}
+WHEN (NEW.{c} NOT NULL AND NOT ST_IsEmpty(NEW.{c}))
+BEGIN
+    {insert_new}
+END;
+CREATE TRIGGER {update1} AFTER UPDATE OF {c} ON {t}
+WHEN OLD.{i} = NEW.{i} AND (NEW.{c} NOTNULL AND NOT ST_IsEmpty(NEW.{c}))
+BEGIN
+    {insert_new}
+END;
+CREATE TRIGGER {update2} AFTER UPDATE OF {c} ON {t}
+WHEN OLD.{i} = NEW.{i} AND (NEW.{c} ISNULL OR ST_IsEmpty(NEW.{c}))
+BEGIN
+    DELETE FROM {rtree} WHERE id = OLD.{i};
+END;
+CREATE TRIGGER {update3} AFTER UPDATE ON {t}
+WHEN OLD.{i} != NEW.{i} AND (NEW.{c} NOTNULL AND NOT ST_IsEmpty(NEW.{c}))
+BEGIN
+    DELETE FROM {rtree} WHERE id = OLD.{i};
+    {insert_new}
+END;
+CREATE TRIGGER {update4} AFTER UPDATE ON {t}
+WHEN OLD.{i} != NEW.{i} AND (NEW.{c} ISNULL OR ST_IsEmpty(NEW.{c}))
+BEGIN
+    DELETE FROM {rtree} WHERE id IN (OLD.{i}, NEW.{i});
+END;
+CREATE TRIGGER {delete} AFTER DELETE ON {t}
+WHEN OLD.{c} NOT NULL
+BEGIN
+    DELETE FROM {rtree} WHERE id = OLD.{i};
+END;
+"#,
+        insert = trigger("insert"),
+        update1 = trigger("update1"),
+        update2 = trigger("update2"),
+        update3 = trigger("update3"),
+        update4 = trigger("update4"),
+        delete = trigger("delete"),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, RecordBatch, RecordBatchIterator};
+    use arrow_schema::Field;
+    use geoarrow_array::GeoArrowArray;
+    use geoarrow_array::test::polygon;
+    use geoarrow_schema::{CoordType, Crs, Dimension, Metadata};
+
+    use super::*;
+    use crate::reader::{GeoPackageReaderBuilder, GeoPackageReaderOptions};
+
+    fn write_polygons(spatial_index: bool) -> Connection {
+        let metadata = Arc::new(Metadata::new(
+            Crs::from_srid("4326".to_string()),
+            Default::default(),
+        ));
+        let geometry = polygon::array(CoordType::Separated, Dimension::XY).with_metadata(metadata);
+        let values = Int32Array::from_iter((0..geometry.len() as i32).map(Some));
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("value", DataType::Int32, true)),
+            geometry.data_type().to_field("geom", true).into(),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(values), geometry.to_array_ref()],
+        )
+        .unwrap();
+
+        let mut conn = Connection::open_in_memory().unwrap();
+        let options = GeoPackageWriterOptions {
+            spatial_index,
+            ..Default::default()
+        };
+        let reader = RecordBatchIterator::new(vec![Ok(batch)], schema);
+        write_geopackage_to_connection(reader, &mut conn, "polygons", options).unwrap();
+        conn
+    }
+
+    #[test]
+    fn round_trip() {
+        let conn = write_polygons(true);
+        let reader = GeoPackageReaderBuilder::new(conn);
+        assert_eq!(reader.layers().unwrap(), vec!["polygons".to_string()]);
+
+        let batches = reader
+            .read("polygons", Default::default())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let batch = &batches[0];
+        assert_eq!(batch.schema().field(0).name(), "fid");
+        assert_eq!(batch.column(1).data_type(), &DataType::Int32);
+
+        let geometry_field = batch.schema().field(2).clone();
+        let geometry = from_arrow_array(batch.column(2).as_ref(), &geometry_field).unwrap();
+        let expected = polygon::array(CoordType::Separated, Dimension::XY);
+        assert_eq!(geometry.len(), expected.len());
+        assert!(matches!(geometry.data_type(), GeoArrowType::Polygon(_)));
+        assert_eq!(
+            geometry.data_type().metadata().crs(),
+            &Crs::from_authority_code("EPSG:4326".to_string())
+        );
+        assert_eq!(geometry.logical_null_count(), expected.logical_null_count());
+    }
+
+    #[test]
+    fn bbox_filter() {
+        for spatial_index in [true, false] {
+            let conn = write_polygons(spatial_index);
+            let options = GeoPackageReaderOptions {
+                bbox: Some((-1000.0, -1000.0, 1000.0, 1000.0)),
+                ..Default::default()
+            };
+            let num_rows: usize = GeoPackageReaderBuilder::new(conn)
+                .read("polygons", options)
+                .unwrap()
+                .map(|batch| batch.unwrap().num_rows())
+                .sum();
+            // Null and empty geometries are never returned by a spatial filter
+            let expected = polygon::array(CoordType::Separated, Dimension::XY);
+            let num_non_empty = expected
+                .iter()
+                .filter(|g| {
+                    g.as_ref()
+                        .is_some_and(|g| !Envelope::from_geometry(g.as_ref().unwrap()).is_empty())
+                })
+                .count();
+            assert_eq!(num_rows, num_non_empty);
+
+            let conn = write_polygons(spatial_index);
+            let options = GeoPackageReaderOptions {
+                bbox: Some((1000.0, 1000.0, 1001.0, 1001.0)),
+                ..Default::default()
+            };
+            let num_rows: usize = GeoPackageReaderBuilder::new(conn)
+                .read("polygons", options)
+                .unwrap()
+                .map(|batch| batch.unwrap().num_rows())
+                .sum();
+            assert_eq!(num_rows, 0);
+        }
+    }
+
+    #[test]
+    fn zero_batch_size() {
+        let options = GeoPackageReaderOptions {
+            batch_size: Some(0),
+            ..Default::default()
+        };
+        let result = GeoPackageReaderBuilder::new(write_polygons(false)).read("polygons", options);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn srs_id_mismatch() {
+        let conn = write_polygons(false);
+        // Overwrite the srs_id stored in the header of every geometry blob
+        conn.execute(
+            "UPDATE polygons SET geom = substr(geom, 1, 4) || x'E7030000' || substr(geom, 9) WHERE geom IS NOT NULL",
+            [],
+        )
+        .unwrap();
+        let result = GeoPackageReaderBuilder::new(conn)
+            .read("polygons", Default::default())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use arrow_array::UInt64Array;
+use geo_types::{Coord, LineString, Polygon};
+use geoarrow_array::array::PolygonArray;
+use geoarrow_array::builder::PolygonBuilder;
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, Dimension, PolygonType};
+use h3o::CellIndex;
+
+use crate::h3_error;
+
+/// The boundary of each H3 cell, as a polygon in longitude and latitude degrees.
+///
+/// Null cells have a null polygon, and invalid cell indexes are an error. The boundaries of cells
+/// crossing the antimeridian are not split, so their longitudes wrap from 180 to -180.
+pub fn cell_to_boundary(
+    array: &UInt64Array,
+    coord_type: CoordType,
+) -> GeoArrowResult<PolygonArray> {
+    let polygons = array
+        .iter()
+        .map(|cell| cell.map(boundary).transpose())
+        .collect::<GeoArrowResult<Vec<_>>>()?;
+    let typ = PolygonType::new(Dimension::XY, Default::default()).with_coord_type(coord_type);
+    Ok(PolygonBuilder::from_nullable_polygons(&polygons, typ).finish())
+}
+
+fn boundary(cell: u64) -> GeoArrowResult<Polygon> {
+    let cell = CellIndex::try_from(cell).map_err(h3_error)?;
+    let coords = cell
+        .boundary()
+        .iter()
+        .map(|latlng| Coord {
+            x: latlng.lng(),
+            y: latlng.lat(),
+        })
+        .collect::<Vec<_>>();
+    // `Polygon::new` closes the ring
+    Ok(Polygon::new(LineString::new(coords), vec![]))
+}
+
+#[cfg(test)]
+mod test {
+    use geo_traits::{CoordTrait, LineStringTrait, PolygonTrait};
+    use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor};
+
+    use super::*;
+
+    #[test]
+    fn hexagon() {
+        let cells = UInt64Array::from(vec![Some(0x87283472bffffff), None]);
+        let polygons = cell_to_boundary(&cells, CoordType::Separated).unwrap();
+        assert_eq!(polygons.len(), 2);
+        assert!(polygons.is_null(1));
+
+        let hexagon = polygons.value(0).unwrap();
+        let exterior = hexagon.exterior().unwrap();
+        assert_eq!(exterior.num_coords(), 7);
+        let first = exterior.coord(0).unwrap();
+        assert!((first.x() + 122.05).abs() < 0.05);
+        assert!((first.y() - 37.36).abs() < 0.05);
+
+        assert!(cell_to_boundary(&UInt64Array::from(vec![0]), CoordType::Separated).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use arrow_array::UInt64Array;
+use arrow_array::builder::UInt64Builder;
+use geo_traits::{CoordTrait, PointTrait};
+use geoarrow_array::GeoArrowArrayAccessor;
+use geoarrow_array::array::PointArray;
+use geoarrow_schema::error::GeoArrowResult;
+use h3o::LatLng;
+
+use crate::{h3_error, resolution};
+
+/// The H3 cell containing each point, at the given resolution.
+///
+/// Points are longitude and latitude in degrees. Null and empty points have a null cell, and
+/// points with non-finite coordinates are an error.
+pub fn latlng_to_cell(array: &PointArray, res: u8) -> GeoArrowResult<UInt64Array> {
+    let res = resolution(res)?;
+    let mut builder = UInt64Builder::with_capacity(array.len());
+    for point in array.iter() {
+        let coord = point.transpose()?.and_then(|point| point.coord());
+        match coord {
+            Some(coord) => {
+                let latlng = LatLng::new(coord.y(), coord.x()).map_err(h3_error)?;
+                builder.append_value(latlng.to_cell(res).into());
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use geo_types::point;
+    use geoarrow_array::builder::PointBuilder;
+    use geoarrow_schema::{Dimension, PointType};
+
+    use super::*;
+
+    #[test]
+    fn cells() {
+        let points = vec![
+            Some(point!(x: -122.0553238, y: 37.3615593)),
+            None,
+            Some(point!(x: 2.349014, y: 48.864716)),
+        ];
+        let typ = PointType::new(Dimension::XY, Default::default());
+        let array = PointBuilder::from_nullable_geometries(&points, typ)
+            .unwrap()
+            .finish();
+
+        let cells = latlng_to_cell(&array, 7).unwrap();
+        assert_eq!(cells.value(0), 0x87283472bffffff);
+        assert!(cells.is_null(1));
+        assert!(cells.is_valid(2));
+
+        assert!(latlng_to_cell(&array, 16).is_err());
+    }
+}
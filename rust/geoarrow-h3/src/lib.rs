@@ -0,0 +1,27 @@
+//! [H3](https://h3geo.org) cell indexing of GeoArrow arrays.
+//!
+//! Cells are stored as their 64-bit H3 index in a `UInt64Array`, and coordinates are longitude
+//! and latitude in degrees.
+
+#![warn(missing_docs)]
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+mod boundary;
+mod cell;
+mod polyfill;
+
+pub use boundary::cell_to_boundary;
+pub use cell::latlng_to_cell;
+pub use polyfill::polygon_to_cells;
+
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use h3o::Resolution;
+
+pub(crate) fn h3_error(err: impl ToString) -> GeoArrowError {
+    GeoArrowError::H3(err.to_string())
+}
+
+/// Parse a resolution between 0 and 15.
+pub(crate) fn resolution(resolution: u8) -> GeoArrowResult<Resolution> {
+    Resolution::try_from(resolution).map_err(h3_error)
+}
@@ -0,0 +1,108 @@
+use arrow_array::builder::{UInt32Builder, UInt64Builder};
+use arrow_array::{UInt32Array, UInt64Array};
+use geo_traits::to_geo::{ToGeoPolygon, ToGeoRect};
+use geo_traits::{GeometryTrait, GeometryType, MultiPolygonTrait};
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use h3o::Resolution;
+use h3o::geom::{ContainmentMode, TilerBuilder};
+
+use crate::{h3_error, resolution};
+
+/// The H3 cells whose centers are within each polygon, at the given resolution.
+///
+/// Returns the row index and cell of every covering cell, ordered by row and then by cell.
+/// Polygons, multi polygons and rectangles in longitude and latitude degrees are supported. Null
+/// geometries have no cells, and any other geometry type is an error.
+pub fn polygon_to_cells(
+    array: &dyn GeoArrowArray,
+    res: u8,
+) -> GeoArrowResult<(UInt32Array, UInt64Array)> {
+    if u32::try_from(array.len()).is_err() {
+        return Err(GeoArrowError::Overflow);
+    }
+    let res = resolution(res)?;
+    downcast_geoarrow_array!(array, polygon_to_cells_impl, res)
+}
+
+fn polygon_to_cells_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    res: Resolution,
+) -> GeoArrowResult<(UInt32Array, UInt64Array)> {
+    let mut rows = UInt32Builder::new();
+    let mut cells = UInt64Builder::new();
+    for (row, geometry) in array.iter().enumerate() {
+        let Some(geometry) = geometry else {
+            continue;
+        };
+        let geometry = geometry?;
+        let mut tiler = TilerBuilder::new(res)
+            .containment_mode(ContainmentMode::ContainsCentroid)
+            .build();
+        let added = match geometry.as_type() {
+            GeometryType::Polygon(polygon) => tiler.add(polygon.to_polygon()),
+            GeometryType::MultiPolygon(multi_polygon) => multi_polygon
+                .polygons()
+                .try_for_each(|polygon| tiler.add(polygon.to_polygon())),
+            GeometryType::Rect(rect) => tiler.add(rect.to_rect().to_polygon()),
+            _ => {
+                return Err(GeoArrowError::IncorrectGeometryType(format!(
+                    "H3 polyfill requires polygons, found a non-polygonal geometry at row {row}"
+                )));
+            }
+        };
+        added.map_err(h3_error)?;
+
+        let mut row_cells = tiler.into_coverage().map(u64::from).collect::<Vec<_>>();
+        row_cells.sort_unstable();
+        row_cells.dedup();
+        for cell in row_cells {
+            rows.append_value(row as u32);
+            cells.append_value(cell);
+        }
+    }
+    Ok((rows.finish(), cells.finish()))
+}
+
+#[cfg(test)]
+mod test {
+    use geo_types::{point, polygon};
+    use geoarrow_array::test::geometry::from_geoms;
+    use h3o::CellIndex;
+
+    use super::*;
+
+    #[test]
+    fn polyfill() {
+        let square = polygon![
+            (x: -122.5, y: 37.5),
+            (x: -122.0, y: 37.5),
+            (x: -122.0, y: 38.0),
+            (x: -122.5, y: 38.0),
+        ];
+        let array = from_geoms(
+            &[Some(square.into()), None, Some(square.into())],
+            Default::default(),
+        );
+        let (rows, cells) = polygon_to_cells(&array, 7).unwrap();
+        assert_eq!(rows.len(), cells.len());
+        let half = rows.len() / 2;
+        assert!(half > 0);
+        assert!(rows.values()[..half].iter().all(|row| *row == 0));
+        assert!(rows.values()[half..].iter().all(|row| *row == 2));
+        assert_eq!(cells.values()[..half], cells.values()[half..]);
+        for cell in cells.values() {
+            let cell = CellIndex::try_from(*cell).unwrap();
+            assert_eq!(cell.resolution(), Resolution::Seven);
+        }
+    }
+
+    #[test]
+    fn non_polygon() {
+        let array = from_geoms(&[Some(point!(x: 0.0, y: 0.0).into())], Default::default());
+        assert!(matches!(
+            polygon_to_cells(&array, 7),
+            Err(GeoArrowError::IncorrectGeometryType(_))
+        ));
+    }
+}
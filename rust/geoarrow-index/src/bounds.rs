@@ -0,0 +1,35 @@
+//! Bounding boxes of the geometries of an array.
+
+use geoarrow_array::bounds::BoundsAccumulator;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+/// A 2D bounding box, as `[min_x, min_y, max_x, max_y]`.
+pub(crate) type Bounds = [f64; 4];
+
+/// The bounds of each non-null, non-empty geometry of an array, with its row index.
+pub(crate) fn array_bounds(array: &dyn GeoArrowArray) -> GeoArrowResult<Vec<(u32, Bounds)>> {
+    if u32::try_from(array.len()).is_err() {
+        return Err(GeoArrowError::Overflow);
+    }
+    downcast_geoarrow_array!(array, impl_array_bounds)
+}
+
+fn impl_array_bounds<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+) -> GeoArrowResult<Vec<(u32, Bounds)>> {
+    let mut bounds = Vec::with_capacity(array.len());
+    for (row, geometry) in array.iter().enumerate() {
+        if let Some(geometry) = geometry {
+            let mut rect = BoundsAccumulator::new();
+            rect.add_geometry(&geometry?);
+            if !rect.is_empty() {
+                bounds.push((
+                    row as u32,
+                    [rect.minx(), rect.miny(), rect.maxx(), rect.maxy()],
+                ));
+            }
+        }
+    }
+    Ok(bounds)
+}
@@ -0,0 +1,139 @@
+//! The parts of an index shared by the R-tree and KD-tree, and their serialized form.
+//!
+//! A serialized index is a 12 byte header, followed by the row of each indexed geometry if not
+//! every row was indexed, followed by the `geo-index` buffer of the tree:
+//!
+//! | bytes | content                                                      |
+//! | ----- | ------------------------------------------------------------ |
+//! | 0-3   | the magic bytes `GAIX`                                       |
+//! | 4     | the format version, 1                                        |
+//! | 5     | the kind of tree, 0 for an R-tree or 1 for a KD-tree         |
+//! | 6     | flags: bit 0 is set if the rows are stored                   |
+//! | 7     | reserved, 0                                                  |
+//! | 8-11  | the number of indexed geometries, as a little-endian `u32`   |
+
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+const MAGIC: [u8; 4] = *b"GAIX";
+const VERSION: u8 = 1;
+const HEADER_SIZE: usize = 12;
+const HAS_ROWS: u8 = 1;
+
+/// The kind of tree in a serialized index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IndexKind {
+    RTree = 0,
+    KDTree = 1,
+}
+
+/// An index tree with the mapping from its items to rows of the source array.
+#[derive(Debug, Clone)]
+pub(crate) struct IndexData {
+    /// The number of indexed geometries.
+    pub(crate) num_items: u32,
+
+    /// The row of each indexed geometry, or `None` if every row was indexed in order.
+    rows: Option<Vec<u32>>,
+
+    /// The `geo-index` buffer of the tree, empty if no geometry was indexed.
+    pub(crate) tree: Vec<u8>,
+}
+
+impl IndexData {
+    /// Create from the rows of the indexed geometries, in insertion order, and the number of
+    /// rows in the source array.
+    pub(crate) fn new(rows: Vec<u32>, array_len: usize, tree: Vec<u8>) -> Self {
+        let num_items = rows.len() as u32;
+        let rows = (rows.len() != array_len).then_some(rows);
+        Self {
+            num_items,
+            rows,
+            tree,
+        }
+    }
+
+    /// Map the items returned by a tree query to rows of the source array.
+    pub(crate) fn rows(&self, items: Vec<u32>) -> Vec<u32> {
+        match &self.rows {
+            Some(rows) => items.into_iter().map(|item| rows[item as usize]).collect(),
+            None => items,
+        }
+    }
+
+    pub(crate) fn to_bytes(&self, kind: IndexKind) -> Vec<u8> {
+        let rows_size = self.rows.as_ref().map_or(0, |rows| rows.len() * 4);
+        let mut out = Vec::with_capacity(HEADER_SIZE + rows_size + self.tree.len());
+        out.extend_from_slice(&MAGIC);
+        out.push(VERSION);
+        out.push(kind as u8);
+        out.push(if self.rows.is_some() { HAS_ROWS } else { 0 });
+        out.push(0);
+        out.extend_from_slice(&self.num_items.to_le_bytes());
+        if let Some(rows) = &self.rows {
+            rows.iter()
+                .for_each(|row| out.extend_from_slice(&row.to_le_bytes()));
+        }
+        out.extend_from_slice(&self.tree);
+        out
+    }
+
+    /// Check that the deserialized tree holds one item per indexed geometry, so that every item
+    /// returned by a query maps to a row.
+    pub(crate) fn validate_num_items(&self, tree_num_items: u32) -> GeoArrowResult<()> {
+        let num_rows = self
+            .rows
+            .as_ref()
+            .map_or(self.num_items as usize, |rows| rows.len());
+        if tree_num_items != self.num_items || num_rows != self.num_items as usize {
+            return Err(GeoArrowError::SpatialIndex(format!(
+                "Serialized index has {} items and {num_rows} rows, but its tree has {tree_num_items} items",
+                self.num_items
+            )));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn try_from_bytes(bytes: &[u8], kind: IndexKind) -> GeoArrowResult<Self> {
+        let error = |msg: &str| GeoArrowError::SpatialIndex(msg.to_string());
+        if bytes.len() < HEADER_SIZE || bytes[0..4] != MAGIC {
+            return Err(error("Not a serialized GeoArrow index"));
+        }
+        if bytes[4] != VERSION {
+            return Err(GeoArrowError::SpatialIndex(format!(
+                "Unsupported index format version {}",
+                bytes[4]
+            )));
+        }
+        if bytes[5] != kind as u8 {
+            return Err(GeoArrowError::SpatialIndex(format!(
+                "Expected a serialized {kind:?}, got index kind {}",
+                bytes[5]
+            )));
+        }
+        let num_items = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        let mut tree = &bytes[HEADER_SIZE..];
+        let rows = if bytes[6] & HAS_ROWS != 0 {
+            let rows_size = num_items as usize * 4;
+            if tree.len() < rows_size {
+                return Err(error("Serialized index is truncated"));
+            }
+            let (rows, rest) = tree.split_at(rows_size);
+            tree = rest;
+            Some(
+                rows.chunks_exact(4)
+                    .map(|row| u32::from_le_bytes(row.try_into().unwrap()))
+                    .collect(),
+            )
+        } else {
+            None
+        };
+        if (num_items == 0) != tree.is_empty() {
+            return Err(error("Serialized index has an invalid tree buffer"));
+        }
+        Ok(Self {
+            num_items,
+            rows,
+            tree: tree.to_vec(),
+        })
+    }
+}
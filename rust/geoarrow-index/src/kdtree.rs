@@ -0,0 +1,177 @@
+//! A KD-tree over the points of a GeoArrow array.
+
+use geo_index::kdtree::{KDTreeBuilder, KDTreeIndex, KDTreeRef};
+use geo_traits::{CoordTrait, GeometryTrait, GeometryType, PointTrait};
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+use crate::data::{IndexData, IndexKind};
+
+/// A KD-tree over an array of points.
+///
+/// The array may be of any GeoArrow type, but every non-null geometry must be a point. Null and
+/// empty points are skipped, and queries return row indices into the source array.
+#[derive(Debug, Clone)]
+pub struct GeoArrowKDTree {
+    data: IndexData,
+}
+
+impl GeoArrowKDTree {
+    /// Build a KD-tree over an array of points, with the default node size of 64.
+    pub fn try_new(array: &dyn GeoArrowArray) -> GeoArrowResult<Self> {
+        Self::try_new_with_node_size(array, 64)
+    }
+
+    /// Build a KD-tree over an array of points with the given node size.
+    pub fn try_new_with_node_size(
+        array: &dyn GeoArrowArray,
+        node_size: u16,
+    ) -> GeoArrowResult<Self> {
+        if u32::try_from(array.len()).is_err() {
+            return Err(GeoArrowError::Overflow);
+        }
+        let points = downcast_geoarrow_array!(array, impl_points)?;
+        let tree = if points.is_empty() {
+            vec![]
+        } else {
+            let mut builder =
+                KDTreeBuilder::<f64>::new_with_node_size(points.len() as u32, node_size);
+            for (_, x, y) in &points {
+                builder.add(*x, *y);
+            }
+            builder.finish().into_inner()
+        };
+        let rows = points.into_iter().map(|(row, _, _)| row).collect();
+        Ok(Self {
+            data: IndexData::new(rows, array.len(), tree),
+        })
+    }
+
+    /// Restore a KD-tree serialized with [`to_bytes`][Self::to_bytes].
+    pub fn try_from_bytes(bytes: &[u8]) -> GeoArrowResult<Self> {
+        let data = IndexData::try_from_bytes(bytes, IndexKind::KDTree)?;
+        if data.num_items > 0 {
+            let tree = KDTreeRef::<f64>::try_new(&data.tree)
+                .map_err(|err| GeoArrowError::SpatialIndex(err.to_string()))?;
+            data.validate_num_items(tree.num_items())?;
+        }
+        Ok(Self { data })
+    }
+
+    /// Serialize the KD-tree, including the mapping from its items to rows.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.data.to_bytes(IndexKind::KDTree)
+    }
+
+    /// The number of indexed points.
+    pub fn num_items(&self) -> usize {
+        self.data.num_items as usize
+    }
+
+    fn tree(&self) -> Option<KDTreeRef<'_, f64>> {
+        (self.data.num_items > 0).then(|| {
+            KDTreeRef::try_new(&self.data.tree).expect("KD-tree buffer is validated on creation")
+        })
+    }
+
+    /// The rows of the points within the given box, in ascending order.
+    pub fn range(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<u32> {
+        let Some(tree) = self.tree() else {
+            return vec![];
+        };
+        let mut rows = self.data.rows(tree.range(min_x, min_y, max_x, max_y));
+        rows.sort_unstable();
+        rows
+    }
+
+    /// The rows of the points within `radius` of a point, in ascending order.
+    pub fn within(&self, x: f64, y: f64, radius: f64) -> Vec<u32> {
+        let Some(tree) = self.tree() else {
+            return vec![];
+        };
+        let mut rows = self.data.rows(tree.within(x, y, radius));
+        rows.sort_unstable();
+        rows
+    }
+}
+
+/// The coordinates of each non-null, non-empty point of an array, with its row index.
+fn impl_points<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+) -> GeoArrowResult<Vec<(u32, f64, f64)>> {
+    let mut points = Vec::with_capacity(array.len());
+    for (row, geometry) in array.iter().enumerate() {
+        let Some(geometry) = geometry else {
+            continue;
+        };
+        let geometry = geometry?;
+        let GeometryType::Point(point) = geometry.as_type() else {
+            return Err(GeoArrowError::IncorrectGeometryType(format!(
+                "KD-tree can only index points, found a non-point geometry at row {row}"
+            )));
+        };
+        if let Some(coord) = point.coord() {
+            points.push((row as u32, coord.x(), coord.y()));
+        }
+    }
+    Ok(points)
+}
+
+#[cfg(test)]
+mod test {
+    use geo_types::{Geometry, LineString, Point, point};
+    use geoarrow_array::builder::{GeometryBuilder, PointBuilder};
+    use geoarrow_schema::{Dimension, GeometryType, PointType};
+
+    use super::*;
+
+    fn point_array() -> impl GeoArrowArray {
+        let points = vec![
+            Some(point!(x: 0.0, y: 0.0)),
+            None,
+            Some(point!(x: 3.0, y: 4.0)),
+            Some(point!(x: 10.0, y: 10.0)),
+        ];
+        let typ = PointType::new(Dimension::XY, Default::default());
+        PointBuilder::from_nullable_geometries(&points, typ)
+            .unwrap()
+            .finish()
+    }
+
+    #[test]
+    fn range_and_within() {
+        let tree = GeoArrowKDTree::try_new(&point_array()).unwrap();
+        assert_eq!(tree.num_items(), 3);
+        assert_eq!(tree.range(-1.0, -1.0, 5.0, 5.0), [0, 2]);
+        assert_eq!(tree.within(0.0, 0.0, 5.0), [0, 2]);
+        assert_eq!(tree.within(0.0, 0.0, 4.9), [0]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let tree = GeoArrowKDTree::try_new(&point_array()).unwrap();
+        let bytes = tree.to_bytes();
+        let restored = GeoArrowKDTree::try_from_bytes(&bytes).unwrap();
+        assert_eq!(restored.range(9.0, 9.0, 11.0, 11.0), [3]);
+        assert!(crate::GeoArrowRTree::try_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn non_point_geometry() {
+        let geometries = vec![
+            Some(Geometry::Point(Point::new(0.0, 0.0))),
+            Some(Geometry::LineString(LineString::from(vec![
+                (0.0, 0.0),
+                (1.0, 1.0),
+            ]))),
+        ];
+        let typ = GeometryType::new(Default::default());
+        let array = GeometryBuilder::from_nullable_geometries(&geometries, typ)
+            .unwrap()
+            .finish();
+        assert!(matches!(
+            GeoArrowKDTree::try_new(&array),
+            Err(GeoArrowError::IncorrectGeometryType(_))
+        ));
+    }
+}
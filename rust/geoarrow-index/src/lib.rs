@@ -0,0 +1,19 @@
+//! Spatial indexes over GeoArrow arrays.
+//!
+//! [`GeoArrowRTree`] is a packed Hilbert R-tree over the bounding boxes of the geometries of any
+//! array, and [`GeoArrowKDTree`] a KD-tree over an array of points. Null and empty geometries are
+//! not indexed, and queries return the row indices of matching geometries in the source array.
+//!
+//! Both indexes can be serialized with `to_bytes` and restored with `try_from_bytes`, to be
+//! persisted next to the data they index.
+
+#![warn(missing_docs)]
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+mod bounds;
+mod data;
+mod kdtree;
+mod rtree;
+
+pub use kdtree::GeoArrowKDTree;
+pub use rtree::GeoArrowRTree;
@@ -0,0 +1,198 @@
+//! A packed Hilbert R-tree over the geometries of any GeoArrow array.
+
+use geo_index::rtree::sort::HilbertSort;
+use geo_index::rtree::{RTreeBuilder, RTreeIndex, RTreeRef};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+use crate::bounds::array_bounds;
+use crate::data::{IndexData, IndexKind};
+
+/// A packed Hilbert R-tree over the bounding boxes of the geometries of a GeoArrow array.
+///
+/// Null and empty geometries are skipped, and queries return row indices into the source array.
+#[derive(Debug, Clone)]
+pub struct GeoArrowRTree {
+    data: IndexData,
+}
+
+impl GeoArrowRTree {
+    /// Build an R-tree over an array, with the default node size of 16.
+    pub fn try_new(array: &dyn GeoArrowArray) -> GeoArrowResult<Self> {
+        Self::try_new_with_node_size(array, 16)
+    }
+
+    /// Build an R-tree over an array with the given node size.
+    pub fn try_new_with_node_size(
+        array: &dyn GeoArrowArray,
+        node_size: u16,
+    ) -> GeoArrowResult<Self> {
+        let bounds = array_bounds(array)?;
+        let tree = if bounds.is_empty() {
+            vec![]
+        } else {
+            let mut builder =
+                RTreeBuilder::<f64>::new_with_node_size(bounds.len() as u32, node_size);
+            for (_, [min_x, min_y, max_x, max_y]) in &bounds {
+                builder.add(*min_x, *min_y, *max_x, *max_y);
+            }
+            builder.finish::<HilbertSort>().into_inner()
+        };
+        let rows = bounds.into_iter().map(|(row, _)| row).collect();
+        Ok(Self {
+            data: IndexData::new(rows, array.len(), tree),
+        })
+    }
+
+    /// Restore an R-tree serialized with [`to_bytes`][Self::to_bytes].
+    pub fn try_from_bytes(bytes: &[u8]) -> GeoArrowResult<Self> {
+        let data = IndexData::try_from_bytes(bytes, IndexKind::RTree)?;
+        if data.num_items > 0 {
+            let tree = RTreeRef::<f64>::try_new(&data.tree)
+                .map_err(|err| GeoArrowError::SpatialIndex(err.to_string()))?;
+            data.validate_num_items(tree.num_items())?;
+        }
+        Ok(Self { data })
+    }
+
+    /// Serialize the R-tree, including the mapping from its items to rows.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.data.to_bytes(IndexKind::RTree)
+    }
+
+    /// The number of indexed geometries.
+    pub fn num_items(&self) -> usize {
+        self.data.num_items as usize
+    }
+
+    fn tree(&self) -> Option<RTreeRef<'_, f64>> {
+        (self.data.num_items > 0).then(|| {
+            RTreeRef::try_new(&self.data.tree).expect("R-tree buffer is validated on creation")
+        })
+    }
+
+    /// The rows whose bounding boxes intersect the given box, in ascending order.
+    pub fn search(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<u32> {
+        let Some(tree) = self.tree() else {
+            return vec![];
+        };
+        let mut rows = self.data.rows(tree.search(min_x, min_y, max_x, max_y));
+        rows.sort_unstable();
+        rows
+    }
+
+    /// The rows whose bounding boxes are nearest to a point, closest first.
+    ///
+    /// At most `max_results` rows are returned if given, and only rows within `max_distance` of
+    /// the point if given.
+    pub fn nearest(
+        &self,
+        x: f64,
+        y: f64,
+        max_results: Option<usize>,
+        max_distance: Option<f64>,
+    ) -> Vec<u32> {
+        let Some(tree) = self.tree() else {
+            return vec![];
+        };
+        self.data
+            .rows(tree.neighbors(x, y, max_results, max_distance))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use geo_types::{Geometry, LineString, Point, Rect, point};
+    use geoarrow_array::builder::{GeometryBuilder, PointBuilder};
+    use geoarrow_schema::{Dimension, GeometryType, PointType};
+
+    use super::*;
+
+    fn points() -> Vec<Option<Point>> {
+        vec![
+            Some(point!(x: 0.0, y: 0.0)),
+            None,
+            Some(point!(x: 5.0, y: 5.0)),
+            Some(point!(x: 10.0, y: 10.0)),
+        ]
+    }
+
+    fn point_array() -> impl GeoArrowArray {
+        let typ = PointType::new(Dimension::XY, Default::default());
+        PointBuilder::from_nullable_geometries(&points(), typ)
+            .unwrap()
+            .finish()
+    }
+
+    #[test]
+    fn search_skips_nulls() {
+        let tree = GeoArrowRTree::try_new(&point_array()).unwrap();
+        assert_eq!(tree.num_items(), 3);
+        assert_eq!(tree.search(4.0, 4.0, 11.0, 11.0), [2, 3]);
+        assert_eq!(tree.search(-1.0, -1.0, 1.0, 1.0), [0]);
+        assert!(tree.search(20.0, 20.0, 30.0, 30.0).is_empty());
+    }
+
+    #[test]
+    fn nearest() {
+        let tree = GeoArrowRTree::try_new(&point_array()).unwrap();
+        assert_eq!(tree.nearest(9.0, 9.0, Some(2), None), [3, 2]);
+        assert_eq!(tree.nearest(9.0, 9.0, None, Some(2.0)), [3]);
+    }
+
+    #[test]
+    fn mixed_geometries() {
+        let geometries = vec![
+            Some(Geometry::Rect(Rect::new((0.0, 0.0), (2.0, 2.0)))),
+            Some(Geometry::LineString(LineString::from(vec![
+                (3.0, 0.0),
+                (6.0, 3.0),
+            ]))),
+            Some(Geometry::LineString(LineString::new(vec![]))),
+        ];
+        let typ = GeometryType::new(Default::default());
+        let array = GeometryBuilder::from_nullable_geometries(&geometries, typ)
+            .unwrap()
+            .finish();
+        let tree = GeoArrowRTree::try_new(&array).unwrap();
+        assert_eq!(tree.num_items(), 2);
+        assert_eq!(tree.search(1.0, 1.0, 4.0, 1.5), [0, 1]);
+        assert_eq!(tree.search(5.0, 2.0, 5.0, 2.0), [1]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let tree = GeoArrowRTree::try_new(&point_array()).unwrap();
+        let restored = GeoArrowRTree::try_from_bytes(&tree.to_bytes()).unwrap();
+        assert_eq!(restored.num_items(), 3);
+        assert_eq!(restored.search(4.0, 4.0, 11.0, 11.0), [2, 3]);
+
+        let empty = PointBuilder::from_nullable_geometries(
+            &[None::<Point>],
+            PointType::new(Dimension::XY, Default::default()),
+        )
+        .unwrap()
+        .finish();
+        let tree = GeoArrowRTree::try_new(&empty).unwrap();
+        let restored = GeoArrowRTree::try_from_bytes(&tree.to_bytes()).unwrap();
+        assert_eq!(restored.num_items(), 0);
+        assert!(restored.search(0.0, 0.0, 1.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        assert!(GeoArrowRTree::try_from_bytes(b"not an index").is_err());
+
+        let mut bytes = GeoArrowRTree::try_new(&point_array()).unwrap().to_bytes();
+        bytes[5] = 1;
+        assert!(GeoArrowRTree::try_from_bytes(&bytes).is_err());
+
+        // Fewer rows than the tree has items
+        let bytes = GeoArrowRTree::try_new(&point_array()).unwrap().to_bytes();
+        let mut short = bytes[..8].to_vec();
+        short.extend_from_slice(&2_u32.to_le_bytes());
+        short.extend_from_slice(&bytes[12..20]);
+        short.extend_from_slice(&bytes[24..]);
+        assert!(GeoArrowRTree::try_from_bytes(&short).is_err());
+    }
+}
@@ -0,0 +1,25 @@
+//! Read from and write to [Arrow IPC](https://arrow.apache.org/docs/format/Columnar.html#serialization-and-interprocess-communication-ipc)
+//! files (Feather v2) and streams with GeoArrow geometry columns.
+
+#![warn(missing_docs)]
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+pub mod reader;
+pub mod writer;
+
+use arrow_schema::Schema;
+use geoarrow_schema::GeoArrowType;
+
+/// The indices of the GeoArrow extension columns in a schema.
+fn geometry_columns(schema: &Schema) -> Vec<usize> {
+    let mut geom_indices = vec![];
+    for (field_idx, field) in schema.fields().iter().enumerate() {
+        // We first check that an extension type name is set and then check that we can coerce to a
+        // GeoArrowType so that we don't accept columns that are _compatible_ with geoarrow storage
+        // but aren't set as geoarrow extension types.
+        if field.extension_type_name().is_some() && GeoArrowType::try_from(field.as_ref()).is_ok() {
+            geom_indices.push(field_idx);
+        }
+    }
+    geom_indices
+}
@@ -0,0 +1,165 @@
+//! Read Arrow IPC files and streams into record batches with parsed GeoArrow columns.
+
+use std::io::{Read, Seek};
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::{FileReader, StreamReader};
+use arrow_schema::SchemaRef;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_schema::error::GeoArrowResult;
+
+use crate::geometry_columns;
+
+/// A [`RecordBatch`] together with its GeoArrow extension columns parsed as
+/// [`GeoArrowArray`]s.
+#[derive(Debug, Clone)]
+pub struct GeoRecordBatch {
+    batch: RecordBatch,
+    geometries: Vec<(usize, Arc<dyn GeoArrowArray>)>,
+}
+
+impl GeoRecordBatch {
+    /// Parse the GeoArrow extension columns of a record batch.
+    ///
+    /// Columns without a GeoArrow extension type are left as plain Arrow arrays.
+    pub fn try_new(batch: RecordBatch) -> GeoArrowResult<Self> {
+        let schema = batch.schema();
+        let geometries = geometry_columns(&schema)
+            .into_iter()
+            .map(|idx| {
+                let array = from_arrow_array(batch.column(idx).as_ref(), schema.field(idx))?;
+                Ok((idx, array))
+            })
+            .collect::<GeoArrowResult<Vec<_>>>()?;
+        Ok(Self { batch, geometries })
+    }
+
+    /// The underlying record batch, with geometry columns as plain Arrow arrays.
+    pub fn batch(&self) -> &RecordBatch {
+        &self.batch
+    }
+
+    /// The column index and GeoArrow array of each geometry column.
+    pub fn geometry_columns(&self) -> &[(usize, Arc<dyn GeoArrowArray>)] {
+        &self.geometries
+    }
+
+    /// The geometry column with the given name, if it exists.
+    pub fn geometry(&self, name: &str) -> Option<&Arc<dyn GeoArrowArray>> {
+        let idx = self.batch.schema().index_of(name).ok()?;
+        self.geometries
+            .iter()
+            .find(|(i, _)| *i == idx)
+            .map(|(_, array)| array)
+    }
+
+    /// Consume this and return the underlying record batch.
+    pub fn into_inner(self) -> RecordBatch {
+        self.batch
+    }
+}
+
+impl From<GeoRecordBatch> for RecordBatch {
+    fn from(value: GeoRecordBatch) -> Self {
+        value.batch
+    }
+}
+
+/// A reader for Arrow IPC files (Feather v2) yielding [`GeoRecordBatch`]es.
+pub struct GeoArrowIpcFileReader<R> {
+    inner: FileReader<R>,
+}
+
+impl<R: Read + Seek> GeoArrowIpcFileReader<R> {
+    /// Open an Arrow IPC file.
+    pub fn try_new(reader: R) -> GeoArrowResult<Self> {
+        Ok(Self {
+            inner: FileReader::try_new(reader, None)?,
+        })
+    }
+
+    /// The schema of the file.
+    pub fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+
+    /// The number of record batches in the file.
+    pub fn num_batches(&self) -> usize {
+        self.inner.num_batches()
+    }
+}
+
+impl<R: Read + Seek> Iterator for GeoArrowIpcFileReader<R> {
+    type Item = GeoArrowResult<GeoRecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|batch| GeoRecordBatch::try_new(batch?))
+    }
+}
+
+/// A reader for Arrow IPC streams yielding [`GeoRecordBatch`]es.
+pub struct GeoArrowIpcStreamReader<R> {
+    inner: StreamReader<R>,
+}
+
+impl<R: Read> GeoArrowIpcStreamReader<R> {
+    /// Open an Arrow IPC stream.
+    pub fn try_new(reader: R) -> GeoArrowResult<Self> {
+        Ok(Self {
+            inner: StreamReader::try_new(reader, None)?,
+        })
+    }
+
+    /// The schema of the stream.
+    pub fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+}
+
+impl<R: Read> Iterator for GeoArrowIpcStreamReader<R> {
+    type Item = GeoArrowResult<GeoRecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|batch| GeoRecordBatch::try_new(batch?))
+    }
+}
+
+/// Read all record batches from an Arrow IPC file (Feather v2).
+pub fn read_ipc_file<R: Read + Seek>(reader: R) -> GeoArrowResult<Vec<GeoRecordBatch>> {
+    GeoArrowIpcFileReader::try_new(reader)?.collect()
+}
+
+/// Read all record batches from an Arrow IPC stream.
+pub fn read_ipc_stream<R: Read>(reader: R) -> GeoArrowResult<Vec<GeoRecordBatch>> {
+    GeoArrowIpcStreamReader::try_new(reader)?.collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+
+    use geoarrow_schema::GeoArrowType;
+
+    use super::*;
+
+    #[test]
+    fn read_nybb() {
+        let file = File::open("../../fixtures/nybb.arrow").unwrap();
+        let batches = read_ipc_file(file).unwrap();
+        let num_rows: usize = batches.iter().map(|b| b.batch().num_rows()).sum();
+        assert_eq!(num_rows, 5);
+
+        let geometry = batches[0].geometry("geometry").unwrap();
+        assert!(matches!(
+            geometry.data_type(),
+            GeoArrowType::MultiPolygon(_)
+        ));
+        assert_eq!(batches[0].geometry_columns().len(), 1);
+    }
+}
@@ -0,0 +1,280 @@
+//! Write record batches with GeoArrow columns to Arrow IPC files and streams.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_ipc::CompressionType;
+use arrow_ipc::writer::{FileWriter, IpcWriteOptions, StreamWriter};
+use arrow_schema::{Field, Schema, SchemaRef};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_cast::cast::cast;
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, GeoArrowType, WkbType, WktType};
+
+use crate::geometry_columns;
+
+/// How to encode geometry columns when writing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GeometryEncoding {
+    /// Write geometry columns exactly as they are.
+    #[default]
+    Unchanged,
+
+    /// Write native geometry columns with the given coordinate type.
+    ///
+    /// Serialized (WKB and WKT) columns are written unchanged.
+    Native(CoordType),
+
+    /// Serialize geometry columns to WKB with 32-bit offsets.
+    Wkb,
+
+    /// Serialize geometry columns to WKB with 64-bit offsets.
+    LargeWkb,
+
+    /// Serialize geometry columns to WKT with 32-bit offsets.
+    Wkt,
+}
+
+impl GeometryEncoding {
+    fn target_type(&self, data_type: GeoArrowType) -> GeoArrowType {
+        let metadata = data_type.metadata().clone();
+        match self {
+            Self::Unchanged => data_type,
+            Self::Native(coord_type) => data_type.with_coord_type(*coord_type),
+            Self::Wkb => GeoArrowType::Wkb(WkbType::new(metadata)),
+            Self::LargeWkb => GeoArrowType::LargeWkb(WkbType::new(metadata)),
+            Self::Wkt => GeoArrowType::Wkt(WktType::new(metadata)),
+        }
+    }
+}
+
+/// Compression codec for IPC buffers.
+///
+/// Writing compressed buffers requires the matching `lz4` or `zstd` crate feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpcCompression {
+    /// LZ4 frame compression
+    Lz4Frame,
+    /// ZSTD compression
+    Zstd,
+}
+
+/// Options for the IPC writers
+#[derive(Debug, Clone, Default)]
+pub struct IpcWriterOptions {
+    /// How to encode geometry columns.
+    pub geometry_encoding: GeometryEncoding,
+
+    /// Compression to apply to IPC buffers. `None` writes uncompressed buffers.
+    pub compression: Option<IpcCompression>,
+}
+
+impl IpcWriterOptions {
+    /// Set how to encode geometry columns.
+    pub fn with_geometry_encoding(mut self, geometry_encoding: GeometryEncoding) -> Self {
+        self.geometry_encoding = geometry_encoding;
+        self
+    }
+
+    /// Set the compression to apply to IPC buffers.
+    pub fn with_compression(mut self, compression: IpcCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    fn ipc_write_options(&self) -> GeoArrowResult<IpcWriteOptions> {
+        let compression = self.compression.map(|c| match c {
+            IpcCompression::Lz4Frame => CompressionType::LZ4_FRAME,
+            IpcCompression::Zstd => CompressionType::ZSTD,
+        });
+        Ok(IpcWriteOptions::default().try_with_compression(compression)?)
+    }
+}
+
+/// Converts the geometry columns of each batch to the requested [`GeometryEncoding`].
+struct Normalizer {
+    schema: SchemaRef,
+    /// The index, input field and target type of each geometry column
+    columns: Vec<(usize, Arc<Field>, GeoArrowType)>,
+}
+
+impl Normalizer {
+    fn try_new(input_schema: &Schema, encoding: GeometryEncoding) -> GeoArrowResult<Self> {
+        let mut fields = input_schema.fields().to_vec();
+        let mut columns = vec![];
+        if encoding != GeometryEncoding::Unchanged {
+            for idx in geometry_columns(input_schema) {
+                let field = input_schema.fields()[idx].clone();
+                let target_type = encoding.target_type(GeoArrowType::try_from(field.as_ref())?);
+
+                // Keep any other field metadata, replacing the extension type
+                let output_field = target_type.to_field(field.name(), field.is_nullable());
+                let mut metadata = field.metadata().clone();
+                metadata.extend(output_field.metadata().clone());
+                fields[idx] = Arc::new(output_field.with_metadata(metadata));
+
+                columns.push((idx, field, target_type));
+            }
+        }
+
+        Ok(Self {
+            schema: Arc::new(Schema::new_with_metadata(
+                fields,
+                input_schema.metadata().clone(),
+            )),
+            columns,
+        })
+    }
+
+    fn normalize(&self, batch: RecordBatch) -> GeoArrowResult<RecordBatch> {
+        if self.columns.is_empty() {
+            return Ok(batch);
+        }
+
+        let mut arrays = batch.columns().to_vec();
+        for (idx, field, target_type) in &self.columns {
+            let array = from_arrow_array(arrays[*idx].as_ref(), field)?;
+            arrays[*idx] = cast(array.as_ref(), target_type)?.to_array_ref();
+        }
+        Ok(RecordBatch::try_new(self.schema.clone(), arrays)?)
+    }
+}
+
+/// Write a stream of record batches to an Arrow IPC file (Feather v2).
+pub fn write_ipc_file<W: Write>(reader: impl RecordBatchReader, writer: W) -> GeoArrowResult<()> {
+    write_ipc_file_with_options(reader, writer, &Default::default())
+}
+
+/// Write a stream of record batches to an Arrow IPC file (Feather v2) with specific writer
+/// options.
+pub fn write_ipc_file_with_options<W: Write>(
+    reader: impl RecordBatchReader,
+    writer: W,
+    options: &IpcWriterOptions,
+) -> GeoArrowResult<()> {
+    let normalizer = Normalizer::try_new(&reader.schema(), options.geometry_encoding)?;
+    let mut writer =
+        FileWriter::try_new_with_options(writer, &normalizer.schema, options.ipc_write_options()?)?;
+    for batch in reader {
+        writer.write(&normalizer.normalize(batch?)?)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Write a stream of record batches to an Arrow IPC stream.
+pub fn write_ipc_stream<W: Write>(reader: impl RecordBatchReader, writer: W) -> GeoArrowResult<()> {
+    write_ipc_stream_with_options(reader, writer, &Default::default())
+}
+
+/// Write a stream of record batches to an Arrow IPC stream with specific writer options.
+pub fn write_ipc_stream_with_options<W: Write>(
+    reader: impl RecordBatchReader,
+    writer: W,
+    options: &IpcWriterOptions,
+) -> GeoArrowResult<()> {
+    let normalizer = Normalizer::try_new(&reader.schema(), options.geometry_encoding)?;
+    let mut writer = StreamWriter::try_new_with_options(
+        writer,
+        &normalizer.schema,
+        options.ipc_write_options()?,
+    )?;
+    for batch in reader {
+        writer.write(&normalizer.normalize(batch?)?)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use arrow_array::{Int32Array, RecordBatchIterator};
+    use arrow_schema::DataType;
+    use geoarrow_array::GeoArrowArray;
+    use geoarrow_array::test::point;
+    use geoarrow_schema::Dimension;
+
+    use super::*;
+    use crate::reader::{read_ipc_file, read_ipc_stream};
+
+    fn point_batch() -> RecordBatch {
+        let points = point::array(CoordType::Separated, Dimension::XYZ);
+        let values = Int32Array::from_iter_values(0..points.len() as i32);
+        let schema = Schema::new(vec![
+            Field::new("value", DataType::Int32, false),
+            points.data_type().to_field("geometry", true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(values), points.to_array_ref()],
+        )
+        .unwrap()
+    }
+
+    fn batch_reader(batch: RecordBatch) -> impl RecordBatchReader {
+        let schema = batch.schema();
+        RecordBatchIterator::new(vec![Ok(batch)], schema)
+    }
+
+    #[test]
+    fn round_trip_file() {
+        let batch = point_batch();
+        let mut buf = vec![];
+        write_ipc_file(batch_reader(batch.clone()), &mut buf).unwrap();
+
+        let batches = read_ipc_file(Cursor::new(buf)).unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].batch(), &batch);
+        let geometry = batches[0].geometry("geometry").unwrap();
+        assert_eq!(
+            geometry.data_type().coord_type(),
+            Some(CoordType::Separated)
+        );
+        assert!(batches[0].geometry("value").is_none());
+    }
+
+    #[test]
+    fn normalize_interleaved() {
+        let batch = point_batch();
+        let options = IpcWriterOptions::default()
+            .with_geometry_encoding(GeometryEncoding::Native(CoordType::Interleaved));
+        let mut buf = vec![];
+        write_ipc_stream_with_options(batch_reader(batch.clone()), &mut buf, &options).unwrap();
+
+        let batches = read_ipc_stream(Cursor::new(buf)).unwrap();
+        let geometry = batches[0].geometry("geometry").unwrap();
+        assert_eq!(
+            geometry.data_type().coord_type(),
+            Some(CoordType::Interleaved)
+        );
+        assert_eq!(geometry.len(), batch.num_rows());
+        assert_eq!(batches[0].batch().column(0), batch.column(0));
+    }
+
+    #[test]
+    fn normalize_wkb() {
+        let batch = point_batch();
+        let options = IpcWriterOptions::default().with_geometry_encoding(GeometryEncoding::Wkb);
+        let mut buf = vec![];
+        write_ipc_file_with_options(batch_reader(batch), &mut buf, &options).unwrap();
+
+        let batches = read_ipc_file(Cursor::new(buf)).unwrap();
+        let geometry = batches[0].geometry("geometry").unwrap();
+        assert!(matches!(geometry.data_type(), GeoArrowType::Wkb(_)));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_compression() {
+        let batch = point_batch();
+        let options = IpcWriterOptions::default().with_compression(IpcCompression::Zstd);
+        let mut buf = vec![];
+        write_ipc_file_with_options(batch_reader(batch.clone()), &mut buf, &options).unwrap();
+
+        let batches = read_ipc_file(Cursor::new(buf)).unwrap();
+        assert_eq!(batches[0].batch(), &batch);
+    }
+}
@@ -0,0 +1,197 @@
+//! Clipping of tile-space geometries to the (buffered) tile square.
+
+type Coord = [f64; 2];
+
+/// An axis-aligned square `[min, max] x [min, max]` to clip to.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ClipBox {
+    pub(crate) min: f64,
+    pub(crate) max: f64,
+}
+
+impl ClipBox {
+    fn contains(&self, c: &Coord) -> bool {
+        c[0] >= self.min && c[0] <= self.max && c[1] >= self.min && c[1] <= self.max
+    }
+
+    /// Keep the points that lie within the box.
+    pub(crate) fn clip_points(&self, points: &mut Vec<Coord>) {
+        points.retain(|c| self.contains(c));
+    }
+
+    /// Clip a line string, which may split it into several pieces.
+    pub(crate) fn clip_line(&self, line: &[Coord]) -> Vec<Vec<Coord>> {
+        let mut pieces = vec![];
+        let mut current: Vec<Coord> = vec![];
+        for segment in line.windows(2) {
+            match self.clip_segment(segment[0], segment[1]) {
+                Some((start, end)) => {
+                    if current.last() != Some(&start) {
+                        if current.len() > 1 {
+                            pieces.push(std::mem::take(&mut current));
+                        }
+                        current.clear();
+                        current.push(start);
+                    }
+                    current.push(end);
+
+                    // The segment left the box, so the next one starts a new piece
+                    if end != segment[1] {
+                        pieces.push(std::mem::take(&mut current));
+                    }
+                }
+                None => {
+                    if current.len() > 1 {
+                        pieces.push(std::mem::take(&mut current));
+                    }
+                    current.clear();
+                }
+            }
+        }
+        if current.len() > 1 {
+            pieces.push(current);
+        }
+        pieces
+    }
+
+    /// Clip a segment with the Liang-Barsky algorithm.
+    fn clip_segment(&self, a: Coord, b: Coord) -> Option<(Coord, Coord)> {
+        let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+        let mut t0: f64 = 0.0;
+        let mut t1: f64 = 1.0;
+        for (p, q) in [
+            (-dx, a[0] - self.min),
+            (dx, self.max - a[0]),
+            (-dy, a[1] - self.min),
+            (dy, self.max - a[1]),
+        ] {
+            if p == 0.0 {
+                if q < 0.0 {
+                    return None;
+                }
+            } else {
+                let t = q / p;
+                if p < 0.0 {
+                    t0 = t0.max(t);
+                } else {
+                    t1 = t1.min(t);
+                }
+            }
+        }
+        if t0 > t1 {
+            return None;
+        }
+
+        let start = if t0 == 0.0 {
+            a
+        } else {
+            [a[0] + t0 * dx, a[1] + t0 * dy]
+        };
+        let end = if t1 == 1.0 {
+            b
+        } else {
+            [a[0] + t1 * dx, a[1] + t1 * dy]
+        };
+        Some((start, end))
+    }
+
+    /// Clip a closed ring with the Sutherland-Hodgman algorithm.
+    ///
+    /// The output is not closed. Parts of a concave ring that are connected only outside the box
+    /// are joined by edges along the box boundary, which renders the same.
+    pub(crate) fn clip_ring(&self, ring: &[Coord]) -> Vec<Coord> {
+        let mut output: Vec<Coord> = ring.to_vec();
+        if output.first() == output.last() {
+            output.pop();
+        }
+
+        // Each edge is (axis, bound, whether the inside is above the bound)
+        for (axis, bound, above) in [
+            (0, self.min, true),
+            (0, self.max, false),
+            (1, self.min, true),
+            (1, self.max, false),
+        ] {
+            if output.is_empty() {
+                break;
+            }
+            let inside = |c: &Coord| {
+                if above {
+                    c[axis] >= bound
+                } else {
+                    c[axis] <= bound
+                }
+            };
+            let intersect = |a: &Coord, b: &Coord| {
+                let t = (bound - a[axis]) / (b[axis] - a[axis]);
+                let mut c = [a[0] + t * (b[0] - a[0]), a[1] + t * (b[1] - a[1])];
+                c[axis] = bound;
+                c
+            };
+
+            let input = std::mem::take(&mut output);
+            let mut prev = input[input.len() - 1];
+            for current in input {
+                match (inside(&prev), inside(&current)) {
+                    (true, true) => output.push(current),
+                    (true, false) => output.push(intersect(&prev, &current)),
+                    (false, true) => {
+                        output.push(intersect(&prev, &current));
+                        output.push(current);
+                    }
+                    (false, false) => {}
+                }
+                prev = current;
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CLIP: ClipBox = ClipBox {
+        min: 0.0,
+        max: 10.0,
+    };
+
+    #[test]
+    fn clip_line() {
+        let line = [
+            [-5.0, 5.0],
+            [5.0, 5.0],
+            [5.0, 15.0],
+            [8.0, 15.0],
+            [8.0, 5.0],
+        ];
+        let pieces = CLIP.clip_line(&line);
+        assert_eq!(
+            pieces,
+            vec![
+                vec![[0.0, 5.0], [5.0, 5.0], [5.0, 10.0]],
+                vec![[8.0, 10.0], [8.0, 5.0]],
+            ]
+        );
+    }
+
+    #[test]
+    fn clip_ring() {
+        let ring = [
+            [-5.0, -5.0],
+            [5.0, -5.0],
+            [5.0, 5.0],
+            [-5.0, 5.0],
+            [-5.0, -5.0],
+        ];
+        let clipped = CLIP.clip_ring(&ring);
+        assert_eq!(
+            clipped,
+            vec![[0.0, 0.0], [5.0, 0.0], [5.0, 5.0], [0.0, 5.0]]
+        );
+
+        let outside = [[20.0, 20.0], [30.0, 20.0], [30.0, 30.0], [20.0, 20.0]];
+        assert!(CLIP.clip_ring(&outside).is_empty());
+    }
+}
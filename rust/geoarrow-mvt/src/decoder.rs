@@ -0,0 +1,500 @@
+//! Decode Mapbox Vector Tiles into record batches with a geometry column.
+
+use std::sync::Arc;
+
+use arrow_array::builder::{
+    BooleanBuilder, Float64Builder, Int64Builder, StringBuilder, UInt64Builder,
+};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Field, Schema};
+use geo_types::{
+    Coord, Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{CoordType, GeometryType, Metadata};
+use prost::Message;
+
+use crate::encoder::ring_area;
+use crate::tile::{SourceProjection, TileCoord, TileTransform};
+use crate::vector_tile::{Feature, GeomType, Layer, Tile, Value};
+
+/// Options for the MVT decoder
+#[derive(Debug, Clone, Default)]
+pub struct MvtDecoderOptions {
+    /// The address of the tile being decoded.
+    ///
+    /// When set, geometries are transformed from tile coordinates back to `projection`.
+    /// Otherwise they are returned in tile coordinates, with the origin in the top-left corner.
+    pub tile: Option<TileCoord>,
+
+    /// The coordinate reference system to transform geometries to when `tile` is set.
+    pub projection: SourceProjection,
+
+    /// The GeoArrow coordinate type to use in the geometry arrays.
+    pub coord_type: CoordType,
+}
+
+impl MvtDecoderOptions {
+    /// Set the address of the tile being decoded.
+    pub fn with_tile(mut self, tile: TileCoord) -> Self {
+        self.tile = Some(tile);
+        self
+    }
+
+    /// Set the coordinate reference system to transform geometries to.
+    pub fn with_projection(mut self, projection: SourceProjection) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Set the GeoArrow coordinate type to use in the geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+}
+
+/// A decoded layer of a vector tile.
+#[derive(Debug, Clone)]
+pub struct MvtLayer {
+    /// The name of the layer
+    pub name: String,
+
+    /// The size of the tile in tile coordinates
+    pub extent: u32,
+
+    /// The features of the layer.
+    ///
+    /// The schema has a nullable `id` column, one column per property key and a `geometry`
+    /// column.
+    pub batch: RecordBatch,
+}
+
+/// Decode all layers of a vector tile.
+pub fn decode_tile(buf: &[u8], options: &MvtDecoderOptions) -> GeoArrowResult<Vec<MvtLayer>> {
+    let tile = Tile::decode(buf).map_err(|err| GeoArrowError::Mvt(err.to_string()))?;
+    tile.layers
+        .into_iter()
+        .map(|layer| decode_layer(layer, options))
+        .collect()
+}
+
+fn decode_layer(layer: Layer, options: &MvtDecoderOptions) -> GeoArrowResult<MvtLayer> {
+    let extent = layer.extent();
+    let transform = options
+        .tile
+        .map(|tile| {
+            tile.validate()?;
+            Ok::<_, GeoArrowError>(TileTransform::new(tile, extent, options.projection))
+        })
+        .transpose()?;
+
+    let mut fields = vec![Field::new("id", DataType::UInt64, true)];
+    let mut columns: Vec<ArrayRef> = vec![Arc::new(
+        layer
+            .features
+            .iter()
+            .map(|f| f.id)
+            .collect::<arrow_array::UInt64Array>(),
+    )];
+
+    for (key_idx, key) in layer.keys.iter().enumerate() {
+        let values = layer
+            .features
+            .iter()
+            .map(|feature| feature_value(feature, &layer.values, key_idx as u32))
+            .collect::<GeoArrowResult<Vec<_>>>()?;
+        let (data_type, array) = property_array(&values);
+        fields.push(Field::new(key, data_type, true));
+        columns.push(array);
+    }
+
+    let geometries = layer
+        .features
+        .iter()
+        .map(|feature| decode_geometry(feature, transform.as_ref()))
+        .collect::<GeoArrowResult<Vec<_>>>()?;
+    let metadata = match options.tile {
+        Some(_) => Metadata::new(options.projection.crs(), None),
+        None => Default::default(),
+    };
+    let geometry_type = GeometryType::new(Arc::new(metadata)).with_coord_type(options.coord_type);
+    let geometry =
+        GeometryBuilder::from_nullable_geometries(&geometries, geometry_type.clone())?.finish();
+    fields.push(geometry_type.to_field("geometry", true));
+    columns.push(geometry.to_array_ref());
+
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(MvtLayer {
+        name: layer.name,
+        extent,
+        batch,
+    })
+}
+
+/// The value of a feature's tag with the given key index, if any.
+fn feature_value<'a>(
+    feature: &Feature,
+    values: &'a [Value],
+    key_idx: u32,
+) -> GeoArrowResult<Option<&'a Value>> {
+    let Some(tag) = feature.tags.chunks_exact(2).find(|tag| tag[0] == key_idx) else {
+        return Ok(None);
+    };
+    values
+        .get(tag[1] as usize)
+        .map(Some)
+        .ok_or_else(|| GeoArrowError::Mvt(format!("Tag value index {} is out of range", tag[1])))
+}
+
+/// Build the array of a property column.
+///
+/// Columns whose values are all booleans, integers or numbers keep that type. Columns mixing
+/// strings with other types are written as strings.
+fn property_array(values: &[Option<&Value>]) -> (DataType, ArrayRef) {
+    let present = || values.iter().flatten();
+    let is_int = |v: &Value| v.int_value.is_some() || v.sint_value.is_some();
+    let is_number = |v: &Value| {
+        is_int(v) || v.uint_value.is_some() || v.float_value.is_some() || v.double_value.is_some()
+    };
+
+    if present().all(|v| v.bool_value.is_some()) {
+        let mut builder = BooleanBuilder::with_capacity(values.len());
+        values
+            .iter()
+            .for_each(|v| builder.append_option(v.and_then(|v| v.bool_value)));
+        (DataType::Boolean, Arc::new(builder.finish()))
+    } else if present().all(|v| v.uint_value.is_some()) {
+        let mut builder = UInt64Builder::with_capacity(values.len());
+        values
+            .iter()
+            .for_each(|v| builder.append_option(v.and_then(|v| v.uint_value)));
+        (DataType::UInt64, Arc::new(builder.finish()))
+    } else if present().all(|v| is_int(v) || v.uint_value.is_some_and(|v| v <= i64::MAX as u64)) {
+        let mut builder = Int64Builder::with_capacity(values.len());
+        values.iter().for_each(|v| {
+            builder.append_option(v.and_then(|v| {
+                v.int_value
+                    .or(v.sint_value)
+                    .or(v.uint_value.map(|v| v as i64))
+            }))
+        });
+        (DataType::Int64, Arc::new(builder.finish()))
+    } else if present().all(|v| is_number(v)) {
+        let mut builder = Float64Builder::with_capacity(values.len());
+        values.iter().for_each(|v| {
+            builder.append_option(v.map(|v| {
+                v.double_value
+                    .or(v.float_value.map(f64::from))
+                    .or(v.int_value.or(v.sint_value).map(|v| v as f64))
+                    .or(v.uint_value.map(|v| v as f64))
+                    .unwrap()
+            }))
+        });
+        (DataType::Float64, Arc::new(builder.finish()))
+    } else {
+        let mut builder = StringBuilder::with_capacity(values.len(), 0);
+        values
+            .iter()
+            .for_each(|v| builder.append_option(v.map(value_to_string)));
+        (DataType::Utf8, Arc::new(builder.finish()))
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    if let Some(v) = &value.string_value {
+        v.clone()
+    } else if let Some(v) = value.float_value {
+        v.to_string()
+    } else if let Some(v) = value.double_value {
+        v.to_string()
+    } else if let Some(v) = value.int_value.or(value.sint_value) {
+        v.to_string()
+    } else if let Some(v) = value.uint_value {
+        v.to_string()
+    } else if let Some(v) = value.bool_value {
+        v.to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// Reads the parts of a feature's geometry command stream.
+struct CommandDecoder<'a> {
+    commands: &'a [u32],
+    pos: usize,
+    cursor: [i64; 2],
+}
+
+impl<'a> CommandDecoder<'a> {
+    fn new(commands: &'a [u32]) -> Self {
+        Self {
+            commands,
+            pos: 0,
+            cursor: [0, 0],
+        }
+    }
+
+    fn next_u32(&mut self) -> GeoArrowResult<u32> {
+        let value = self.commands.get(self.pos).copied().ok_or_else(|| {
+            GeoArrowError::Mvt("Geometry command stream ended unexpectedly".to_string())
+        })?;
+        self.pos += 1;
+        Ok(value)
+    }
+
+    /// Read a command, returning its id and count.
+    fn command(&mut self) -> GeoArrowResult<Option<(u32, u32)>> {
+        if self.pos >= self.commands.len() {
+            return Ok(None);
+        }
+        let command = self.next_u32()?;
+        Ok(Some((command & 0x7, command >> 3)))
+    }
+
+    fn expect_command(&mut self, id: u32) -> GeoArrowResult<u32> {
+        match self.command()? {
+            Some((command, count)) if command == id => Ok(count),
+            other => Err(GeoArrowError::Mvt(format!(
+                "Expected geometry command {id}, found {other:?}"
+            ))),
+        }
+    }
+
+    fn point(&mut self) -> GeoArrowResult<[i64; 2]> {
+        let dx = unzigzag(self.next_u32()?);
+        let dy = unzigzag(self.next_u32()?);
+        self.cursor = [self.cursor[0] + dx, self.cursor[1] + dy];
+        Ok(self.cursor)
+    }
+
+    /// Read a MoveTo(1) followed by a LineTo, as used by line strings and rings.
+    fn line(&mut self) -> GeoArrowResult<Vec<[i64; 2]>> {
+        let mut line = vec![self.point()?];
+        let count = self.expect_command(2)?;
+        for _ in 0..count {
+            line.push(self.point()?);
+        }
+        Ok(line)
+    }
+}
+
+fn unzigzag(value: u32) -> i64 {
+    i64::from((value >> 1) as i32 ^ -((value & 1) as i32))
+}
+
+fn decode_geometry(
+    feature: &Feature,
+    transform: Option<&TileTransform>,
+) -> GeoArrowResult<Option<Geometry>> {
+    let coord = |c: [i64; 2]| {
+        let (x, y) = (c[0] as f64, c[1] as f64);
+        let [x, y] = transform.map_or([x, y], |t| t.inverse(x, y));
+        Coord { x, y }
+    };
+    let line_string = |line: &[[i64; 2]]| LineString::from_iter(line.iter().map(|c| coord(*c)));
+
+    let mut decoder = CommandDecoder::new(&feature.geometry);
+    let geometry = match feature.r#type() {
+        GeomType::Unknown => return Ok(None),
+        GeomType::Point => {
+            let mut points = vec![];
+            while let Some((1, count)) = decoder.command()? {
+                for _ in 0..count {
+                    points.push(Point(coord(decoder.point()?)));
+                }
+            }
+            match points.len() {
+                0 => return Ok(None),
+                1 => Geometry::Point(points[0]),
+                _ => Geometry::MultiPoint(MultiPoint(points)),
+            }
+        }
+        GeomType::Linestring => {
+            let mut lines = vec![];
+            while let Some((1, 1)) = decoder.command()? {
+                lines.push(line_string(&decoder.line()?));
+            }
+            match lines.len() {
+                0 => return Ok(None),
+                1 => Geometry::LineString(lines.remove(0)),
+                _ => Geometry::MultiLineString(MultiLineString(lines)),
+            }
+        }
+        GeomType::Polygon => {
+            let mut polygons: Vec<(LineString, Vec<LineString>)> = vec![];
+            while let Some((1, 1)) = decoder.command()? {
+                let ring = decoder.line()?;
+                decoder.expect_command(7)?;
+
+                // A positive area starts a new polygon, and a negative area is one of its holes
+                let ring_i32 = ring
+                    .iter()
+                    .map(|c| [c[0] as i32, c[1] as i32])
+                    .collect::<Vec<_>>();
+                let area = ring_area(&ring_i32);
+                let mut ring = line_string(&ring);
+                ring.close();
+                match (area, polygons.last_mut()) {
+                    (0, _) => {}
+                    (area, Some((_, interiors))) if area < 0 => interiors.push(ring),
+                    _ => polygons.push((ring, vec![])),
+                }
+            }
+            let mut polygons = polygons
+                .into_iter()
+                .map(|(exterior, interiors)| Polygon::new(exterior, interiors))
+                .collect::<Vec<_>>();
+            match polygons.len() {
+                0 => return Ok(None),
+                1 => Geometry::Polygon(polygons.remove(0)),
+                _ => Geometry::MultiPolygon(MultiPolygon(polygons)),
+            }
+        }
+    };
+    Ok(Some(geometry))
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int64Type, UInt64Type};
+    use arrow_array::{Int32Array, StringArray};
+    use geo_traits::{CoordTrait, GeometryTrait, PointTrait};
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::{GeometryArray, from_arrow_array};
+    use geoarrow_array::cast::AsGeoArrowArray;
+    use geoarrow_schema::GeoArrowType;
+
+    use super::*;
+    use crate::encoder::{MvtEncoder, MvtEncoderOptions, encode_tile};
+
+    fn point_batch() -> RecordBatch {
+        let points = vec![
+            Some(Geometry::Point(Point::new(10.0, 20.0))),
+            Some(Geometry::Point(Point::new(-100.0, -40.0))),
+            None,
+        ];
+        let geometry_type = GeometryType::new(Default::default());
+        let geometry =
+            GeometryBuilder::from_nullable_geometries(&points, geometry_type.clone()).unwrap();
+        let schema = Schema::new(vec![
+            Field::new("fid", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+            geometry_type.to_field("geometry", true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+                geometry.finish().to_array_ref(),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn round_trip_points() {
+        let tile = TileCoord::new(0, 0, 0);
+        let options = MvtEncoderOptions::default().with_id_column("fid");
+        let buf = encode_tile("points", &point_batch(), tile, options).unwrap();
+
+        let layers = decode_tile(&buf, &MvtDecoderOptions::default().with_tile(tile)).unwrap();
+        assert_eq!(layers.len(), 1);
+        let layer = &layers[0];
+        assert_eq!(layer.name, "points");
+        assert_eq!(layer.extent, 4096);
+
+        // The null geometry is dropped
+        let batch = &layer.batch;
+        assert_eq!(batch.num_rows(), 2);
+        let ids = batch.column(0).as_primitive::<UInt64Type>();
+        assert_eq!(ids.values().to_vec(), vec![1, 2]);
+        let names = batch.column_by_name("name").unwrap().as_string::<i32>();
+        assert_eq!(names.value(0), "a");
+        assert!(names.is_null(1));
+
+        let schema = batch.schema();
+        let geometry = from_arrow_array(batch.column(2).as_ref(), schema.field(2)).unwrap();
+        assert_eq!(
+            geometry.data_type().metadata().crs(),
+            &SourceProjection::LonLat.crs()
+        );
+        let geometry: &GeometryArray = geometry.as_geometry();
+        let geo_traits::GeometryType::Point(point) = geometry.value(0).unwrap().as_type() else {
+            panic!("expected a point");
+        };
+        // At zoom 0 a tile coordinate is about 10km wide
+        let coord = point.coord().unwrap();
+        assert_relative_eq!(coord.x(), 10.0, epsilon = 0.1);
+        assert_relative_eq!(coord.y(), 20.0, epsilon = 0.1);
+    }
+
+    #[test]
+    fn merge_tile() {
+        let tile = TileCoord::new(0, 0, 0);
+        let options = MvtEncoderOptions::default().with_id_column("fid");
+        let batch = point_batch();
+        let first = encode_tile("points", &batch.slice(1, 2), tile, options.clone()).unwrap();
+
+        let mut encoder = MvtEncoder::try_new(tile, options.clone()).unwrap();
+        encoder.add_batch("points", &batch.slice(0, 1)).unwrap();
+        encoder.merge_tile(&first).unwrap();
+        let merged = decode_tile(&encoder.finish(), &Default::default()).unwrap();
+        assert_eq!(merged.len(), 1);
+
+        let batch = &merged[0].batch;
+        let ids = batch.column(0).as_primitive::<UInt64Type>();
+        assert_eq!(ids.values().to_vec(), vec![1, 2]);
+        let names = batch.column_by_name("name").unwrap().as_string::<i32>();
+        assert_eq!(names.value(0), "a");
+        assert!(names.is_null(1));
+
+        let other_extent = options.with_extent(512);
+        let mut encoder = MvtEncoder::try_new(tile, other_extent).unwrap();
+        assert!(encoder.merge_tile(&first).is_err());
+    }
+
+    #[test]
+    fn clip_to_tile() {
+        // Only the first point lies in the north-east quadrant at zoom 1
+        let tile = TileCoord::new(1, 1, 0);
+        let buf = encode_tile("points", &point_batch(), tile, Default::default()).unwrap();
+        let layers = decode_tile(&buf, &Default::default()).unwrap();
+        let batch = &layers[0].batch;
+        assert_eq!(batch.num_rows(), 1);
+        let ids = batch.column_by_name("fid").unwrap();
+        assert_eq!(ids.as_primitive::<Int64Type>().value(0), 1);
+
+        // Without a tile, geometries are returned in tile coordinates
+        let schema = batch.schema();
+        let geometry = from_arrow_array(batch.column(3).as_ref(), schema.field(3)).unwrap();
+        assert!(matches!(geometry.data_type(), GeoArrowType::Geometry(_)));
+        assert_eq!(geometry.data_type().metadata().crs(), &Default::default());
+    }
+
+    #[test]
+    fn decode_polygon() {
+        // Two polygons, the second with a hole, from section 4.3.5.3 of the specification
+        let feature = Feature {
+            id: None,
+            tags: vec![],
+            r#type: Some(GeomType::Polygon as i32),
+            geometry: vec![
+                9, 0, 0, 26, 20, 0, 0, 20, 19, 0, 15, 9, 22, 2, 26, 18, 0, 0, 18, 17, 0, 15, 9, 4,
+                13, 26, 0, 8, 8, 0, 0, 7, 15,
+            ],
+        };
+        let Some(Geometry::MultiPolygon(polygons)) = decode_geometry(&feature, None).unwrap()
+        else {
+            panic!("expected a multipolygon");
+        };
+        assert_eq!(polygons.0.len(), 2);
+        assert_eq!(polygons.0[0].interiors().len(), 0);
+        assert_eq!(polygons.0[1].interiors().len(), 1);
+    }
+}
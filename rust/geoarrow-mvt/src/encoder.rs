@@ -0,0 +1,722 @@
+//! Encode record batches with a geometry column into Mapbox Vector Tiles.
+
+use std::collections::HashMap;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float32Type, Float64Type, Int64Type, UInt64Type};
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_cast::cast;
+use arrow_schema::{DataType, Schema};
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+use geoarrow_array::GeoArrowArrayAccessor;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::cast::to_wkb;
+use geoarrow_schema::GeoArrowType;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use prost::Message;
+
+use crate::clip::ClipBox;
+use crate::tile::{SourceProjection, TileCoord, TileTransform};
+use crate::vector_tile::{Feature, GeomType, Layer, Tile, Value};
+
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+/// Options for the MVT encoder
+#[derive(Debug, Clone)]
+pub struct MvtEncoderOptions {
+    /// The size of the tile in tile coordinates, defaults to `4096`
+    pub extent: u32,
+
+    /// How far geometries extend beyond the tile edge before being clipped, in tile coordinates,
+    /// defaults to `64`
+    pub buffer: u32,
+
+    /// The coordinate reference system of the source geometries.
+    pub projection: SourceProjection,
+
+    /// The name of the geometry column. By default, the first GeoArrow extension column is used.
+    pub geometry_column: Option<String>,
+
+    /// The name of an integer column to use as feature ids instead of writing it as a property.
+    pub id_column: Option<String>,
+}
+
+impl MvtEncoderOptions {
+    /// Set the size of the tile in tile coordinates.
+    pub fn with_extent(mut self, extent: u32) -> Self {
+        self.extent = extent;
+        self
+    }
+
+    /// Set how far geometries extend beyond the tile edge, in tile coordinates.
+    pub fn with_buffer(mut self, buffer: u32) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    /// Set the coordinate reference system of the source geometries.
+    pub fn with_projection(mut self, projection: SourceProjection) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Set the name of the geometry column.
+    pub fn with_geometry_column(mut self, name: impl Into<String>) -> Self {
+        self.geometry_column = Some(name.into());
+        self
+    }
+
+    /// Set the name of the column to use as feature ids.
+    pub fn with_id_column(mut self, name: impl Into<String>) -> Self {
+        self.id_column = Some(name.into());
+        self
+    }
+}
+
+impl Default for MvtEncoderOptions {
+    fn default() -> Self {
+        Self {
+            extent: 4096,
+            buffer: 64,
+            projection: Default::default(),
+            geometry_column: None,
+            id_column: None,
+        }
+    }
+}
+
+/// A hashable version of a tag [`Value`], used to deduplicate layer values.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ValueKey {
+    String(String),
+    /// The bits of an `f32`
+    Float(u32),
+    /// The bits of an `f64`
+    Double(u64),
+    Int(i64),
+    Uint(u64),
+    Bool(bool),
+}
+
+impl From<ValueKey> for Value {
+    fn from(value: ValueKey) -> Self {
+        let mut out = Value::default();
+        match value {
+            ValueKey::String(v) => out.string_value = Some(v),
+            ValueKey::Float(v) => out.float_value = Some(f32::from_bits(v)),
+            ValueKey::Double(v) => out.double_value = Some(f64::from_bits(v)),
+            ValueKey::Int(v) => out.int_value = Some(v),
+            ValueKey::Uint(v) => out.uint_value = Some(v),
+            ValueKey::Bool(v) => out.bool_value = Some(v),
+        }
+        out
+    }
+}
+
+impl ValueKey {
+    /// The key of a decoded tag value, or `None` if no field is set.
+    fn from_value(value: &Value) -> Option<Self> {
+        if let Some(v) = &value.string_value {
+            Some(ValueKey::String(v.clone()))
+        } else if let Some(v) = value.float_value {
+            Some(ValueKey::Float(v.to_bits()))
+        } else if let Some(v) = value.double_value {
+            Some(ValueKey::Double(v.to_bits()))
+        } else if let Some(v) = value.int_value.or(value.sint_value) {
+            Some(ValueKey::Int(v))
+        } else if let Some(v) = value.uint_value {
+            Some(ValueKey::Uint(v))
+        } else {
+            value.bool_value.map(ValueKey::Bool)
+        }
+    }
+}
+
+/// A property column cast to one of the types representable as a tag value.
+struct PropertyColumn {
+    key: u32,
+    array: ArrayRef,
+}
+
+impl PropertyColumn {
+    fn try_new(key: u32, array: &ArrayRef) -> GeoArrowResult<Self> {
+        let target = match array.data_type() {
+            DataType::Boolean => DataType::Boolean,
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => DataType::Int64,
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                DataType::UInt64
+            }
+            DataType::Float16 | DataType::Float32 => DataType::Float32,
+            DataType::Float64 => DataType::Float64,
+            _ => DataType::Utf8,
+        };
+        Ok(Self {
+            key,
+            array: cast(array, &target)?,
+        })
+    }
+
+    fn value(&self, row: usize) -> Option<ValueKey> {
+        if self.array.is_null(row) {
+            return None;
+        }
+        let value = match self.array.data_type() {
+            DataType::Boolean => ValueKey::Bool(self.array.as_boolean().value(row)),
+            DataType::Int64 => ValueKey::Int(self.array.as_primitive::<Int64Type>().value(row)),
+            DataType::UInt64 => ValueKey::Uint(self.array.as_primitive::<UInt64Type>().value(row)),
+            DataType::Float32 => ValueKey::Float(
+                self.array
+                    .as_primitive::<Float32Type>()
+                    .value(row)
+                    .to_bits(),
+            ),
+            DataType::Float64 => ValueKey::Double(
+                self.array
+                    .as_primitive::<Float64Type>()
+                    .value(row)
+                    .to_bits(),
+            ),
+            _ => ValueKey::String(self.array.as_string::<i32>().value(row).to_string()),
+        };
+        Some(value)
+    }
+}
+
+/// Accumulates the features of one layer, deduplicating keys and values.
+#[derive(Debug)]
+struct LayerBuilder {
+    name: String,
+    keys: Vec<String>,
+    key_index: HashMap<String, u32>,
+    values: Vec<Value>,
+    value_index: HashMap<ValueKey, u32>,
+    features: Vec<Feature>,
+}
+
+impl LayerBuilder {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            keys: vec![],
+            key_index: HashMap::new(),
+            values: vec![],
+            value_index: HashMap::new(),
+            features: vec![],
+        }
+    }
+
+    fn key(&mut self, key: &str) -> u32 {
+        if let Some(idx) = self.key_index.get(key) {
+            return *idx;
+        }
+        let idx = self.keys.len() as u32;
+        self.keys.push(key.to_string());
+        self.key_index.insert(key.to_string(), idx);
+        idx
+    }
+
+    fn value(&mut self, value: ValueKey) -> u32 {
+        if let Some(idx) = self.value_index.get(&value) {
+            return *idx;
+        }
+        let idx = self.values.len() as u32;
+        self.values.push(value.clone().into());
+        self.value_index.insert(value, idx);
+        idx
+    }
+
+    fn finish(self, extent: u32) -> Layer {
+        Layer {
+            version: 2,
+            name: self.name,
+            features: self.features,
+            keys: self.keys,
+            values: self.values,
+            extent: Some(extent),
+        }
+    }
+}
+
+/// The builder of the layer with the given name, added if it doesn't exist yet.
+fn layer_builder<'a>(layers: &'a mut Vec<LayerBuilder>, name: &str) -> &'a mut LayerBuilder {
+    let idx = match layers.iter().position(|l| l.name == name) {
+        Some(idx) => idx,
+        None => {
+            layers.push(LayerBuilder::new(name.to_string()));
+            layers.len() - 1
+        }
+    };
+    &mut layers[idx]
+}
+
+/// The parts of a geometry in tile coordinates, grouped by MVT geometry type.
+#[derive(Debug, Default)]
+struct TileGeometry {
+    points: Vec<[f64; 2]>,
+    lines: Vec<Vec<[f64; 2]>>,
+    polygons: Vec<Vec<Vec<[f64; 2]>>>,
+}
+
+fn tile_coord(coord: &impl CoordTrait<T = f64>, transform: &TileTransform) -> [f64; 2] {
+    transform.forward(coord.x(), coord.y())
+}
+
+fn tile_line(line: &impl LineStringTrait<T = f64>, transform: &TileTransform) -> Vec<[f64; 2]> {
+    line.coords().map(|c| tile_coord(&c, transform)).collect()
+}
+
+impl TileGeometry {
+    fn add_geometry(&mut self, geometry: &impl GeometryTrait<T = f64>, transform: &TileTransform) {
+        match geometry.as_type() {
+            GeometryType::Point(g) => {
+                if let Some(c) = g.coord() {
+                    self.points.push(tile_coord(&c, transform));
+                }
+            }
+            GeometryType::MultiPoint(g) => {
+                for p in g.points() {
+                    if let Some(c) = p.coord() {
+                        self.points.push(tile_coord(&c, transform));
+                    }
+                }
+            }
+            GeometryType::LineString(g) => self.lines.push(tile_line(g, transform)),
+            GeometryType::MultiLineString(g) => {
+                for l in g.line_strings() {
+                    self.lines.push(tile_line(&l, transform));
+                }
+            }
+            GeometryType::Polygon(g) => self.add_polygon(g, transform),
+            GeometryType::MultiPolygon(g) => {
+                for p in g.polygons() {
+                    self.add_polygon(&p, transform);
+                }
+            }
+            GeometryType::GeometryCollection(g) => {
+                for g in g.geometries() {
+                    self.add_geometry(&g, transform);
+                }
+            }
+            GeometryType::Rect(g) => {
+                let (min, max) = (g.min(), g.max());
+                let ring = [
+                    (min.x(), min.y()),
+                    (max.x(), min.y()),
+                    (max.x(), max.y()),
+                    (min.x(), max.y()),
+                    (min.x(), min.y()),
+                ];
+                let ring = ring.map(|(x, y)| transform.forward(x, y)).to_vec();
+                self.polygons.push(vec![ring]);
+            }
+            GeometryType::Triangle(g) => {
+                let mut ring = g
+                    .coords()
+                    .iter()
+                    .map(|c| tile_coord(c, transform))
+                    .collect::<Vec<_>>();
+                ring.push(ring[0]);
+                self.polygons.push(vec![ring]);
+            }
+            GeometryType::Line(g) => self.lines.push(
+                g.coords()
+                    .iter()
+                    .map(|c| tile_coord(c, transform))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &impl PolygonTrait<T = f64>, transform: &TileTransform) {
+        if let Some(exterior) = polygon.exterior() {
+            let mut rings = vec![tile_line(&exterior, transform)];
+            rings.extend(polygon.interiors().map(|r| tile_line(&r, transform)));
+            self.polygons.push(rings);
+        }
+    }
+}
+
+/// Writes MVT geometry commands, tracking the cursor across all parts of a feature.
+#[derive(Debug, Default)]
+struct CommandEncoder {
+    commands: Vec<u32>,
+    cursor: [i32; 2],
+}
+
+impl CommandEncoder {
+    fn command(&mut self, id: u32, count: usize) {
+        self.commands.push((id & 0x7) | ((count as u32) << 3));
+    }
+
+    fn point(&mut self, point: [i32; 2]) {
+        let dx = point[0] - self.cursor[0];
+        let dy = point[1] - self.cursor[1];
+        self.commands.push(zigzag(dx));
+        self.commands.push(zigzag(dy));
+        self.cursor = point;
+    }
+
+    fn points(&mut self, points: &[[i32; 2]]) {
+        self.command(MOVE_TO, points.len());
+        for point in points {
+            self.point(*point);
+        }
+    }
+
+    fn line(&mut self, line: &[[i32; 2]]) {
+        self.command(MOVE_TO, 1);
+        self.point(line[0]);
+        self.command(LINE_TO, line.len() - 1);
+        for point in &line[1..] {
+            self.point(*point);
+        }
+    }
+
+    /// Write an unclosed ring.
+    fn ring(&mut self, ring: &[[i32; 2]]) {
+        self.line(ring);
+        self.command(CLOSE_PATH, 1);
+    }
+}
+
+fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Round to integer tile coordinates, dropping consecutive duplicates.
+fn quantize(coords: &[[f64; 2]]) -> Vec<[i32; 2]> {
+    let mut out: Vec<[i32; 2]> = Vec::with_capacity(coords.len());
+    for c in coords {
+        let c = [c[0].round() as i32, c[1].round() as i32];
+        if out.last() != Some(&c) {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Twice the signed area of an unclosed ring by the surveyor's formula.
+///
+/// In tile coordinates, with y pointing down, exterior rings have a positive area.
+pub(crate) fn ring_area(ring: &[[i32; 2]]) -> i64 {
+    let mut area = 0;
+    for (i, a) in ring.iter().enumerate() {
+        let b = ring[(i + 1) % ring.len()];
+        area += i64::from(a[0]) * i64::from(b[1]) - i64::from(b[0]) * i64::from(a[1]);
+    }
+    area
+}
+
+/// Clip, quantize and encode the parts of a geometry as one feature per MVT geometry type.
+fn encode_geometry(geometry: TileGeometry, clip: &ClipBox) -> Vec<(GeomType, Vec<u32>)> {
+    let mut out = vec![];
+
+    let mut points = geometry.points;
+    clip.clip_points(&mut points);
+    let points = quantize(&points);
+    if !points.is_empty() {
+        let mut encoder = CommandEncoder::default();
+        encoder.points(&points);
+        out.push((GeomType::Point, encoder.commands));
+    }
+
+    let mut encoder = CommandEncoder::default();
+    for line in &geometry.lines {
+        for piece in clip.clip_line(line) {
+            let piece = quantize(&piece);
+            if piece.len() > 1 {
+                encoder.line(&piece);
+            }
+        }
+    }
+    if !encoder.commands.is_empty() {
+        out.push((GeomType::Linestring, encoder.commands));
+    }
+
+    let mut encoder = CommandEncoder::default();
+    for polygon in &geometry.polygons {
+        let mut rings = polygon.iter().map(|ring| {
+            let mut ring = quantize(&clip.clip_ring(ring));
+            if ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+            ring
+        });
+
+        // Exterior rings must have a positive area, and interior rings a negative area
+        let mut exterior = match rings.next() {
+            Some(ring) if ring.len() >= 3 && ring_area(&ring) != 0 => ring,
+            _ => continue,
+        };
+        if ring_area(&exterior) < 0 {
+            exterior.reverse();
+        }
+        encoder.ring(&exterior);
+        for mut interior in rings {
+            if interior.len() < 3 || ring_area(&interior) == 0 {
+                continue;
+            }
+            if ring_area(&interior) > 0 {
+                interior.reverse();
+            }
+            encoder.ring(&interior);
+        }
+    }
+    if !encoder.commands.is_empty() {
+        out.push((GeomType::Polygon, encoder.commands));
+    }
+
+    out
+}
+
+/// Find the geometry column of a schema.
+fn geometry_column(schema: &Schema, name: Option<&str>) -> GeoArrowResult<usize> {
+    if let Some(name) = name {
+        return Ok(schema.index_of(name)?);
+    }
+    schema
+        .fields()
+        .iter()
+        .position(|field| {
+            field.extension_type_name().is_some() && GeoArrowType::try_from(field.as_ref()).is_ok()
+        })
+        .ok_or_else(|| GeoArrowError::Mvt("No geometry column found in record batch".to_string()))
+}
+
+/// An encoder for a single vector tile, made up of one or more layers.
+#[derive(Debug)]
+pub struct MvtEncoder {
+    tile: TileCoord,
+    options: MvtEncoderOptions,
+    transform: TileTransform,
+    clip: ClipBox,
+    layers: Vec<LayerBuilder>,
+}
+
+impl MvtEncoder {
+    /// Create an encoder for the given tile.
+    pub fn try_new(tile: TileCoord, options: MvtEncoderOptions) -> GeoArrowResult<Self> {
+        tile.validate()?;
+        let transform = TileTransform::new(tile, options.extent, options.projection);
+        let clip = ClipBox {
+            min: -f64::from(options.buffer),
+            max: f64::from(options.extent) + f64::from(options.buffer),
+        };
+        Ok(Self {
+            tile,
+            options,
+            transform,
+            clip,
+            layers: vec![],
+        })
+    }
+
+    /// The tile being encoded.
+    pub fn tile(&self) -> TileCoord {
+        self.tile
+    }
+
+    /// Add the rows of a record batch as features of the layer with the given name.
+    ///
+    /// Calling this several times with the same layer name appends to that layer. All columns
+    /// other than the geometry and id columns are written as properties. Boolean, integer and
+    /// floating point columns keep their type, and all other columns are written as strings. Null
+    /// values are omitted, as are rows whose geometry is null or lies outside the buffered tile.
+    pub fn add_batch(&mut self, layer_name: &str, batch: &RecordBatch) -> GeoArrowResult<()> {
+        let schema = batch.schema();
+        let geometry_idx = geometry_column(&schema, self.options.geometry_column.as_deref())?;
+        let id_idx = self
+            .options
+            .id_column
+            .as_deref()
+            .map(|name| schema.index_of(name))
+            .transpose()?;
+
+        let layer = layer_builder(&mut self.layers, layer_name);
+
+        let mut properties = vec![];
+        for (idx, field) in schema.fields().iter().enumerate() {
+            if idx != geometry_idx && Some(idx) != id_idx {
+                let key = layer.key(field.name());
+                properties.push(PropertyColumn::try_new(key, batch.column(idx))?);
+            }
+        }
+        let ids = id_idx
+            .map(|idx| cast(batch.column(idx), &DataType::UInt64))
+            .transpose()?;
+        let ids = ids.as_ref().map(|ids| ids.as_primitive::<UInt64Type>());
+
+        // Iterating over WKB gives uniform access to every geometry type
+        let geometry = from_arrow_array(
+            batch.column(geometry_idx).as_ref(),
+            schema.field(geometry_idx),
+        )?;
+        let wkb = to_wkb::<i32>(geometry.as_ref())?;
+
+        for (row, geometry) in wkb.iter().enumerate() {
+            let Some(geometry) = geometry.transpose()? else {
+                continue;
+            };
+            let mut tile_geometry = TileGeometry::default();
+            tile_geometry.add_geometry(&geometry, &self.transform);
+            let parts = encode_geometry(tile_geometry, &self.clip);
+            if parts.is_empty() {
+                continue;
+            }
+
+            let mut tags = vec![];
+            for property in &properties {
+                if let Some(value) = property.value(row) {
+                    tags.push(property.key);
+                    tags.push(layer.value(value));
+                }
+            }
+            let id = ids.and_then(|ids| ids.is_valid(row).then(|| ids.value(row)));
+
+            for (geom_type, commands) in parts {
+                layer.features.push(Feature {
+                    id,
+                    tags: tags.clone(),
+                    r#type: Some(geom_type as i32),
+                    geometry: commands,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Merge the features of an encoded tile, such as an earlier result of [`Self::finish`] for
+    /// the same tile, into this tile.
+    ///
+    /// Features are appended to the layer of the same name, and their keys and values are
+    /// deduplicated with those already in the layer. Geometries are copied as they are, so the
+    /// tile must have been encoded with the same extent.
+    pub fn merge_tile(&mut self, buf: &[u8]) -> GeoArrowResult<()> {
+        let tile = Tile::decode(buf).map_err(|err| GeoArrowError::Mvt(err.to_string()))?;
+        let extent = self.options.extent;
+        for layer in tile.layers {
+            if layer.extent.unwrap_or(4096) != extent {
+                return Err(GeoArrowError::Mvt(format!(
+                    "Cannot merge layer {} with extent {:?} into a tile with extent {extent}",
+                    layer.name, layer.extent
+                )));
+            }
+            let builder = layer_builder(&mut self.layers, &layer.name);
+            let keys = layer
+                .keys
+                .iter()
+                .map(|key| builder.key(key))
+                .collect::<Vec<_>>();
+            let values = layer
+                .values
+                .iter()
+                .map(|value| {
+                    let value = ValueKey::from_value(value).ok_or_else(|| {
+                        GeoArrowError::Mvt("Tag value without a value".to_string())
+                    })?;
+                    Ok(builder.value(value))
+                })
+                .collect::<GeoArrowResult<Vec<_>>>()?;
+            for mut feature in layer.features {
+                for tag in feature.tags.chunks_exact_mut(2) {
+                    let (Some(key), Some(value)) =
+                        (keys.get(tag[0] as usize), values.get(tag[1] as usize))
+                    else {
+                        return Err(GeoArrowError::Mvt(format!(
+                            "Tag index out of range in layer {}",
+                            layer.name
+                        )));
+                    };
+                    tag[0] = *key;
+                    tag[1] = *value;
+                }
+                builder.features.push(feature);
+            }
+        }
+        Ok(())
+    }
+
+    /// Finish the tile and return its protobuf encoding.
+    ///
+    /// Layers without any features are omitted.
+    pub fn finish(self) -> Vec<u8> {
+        let extent = self.options.extent;
+        let tile = Tile {
+            layers: self
+                .layers
+                .into_iter()
+                .filter(|layer| !layer.features.is_empty())
+                .map(|layer| layer.finish(extent))
+                .collect(),
+        };
+        tile.encode_to_vec()
+    }
+}
+
+/// Encode a record batch as a single-layer vector tile.
+pub fn encode_tile(
+    layer_name: &str,
+    batch: &RecordBatch,
+    tile: TileCoord,
+    options: MvtEncoderOptions,
+) -> GeoArrowResult<Vec<u8>> {
+    let mut encoder = MvtEncoder::try_new(tile, options)?;
+    encoder.add_batch(layer_name, batch)?;
+    Ok(encoder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Examples from section 4.3.5 of the specification
+    #[test]
+    fn encode_commands() {
+        let mut encoder = CommandEncoder::default();
+        encoder.points(&[[25, 17]]);
+        assert_eq!(encoder.commands, [9, 50, 34]);
+
+        let mut encoder = CommandEncoder::default();
+        encoder.points(&[[5, 7], [3, 2]]);
+        assert_eq!(encoder.commands, [17, 10, 14, 3, 9]);
+
+        let mut encoder = CommandEncoder::default();
+        encoder.line(&[[2, 2], [2, 10], [10, 10]]);
+        assert_eq!(encoder.commands, [9, 4, 4, 18, 0, 16, 16, 0]);
+
+        let mut encoder = CommandEncoder::default();
+        encoder.ring(&[[3, 6], [8, 12], [20, 34]]);
+        assert_eq!(encoder.commands, [9, 6, 12, 18, 10, 12, 24, 44, 15]);
+    }
+
+    #[test]
+    fn polygon_winding() {
+        let clip = ClipBox {
+            min: 0.0,
+            max: 100.0,
+        };
+        // A counter-clockwise exterior ring in tile coordinates is reversed
+        let ring = vec![
+            [0.0, 0.0],
+            [0.0, 10.0],
+            [10.0, 10.0],
+            [10.0, 0.0],
+            [0.0, 0.0],
+        ];
+        let geometry = TileGeometry {
+            polygons: vec![vec![ring]],
+            ..Default::default()
+        };
+        let parts = encode_geometry(geometry, &clip);
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].0, GeomType::Polygon);
+        // MoveTo(10, 0), LineTo(10, 10), (0, 10), (0, 0), ClosePath
+        assert_eq!(parts[0].1, [9, 20, 0, 26, 0, 20, 19, 0, 0, 19, 15]);
+    }
+}
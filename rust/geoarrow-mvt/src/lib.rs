@@ -0,0 +1,18 @@
+//! Encode and decode [Mapbox Vector Tiles](https://github.com/mapbox/vector-tile-spec) from and
+//! to record batches with GeoArrow geometry columns.
+//!
+//! The encoder projects, clips and quantizes geometries to a tile's coordinate space and writes
+//! the other columns as feature properties. The decoder reads each layer of a tile back into a
+//! record batch. Tiles are addressed with [`TileCoord`] from [`geoarrow_geo::tiling`], which also
+//! has kernels computing the Web Mercator tiles of points and geometries.
+
+#![warn(missing_docs)]
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+mod clip;
+pub mod decoder;
+pub mod encoder;
+mod tile;
+mod vector_tile;
+
+pub use tile::{SourceProjection, TileCoord};
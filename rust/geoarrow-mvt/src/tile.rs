@@ -0,0 +1,70 @@
+//! The transform between source coordinates and tile coordinates.
+
+pub use geoarrow_geo::tiling::{SourceProjection, TileCoord};
+
+/// Converts between source coordinates and tile coordinates in `[0, extent]`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TileTransform {
+    projection: SourceProjection,
+    /// The size of the world in tile coordinates
+    scale: f64,
+    origin_x: f64,
+    origin_y: f64,
+}
+
+impl TileTransform {
+    pub(crate) fn new(tile: TileCoord, extent: u32, projection: SourceProjection) -> Self {
+        let extent = f64::from(extent);
+        Self {
+            projection,
+            scale: (1_u64 << tile.z) as f64 * extent,
+            origin_x: f64::from(tile.x) * extent,
+            origin_y: f64::from(tile.y) * extent,
+        }
+    }
+
+    pub(crate) fn forward(&self, x: f64, y: f64) -> [f64; 2] {
+        let (wx, wy) = self.projection.to_world(x, y);
+        [
+            wx * self.scale - self.origin_x,
+            wy * self.scale - self.origin_y,
+        ]
+    }
+
+    pub(crate) fn inverse(&self, x: f64, y: f64) -> [f64; 2] {
+        let (x, y) = self.projection.from_world(
+            (x + self.origin_x) / self.scale,
+            (y + self.origin_y) / self.scale,
+        );
+        [x, y]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+    use geoarrow_geo::web_mercator::MAX_LATITUDE;
+
+    use super::*;
+
+    #[test]
+    fn round_trip_transform() {
+        let tile = TileCoord::new(10, 301, 384);
+        for projection in [SourceProjection::LonLat, SourceProjection::WebMercator] {
+            let transform = TileTransform::new(tile, 4096, projection);
+            let source = transform.inverse(100.0, 200.0);
+            let [x, y] = transform.forward(source[0], source[1]);
+            assert_relative_eq!(x, 100.0, epsilon = 1e-6);
+            assert_relative_eq!(y, 200.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn world_tile() {
+        let transform = TileTransform::new(TileCoord::new(0, 0, 0), 4096, SourceProjection::LonLat);
+        assert_eq!(transform.forward(0.0, 0.0), [2048.0, 2048.0]);
+        let [x, y] = transform.forward(-180.0, MAX_LATITUDE);
+        assert_relative_eq!(x, 0.0);
+        assert_relative_eq!(y, 0.0, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,196 @@
+//! Encoding, decoding and lookup of PMTiles directories.
+
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+use crate::header::{Compression, HEADER_LEN, MAX_INITIAL_BYTES};
+
+/// A directory entry.
+///
+/// An entry with a `run_length` of 0 points to a leaf directory rather than to tile data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Entry {
+    pub(crate) tile_id: u64,
+    pub(crate) offset: u64,
+    pub(crate) length: u32,
+    pub(crate) run_length: u32,
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(buf: &[u8], pos: &mut usize) -> GeoArrowResult<u64> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = *buf.get(*pos).ok_or_else(|| {
+            GeoArrowError::PmTiles("Directory ended in the middle of a varint".to_string())
+        })?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(GeoArrowError::PmTiles("Varint is too long".to_string()))
+}
+
+/// Serialize and compress a directory. Entries must be sorted by tile id.
+pub(crate) fn serialize(entries: &[Entry], compression: Compression) -> GeoArrowResult<Vec<u8>> {
+    let mut buf = vec![];
+    write_varint(&mut buf, entries.len() as u64);
+
+    let mut last_id = 0;
+    for entry in entries {
+        write_varint(&mut buf, entry.tile_id - last_id);
+        last_id = entry.tile_id;
+    }
+    for entry in entries {
+        write_varint(&mut buf, entry.run_length.into());
+    }
+    for entry in entries {
+        write_varint(&mut buf, entry.length.into());
+    }
+    for (i, entry) in entries.iter().enumerate() {
+        // An offset directly following the previous entry is written as 0
+        if i > 0 && entry.offset == entries[i - 1].offset + u64::from(entries[i - 1].length) {
+            write_varint(&mut buf, 0);
+        } else {
+            write_varint(&mut buf, entry.offset + 1);
+        }
+    }
+
+    compression.compress(&buf)
+}
+
+/// Decompress and deserialize a directory.
+pub(crate) fn deserialize(buf: &[u8], compression: Compression) -> GeoArrowResult<Vec<Entry>> {
+    let buf = compression.decompress(buf)?;
+    let mut pos = 0;
+    let num_entries = read_varint(&buf, &mut pos)? as usize;
+    let mut entries = Vec::with_capacity(num_entries.min(buf.len()));
+
+    let mut last_id = 0;
+    for _ in 0..num_entries {
+        last_id += read_varint(&buf, &mut pos)?;
+        entries.push(Entry {
+            tile_id: last_id,
+            offset: 0,
+            length: 0,
+            run_length: 0,
+        });
+    }
+    for entry in entries.iter_mut() {
+        entry.run_length = read_varint(&buf, &mut pos)? as u32;
+    }
+    for entry in entries.iter_mut() {
+        entry.length = read_varint(&buf, &mut pos)? as u32;
+    }
+    for i in 0..entries.len() {
+        let value = read_varint(&buf, &mut pos)?;
+        entries[i].offset = if value == 0 && i > 0 {
+            entries[i - 1].offset + u64::from(entries[i - 1].length)
+        } else {
+            value.saturating_sub(1)
+        };
+    }
+    Ok(entries)
+}
+
+/// Serialize the root directory and any leaf directories needed to keep the root directory
+/// within the first 16 KiB of the archive.
+///
+/// Returns the root directory and the concatenated leaf directories.
+pub(crate) fn build_directories(
+    entries: &[Entry],
+    compression: Compression,
+) -> GeoArrowResult<(Vec<u8>, Vec<u8>)> {
+    let root = serialize(entries, compression)?;
+    if root.len() <= MAX_INITIAL_BYTES - HEADER_LEN {
+        return Ok((root, vec![]));
+    }
+
+    let mut leaf_size = 4096;
+    loop {
+        let mut leaves = vec![];
+        let mut root_entries = vec![];
+        for chunk in entries.chunks(leaf_size) {
+            let leaf = serialize(chunk, compression)?;
+            root_entries.push(Entry {
+                tile_id: chunk[0].tile_id,
+                offset: leaves.len() as u64,
+                length: leaf.len() as u32,
+                run_length: 0,
+            });
+            leaves.extend_from_slice(&leaf);
+        }
+
+        let root = serialize(&root_entries, compression)?;
+        if root.len() <= MAX_INITIAL_BYTES - HEADER_LEN {
+            return Ok((root, leaves));
+        }
+        leaf_size *= 2;
+    }
+}
+
+/// Find the entry for a tile id in a directory sorted by tile id.
+///
+/// The returned entry either contains the tile or is a leaf directory that may contain it.
+pub(crate) fn find_tile(entries: &[Entry], tile_id: u64) -> Option<&Entry> {
+    let idx = entries.partition_point(|entry| entry.tile_id <= tile_id);
+    let entry = entries.get(idx.checked_sub(1)?)?;
+    if entry.run_length == 0 || tile_id - entry.tile_id < u64::from(entry.run_length) {
+        Some(entry)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entries(n: u64) -> Vec<Entry> {
+        (0..n)
+            .map(|i| Entry {
+                tile_id: i * 2,
+                offset: i * 10,
+                length: 10,
+                run_length: 1,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn round_trip_directory() {
+        let entries = entries(100);
+        for compression in [Compression::None, Compression::Gzip] {
+            let buf = serialize(&entries, compression).unwrap();
+            assert_eq!(deserialize(&buf, compression).unwrap(), entries);
+        }
+    }
+
+    #[test]
+    fn leaf_directories() {
+        let entries = entries(50_000);
+        let (root, leaves) = build_directories(&entries, Compression::None).unwrap();
+        assert!(root.len() <= MAX_INITIAL_BYTES - HEADER_LEN);
+        assert!(!leaves.is_empty());
+
+        let root = deserialize(&root, Compression::None).unwrap();
+        let leaf_entry = find_tile(&root, 20_000).unwrap();
+        assert_eq!(leaf_entry.run_length, 0);
+        let start = leaf_entry.offset as usize;
+        let leaf = deserialize(
+            &leaves[start..start + leaf_entry.length as usize],
+            Compression::None,
+        )
+        .unwrap();
+        let entry = find_tile(&leaf, 20_000).unwrap();
+        assert_eq!(entry.offset, 100_000);
+        assert!(find_tile(&leaf, 20_001).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+//! The fixed-size PMTiles v3 header and the compression codecs it refers to.
+
+use std::io::{Read, Write};
+
+use flate2::Compression as GzipLevel;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+/// The length of the header in bytes.
+pub(crate) const HEADER_LEN: usize = 127;
+
+/// The root directory must end within this many bytes of the start of the archive.
+pub(crate) const MAX_INITIAL_BYTES: usize = 16_384;
+
+const MAGIC: &[u8; 7] = b"PMTiles";
+const VERSION: u8 = 3;
+
+/// The compression of tiles or of directories and metadata.
+///
+/// Only the codecs this crate can write are supported when reading.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compression {
+    /// No compression
+    None,
+    /// gzip compression
+    #[default]
+    Gzip,
+}
+
+impl Compression {
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::None => 1,
+            Self::Gzip => 2,
+        }
+    }
+
+    fn from_byte(value: u8) -> GeoArrowResult<Self> {
+        match value {
+            1 => Ok(Self::None),
+            2 => Ok(Self::Gzip),
+            other => Err(GeoArrowError::PmTiles(format!(
+                "Unsupported compression {other}"
+            ))),
+        }
+    }
+
+    pub(crate) fn compress(self, data: &[u8]) -> GeoArrowResult<Vec<u8>> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(vec![], GzipLevel::default());
+                encoder.write_all(data)?;
+                Ok(encoder.finish()?)
+            }
+        }
+    }
+
+    pub(crate) fn decompress(self, data: &[u8]) -> GeoArrowResult<Vec<u8>> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            Self::Gzip => {
+                let mut out = vec![];
+                GzDecoder::new(data).read_to_end(&mut out)?;
+                Ok(out)
+            }
+        }
+    }
+}
+
+/// The header of a PMTiles v3 archive.
+///
+/// Offsets and lengths are in bytes from the start of the archive. Bounds and the center are in
+/// degrees of longitude and latitude.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Header {
+    /// Offset of the root directory
+    pub root_dir_offset: u64,
+    /// Length of the root directory
+    pub root_dir_length: u64,
+    /// Offset of the JSON metadata
+    pub metadata_offset: u64,
+    /// Length of the JSON metadata
+    pub metadata_length: u64,
+    /// Offset of the leaf directories
+    pub leaf_dirs_offset: u64,
+    /// Length of the leaf directories
+    pub leaf_dirs_length: u64,
+    /// Offset of the tile data
+    pub tile_data_offset: u64,
+    /// Length of the tile data
+    pub tile_data_length: u64,
+    /// The number of tiles that have data
+    pub addressed_tiles: u64,
+    /// The number of directory entries pointing to tiles
+    pub tile_entries: u64,
+    /// The number of distinct tile contents
+    pub tile_contents: u64,
+    /// Whether the tile data is ordered by tile id
+    pub clustered: bool,
+    /// The compression of directories and metadata
+    pub internal_compression: Compression,
+    /// The compression of tiles
+    pub tile_compression: Compression,
+    /// The minimum zoom level of the tiles
+    pub min_zoom: u8,
+    /// The maximum zoom level of the tiles
+    pub max_zoom: u8,
+    /// The bounds of the data as `(min_lon, min_lat, max_lon, max_lat)`
+    pub bounds: (f64, f64, f64, f64),
+    /// The zoom level of the initial view
+    pub center_zoom: u8,
+    /// The center of the initial view as `(lon, lat)`
+    pub center: (f64, f64),
+}
+
+/// Tile type byte for Mapbox Vector Tiles
+const TILE_TYPE_MVT: u8 = 1;
+
+fn to_e7(value: f64) -> [u8; 4] {
+    ((value * 10_000_000.0).round() as i32).to_le_bytes()
+}
+
+fn from_e7(bytes: &[u8]) -> f64 {
+    f64::from(i32::from_le_bytes(bytes.try_into().unwrap())) / 10_000_000.0
+}
+
+impl Header {
+    pub(crate) fn write(&self, out: &mut impl Write) -> GeoArrowResult<()> {
+        let mut buf = Vec::with_capacity(HEADER_LEN);
+        buf.extend_from_slice(MAGIC);
+        buf.push(VERSION);
+        for value in [
+            self.root_dir_offset,
+            self.root_dir_length,
+            self.metadata_offset,
+            self.metadata_length,
+            self.leaf_dirs_offset,
+            self.leaf_dirs_length,
+            self.tile_data_offset,
+            self.tile_data_length,
+            self.addressed_tiles,
+            self.tile_entries,
+            self.tile_contents,
+        ] {
+            buf.extend_from_slice(&value.to_le_bytes());
+        }
+        buf.push(u8::from(self.clustered));
+        buf.push(self.internal_compression.to_byte());
+        buf.push(self.tile_compression.to_byte());
+        buf.push(TILE_TYPE_MVT);
+        buf.push(self.min_zoom);
+        buf.push(self.max_zoom);
+        let (min_lon, min_lat, max_lon, max_lat) = self.bounds;
+        for value in [min_lon, min_lat, max_lon, max_lat] {
+            buf.extend_from_slice(&to_e7(value));
+        }
+        buf.push(self.center_zoom);
+        buf.extend_from_slice(&to_e7(self.center.0));
+        buf.extend_from_slice(&to_e7(self.center.1));
+        debug_assert_eq!(buf.len(), HEADER_LEN);
+
+        out.write_all(&buf)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(buf: &[u8]) -> GeoArrowResult<Self> {
+        if buf.len() < HEADER_LEN || &buf[0..7] != MAGIC {
+            return Err(GeoArrowError::PmTiles(
+                "File is missing the 'PMTiles' magic bytes".to_string(),
+            ));
+        }
+        if buf[7] != VERSION {
+            return Err(GeoArrowError::PmTiles(format!(
+                "Unsupported PMTiles version {}",
+                buf[7]
+            )));
+        }
+        if buf[99] != TILE_TYPE_MVT {
+            return Err(GeoArrowError::PmTiles(format!(
+                "Unsupported tile type {}, only vector tiles are supported",
+                buf[99]
+            )));
+        }
+
+        let u64_at = |idx: usize| {
+            let start = 8 + idx * 8;
+            u64::from_le_bytes(buf[start..start + 8].try_into().unwrap())
+        };
+        Ok(Self {
+            root_dir_offset: u64_at(0),
+            root_dir_length: u64_at(1),
+            metadata_offset: u64_at(2),
+            metadata_length: u64_at(3),
+            leaf_dirs_offset: u64_at(4),
+            leaf_dirs_length: u64_at(5),
+            tile_data_offset: u64_at(6),
+            tile_data_length: u64_at(7),
+            addressed_tiles: u64_at(8),
+            tile_entries: u64_at(9),
+            tile_contents: u64_at(10),
+            clustered: buf[96] == 1,
+            internal_compression: Compression::from_byte(buf[97])?,
+            tile_compression: Compression::from_byte(buf[98])?,
+            min_zoom: buf[100],
+            max_zoom: buf[101],
+            bounds: (
+                from_e7(&buf[102..106]),
+                from_e7(&buf[106..110]),
+                from_e7(&buf[110..114]),
+                from_e7(&buf[114..118]),
+            ),
+            center_zoom: buf[118],
+            center: (from_e7(&buf[119..123]), from_e7(&buf[123..127])),
+        })
+    }
+}
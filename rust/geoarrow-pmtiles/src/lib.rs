@@ -0,0 +1,17 @@
+//! Write record batches with GeoArrow geometry columns to
+//! [PMTiles](https://github.com/protomaps/PMTiles/blob/main/spec/v3/spec.md) v3 archives of
+//! Mapbox Vector Tiles, and read the tiles back into record batches.
+//!
+//! Only local files are supported. Tiles are encoded and decoded with [`geoarrow_mvt`].
+
+#![warn(missing_docs)]
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+mod directory;
+mod header;
+pub mod reader;
+mod tile_id;
+pub mod writer;
+
+pub use header::{Compression, Header};
+pub use tile_id::{tile_coord, tile_id};
@@ -0,0 +1,330 @@
+//! Read vector tiles from a PMTiles archive into record batches.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use geoarrow_mvt::TileCoord;
+use geoarrow_mvt::decoder::{MvtDecoderOptions, MvtLayer, decode_tile};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+use crate::directory::{Entry, deserialize, find_tile};
+use crate::header::{HEADER_LEN, Header};
+use crate::tile_id::{tile_coord, tile_id};
+
+/// The maximum depth of leaf directories, as allowed by the specification.
+const MAX_DEPTH: usize = 3;
+
+/// A reader for local PMTiles v3 archives of vector tiles.
+#[derive(Debug)]
+pub struct PmTilesReader<R> {
+    reader: R,
+    /// The length of the archive, which bounds every range read from it
+    len: u64,
+    header: Header,
+    root: Vec<Entry>,
+}
+
+impl<R: Read + Seek> PmTilesReader<R> {
+    /// Open a PMTiles archive, reading its header and root directory.
+    pub fn try_new(mut reader: R) -> GeoArrowResult<Self> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        let mut buf = [0; HEADER_LEN];
+        reader.rewind()?;
+        reader.read_exact(&mut buf)?;
+        let header = Header::read(&buf)?;
+
+        let mut reader = Self {
+            reader,
+            len,
+            header,
+            root: vec![],
+        };
+        let root =
+            reader.read_range(reader.header.root_dir_offset, reader.header.root_dir_length)?;
+        reader.root = deserialize(&root, reader.header.internal_compression)?;
+        Ok(reader)
+    }
+
+    /// The header of the archive.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// The JSON metadata of the archive.
+    pub fn metadata(&mut self) -> GeoArrowResult<String> {
+        let buf = self.read_range(self.header.metadata_offset, self.header.metadata_length)?;
+        let buf = self.header.internal_compression.decompress(&buf)?;
+        String::from_utf8(buf).map_err(|err| GeoArrowError::PmTiles(err.to_string()))
+    }
+
+    /// Read `length` bytes at `offset`, which come from the archive and are checked against its
+    /// length before allocating.
+    fn read_range(&mut self, offset: u64, length: u64) -> GeoArrowResult<Vec<u8>> {
+        if offset.checked_add(length).is_none_or(|end| end > self.len) {
+            return Err(GeoArrowError::PmTiles(format!(
+                "Range of {length} bytes at offset {offset} is outside the archive of {} bytes",
+                self.len
+            )));
+        }
+        let mut buf = vec![0; length as usize];
+        self.reader.seek(SeekFrom::Start(offset))?;
+        self.reader.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn read_leaf(&mut self, entry: &Entry) -> GeoArrowResult<Vec<Entry>> {
+        let buf = self.read_range(
+            self.header.leaf_dirs_offset + entry.offset,
+            entry.length.into(),
+        )?;
+        deserialize(&buf, self.header.internal_compression)
+    }
+
+    /// The decompressed contents of a tile, or `None` if the archive doesn't contain it.
+    pub fn get_tile(&mut self, tile: TileCoord) -> GeoArrowResult<Option<Vec<u8>>> {
+        let id = tile_id(tile)?;
+        let mut directory = self.root.clone();
+        for _ in 0..=MAX_DEPTH {
+            let Some(entry) = find_tile(&directory, id).copied() else {
+                return Ok(None);
+            };
+            if entry.run_length > 0 {
+                let buf = self.read_range(
+                    self.header.tile_data_offset + entry.offset,
+                    entry.length.into(),
+                )?;
+                return Ok(Some(self.header.tile_compression.decompress(&buf)?));
+            }
+            directory = self.read_leaf(&entry)?;
+        }
+        Err(GeoArrowError::PmTiles(
+            "Leaf directories are nested too deeply".to_string(),
+        ))
+    }
+
+    /// Decode a tile into one record batch per layer, with geometries in the projection set in
+    /// `options`.
+    ///
+    /// Returns an empty list if the archive doesn't contain the tile.
+    pub fn read_tile(
+        &mut self,
+        tile: TileCoord,
+        options: &MvtDecoderOptions,
+    ) -> GeoArrowResult<Vec<MvtLayer>> {
+        match self.get_tile(tile)? {
+            Some(buf) => decode_tile(&buf, &options.clone().with_tile(tile)),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// The addresses of all tiles in the archive, ordered by tile id.
+    pub fn tiles(&mut self) -> GeoArrowResult<Vec<TileCoord>> {
+        let mut tiles = vec![];
+        let root = self.root.clone();
+        self.collect_tiles(&root, 0, &mut tiles)?;
+        Ok(tiles)
+    }
+
+    fn collect_tiles(
+        &mut self,
+        directory: &[Entry],
+        depth: usize,
+        tiles: &mut Vec<TileCoord>,
+    ) -> GeoArrowResult<()> {
+        for entry in directory {
+            if entry.run_length > 0 {
+                for id in entry.tile_id..entry.tile_id + u64::from(entry.run_length) {
+                    tiles.push(tile_coord(id)?);
+                }
+            } else if depth < MAX_DEPTH {
+                let leaf = self.read_leaf(entry)?;
+                self.collect_tiles(&leaf, depth + 1, tiles)?;
+            } else {
+                return Err(GeoArrowError::PmTiles(
+                    "Leaf directories are nested too deeply".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Consume this and return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Int64Array, RecordBatch, RecordBatchIterator, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use geo_types::{Geometry, LineString, Point};
+    use geoarrow_array::GeoArrowArray;
+    use geoarrow_array::builder::GeometryBuilder;
+    use geoarrow_schema::GeometryType;
+
+    use super::*;
+    use crate::header::Compression;
+    use crate::writer::{PmTilesWriterOptions, write_pmtiles};
+
+    fn batch() -> RecordBatch {
+        let geometries = vec![
+            Some(Geometry::Point(Point::new(13.4, 52.5))),
+            Some(Geometry::Point(Point::new(-74.0, 40.7))),
+            Some(Geometry::LineString(LineString::from(vec![
+                (-10.0, 10.0),
+                (10.0, -10.0),
+            ]))),
+        ];
+        let geometry_type = GeometryType::new(Default::default());
+        let geometry =
+            GeometryBuilder::from_nullable_geometries(&geometries, geometry_type.clone())
+                .unwrap()
+                .finish();
+        let schema = Schema::new(vec![
+            Field::new("population", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, false),
+            geometry_type.to_field("geometry", true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int64Array::from(vec![3_700_000, 8_300_000, 0])),
+                Arc::new(StringArray::from(vec!["Berlin", "New York", "line"])),
+                geometry.to_array_ref(),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn write_batches(
+        batches: Vec<RecordBatch>,
+        options: &PmTilesWriterOptions,
+    ) -> GeoArrowResult<Vec<u8>> {
+        let schema = batches[0].schema();
+        let mut buf = Cursor::new(vec![]);
+        write_pmtiles(
+            RecordBatchIterator::new(batches.into_iter().map(Ok), schema),
+            &mut buf,
+            options,
+        )?;
+        Ok(buf.into_inner())
+    }
+
+    fn write(options: &PmTilesWriterOptions) -> Vec<u8> {
+        write_batches(vec![batch()], options).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let options = PmTilesWriterOptions::default()
+            .with_zoom_range(0, 3)
+            .with_layer_name("places");
+        let buf = write(&options);
+
+        let mut reader = PmTilesReader::try_new(Cursor::new(buf)).unwrap();
+        let header = reader.header().clone();
+        assert_eq!((header.min_zoom, header.max_zoom), (0, 3));
+        assert!((header.bounds.0 - -74.0).abs() < 1e-6);
+        assert!((header.bounds.3 - 52.5).abs() < 1e-6);
+        assert!(
+            reader
+                .metadata()
+                .unwrap()
+                .contains("\"population\":\"Number\"")
+        );
+
+        // Each of the three geometries touches at least one tile at every zoom level
+        let tiles = reader.tiles().unwrap();
+        assert_eq!(tiles[0], TileCoord::new(0, 0, 0));
+        assert_eq!(tiles.len() as u64, header.addressed_tiles);
+        assert!(tiles.iter().all(|t| t.z <= 3));
+
+        let layers = reader
+            .read_tile(TileCoord::new(0, 0, 0), &Default::default())
+            .unwrap();
+        assert_eq!(layers.len(), 1);
+        assert_eq!(layers[0].name, "places");
+        let names = layers[0].batch.column_by_name("name").unwrap();
+        assert_eq!(names.as_string::<i32>().len(), 3);
+
+        // Berlin lies in the north-east quadrant at zoom 1
+        let layers = reader
+            .read_tile(TileCoord::new(1, 1, 0), &Default::default())
+            .unwrap();
+        let names = layers[0].batch.column_by_name("name").unwrap();
+        let names = names.as_string::<i32>();
+        assert!(names.iter().any(|name| name == Some("Berlin")));
+        assert!(names.iter().all(|name| name != Some("New York")));
+
+        assert!(reader.get_tile(TileCoord::new(3, 0, 7)).unwrap().is_none());
+    }
+
+    #[test]
+    fn uncompressed() {
+        let options = PmTilesWriterOptions::default()
+            .with_zoom_range(2, 2)
+            .with_tile_compression(Compression::None)
+            .with_internal_compression(Compression::None);
+        let buf = write(&options);
+
+        let mut reader = PmTilesReader::try_new(Cursor::new(buf)).unwrap();
+        assert_eq!(reader.header().tile_compression, Compression::None);
+        let tiles = reader.tiles().unwrap();
+        assert!(tiles.iter().all(|t| t.z == 2));
+        for tile in tiles {
+            assert!(
+                !reader
+                    .read_tile(tile, &Default::default())
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+    }
+
+    #[test]
+    fn sorted_stream() {
+        let options = PmTilesWriterOptions::default().with_zoom_range(0, 3);
+        let batch = batch();
+        // New York, the line and Berlin, sorted from west to east
+        let sorted = vec![batch.slice(1, 1), batch.slice(2, 1), batch.slice(0, 1)];
+        let buf = write_batches(sorted, &options).unwrap();
+
+        let mut sorted_reader = PmTilesReader::try_new(Cursor::new(buf)).unwrap();
+        let mut reader = PmTilesReader::try_new(Cursor::new(write(&options))).unwrap();
+        assert_eq!(sorted_reader.tiles().unwrap(), reader.tiles().unwrap());
+        assert_eq!(
+            sorted_reader.header().addressed_tiles,
+            reader.header().addressed_tiles
+        );
+
+        // New York and Berlin, then the line, which touches tiles west of Berlin that were written
+        // once the stream passed them. Those rows are merged with the written tiles at the end
+        let unsorted = vec![batch.slice(1, 1), batch.slice(0, 1), batch.slice(2, 1)];
+        let buf = write_batches(unsorted, &options).unwrap();
+        let mut unsorted_reader = PmTilesReader::try_new(Cursor::new(buf)).unwrap();
+        assert_eq!(unsorted_reader.tiles().unwrap(), reader.tiles().unwrap());
+        for tile in reader.tiles().unwrap() {
+            let expected = reader.read_tile(tile, &Default::default()).unwrap();
+            let layers = unsorted_reader
+                .read_tile(tile, &Default::default())
+                .unwrap();
+            assert_eq!(layers.len(), expected.len());
+            for (layer, expected) in layers.iter().zip(&expected) {
+                assert_eq!(layer.batch.num_rows(), expected.batch.num_rows());
+            }
+        }
+    }
+
+    #[test]
+    fn range_outside_archive() {
+        let mut buf = write(&PmTilesWriterOptions::default().with_zoom_range(0, 1));
+        // Point the root directory past the end of the archive
+        let len = buf.len() as u64;
+        buf[8..16].copy_from_slice(&len.to_le_bytes());
+        assert!(PmTilesReader::try_new(Cursor::new(buf)).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+//! Conversion between XYZ tile addresses and PMTiles tile ids.
+//!
+//! A tile id is the position of a tile on the Hilbert curve at its zoom level, offset by the
+//! number of tiles at all lower zoom levels.
+
+use geoarrow_mvt::TileCoord;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+/// The largest zoom level whose tile ids fit in a `u64`.
+const MAX_ZOOM: u8 = 31;
+
+/// The number of tiles at all zoom levels below `z`.
+fn zoom_offset(z: u8) -> u64 {
+    // Computed in u128 so that the offset of zoom level 32, the end of the id space, fits
+    (((1_u128 << (2 * u32::from(z))) - 1) / 3) as u64
+}
+
+fn rotate(n: u64, x: &mut u64, y: &mut u64, rx: u64, ry: u64) {
+    if ry == 0 {
+        if rx == 1 {
+            *x = n - 1 - *x;
+            *y = n - 1 - *y;
+        }
+        std::mem::swap(x, y);
+    }
+}
+
+/// The tile id of a tile.
+pub fn tile_id(tile: TileCoord) -> GeoArrowResult<u64> {
+    tile.validate()?;
+    let (mut x, mut y) = (u64::from(tile.x), u64::from(tile.y));
+    let mut d = 0;
+    let mut s = (1_u64 << tile.z) / 2;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        d += s * s * ((3 * rx) ^ ry);
+        rotate(s, &mut x, &mut y, rx, ry);
+        s /= 2;
+    }
+    Ok(zoom_offset(tile.z) + d)
+}
+
+/// The tile with the given tile id.
+pub fn tile_coord(tile_id: u64) -> GeoArrowResult<TileCoord> {
+    let z = (0..=MAX_ZOOM)
+        .find(|z| tile_id < zoom_offset(z + 1))
+        .ok_or_else(|| GeoArrowError::PmTiles(format!("Tile id {tile_id} is out of range")))?;
+
+    let n = 1_u64 << z;
+    let mut t = tile_id - zoom_offset(z);
+    let (mut x, mut y) = (0, 0);
+    let mut s = 1;
+    while s < n {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+        rotate(s, &mut x, &mut y, rx, ry);
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+    Ok(TileCoord::new(z, x as u32, y as u32))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tile_ids() {
+        // Test vectors from the PMTiles specification
+        let cases = [
+            ((0, 0, 0), 0),
+            ((1, 0, 0), 1),
+            ((1, 0, 1), 2),
+            ((1, 1, 1), 3),
+            ((1, 1, 0), 4),
+            ((2, 0, 0), 5),
+            ((12, 3423, 1763), 19078479),
+        ];
+        for ((z, x, y), id) in cases {
+            let tile = TileCoord::new(z, x, y);
+            assert_eq!(tile_id(tile).unwrap(), id);
+            assert_eq!(tile_coord(id).unwrap(), tile);
+        }
+    }
+}
@@ -0,0 +1,527 @@
+//! Tile a stream of record batches across a zoom range and write a PMTiles archive.
+
+use std::collections::{BTreeMap, HashSet, btree_map};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use arrow_array::{RecordBatch, RecordBatchReader, UInt32Array};
+use arrow_schema::{DataType, Schema};
+use arrow_select::take::take_record_batch;
+use geo_traits::GeometryTrait;
+use geoarrow_array::GeoArrowArrayAccessor;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::bounds::BoundsAccumulator;
+use geoarrow_array::cast::to_wkb;
+use geoarrow_mvt::encoder::{MvtEncoder, MvtEncoderOptions};
+use geoarrow_mvt::{SourceProjection, TileCoord};
+use geoarrow_schema::GeoArrowType;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+use crate::directory::{Entry, build_directories};
+use crate::header::{Compression, HEADER_LEN, Header, MAX_INITIAL_BYTES};
+use crate::tile_id::tile_id;
+
+/// Options for the PMTiles writer
+#[derive(Debug, Clone)]
+pub struct PmTilesWriterOptions {
+    /// The lowest zoom level to generate tiles for, defaults to `0`
+    pub min_zoom: u8,
+
+    /// The highest zoom level to generate tiles for, defaults to `10`
+    pub max_zoom: u8,
+
+    /// The name of the vector tile layer, defaults to `"layer"`
+    pub layer_name: String,
+
+    /// Options for encoding each tile, including the source projection and the geometry and id
+    /// columns.
+    pub mvt: MvtEncoderOptions,
+
+    /// The compression of tiles, defaults to gzip
+    pub tile_compression: Compression,
+
+    /// The compression of directories and metadata, defaults to gzip
+    pub internal_compression: Compression,
+}
+
+impl PmTilesWriterOptions {
+    /// Set the range of zoom levels to generate tiles for.
+    pub fn with_zoom_range(mut self, min_zoom: u8, max_zoom: u8) -> Self {
+        self.min_zoom = min_zoom;
+        self.max_zoom = max_zoom;
+        self
+    }
+
+    /// Set the name of the vector tile layer.
+    pub fn with_layer_name(mut self, layer_name: impl Into<String>) -> Self {
+        self.layer_name = layer_name.into();
+        self
+    }
+
+    /// Set the options for encoding each tile.
+    pub fn with_mvt_options(mut self, mvt: MvtEncoderOptions) -> Self {
+        self.mvt = mvt;
+        self
+    }
+
+    /// Set the compression of tiles.
+    pub fn with_tile_compression(mut self, compression: Compression) -> Self {
+        self.tile_compression = compression;
+        self
+    }
+
+    /// Set the compression of directories and metadata.
+    pub fn with_internal_compression(mut self, compression: Compression) -> Self {
+        self.internal_compression = compression;
+        self
+    }
+}
+
+impl Default for PmTilesWriterOptions {
+    fn default() -> Self {
+        Self {
+            min_zoom: 0,
+            max_zoom: 10,
+            layer_name: "layer".to_string(),
+            mvt: Default::default(),
+            tile_compression: Default::default(),
+            internal_compression: Default::default(),
+        }
+    }
+}
+
+/// Bounds in normalized world coordinates as `[minx, miny, maxx, maxy]`, with y pointing south.
+type WorldBounds = [f64; 4];
+
+fn world_bounds(
+    geometry: &impl GeometryTrait<T = f64>,
+    projection: SourceProjection,
+) -> Option<WorldBounds> {
+    // NaN coordinates, as used for empty points, never compare as smaller or larger
+    let mut rect = BoundsAccumulator::new();
+    rect.add_geometry(geometry);
+    if rect.is_empty() {
+        return None;
+    }
+    // Both projections preserve the order of x and reverse the order of y
+    let (minx, miny) = projection.to_world(rect.minx(), rect.maxy());
+    let (maxx, maxy) = projection.to_world(rect.maxx(), rect.miny());
+    Some([minx, miny, maxx, maxy])
+}
+
+fn union(a: Option<WorldBounds>, b: WorldBounds) -> WorldBounds {
+    match a {
+        Some(a) => [
+            a[0].min(b[0]),
+            a[1].min(b[1]),
+            a[2].max(b[2]),
+            a[3].max(b[3]),
+        ],
+        None => b,
+    }
+}
+
+/// Find the geometry column of a schema.
+fn geometry_column(schema: &Schema, name: Option<&str>) -> GeoArrowResult<usize> {
+    if let Some(name) = name {
+        return Ok(schema.index_of(name)?);
+    }
+    schema
+        .fields()
+        .iter()
+        .position(|field| {
+            field.extension_type_name().is_some() && GeoArrowType::try_from(field.as_ref()).is_ok()
+        })
+        .ok_or_else(|| {
+            GeoArrowError::PmTiles("No geometry column found in record batch".to_string())
+        })
+}
+
+/// The world bounds of each row of a batch.
+fn row_bounds(
+    batch: &RecordBatch,
+    geometry_idx: usize,
+    projection: SourceProjection,
+) -> GeoArrowResult<Vec<Option<WorldBounds>>> {
+    let schema = batch.schema();
+    let geometry = from_arrow_array(
+        batch.column(geometry_idx).as_ref(),
+        schema.field(geometry_idx),
+    )?;
+    let wkb = to_wkb::<i32>(geometry.as_ref())?;
+    wkb.iter()
+        .map(|geometry| {
+            Ok(geometry
+                .transpose()?
+                .and_then(|geometry| world_bounds(&geometry, projection)))
+        })
+        .collect()
+}
+
+/// The tile column or row at zoom `z` of a normalized world coordinate.
+fn to_tile(value: f64, z: u8) -> u32 {
+    let max_tile = (1_u64 << z) - 1;
+    (value * (1_u64 << z) as f64)
+        .floor()
+        .clamp(0.0, max_tile as f64) as u32
+}
+
+/// Assign the rows of a batch to the tiles they touch at one zoom level, keyed by `(x, y)`.
+fn assign_tiles(
+    bounds: &[Option<WorldBounds>],
+    z: u8,
+    buffer: f64,
+) -> BTreeMap<(u32, u32), Vec<u32>> {
+    let buffer = buffer / (1_u64 << z) as f64;
+    let mut tiles: BTreeMap<(u32, u32), Vec<u32>> = BTreeMap::new();
+    for (row, row_bounds) in bounds.iter().enumerate() {
+        let Some([minx, miny, maxx, maxy]) = row_bounds else {
+            continue;
+        };
+        let (x0, x1) = (to_tile(minx - buffer, z), to_tile(maxx + buffer, z));
+        let (y0, y1) = (to_tile(miny - buffer, z), to_tile(maxy + buffer, z));
+        for x in x0..=x1 {
+            for y in y0..=y1 {
+                tiles.entry((x, y)).or_default().push(row as u32);
+            }
+        }
+    }
+    tiles
+}
+
+/// Tiles that have been encoded but not yet finished, keyed by `(x, y)` for each zoom level.
+struct OpenTiles {
+    min_zoom: u8,
+    zooms: Vec<BTreeMap<(u32, u32), MvtEncoder>>,
+    /// Rows touching tiles that were already written, keyed by tile id, which are merged with the
+    /// written tiles at the end
+    late: BTreeMap<u64, MvtEncoder>,
+}
+
+impl OpenTiles {
+    fn new(min_zoom: u8, max_zoom: u8) -> Self {
+        Self {
+            min_zoom,
+            zooms: (min_zoom..=max_zoom).map(|_| BTreeMap::new()).collect(),
+            late: BTreeMap::new(),
+        }
+    }
+
+    /// Encode the rows of a batch into every tile they touch.
+    fn add_batch(
+        &mut self,
+        batch: &RecordBatch,
+        bounds: &[Option<WorldBounds>],
+        options: &PmTilesWriterOptions,
+        finished: &HashSet<u64>,
+    ) -> GeoArrowResult<()> {
+        let buffer = f64::from(options.mvt.buffer) / f64::from(options.mvt.extent);
+        for (z, tiles) in (self.min_zoom..).zip(self.zooms.iter_mut()) {
+            for ((x, y), rows) in assign_tiles(bounds, z, buffer) {
+                let coord = TileCoord::new(z, x, y);
+                let id = tile_id(coord)?;
+                let encoder = if finished.contains(&id) {
+                    match self.late.entry(id) {
+                        btree_map::Entry::Occupied(entry) => entry.into_mut(),
+                        btree_map::Entry::Vacant(entry) => {
+                            entry.insert(MvtEncoder::try_new(coord, options.mvt.clone())?)
+                        }
+                    }
+                } else {
+                    match tiles.entry((x, y)) {
+                        btree_map::Entry::Occupied(entry) => entry.into_mut(),
+                        btree_map::Entry::Vacant(entry) => {
+                            entry.insert(MvtEncoder::try_new(coord, options.mvt.clone())?)
+                        }
+                    }
+                };
+                let batch = take_record_batch(batch, &UInt32Array::from(rows))?;
+                encoder.add_batch(&options.layer_name, &batch)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the tiles that no row with a minimum x of at least `sweep` can touch, or all tiles
+    /// if `sweep` is `None`, in tile id order.
+    fn take_finished(
+        &mut self,
+        sweep: Option<f64>,
+        buffer: f64,
+    ) -> GeoArrowResult<Vec<(u64, MvtEncoder)>> {
+        let mut finished = vec![];
+        for (z, tiles) in (self.min_zoom..).zip(self.zooms.iter_mut()) {
+            let done = match sweep {
+                Some(sweep) => {
+                    let x = to_tile(sweep - buffer / (1_u64 << z) as f64, z);
+                    let rest = tiles.split_off(&(x, 0));
+                    std::mem::replace(tiles, rest)
+                }
+                None => std::mem::take(tiles),
+            };
+            for encoder in done.into_values() {
+                finished.push((tile_id(encoder.tile())?, encoder));
+            }
+        }
+        finished.sort_unstable_by_key(|(id, _)| *id);
+        Ok(finished)
+    }
+}
+
+/// Writes finished tiles to the tile data section and records their directory entries.
+struct TileData<'a, W> {
+    writer: &'a mut W,
+    compression: Compression,
+    /// The offset of the tile data section in `writer`
+    offset: u64,
+    length: u64,
+    /// The entry of every non-empty tile that has been written, keyed by tile id
+    entries: BTreeMap<u64, Entry>,
+    /// Tiles that have been written, which later rows are merged into at the end
+    finished: HashSet<u64>,
+    last_tile: Option<(Vec<u8>, Entry)>,
+}
+
+impl<W: Read + Write + Seek> TileData<'_, W> {
+    fn push(&mut self, id: u64, encoder: MvtEncoder) -> GeoArrowResult<()> {
+        self.finished.insert(id);
+        let tile = encoder.finish();
+        if tile.is_empty() {
+            return Ok(());
+        }
+
+        // Identical consecutive tiles share their data, and are merged into runs once sorted
+        let last = self
+            .last_tile
+            .as_ref()
+            .filter(|(last_tile, _)| *last_tile == tile);
+        if let Some((_, last)) = last {
+            let entry = Entry {
+                tile_id: id,
+                ..*last
+            };
+            self.entries.insert(id, entry);
+            return Ok(());
+        }
+
+        let compressed = self.compression.compress(&tile)?;
+        self.writer.write_all(&compressed)?;
+        let entry = Entry {
+            tile_id: id,
+            offset: self.length,
+            length: compressed.len() as u32,
+            run_length: 1,
+        };
+        self.entries.insert(id, entry);
+        self.length += compressed.len() as u64;
+        self.last_tile = Some((tile, entry));
+        Ok(())
+    }
+
+    /// Merge rows that arrived after their tile was written into that tile, and write it again.
+    ///
+    /// The earlier copy of the tile is left in the tile data section but no longer addressed.
+    fn push_late(&mut self, id: u64, mut encoder: MvtEncoder) -> GeoArrowResult<()> {
+        if let Some(entry) = self.entries.get(&id) {
+            let mut buf = vec![0; entry.length as usize];
+            self.writer
+                .seek(SeekFrom::Start(self.offset + entry.offset))?;
+            self.writer.read_exact(&mut buf)?;
+            self.writer
+                .seek(SeekFrom::Start(self.offset + self.length))?;
+            encoder.merge_tile(&self.compression.decompress(&buf)?)?;
+        }
+        self.push(id, encoder)
+    }
+
+    /// The number of addressed tiles and of distinct tile contents.
+    fn counts(&self) -> (u64, u64) {
+        let contents = self
+            .entries
+            .values()
+            .map(|entry| entry.offset)
+            .collect::<HashSet<_>>();
+        (self.entries.len() as u64, contents.len() as u64)
+    }
+
+    /// The directory entries sorted by tile id, with runs of identical consecutive tiles merged.
+    fn into_entries(self) -> Vec<Entry> {
+        let mut merged: Vec<Entry> = Vec::with_capacity(self.entries.len());
+        for entry in self.entries.into_values() {
+            match merged.last_mut() {
+                Some(last)
+                    if last.offset == entry.offset
+                        && last.tile_id + u64::from(last.run_length) == entry.tile_id =>
+                {
+                    last.run_length += 1
+                }
+                _ => merged.push(entry),
+            }
+        }
+        merged
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if u32::from(c) < 0x20 => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// The TileJSON-style metadata describing the layer and its fields.
+fn metadata_json(schema: &Schema, skip: &[usize], options: &PmTilesWriterOptions) -> String {
+    let fields = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !skip.contains(idx))
+        .map(|(_, field)| {
+            let field_type = match field.data_type() {
+                DataType::Boolean => "Boolean",
+                data_type if data_type.is_numeric() => "Number",
+                _ => "String",
+            };
+            format!("{}:{}", json_string(field.name()), json_string(field_type))
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let layer = json_string(&options.layer_name);
+    format!(
+        "{{\"name\":{layer},\"format\":\"pbf\",\"vector_layers\":[{{\"id\":{layer},\"fields\":{{{fields}}},\"minzoom\":{},\"maxzoom\":{}}}]}}",
+        options.min_zoom, options.max_zoom
+    )
+}
+
+/// Tile a stream of record batches and write them to a PMTiles archive.
+///
+/// Batches are encoded into the tiles they touch as they are read and are not kept. Tiles are
+/// held in memory until no later row can touch them. When the rows of the input are sorted by the
+/// minimum x coordinate of their geometry, each tile is written as soon as the stream has moved
+/// east of it. From the first row that breaks this order, the remaining tiles are held until the
+/// end of the stream. Rows touching a tile that was already written are encoded separately and
+/// merged with the written tile at the end, which reads it back from `writer`. The order of the
+/// input therefore only affects memory use, not the archive.
+///
+/// Tile data is written directly after a reserved block for the header and root directory, which
+/// is filled in at the end, so the archive is written from the start of `writer`. Runs of identical
+/// consecutive tiles are stored once. The header of the written archive is returned.
+pub fn write_pmtiles<W: Read + Write + Seek>(
+    reader: impl RecordBatchReader,
+    mut writer: W,
+    options: &PmTilesWriterOptions,
+) -> GeoArrowResult<Header> {
+    if options.min_zoom > options.max_zoom {
+        return Err(GeoArrowError::PmTiles(format!(
+            "Minimum zoom {} is larger than maximum zoom {}",
+            options.min_zoom, options.max_zoom
+        )));
+    }
+
+    let schema = reader.schema();
+    let geometry_idx = geometry_column(&schema, options.mvt.geometry_column.as_deref())?;
+    let mut skip = vec![geometry_idx];
+    if let Some(id_column) = &options.mvt.id_column {
+        skip.push(schema.index_of(id_column)?);
+    }
+
+    // The header and root directory are written into this block once the tile data is known
+    let tile_data_offset = MAX_INITIAL_BYTES as u64;
+    writer.rewind()?;
+    writer.write_all(&[0; MAX_INITIAL_BYTES])?;
+
+    let buffer = f64::from(options.mvt.buffer) / f64::from(options.mvt.extent);
+    let mut open_tiles = OpenTiles::new(options.min_zoom, options.max_zoom);
+    let mut tile_data = TileData {
+        writer: &mut writer,
+        compression: options.tile_compression,
+        offset: tile_data_offset,
+        length: 0,
+        entries: BTreeMap::new(),
+        finished: HashSet::new(),
+        last_tile: None,
+    };
+    let mut data_bounds = None;
+    // The minimum x of the last row, as long as the rows are sorted by it
+    let mut sweep = Some(f64::NEG_INFINITY);
+    for batch in reader {
+        let batch = batch?;
+        let bounds = row_bounds(&batch, geometry_idx, options.mvt.projection)?;
+        for row_bounds in bounds.iter().flatten() {
+            data_bounds = Some(union(data_bounds, *row_bounds));
+            sweep = sweep
+                .filter(|sweep| row_bounds[0] >= *sweep)
+                .map(|_| row_bounds[0]);
+        }
+
+        open_tiles.add_batch(&batch, &bounds, options, &tile_data.finished)?;
+        if let Some(sweep) = sweep {
+            for (id, encoder) in open_tiles.take_finished(Some(sweep), buffer)? {
+                tile_data.push(id, encoder)?;
+            }
+        }
+    }
+    for (id, encoder) in open_tiles.take_finished(None, buffer)? {
+        tile_data.push(id, encoder)?;
+    }
+    for (id, encoder) in std::mem::take(&mut open_tiles.late) {
+        tile_data.push_late(id, encoder)?;
+    }
+
+    let tile_data_length = tile_data.length;
+    let (addressed_tiles, tile_contents) = tile_data.counts();
+    let entries = tile_data.into_entries();
+    let clustered = entries.windows(2).all(|w| w[0].offset <= w[1].offset);
+
+    let (root, leaves) = build_directories(&entries, options.internal_compression)?;
+    let metadata = options
+        .internal_compression
+        .compress(metadata_json(&schema, &skip, options).as_bytes())?;
+    writer.write_all(&metadata)?;
+    writer.write_all(&leaves)?;
+
+    let (min_lon, min_lat, max_lon, max_lat) = match data_bounds {
+        Some([minx, miny, maxx, maxy]) => {
+            let (min_lon, max_lat) = SourceProjection::LonLat.from_world(minx, miny);
+            let (max_lon, min_lat) = SourceProjection::LonLat.from_world(maxx, maxy);
+            (min_lon, min_lat, max_lon, max_lat)
+        }
+        None => (0.0, 0.0, 0.0, 0.0),
+    };
+
+    let metadata_offset = tile_data_offset + tile_data_length;
+    let header = Header {
+        root_dir_offset: HEADER_LEN as u64,
+        root_dir_length: root.len() as u64,
+        metadata_offset,
+        metadata_length: metadata.len() as u64,
+        leaf_dirs_offset: metadata_offset + metadata.len() as u64,
+        leaf_dirs_length: leaves.len() as u64,
+        tile_data_offset,
+        tile_data_length,
+        addressed_tiles,
+        tile_entries: entries.len() as u64,
+        tile_contents,
+        clustered,
+        internal_compression: options.internal_compression,
+        tile_compression: options.tile_compression,
+        min_zoom: options.min_zoom,
+        max_zoom: options.max_zoom,
+        bounds: (min_lon, min_lat, max_lon, max_lat),
+        center_zoom: options.min_zoom,
+        center: ((min_lon + max_lon) / 2.0, (min_lat + max_lat) / 2.0),
+    };
+
+    writer.rewind()?;
+    header.write(&mut writer)?;
+    writer.write_all(&root)?;
+    writer.flush()?;
+    Ok(header)
+}
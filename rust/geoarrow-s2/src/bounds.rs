@@ -0,0 +1,96 @@
+//! Latitude and longitude bounds of geometries, as S2 regions to cover.
+
+use std::f64::consts::PI;
+
+use geo_traits::GeometryTrait;
+use geoarrow_geo::{BoundsAccumulator, SphericalBoundsAccumulator};
+use s2::rect::Rect;
+use s2::{r1, s1};
+
+/// The latitude and longitude bounds of a geometry as an S2 rectangle, or `None` if the
+/// geometry is empty.
+///
+/// With planar edges the bounds are those of the vertices. With spherical edges every edge is
+/// the shorter great circle arc between its vertices, as computed by [`SphericalBoundsAccumulator`].
+pub(crate) fn lat_lng_rect(
+    geometry: &impl GeometryTrait<T = f64>,
+    spherical: bool,
+) -> Option<Rect> {
+    let bounds = if spherical {
+        let mut bounds = SphericalBoundsAccumulator::new();
+        bounds.add_geometry(geometry);
+        bounds.finish()
+    } else {
+        let mut bounds = BoundsAccumulator::new();
+        bounds.add_geometry(geometry);
+        bounds
+    };
+    if bounds.is_empty() {
+        return None;
+    }
+
+    let lat = r1::interval::Interval::new(
+        bounds.miny().max(-90.0).to_radians(),
+        bounds.maxy().min(90.0).to_radians(),
+    );
+    // A minimum longitude greater than the maximum wraps across the antimeridian, which S2
+    // intervals represent the same way
+    let lng = if bounds.minx() <= bounds.maxx() && bounds.maxx() - bounds.minx() >= 360.0 {
+        s1::interval::Interval::new(-PI, PI)
+    } else {
+        s1::interval::Interval::new(bounds.minx().to_radians(), bounds.maxx().to_radians())
+    };
+    Some(Rect { lat, lng })
+}
+
+#[cfg(test)]
+mod test {
+    use geo_types::{Geometry, line_string, polygon};
+
+    use super::*;
+
+    fn bounds(geometry: Geometry, spherical: bool) -> Rect {
+        lat_lng_rect(&geometry, spherical).unwrap()
+    }
+
+    #[test]
+    fn arc_bulges_poleward() {
+        let line = line_string![(x: -90.0, y: 45.0), (x: 89.0, y: 45.0)];
+        let planar = bounds(line.clone().into(), false);
+        assert!((planar.lat.hi.to_degrees() - 45.0).abs() < 1e-9);
+
+        // The great circle path between these points passes close to the north pole
+        let spherical = bounds(line.into(), true);
+        assert!(spherical.lat.hi.to_degrees() > 89.0);
+        assert!((spherical.lng.lo.to_degrees() + 90.0).abs() < 1e-9);
+        assert!((spherical.lng.hi.to_degrees() - 89.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn crosses_antimeridian() {
+        let square = polygon![
+            (x: 170.0, y: -10.0),
+            (x: -170.0, y: -10.0),
+            (x: -170.0, y: 10.0),
+            (x: 170.0, y: 10.0),
+        ];
+        let rect = bounds(square.into(), true);
+        assert!((rect.lng.lo.to_degrees() - 170.0).abs() < 1e-9);
+        assert!((rect.lng.hi.to_degrees() + 170.0).abs() < 1e-9);
+        assert!(rect.lat.lo.to_degrees() < -10.0);
+        assert!(rect.lat.hi.to_degrees() > 10.0);
+    }
+
+    #[test]
+    fn ring_around_pole() {
+        let cap = polygon![
+            (x: 0.0, y: 80.0),
+            (x: 90.0, y: 80.0),
+            (x: 180.0, y: 80.0),
+            (x: -90.0, y: 80.0),
+        ];
+        let rect = bounds(cap.into(), true);
+        assert!((rect.lat.hi.to_degrees() - 90.0).abs() < 1e-9);
+        assert!(rect.lng.is_full());
+    }
+}
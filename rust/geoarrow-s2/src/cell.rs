@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use arrow_array::UInt64Array;
+use arrow_array::builder::UInt64Builder;
+use geo_traits::{CoordTrait, PointTrait};
+use geo_types::{Coord, LineString, Polygon};
+use geoarrow_array::GeoArrowArrayAccessor;
+use geoarrow_array::array::{PointArray, PolygonArray};
+use geoarrow_array::builder::PolygonBuilder;
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, Crs, Dimension, Edges, Metadata, PolygonType};
+use s2::cell::Cell;
+use s2::cellid::CellID;
+use s2::latlng::LatLng;
+
+use crate::{MAX_LEVEL, s2_error};
+
+/// The `S2CellId` of the cell containing each point, at the given level.
+///
+/// Points are longitude and latitude in degrees. Null and empty points have a null cell, and
+/// points with non-finite coordinates are an error.
+pub fn cell_id(array: &PointArray, level: u8) -> GeoArrowResult<UInt64Array> {
+    if level > MAX_LEVEL {
+        return Err(s2_error(format!(
+            "Invalid S2 level {level}, must be at most {MAX_LEVEL}"
+        )));
+    }
+    let mut builder = UInt64Builder::with_capacity(array.len());
+    for point in array.iter() {
+        match point.transpose()?.and_then(|point| point.coord()) {
+            Some(coord) => {
+                if !coord.x().is_finite() || !coord.y().is_finite() {
+                    return Err(s2_error("Point coordinates must be finite"));
+                }
+                let leaf = CellID::from(&LatLng::from_degrees(coord.y(), coord.x()));
+                builder.append_value(leaf.parent(level as u64).0);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// The boundary of each S2 cell, as a polygon in longitude and latitude degrees.
+///
+/// The edges of S2 cells are great circle arcs, so the polygons have spherical edges. Null cells
+/// have a null polygon, and invalid cell ids are an error.
+pub fn cell_to_polygon(array: &UInt64Array, coord_type: CoordType) -> GeoArrowResult<PolygonArray> {
+    let polygons = array
+        .iter()
+        .map(|cell| cell.map(boundary).transpose())
+        .collect::<GeoArrowResult<Vec<_>>>()?;
+    let metadata = Arc::new(Metadata::new(Crs::default(), Some(Edges::Spherical)));
+    let typ = PolygonType::new(Dimension::XY, metadata).with_coord_type(coord_type);
+    Ok(PolygonBuilder::from_nullable_polygons(&polygons, typ).finish())
+}
+
+fn boundary(cell_id: u64) -> GeoArrowResult<Polygon> {
+    let cell_id = CellID(cell_id);
+    if !cell_id.is_valid() {
+        return Err(s2_error(format!("Invalid S2 cell id {}", cell_id.0)));
+    }
+    let cell = Cell::from(&cell_id);
+    let coords = (0..4)
+        .map(|k| {
+            let vertex = LatLng::from(&cell.vertex(k));
+            Coord {
+                x: vertex.lng.deg(),
+                y: vertex.lat.deg(),
+            }
+        })
+        .collect::<Vec<_>>();
+    // `Polygon::new` closes the ring
+    Ok(Polygon::new(LineString::new(coords), vec![]))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use geo_traits::{LineStringTrait, PolygonTrait};
+    use geo_types::point;
+    use geoarrow_array::GeoArrowArray;
+    use geoarrow_array::builder::PointBuilder;
+    use geoarrow_schema::PointType;
+
+    use super::*;
+
+    fn points() -> PointArray {
+        let points = vec![
+            Some(point!(x: -122.4194, y: 37.7749)),
+            None,
+            Some(point!(x: 151.2093, y: -33.8688)),
+        ];
+        let typ = PointType::new(Dimension::XY, Default::default());
+        PointBuilder::from_nullable_geometries(&points, typ)
+            .unwrap()
+            .finish()
+    }
+
+    #[test]
+    fn cell_ids() {
+        let leaves = cell_id(&points(), 30).unwrap();
+        let cells = cell_id(&points(), 10).unwrap();
+        assert!(cells.is_null(1));
+        for i in [0, 2] {
+            assert_eq!(CellID(leaves.value(i)).level(), 30);
+            assert_eq!(CellID(cells.value(i)).level(), 10);
+            assert_eq!(CellID(leaves.value(i)).parent(10).0, cells.value(i));
+        }
+        assert!(cell_id(&points(), 31).is_err());
+    }
+
+    #[test]
+    fn cell_polygons() {
+        let cells = cell_id(&points(), 12).unwrap();
+        let polygons = cell_to_polygon(&cells, CoordType::Separated).unwrap();
+        assert_eq!(polygons.len(), 3);
+        assert!(polygons.is_null(1));
+        assert_eq!(
+            polygons.data_type().metadata().edges(),
+            Some(Edges::Spherical)
+        );
+
+        let polygon = polygons.value(0).unwrap();
+        let exterior = polygon.exterior().unwrap();
+        assert_eq!(exterior.num_coords(), 5);
+        // A level 12 cell is about 2km across
+        for coord in exterior.coords() {
+            assert!((coord.x() + 122.4194).abs() < 0.05);
+            assert!((coord.y() - 37.7749).abs() < 0.05);
+        }
+
+        assert!(cell_to_polygon(&UInt64Array::from(vec![0]), CoordType::Separated).is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use arrow_array::ListArray;
+use arrow_array::builder::{ListBuilder, UInt64Builder};
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use s2::region::RegionCoverer;
+
+use crate::bounds::lat_lng_rect;
+use crate::{MAX_LEVEL, s2_error};
+
+/// Options for computing S2 coverings with [`covering`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoveringOptions {
+    /// The maximum number of cells in each covering, defaults to 8.
+    ///
+    /// A covering may use more cells if `min_level` is too fine to cover a geometry otherwise.
+    pub max_cells: usize,
+
+    /// The coarsest level of cells in a covering, defaults to 0.
+    pub min_level: u8,
+
+    /// The finest level of cells in a covering, defaults to 30.
+    pub max_level: u8,
+}
+
+impl CoveringOptions {
+    /// Set the maximum number of cells in each covering.
+    pub fn with_max_cells(self, max_cells: usize) -> Self {
+        Self { max_cells, ..self }
+    }
+
+    /// Set the coarsest level of cells in a covering.
+    pub fn with_min_level(self, min_level: u8) -> Self {
+        Self { min_level, ..self }
+    }
+
+    /// Set the finest level of cells in a covering.
+    pub fn with_max_level(self, max_level: u8) -> Self {
+        Self { max_level, ..self }
+    }
+
+    fn coverer(&self) -> GeoArrowResult<RegionCoverer> {
+        if self.max_cells == 0 {
+            return Err(s2_error("S2 covering must allow at least one cell"));
+        }
+        if self.min_level > self.max_level || self.max_level > MAX_LEVEL {
+            return Err(s2_error(format!(
+                "Invalid S2 covering levels {} to {}",
+                self.min_level, self.max_level
+            )));
+        }
+        Ok(RegionCoverer {
+            min_level: self.min_level,
+            max_level: self.max_level,
+            level_mod: 1,
+            max_cells: self.max_cells,
+        })
+    }
+}
+
+impl Default for CoveringOptions {
+    fn default() -> Self {
+        Self {
+            max_cells: 8,
+            min_level: 0,
+            max_level: MAX_LEVEL,
+        }
+    }
+}
+
+/// An S2 covering of each geometry, as a list of `S2CellId`s.
+///
+/// Coordinates are longitude and latitude in degrees. Each covering covers the latitude and
+/// longitude bounds of its geometry, so it may include cells the geometry doesn't touch but never
+/// misses one it does. If the array has non-planar edges, every edge is treated as a great circle
+/// arc, and otherwise as a straight line in longitude and latitude.
+///
+/// Null geometries have a null covering, and empty geometries an empty one.
+///
+/// The GeoParquet writer doesn't write these coverings. The GeoParquet specification only defines
+/// a `bbox` covering, so readers would not recognize an S2 covering column. To store coverings,
+/// add the result as an ordinary column of the record batch.
+pub fn covering(array: &dyn GeoArrowArray, options: &CoveringOptions) -> GeoArrowResult<ListArray> {
+    let coverer = options.coverer()?;
+    let spherical = array.data_type().metadata().edges().is_some();
+    downcast_geoarrow_array!(array, covering_impl, &coverer, spherical)
+}
+
+fn covering_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    coverer: &RegionCoverer,
+    spherical: bool,
+) -> GeoArrowResult<ListArray> {
+    let mut builder = ListBuilder::with_capacity(UInt64Builder::new(), array.len());
+    for geometry in array.iter() {
+        let Some(geometry) = geometry else {
+            builder.append_null();
+            continue;
+        };
+        if let Some(rect) = lat_lng_rect(&geometry?, spherical) {
+            let cells = coverer.covering(&rect);
+            for cell in cells.0 {
+                builder.values().append_value(cell.0);
+            }
+        }
+        builder.append(true);
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt64Type;
+    use geo_types::{point, polygon};
+    use geoarrow_array::test::geometry::from_geoms;
+    use geoarrow_schema::{Crs, Edges, Metadata};
+    use s2::cellid::CellID;
+    use s2::latlng::LatLng;
+
+    use super::*;
+
+    fn cells(list: &ListArray, i: usize) -> Vec<CellID> {
+        list.value(i)
+            .as_primitive::<UInt64Type>()
+            .values()
+            .iter()
+            .map(|cell| CellID(*cell))
+            .collect()
+    }
+
+    fn covers(cells: &[CellID], lng: f64, lat: f64) -> bool {
+        let leaf = CellID::from(&LatLng::from_degrees(lat, lng));
+        cells.iter().any(|cell| cell.contains(&leaf))
+    }
+
+    #[test]
+    fn covers_geometries() {
+        let square = polygon![
+            (x: 10.0, y: 10.0),
+            (x: 11.0, y: 10.0),
+            (x: 11.0, y: 11.0),
+            (x: 10.0, y: 11.0),
+        ];
+        let array = from_geoms(
+            &[
+                Some(square.into()),
+                None,
+                Some(point!(x: -40.0, y: 20.0).into()),
+            ],
+            Arc::new(Metadata::new(Crs::default(), Some(Edges::Spherical))),
+        );
+        let options = CoveringOptions::default()
+            .with_max_cells(4)
+            .with_max_level(16);
+        let list = covering(&array, &options).unwrap();
+        assert_eq!(list.len(), 3);
+        assert!(list.is_null(1));
+
+        let square_cells = cells(&list, 0);
+        assert!(!square_cells.is_empty() && square_cells.len() <= 4);
+        assert!(covers(&square_cells, 10.5, 10.5));
+        assert!(covers(&square_cells, 10.0, 11.0));
+        assert!(!covers(&square_cells, -40.0, 20.0));
+
+        let point_cells = cells(&list, 2);
+        assert!(covers(&point_cells, -40.0, 20.0));
+        assert!(point_cells.iter().all(|cell| cell.level() <= 16));
+    }
+
+    #[test]
+    fn invalid_options() {
+        let array = from_geoms(&[], Default::default());
+        let options = CoveringOptions::default()
+            .with_min_level(12)
+            .with_max_level(10);
+        assert!(covering(&array, &options).is_err());
+        let options = CoveringOptions::default().with_max_cells(0);
+        assert!(covering(&array, &options).is_err());
+    }
+}
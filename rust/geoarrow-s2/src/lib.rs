@@ -0,0 +1,23 @@
+//! [S2](https://s2geometry.io) cell indexing and coverings of GeoArrow arrays.
+//!
+//! Cells are stored as their 64-bit `S2CellId` in a `UInt64Array`, and coordinates are longitude
+//! and latitude in degrees.
+
+#![warn(missing_docs)]
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+mod bounds;
+mod cell;
+mod covering;
+
+pub use cell::{cell_id, cell_to_polygon};
+pub use covering::{CoveringOptions, covering};
+
+use geoarrow_schema::error::GeoArrowError;
+
+/// The maximum level of an S2 cell, of leaf cells about 1cm across.
+pub const MAX_LEVEL: u8 = 30;
+
+pub(crate) fn s2_error(err: impl ToString) -> GeoArrowError {
+    GeoArrowError::S2(err.to_string())
+}
@@ -129,6 +129,27 @@ impl Crs {
         self.crs.as_ref()
     }
 
+    /// The integer SRID of this CRS, if one can be determined without a CRS database.
+    ///
+    /// This is the case when the CRS was constructed with [`from_srid`][Self::from_srid] from an
+    /// integer string, or with [`from_authority_code`][Self::from_authority_code] from an
+    /// `EPSG:<code>` string.
+    pub fn srid(&self) -> Option<i32> {
+        let value = self.crs.as_ref()?.as_str()?;
+        match self.crs_type? {
+            CrsType::Srid => value.trim().parse().ok(),
+            CrsType::AuthorityCode => {
+                let (authority, code) = value.split_once(':')?;
+                if authority.eq_ignore_ascii_case("EPSG") {
+                    code.trim().parse().ok()
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
     /// Return `true` if we should include a CRS key in the GeoArrow metadata
     pub(crate) fn should_serialize(&self) -> bool {
         self.crs.is_some()
@@ -241,6 +262,21 @@ mod test {
         assert!(!crs.should_serialize());
     }
 
+    #[test]
+    fn crs_srid() {
+        assert_eq!(Crs::from_srid("4326".to_string()).srid(), Some(4326));
+        assert_eq!(
+            Crs::from_authority_code("epsg:3857".to_string()).srid(),
+            Some(3857)
+        );
+        assert_eq!(
+            Crs::from_authority_code("OGC:CRS84".to_string()).srid(),
+            None
+        );
+        assert_eq!(Crs::from_unknown_crs_type("4326".to_string()).srid(), None);
+        assert_eq!(Crs::default().srid(), None);
+    }
+
     #[test]
     fn crs_projjson() {
         let crs = Crs::from_projjson(json!({}));
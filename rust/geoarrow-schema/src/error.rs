@@ -26,10 +26,18 @@ pub enum GeoArrowError {
     #[error("FlatGeobuf error: {0}")]
     FlatGeobuf(String),
 
+    /// GeoPackage error
+    #[error("GeoPackage error: {0}")]
+    GeoPackage(String),
+
     /// GeoParquet error
     #[error("GeoParquet error: {0}")]
     GeoParquet(String),
 
+    /// H3 error
+    #[error("H3 error: {0}")]
+    H3(String),
+
     /// [std::io::Error]
     #[error(transparent)]
     IOError(#[from] std::io::Error),
@@ -42,6 +50,30 @@ pub enum GeoArrowError {
     #[error("Incorrect geometry type for operation: {0}")]
     IncorrectGeometryType(String),
 
+    /// Mapbox Vector Tile error
+    #[error("MVT error: {0}")]
+    Mvt(String),
+
+    /// PMTiles error
+    #[error("PMTiles error: {0}")]
+    PmTiles(String),
+
+    /// S2 error
+    #[error("S2 error: {0}")]
+    S2(String),
+
+    /// Spatial index error
+    #[error("Spatial index error: {0}")]
+    SpatialIndex(String),
+
+    /// Web Mercator tile error
+    #[error("Tile error: {0}")]
+    Tile(String),
+
+    /// TopoJSON error
+    #[error("TopoJSON error: {0}")]
+    TopoJson(String),
+
     /// Whenever pushing to a container fails because it does not support more entries.
     ///
     /// The solution is usually to use a higher-capacity container-backing type.
@@ -55,6 +87,10 @@ pub enum GeoArrowError {
     /// WKT Error
     #[error("WKT error: {0}")]
     Wkt(String),
+
+    /// XML error, from reading or writing KML, GML or GPX
+    #[error("XML error: {0}")]
+    Xml(String),
 }
 
 /// Crate-specific result type.
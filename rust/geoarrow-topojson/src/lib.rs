@@ -0,0 +1,14 @@
+//! Read TopoJSON topologies into GeoArrow record batches.
+//!
+//! A [`Topology`] is parsed in full and its arcs decoded once. Each of its objects can then be
+//! read as a [`TopoJsonReader`], with one row per geometry of a top-level geometry collection,
+//! or a single row for any other object.
+
+#![warn(missing_docs)]
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+mod reader;
+mod topology;
+
+pub use reader::{TopoJsonReader, TopoJsonReaderOptions};
+pub use topology::{Topology, Transform};
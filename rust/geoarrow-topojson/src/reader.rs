@@ -0,0 +1,381 @@
+//! Reading the features of a TopoJSON object as record batches.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch, RecordBatchOptions, RecordBatchReader};
+use arrow_json::ReaderBuilder;
+use arrow_json::reader::infer_json_schema_from_iterator;
+use arrow_schema::{ArrowError, FieldRef, Schema, SchemaRef};
+use geo_types::Geometry;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::builder::{
+    GeometryBuilder, LineStringBuilder, MultiLineStringBuilder, MultiPointBuilder,
+    MultiPolygonBuilder, PointBuilder, PolygonBuilder,
+};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{
+    CoordType, Dimension, GeoArrowType, GeometryType, LineStringType, Metadata,
+    MultiLineStringType, MultiPointType, MultiPolygonType, PointType, PolygonType,
+};
+use serde_json::{Map, Value};
+
+use crate::topology::{GeometryObject, Topology};
+
+/// Options for reading the objects of a [`Topology`].
+#[derive(Debug, Clone)]
+pub struct TopoJsonReaderOptions {
+    /// The GeoArrow coordinate type to use in the geometry arrays.
+    pub coord_type: CoordType,
+
+    /// The maximum number of rows in each record batch.
+    pub batch_size: usize,
+}
+
+impl TopoJsonReaderOptions {
+    /// Set the GeoArrow coordinate type to use in the geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the maximum number of rows in each record batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+}
+
+impl Default for TopoJsonReaderOptions {
+    fn default() -> Self {
+        Self {
+            coord_type: Default::default(),
+            batch_size: 65_536,
+        }
+    }
+}
+
+/// A reader of the features of one TopoJSON object.
+///
+/// An object of type `GeometryCollection` has one row per member geometry, and any other object
+/// a single row. The schema has an `id` column if any feature has an id and none has an `id`
+/// property, one column per property with types inferred from the JSON values, and a `geometry`
+/// column.
+///
+/// The geometry column is of a single type if every geometry is a point, line or polygon
+/// geometry, promoted to the multi type if any geometry is one, or a mixed geometry column
+/// otherwise.
+#[derive(Debug)]
+pub struct TopoJsonReader {
+    batch: RecordBatch,
+    batch_size: usize,
+    offset: usize,
+}
+
+impl TopoJsonReader {
+    pub(crate) fn try_new(
+        topology: &Topology,
+        object: &GeometryObject,
+        options: TopoJsonReaderOptions,
+    ) -> GeoArrowResult<Self> {
+        let features = match object.typ.as_deref() {
+            Some("GeometryCollection") => object.geometries.iter().collect(),
+            _ => vec![object],
+        };
+
+        let mut fields: Vec<FieldRef> = vec![];
+        let mut columns = vec![];
+        let has_id = features.iter().any(|f| f.id.is_some())
+            && !features.iter().any(|f| {
+                f.properties
+                    .as_ref()
+                    .is_some_and(|properties| properties.contains_key("id"))
+            });
+        if has_id {
+            let ids = features
+                .iter()
+                .map(|f| {
+                    let id = f.id.clone().unwrap_or(Value::Null);
+                    Value::Object(Map::from_iter([("id".to_string(), id)]))
+                })
+                .collect::<Vec<_>>();
+            json_columns(&ids, &mut fields, &mut columns)?;
+        }
+        let properties = features
+            .iter()
+            .map(|f| Value::Object(f.properties.clone().unwrap_or_default()))
+            .collect::<Vec<_>>();
+        json_columns(&properties, &mut fields, &mut columns)?;
+
+        let geometries = features
+            .iter()
+            .map(|f| topology.geometry(f))
+            .collect::<GeoArrowResult<Vec<_>>>()?;
+        let geometry_type = geometry_type(&geometries, options.coord_type);
+        fields.push(Arc::new(geometry_type.to_field("geometry", true)));
+        columns.push(build_geometry(&geometries, &geometry_type)?);
+
+        let batch = RecordBatch::try_new_with_options(
+            Arc::new(Schema::new(fields)),
+            columns,
+            &RecordBatchOptions::new().with_row_count(Some(features.len())),
+        )?;
+        Ok(Self {
+            batch,
+            batch_size: options.batch_size.max(1),
+            offset: 0,
+        })
+    }
+
+    /// The schema of the record batches.
+    pub fn schema(&self) -> SchemaRef {
+        self.batch.schema()
+    }
+}
+
+impl Iterator for TopoJsonReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.batch.num_rows() - self.offset;
+        if remaining == 0 {
+            return None;
+        }
+        let length = remaining.min(self.batch_size);
+        let batch = self.batch.slice(self.offset, length);
+        self.offset += length;
+        Some(Ok(batch))
+    }
+}
+
+impl RecordBatchReader for TopoJsonReader {
+    fn schema(&self) -> SchemaRef {
+        self.batch.schema()
+    }
+}
+
+/// Decode JSON objects into columns, with a schema inferred from their values.
+fn json_columns(
+    rows: &[Value],
+    fields: &mut Vec<FieldRef>,
+    columns: &mut Vec<ArrayRef>,
+) -> GeoArrowResult<()> {
+    let schema = infer_json_schema_from_iterator(rows.iter().map(Ok::<_, ArrowError>))?;
+    if schema.fields().is_empty() {
+        return Ok(());
+    }
+    let mut decoder = ReaderBuilder::new(Arc::new(schema))
+        .with_batch_size(rows.len())
+        .build_decoder()?;
+    decoder.serialize(rows)?;
+    if let Some(batch) = decoder.flush()? {
+        fields.extend(batch.schema().fields().iter().cloned());
+        columns.extend(batch.columns().iter().cloned());
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Family {
+    Point,
+    Line,
+    Polygon,
+}
+
+/// The family of a geometry, and whether it is a multi geometry.
+fn family(geometry: &Geometry) -> Option<(Family, bool)> {
+    match geometry {
+        Geometry::Point(_) => Some((Family::Point, false)),
+        Geometry::MultiPoint(_) => Some((Family::Point, true)),
+        Geometry::LineString(_) => Some((Family::Line, false)),
+        Geometry::MultiLineString(_) => Some((Family::Line, true)),
+        Geometry::Polygon(_) => Some((Family::Polygon, false)),
+        Geometry::MultiPolygon(_) => Some((Family::Polygon, true)),
+        _ => None,
+    }
+}
+
+fn geometry_type(geometries: &[Option<Geometry>], coord_type: CoordType) -> GeoArrowType {
+    let metadata = Arc::new(Metadata::default());
+    let mut families = geometries.iter().flatten().map(family);
+    let Some(Some((first, mut multi))) = families.next() else {
+        return GeometryType::new(metadata)
+            .with_coord_type(coord_type)
+            .into();
+    };
+    for next in families {
+        match next {
+            Some((family, is_multi)) if family == first => multi |= is_multi,
+            _ => {
+                return GeometryType::new(metadata)
+                    .with_coord_type(coord_type)
+                    .into();
+            }
+        }
+    }
+
+    let dim = Dimension::XY;
+    match (first, multi) {
+        (Family::Point, false) => PointType::new(dim, metadata)
+            .with_coord_type(coord_type)
+            .into(),
+        (Family::Point, true) => MultiPointType::new(dim, metadata)
+            .with_coord_type(coord_type)
+            .into(),
+        (Family::Line, false) => LineStringType::new(dim, metadata)
+            .with_coord_type(coord_type)
+            .into(),
+        (Family::Line, true) => MultiLineStringType::new(dim, metadata)
+            .with_coord_type(coord_type)
+            .into(),
+        (Family::Polygon, false) => PolygonType::new(dim, metadata)
+            .with_coord_type(coord_type)
+            .into(),
+        (Family::Polygon, true) => MultiPolygonType::new(dim, metadata)
+            .with_coord_type(coord_type)
+            .into(),
+    }
+}
+
+fn build_geometry(geometries: &[Option<Geometry>], typ: &GeoArrowType) -> GeoArrowResult<ArrayRef> {
+    let array = match typ {
+        GeoArrowType::Point(typ) => {
+            PointBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        GeoArrowType::MultiPoint(typ) => {
+            MultiPointBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        GeoArrowType::LineString(typ) => {
+            LineStringBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        GeoArrowType::MultiLineString(typ) => {
+            MultiLineStringBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        GeoArrowType::Polygon(typ) => {
+            PolygonBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        GeoArrowType::MultiPolygon(typ) => {
+            MultiPolygonBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        GeoArrowType::Geometry(typ) => {
+            GeometryBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        typ => {
+            return Err(GeoArrowError::IncorrectGeometryType(format!(
+                "Unsupported output type {typ:?}"
+            )));
+        }
+    };
+    Ok(array)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_schema::DataType;
+    use geoarrow_array::array::from_arrow_array;
+    use geoarrow_array::cast::to_wkt;
+
+    use super::*;
+    use crate::topology::test::TOPOLOGY;
+
+    fn geometry_wkt(batch: &RecordBatch) -> Vec<String> {
+        let schema = batch.schema();
+        let idx = schema.index_of("geometry").unwrap();
+        let geometry = from_arrow_array(batch.column(idx).as_ref(), schema.field(idx)).unwrap();
+        let wkt = to_wkt::<i32>(geometry.as_ref()).unwrap().to_array_ref();
+        wkt.as_string::<i32>()
+            .iter()
+            .map(|value| value.unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn read_regions() {
+        let topology = Topology::try_new(TOPOLOGY.as_bytes()).unwrap();
+        let reader = topology.read_object("regions", Default::default()).unwrap();
+        let schema = reader.schema();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["id", "name", "population", "geometry"]);
+        assert!(matches!(
+            GeoArrowType::try_from(schema.field(3)).unwrap(),
+            GeoArrowType::Polygon(_)
+        ));
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        let ids = batch.column(0).as_string::<i32>();
+        assert_eq!(ids.value(0), "left");
+        assert_eq!(ids.value(1), "right");
+        assert_eq!(batch.column(2).data_type(), &DataType::Int64);
+        assert_eq!(
+            batch
+                .column(2)
+                .as_primitive::<Int64Type>()
+                .values()
+                .to_vec(),
+            vec![10, 20]
+        );
+        assert_eq!(
+            geometry_wkt(batch),
+            [
+                "POLYGON((11 20,11 21,10 21,10 20,11 20))",
+                "POLYGON((11 20,12 20,12 21,11 21,11 20))",
+            ]
+        );
+    }
+
+    #[test]
+    fn read_objects() {
+        let topology = Topology::try_new(TOPOLOGY.as_bytes()).unwrap();
+        let readers = topology.read_objects(Default::default()).unwrap();
+        let names = readers
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["regions", "border", "capital"]);
+
+        let wkt = readers
+            .into_iter()
+            .skip(1)
+            .map(|(_, reader)| {
+                assert_eq!(reader.schema().fields().len(), 1);
+                let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+                geometry_wkt(&batches[0])
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(wkt, [["LINESTRING(11 20,11 21)"], ["POINT(10.5 20.5)"]]);
+    }
+
+    #[test]
+    fn small_batches() {
+        let topology = Topology::try_new(TOPOLOGY.as_bytes()).unwrap();
+        let options = TopoJsonReaderOptions::default().with_batch_size(1);
+        let reader = topology.read_object("regions", options).unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            [1, 1]
+        );
+        assert_eq!(batches[1].column(1).as_string::<i32>().value(0), "Right");
+    }
+}
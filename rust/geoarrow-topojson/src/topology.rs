@@ -0,0 +1,378 @@
+//! Parsing TopoJSON topologies and resolving their geometries from shared arcs.
+
+use std::fmt;
+use std::io::Read;
+
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use serde::de::{DeserializeOwned, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use crate::reader::{TopoJsonReader, TopoJsonReaderOptions};
+
+pub(crate) fn topojson_error(err: impl ToString) -> GeoArrowError {
+    GeoArrowError::TopoJson(err.to_string())
+}
+
+/// The quantization transform of a topology.
+///
+/// Quantized positions are integers, mapped back to coordinates as
+/// `position * scale + translate`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Transform {
+    /// The scale of x and y.
+    pub scale: [f64; 2],
+
+    /// The translation of x and y.
+    pub translate: [f64; 2],
+}
+
+impl Transform {
+    fn apply(&self, x: f64, y: f64) -> Coord {
+        Coord {
+            x: x * self.scale[0] + self.translate[0],
+            y: y * self.scale[1] + self.translate[1],
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawTopology {
+    #[serde(rename = "type")]
+    typ: String,
+    transform: Option<Transform>,
+    #[serde(default)]
+    arcs: Vec<Vec<Vec<f64>>>,
+    #[serde(deserialize_with = "ordered_objects")]
+    objects: Vec<(String, GeometryObject)>,
+}
+
+/// Deserialize the `objects` member in document order, which a JSON map would not keep.
+fn ordered_objects<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<(String, GeometryObject)>, D::Error> {
+    struct ObjectsVisitor;
+
+    impl<'de> Visitor<'de> for ObjectsVisitor {
+        type Value = Vec<(String, GeometryObject)>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map of TopoJSON geometry objects")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut objects = Vec::with_capacity(map.size_hint().unwrap_or(0));
+            while let Some(entry) = map.next_entry()? {
+                objects.push(entry);
+            }
+            Ok(objects)
+        }
+    }
+
+    deserializer.deserialize_map(ObjectsVisitor)
+}
+
+/// A geometry object of a topology, with its arcs and positions not yet resolved.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct GeometryObject {
+    /// The geometry type, or `None` for a null geometry.
+    #[serde(rename = "type")]
+    pub(crate) typ: Option<String>,
+    arcs: Option<Value>,
+    coordinates: Option<Value>,
+    #[serde(default)]
+    pub(crate) geometries: Vec<GeometryObject>,
+    pub(crate) id: Option<Value>,
+    pub(crate) properties: Option<Map<String, Value>>,
+}
+
+impl GeometryObject {
+    /// Deserialize the `arcs` or `coordinates` member of an object of type `typ`.
+    fn member<T: DeserializeOwned>(
+        value: &Option<Value>,
+        name: &str,
+        typ: &str,
+    ) -> GeoArrowResult<T> {
+        let value = value
+            .as_ref()
+            .ok_or_else(|| topojson_error(format!("{typ} object has no '{name}' member")))?;
+        T::deserialize(value)
+            .map_err(|err| topojson_error(format!("Invalid '{name}' of {typ} object: {err}")))
+    }
+}
+
+/// A parsed TopoJSON topology.
+///
+/// The arcs are decoded up front, undoing delta encoding and quantization, and shared by the
+/// geometries of all objects.
+#[derive(Debug, Clone)]
+pub struct Topology {
+    transform: Option<Transform>,
+    arcs: Vec<Vec<Coord>>,
+    objects: Vec<(String, GeometryObject)>,
+}
+
+impl Topology {
+    /// Parse a topology from a TopoJSON document.
+    pub fn try_new(reader: impl Read) -> GeoArrowResult<Self> {
+        let raw: RawTopology = serde_json::from_reader(reader).map_err(topojson_error)?;
+        if raw.typ != "Topology" {
+            return Err(topojson_error(format!(
+                "Expected an object of type 'Topology', got '{}'",
+                raw.typ
+            )));
+        }
+        let arcs = raw
+            .arcs
+            .iter()
+            .map(|arc| decode_arc(arc, raw.transform.as_ref()))
+            .collect::<GeoArrowResult<_>>()?;
+        Ok(Self {
+            transform: raw.transform,
+            arcs,
+            objects: raw.objects,
+        })
+    }
+
+    /// The quantization transform of the topology, if it is quantized.
+    pub fn transform(&self) -> Option<&Transform> {
+        self.transform.as_ref()
+    }
+
+    /// The names of the objects of the topology, in document order.
+    pub fn object_names(&self) -> impl Iterator<Item = &str> {
+        self.objects.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Read the object with the given name.
+    pub fn read_object(
+        &self,
+        name: &str,
+        options: TopoJsonReaderOptions,
+    ) -> GeoArrowResult<TopoJsonReader> {
+        let (_, object) = self
+            .objects
+            .iter()
+            .find(|(object_name, _)| object_name == name)
+            .ok_or_else(|| topojson_error(format!("No object named '{name}' in topology")))?;
+        TopoJsonReader::try_new(self, object, options)
+    }
+
+    /// Read every object of the topology, in document order.
+    pub fn read_objects(
+        &self,
+        options: TopoJsonReaderOptions,
+    ) -> GeoArrowResult<Vec<(String, TopoJsonReader)>> {
+        self.objects
+            .iter()
+            .map(|(name, object)| {
+                TopoJsonReader::try_new(self, object, options.clone())
+                    .map(|reader| (name.clone(), reader))
+            })
+            .collect()
+    }
+
+    /// Resolve the geometry of an object, or `None` if it has a null type.
+    pub(crate) fn geometry(&self, object: &GeometryObject) -> GeoArrowResult<Option<Geometry>> {
+        let Some(typ) = object.typ.as_deref() else {
+            return Ok(None);
+        };
+        let geometry = match typ {
+            "Point" => {
+                let position: Vec<f64> =
+                    GeometryObject::member(&object.coordinates, "coordinates", typ)?;
+                Geometry::Point(Point(self.position(&position)?))
+            }
+            "MultiPoint" => {
+                let positions: Vec<Vec<f64>> =
+                    GeometryObject::member(&object.coordinates, "coordinates", typ)?;
+                let points = positions
+                    .iter()
+                    .map(|position| Ok(Point(self.position(position)?)))
+                    .collect::<GeoArrowResult<_>>()?;
+                Geometry::MultiPoint(MultiPoint::new(points))
+            }
+            "LineString" => {
+                let arcs: Vec<i64> = GeometryObject::member(&object.arcs, "arcs", typ)?;
+                Geometry::LineString(self.line(&arcs)?)
+            }
+            "MultiLineString" => {
+                let lines: Vec<Vec<i64>> = GeometryObject::member(&object.arcs, "arcs", typ)?;
+                let lines = lines
+                    .iter()
+                    .map(|arcs| self.line(arcs))
+                    .collect::<GeoArrowResult<_>>()?;
+                Geometry::MultiLineString(MultiLineString::new(lines))
+            }
+            "Polygon" => {
+                let rings: Vec<Vec<i64>> = GeometryObject::member(&object.arcs, "arcs", typ)?;
+                Geometry::Polygon(self.polygon(&rings)?)
+            }
+            "MultiPolygon" => {
+                let polygons: Vec<Vec<Vec<i64>>> =
+                    GeometryObject::member(&object.arcs, "arcs", typ)?;
+                let polygons = polygons
+                    .iter()
+                    .map(|rings| self.polygon(rings))
+                    .collect::<GeoArrowResult<_>>()?;
+                Geometry::MultiPolygon(MultiPolygon::new(polygons))
+            }
+            "GeometryCollection" => {
+                let mut geometries = vec![];
+                for member in &object.geometries {
+                    geometries.extend(self.geometry(member)?);
+                }
+                Geometry::GeometryCollection(GeometryCollection(geometries))
+            }
+            typ => {
+                return Err(topojson_error(format!("Unknown geometry type '{typ}'")));
+            }
+        };
+        Ok(Some(geometry))
+    }
+
+    /// A position of a point, which is quantized but not delta-encoded.
+    fn position(&self, position: &[f64]) -> GeoArrowResult<Coord> {
+        let [x, y] = xy(position)?;
+        Ok(match &self.transform {
+            Some(transform) => transform.apply(x, y),
+            None => Coord { x, y },
+        })
+    }
+
+    /// Join arcs into one line, dropping the first point of each arc after the first since it
+    /// repeats the last point of the previous one. A negative index `i` references arc `!i`
+    /// reversed.
+    fn line(&self, arcs: &[i64]) -> GeoArrowResult<LineString> {
+        let mut coords: Vec<Coord> = vec![];
+        for &index in arcs {
+            let (arc_idx, reversed) = if index >= 0 {
+                (index as usize, false)
+            } else {
+                ((!index) as usize, true)
+            };
+            let arc = self
+                .arcs
+                .get(arc_idx)
+                .ok_or_else(|| topojson_error(format!("Arc index {index} is out of range")))?;
+            coords.pop();
+            if reversed {
+                coords.extend(arc.iter().rev());
+            } else {
+                coords.extend(arc);
+            }
+        }
+        Ok(LineString::new(coords))
+    }
+
+    fn polygon(&self, rings: &[Vec<i64>]) -> GeoArrowResult<Polygon> {
+        let mut rings = rings
+            .iter()
+            .map(|arcs| self.line(arcs))
+            .collect::<GeoArrowResult<Vec<_>>>()?
+            .into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+}
+
+fn xy(position: &[f64]) -> GeoArrowResult<[f64; 2]> {
+    match position {
+        [x, y, ..] => Ok([*x, *y]),
+        _ => Err(topojson_error("Position has fewer than two values")),
+    }
+}
+
+/// Decode the positions of an arc. Positions of a quantized topology are deltas from the
+/// previous position.
+fn decode_arc(arc: &[Vec<f64>], transform: Option<&Transform>) -> GeoArrowResult<Vec<Coord>> {
+    let (mut x, mut y) = (0.0, 0.0);
+    arc.iter()
+        .map(|position| {
+            let [dx, dy] = xy(position)?;
+            Ok(match transform {
+                Some(transform) => {
+                    x += dx;
+                    y += dy;
+                    transform.apply(x, y)
+                }
+                None => Coord { x: dx, y: dy },
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    /// Two unit squares sharing an edge, the shared edge as a line, and a point.
+    pub(crate) const TOPOLOGY: &str = r#"{
+        "type": "Topology",
+        "transform": {"scale": [0.5, 0.5], "translate": [10, 20]},
+        "objects": {
+            "regions": {
+                "type": "GeometryCollection",
+                "geometries": [
+                    {"type": "Polygon", "id": "left", "arcs": [[0, 1]],
+                     "properties": {"name": "Left", "population": 10}},
+                    {"type": "Polygon", "id": "right", "arcs": [[2, -1]],
+                     "properties": {"name": "Right", "population": 20}}
+                ]
+            },
+            "border": {"type": "LineString", "arcs": [0]},
+            "capital": {"type": "Point", "coordinates": [1, 1]}
+        },
+        "arcs": [
+            [[2, 0], [0, 2]],
+            [[2, 2], [-2, 0], [0, -2], [2, 0]],
+            [[2, 0], [2, 0], [0, 2], [-2, 0]]
+        ]
+    }"#;
+
+    #[test]
+    fn decode_arcs() {
+        let topology = Topology::try_new(TOPOLOGY.as_bytes()).unwrap();
+        assert_eq!(
+            topology.object_names().collect::<Vec<_>>(),
+            ["regions", "border", "capital"]
+        );
+        assert_eq!(
+            topology.arcs[1],
+            [
+                Coord { x: 11.0, y: 21.0 },
+                Coord { x: 10.0, y: 21.0 },
+                Coord { x: 10.0, y: 20.0 },
+                Coord { x: 11.0, y: 20.0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn shared_arcs() {
+        let topology = Topology::try_new(TOPOLOGY.as_bytes()).unwrap();
+        let (_, regions) = &topology.objects[0];
+        let right = topology.geometry(&regions.geometries[1]).unwrap().unwrap();
+        let expected = Polygon::new(
+            LineString::from(vec![(11.0, 20.0), (12.0, 20.0), (12.0, 21.0), (11.0, 21.0)]),
+            vec![],
+        );
+        assert_eq!(right, Geometry::Polygon(expected));
+    }
+
+    #[test]
+    fn arc_out_of_range() {
+        let topology = Topology::try_new(
+            r#"{"type": "Topology", "objects": {"a": {"type": "LineString", "arcs": [-2]}},
+                "arcs": [[[0, 0], [1, 1]]]}"#
+                .as_bytes(),
+        )
+        .unwrap();
+        let err = topology.read_object("a", Default::default()).unwrap_err();
+        assert!(matches!(err, GeoArrowError::TopoJson(_)));
+    }
+}
@@ -0,0 +1,109 @@
+//! Building geometries from the coordinate text of KML and GML documents.
+
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use wkt::Wkt;
+use wkt::types::{Coord, Dimension, GeometryCollection, MultiLineString, MultiPoint, MultiPolygon};
+
+/// A coordinate with a z value if and only if `dim` is XYZ, defaulting a missing z to 0.
+pub(crate) fn coord(x: f64, y: f64, z: Option<f64>, dim: Dimension) -> Coord<f64> {
+    Coord {
+        x,
+        y,
+        z: (dim == Dimension::XYZ).then(|| z.unwrap_or(0.0)),
+        m: None,
+    }
+}
+
+fn parse_number(value: &str) -> GeoArrowResult<f64> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| GeoArrowError::Xml(format!("Invalid coordinate value '{value}'")))
+}
+
+/// Parse whitespace-separated tuples of comma-separated numbers, as in KML `<coordinates>` and
+/// GML 2 `<gml:coordinates>`.
+pub(crate) fn parse_tuples(text: &str, dim: Dimension) -> GeoArrowResult<Vec<Coord<f64>>> {
+    text.split_whitespace()
+        .map(|tuple| {
+            let values = tuple
+                .split(',')
+                .map(parse_number)
+                .collect::<GeoArrowResult<Vec<_>>>()?;
+            match values[..] {
+                [x, y] => Ok(coord(x, y, None, dim)),
+                [x, y, z, ..] => Ok(coord(x, y, Some(z), dim)),
+                _ => Err(GeoArrowError::Xml(format!(
+                    "Coordinate tuple '{tuple}' has fewer than two values"
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// Whether any tuple in a text of coordinate tuples has a third value.
+pub(crate) fn tuples_have_z(text: &str) -> bool {
+    text.split_whitespace()
+        .any(|tuple| tuple.split(',').count() >= 3)
+}
+
+/// Parse a whitespace-separated list of numbers with `size` numbers per coordinate, as in GML 3
+/// `<gml:pos>` and `<gml:posList>`.
+pub(crate) fn parse_list(
+    text: &str,
+    size: usize,
+    dim: Dimension,
+) -> GeoArrowResult<Vec<Coord<f64>>> {
+    let values = text
+        .split_whitespace()
+        .map(parse_number)
+        .collect::<GeoArrowResult<Vec<_>>>()?;
+    if size < 2 || values.len() % size != 0 {
+        return Err(GeoArrowError::Xml(format!(
+            "Position list of {} values doesn't have {size} values per coordinate",
+            values.len()
+        )));
+    }
+    Ok(values
+        .chunks_exact(size)
+        .map(|values| coord(values[0], values[1], values.get(2).copied(), dim))
+        .collect())
+}
+
+/// Combine the members of a multi-geometry element. Members of a single type become the matching
+/// multi geometry, and anything else a geometry collection.
+pub(crate) fn combine(geometries: Vec<Wkt<f64>>, dim: Dimension) -> Wkt<f64> {
+    if geometries.is_empty() {
+        return Wkt::GeometryCollection(GeometryCollection::new(vec![], dim));
+    }
+    if geometries.iter().all(|g| matches!(g, Wkt::Point(_))) {
+        let points = geometries
+            .into_iter()
+            .filter_map(|g| match g {
+                Wkt::Point(g) => Some(g),
+                _ => None,
+            })
+            .collect();
+        Wkt::MultiPoint(MultiPoint::new(points, dim))
+    } else if geometries.iter().all(|g| matches!(g, Wkt::LineString(_))) {
+        let lines = geometries
+            .into_iter()
+            .filter_map(|g| match g {
+                Wkt::LineString(g) => Some(g),
+                _ => None,
+            })
+            .collect();
+        Wkt::MultiLineString(MultiLineString::new(lines, dim))
+    } else if geometries.iter().all(|g| matches!(g, Wkt::Polygon(_))) {
+        let polygons = geometries
+            .into_iter()
+            .filter_map(|g| match g {
+                Wkt::Polygon(g) => Some(g),
+                _ => None,
+            })
+            .collect();
+        Wkt::MultiPolygon(MultiPolygon::new(polygons, dim))
+    } else {
+        Wkt::GeometryCollection(GeometryCollection::new(geometries, dim))
+    }
+}
@@ -0,0 +1,397 @@
+//! Read GML feature collections.
+//!
+//! Each feature inside a `<gml:featureMember>`, `<gml:featureMembers>` or `<wfs:member>` element
+//! becomes a row. Simple child elements become string columns, the feature's `gml:id` attribute
+//! becomes a `gml_id` column and the first child holding a GML 2 or GML 3 geometry becomes the
+//! geometry.
+
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, Crs, GeoArrowType};
+use wkt::Wkt;
+use wkt::types::{Coord, Dimension, LineString, Point, Polygon};
+
+use crate::geometry::{combine, coord, parse_list, parse_tuples, tuples_have_z};
+use crate::reader::{Feature, FeatureParser, feature_reader};
+use crate::xml::Element;
+
+/// Options for the GML reader
+#[derive(Debug, Clone)]
+pub struct GmlReaderOptions {
+    /// The GeoArrow coordinate type to use in the geometry arrays.
+    pub coord_type: CoordType,
+
+    /// The number of rows in each batch.
+    pub batch_size: usize,
+
+    /// The CRS of the geometries.
+    ///
+    /// If not set, it is taken from the `srsName` of the first geometry.
+    pub crs: Option<Crs>,
+
+    /// Whether to swap the first two values of every coordinate.
+    ///
+    /// GML 3 coordinates follow the axis order of their CRS, so that for example coordinates in
+    /// `urn:ogc:def:crs:EPSG::4326` are latitude first. Set this to read such coordinates as x/y.
+    pub swap_xy: bool,
+}
+
+impl GmlReaderOptions {
+    /// Set the GeoArrow coordinate type.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the CRS of the geometries.
+    pub fn with_crs(mut self, crs: Crs) -> Self {
+        self.crs = Some(crs);
+        self
+    }
+
+    /// Set whether to swap the first two values of every coordinate.
+    pub fn with_swap_xy(mut self, swap_xy: bool) -> Self {
+        self.swap_xy = swap_xy;
+        self
+    }
+}
+
+impl Default for GmlReaderOptions {
+    fn default() -> Self {
+        Self {
+            coord_type: Default::default(),
+            batch_size: 65_536,
+            crs: None,
+            swap_xy: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GmlParser {
+    crs: Option<Crs>,
+    swap_xy: bool,
+    /// The `srsName` of the first geometry that has one.
+    srs_name: Option<String>,
+}
+
+impl GmlParser {
+    fn new(options: GmlReaderOptions) -> Self {
+        Self {
+            crs: options.crs,
+            swap_xy: options.swap_xy,
+            srs_name: None,
+        }
+    }
+
+    fn coords(&self, element: &Element, dim: Dimension) -> GeoArrowResult<Vec<Coord<f64>>> {
+        let mut coords = if let Some(pos_list) = element.child("posList") {
+            parse_list(&pos_list.text, srs_dimension(pos_list, dim), dim)?
+        } else if let Some(coordinates) = element.child("coordinates") {
+            parse_tuples(&coordinates.text, dim)?
+        } else {
+            let mut coords = vec![];
+            for pos in element.children_named("pos") {
+                coords.extend(parse_list(&pos.text, srs_dimension(pos, dim), dim)?);
+            }
+            for c in element.children_named("coord") {
+                let value = |name| {
+                    c.child_text(name)
+                        .and_then(|value| value.trim().parse().ok())
+                };
+                if let (Some(x), Some(y)) = (value("X"), value("Y")) {
+                    coords.push(coord(x, y, value("Z"), dim));
+                }
+            }
+            coords
+        };
+        if self.swap_xy {
+            coords
+                .iter_mut()
+                .for_each(|c| std::mem::swap(&mut c.x, &mut c.y));
+        }
+        Ok(coords)
+    }
+
+    fn ring(&self, boundary: &Element, dim: Dimension) -> GeoArrowResult<Option<LineString<f64>>> {
+        match boundary.child("LinearRing") {
+            Some(ring) => Ok(Some(LineString::new(self.coords(ring, dim)?, dim))),
+            None => Ok(None),
+        }
+    }
+
+    fn parse_geometry(
+        &mut self,
+        element: &Element,
+        dim: Dimension,
+    ) -> GeoArrowResult<Option<Wkt<f64>>> {
+        if self.srs_name.is_none() {
+            self.srs_name = element.attribute("srsName").map(String::from);
+        }
+        let geometry = match element.name.as_str() {
+            "Point" => Wkt::Point(Point::new(
+                self.coords(element, dim)?.into_iter().next(),
+                dim,
+            )),
+            "LineString" | "LinearRing" => {
+                Wkt::LineString(LineString::new(self.coords(element, dim)?, dim))
+            }
+            "Curve" => {
+                let mut coords = vec![];
+                for segment in element
+                    .children_named("segments")
+                    .flat_map(|segments| segments.children.iter())
+                {
+                    let segment_coords = self.coords(segment, dim)?;
+                    // Consecutive segments share their end and start points
+                    let skip = usize::from(coords.last() == segment_coords.first());
+                    coords.extend(segment_coords.into_iter().skip(skip));
+                }
+                Wkt::LineString(LineString::new(coords, dim))
+            }
+            "Polygon" => {
+                let mut rings = vec![];
+                for boundary in element.children.iter().filter(|c| {
+                    matches!(
+                        c.name.as_str(),
+                        "exterior" | "outerBoundaryIs" | "interior" | "innerBoundaryIs"
+                    )
+                }) {
+                    rings.extend(self.ring(boundary, dim)?);
+                }
+                Wkt::Polygon(Polygon::new(rings, dim))
+            }
+            "MultiPoint" | "MultiLineString" | "MultiCurve" | "MultiPolygon" | "MultiSurface"
+            | "MultiGeometry" => {
+                // Members are wrapped in one property element each, such as <gml:pointMember>,
+                // or all together, such as <gml:surfaceMembers>
+                let mut members = vec![];
+                for member in element.children.iter().flat_map(|c| c.children.iter()) {
+                    members.extend(self.parse_geometry(member, dim)?);
+                }
+                combine(members, dim)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(geometry))
+    }
+}
+
+/// The number of values per coordinate in a `<gml:pos>` or `<gml:posList>`.
+fn srs_dimension(element: &Element, dim: Dimension) -> usize {
+    element
+        .attribute("srsDimension")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(if dim == Dimension::XYZ { 3 } else { 2 })
+}
+
+/// Whether the coordinates of a geometry element are 3D.
+fn has_z(element: &Element) -> bool {
+    if let Some(dimension) = element.attribute("srsDimension") {
+        return dimension == "3";
+    }
+    match element.name.as_str() {
+        "coordinates" => tuples_have_z(&element.text),
+        "pos" => element.text.split_whitespace().count() == 3,
+        "coord" => element.child("Z").is_some(),
+        _ => element.children.iter().any(has_z),
+    }
+}
+
+/// The CRS named by an `srsName`, as an EPSG authority code where possible.
+fn parse_srs_name(srs_name: &str) -> Crs {
+    let code = srs_name.rsplit([':', '/', '#']).next().unwrap_or_default();
+    if srs_name.to_ascii_uppercase().contains("EPSG") && code.parse::<u32>().is_ok() {
+        Crs::from_authority_code(format!("EPSG:{code}"))
+    } else if srs_name.ends_with("CRS84") {
+        Crs::from_authority_code("OGC:CRS84".to_string())
+    } else {
+        Crs::from_unknown_crs_type(srs_name.to_string())
+    }
+}
+
+impl FeatureParser for GmlParser {
+    fn is_feature(&self, ancestors: &[String], _name: &str) -> bool {
+        ancestors.last().is_some_and(|parent| {
+            matches!(
+                parent.as_str(),
+                "featureMember" | "featureMembers" | "member"
+            )
+        })
+    }
+
+    fn parse(&mut self, element: &Element, features: &mut Vec<Feature>) -> GeoArrowResult<()> {
+        let mut properties = vec![];
+        if let Some(id) = element.attribute("id") {
+            properties.push(("gml_id".to_string(), id.to_string()));
+        }
+
+        let mut geometry = None;
+        for child in &element.children {
+            if child.name == "boundedBy" {
+                continue;
+            }
+            if child.is_leaf() {
+                // Empty elements are null values
+                if !child.text.is_empty() {
+                    properties.push((child.name.clone(), child.text.clone()));
+                }
+            } else if geometry.is_none() {
+                for value in &child.children {
+                    let dim = if has_z(value) {
+                        Dimension::XYZ
+                    } else {
+                        Dimension::XY
+                    };
+                    geometry = self.parse_geometry(value, dim)?;
+                    if geometry.is_some() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        features.push(Feature {
+            geometry,
+            properties,
+        });
+        Ok(())
+    }
+
+    fn geometry_type(&self) -> GeoArrowType {
+        GeoArrowType::Geometry(geoarrow_schema::GeometryType::new(Default::default()))
+    }
+
+    fn crs(&self) -> Option<Crs> {
+        self.crs
+            .clone()
+            .or_else(|| self.srs_name.as_deref().map(parse_srs_name))
+    }
+}
+
+feature_reader!(
+    /// A reader of GML features into record batches.
+    ///
+    /// Columns are read as strings.
+    GmlReader,
+    GmlParser,
+    GmlReaderOptions
+);
+
+/// Read all features of a GML document.
+pub fn read_gml<R: std::io::BufRead + std::io::Seek>(
+    reader: R,
+    options: GmlReaderOptions,
+) -> GeoArrowResult<Vec<arrow_array::RecordBatch>> {
+    Ok(GmlReader::try_new(reader, options)?.collect::<Result<Vec<_>, _>>()?)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use arrow_array::cast::AsArray;
+    use geoarrow_schema::CrsType;
+
+    use super::*;
+    use crate::test::geometry_wkt;
+
+    #[test]
+    fn read_gml3() {
+        let gml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ogr:FeatureCollection xmlns:ogr="http://ogr.maptools.org/" xmlns:gml="http://www.opengis.net/gml/3.2">
+  <ogr:featureMember>
+    <ogr:roads gml:id="roads.1">
+      <gml:boundedBy><gml:Envelope><gml:lowerCorner>0 0</gml:lowerCorner></gml:Envelope></gml:boundedBy>
+      <ogr:geometryProperty>
+        <gml:LineString srsName="urn:ogc:def:crs:EPSG::3857">
+          <gml:posList>0 0 10 10 20 0</gml:posList>
+        </gml:LineString>
+      </ogr:geometryProperty>
+      <ogr:name>Main Street</ogr:name>
+      <ogr:lanes>2</ogr:lanes>
+    </ogr:roads>
+  </ogr:featureMember>
+  <ogr:featureMember>
+    <ogr:roads gml:id="roads.2">
+      <ogr:geometryProperty>
+        <gml:MultiSurface>
+          <gml:surfaceMember>
+            <gml:Polygon srsDimension="3">
+              <gml:exterior><gml:LinearRing><gml:posList>0 0 1 4 0 1 4 4 1 0 0 1</gml:posList></gml:LinearRing></gml:exterior>
+            </gml:Polygon>
+          </gml:surfaceMember>
+        </gml:MultiSurface>
+      </ogr:geometryProperty>
+      <ogr:name/>
+    </ogr:roads>
+  </ogr:featureMember>
+</ogr:FeatureCollection>"#;
+        let batches = read_gml(Cursor::new(gml), Default::default()).unwrap();
+        let batch = &batches[0];
+        let schema = batch.schema();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["gml_id", "name", "lanes", "geometry"]);
+        assert_eq!(batch.column(0).as_string::<i32>().value(1), "roads.2");
+        assert!(batch.column(1).is_null(1));
+
+        assert_eq!(
+            geometry_wkt(batch),
+            vec![
+                Some("LINESTRING(0 0,10 10,20 0)".to_string()),
+                Some("MULTIPOLYGON Z(((0 0 1,4 0 1,4 4 1,0 0 1)))".to_string()),
+            ]
+        );
+        let geometry_type = GeoArrowType::try_from(schema.field(3)).unwrap();
+        assert_eq!(
+            geometry_type.metadata().crs().crs_type(),
+            Some(CrsType::AuthorityCode)
+        );
+    }
+
+    #[test]
+    fn read_gml2() {
+        let gml = r#"<wfs:FeatureCollection xmlns:wfs="http://www.opengis.net/wfs" xmlns:gml="http://www.opengis.net/gml">
+  <gml:featureMember>
+    <topp:states xmlns:topp="http://www.openplans.org/topp">
+      <topp:the_geom>
+        <gml:MultiPolygon srsName="http://www.opengis.net/gml/srs/epsg.xml#4326">
+          <gml:polygonMember><gml:Polygon>
+            <gml:outerBoundaryIs><gml:LinearRing><gml:coordinates>0,0 0,10 10,10 0,0</gml:coordinates></gml:LinearRing></gml:outerBoundaryIs>
+            <gml:innerBoundaryIs><gml:LinearRing><gml:coordinates>1,1 1,2 2,2 1,1</gml:coordinates></gml:LinearRing></gml:innerBoundaryIs>
+          </gml:Polygon></gml:polygonMember>
+        </gml:MultiPolygon>
+      </topp:the_geom>
+      <topp:STATE_NAME>Somewhere</topp:STATE_NAME>
+    </topp:states>
+  </gml:featureMember>
+  <gml:featureMember>
+    <topp:states xmlns:topp="http://www.openplans.org/topp">
+      <topp:the_geom><gml:Point><gml:coordinates>5,52</gml:coordinates></gml:Point></topp:the_geom>
+    </topp:states>
+  </gml:featureMember>
+</wfs:FeatureCollection>"#;
+        let options = GmlReaderOptions::default().with_swap_xy(true);
+        let batches = read_gml(Cursor::new(gml), options).unwrap();
+        assert_eq!(
+            geometry_wkt(&batches[0]),
+            vec![
+                Some("MULTIPOLYGON(((0 0,10 0,10 10,0 0),(1 1,2 1,2 2,1 1)))".to_string()),
+                Some("POINT(52 5)".to_string()),
+            ]
+        );
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "Somewhere"
+        );
+    }
+}
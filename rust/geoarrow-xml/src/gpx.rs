@@ -0,0 +1,563 @@
+//! Read and write GPX waypoints, routes and tracks.
+//!
+//! A GPX document is read as one of several layers, such as its waypoints or the points of its
+//! tracks. Geometries are XYZ with elevation as z, and `NaN` z values where a point has no
+//! elevation. Simple child elements and the leaf elements of `<extensions>` become columns.
+
+use std::fmt::Write as _;
+use std::io::Write;
+
+use arrow_array::RecordBatchReader;
+use arrow_cast::display::FormatOptions;
+use arrow_schema::{DataType, TimeUnit};
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
+    MultiPointTrait, PointTrait,
+};
+use geoarrow_array::GeoArrowArrayAccessor;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{
+    CoordType, Crs, Dimension, GeoArrowType, LineStringType, MultiLineStringType, PointType,
+};
+use quick_xml::escape::escape;
+use wkt::Wkt;
+use wkt::types::{Coord, LineString, MultiLineString, Point};
+
+use crate::reader::{Feature, FeatureParser, feature_reader};
+use crate::writer::{Column, geometry_column, geometry_wkb};
+use crate::xml::Element;
+
+/// The layer of a GPX document to read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GpxLayer {
+    /// One point per `<wpt>`
+    #[default]
+    Waypoints,
+
+    /// One linestring per `<rte>`
+    Routes,
+
+    /// One multilinestring per `<trk>`, with a linestring per `<trkseg>`
+    Tracks,
+
+    /// One point per `<trkpt>` of every track, with the `track_fid`, `track_seg_id` and
+    /// `track_seg_point_id` of the point and the `track_name` of its track.
+    TrackPoints,
+}
+
+/// Options for the GPX reader
+#[derive(Debug, Clone)]
+pub struct GpxReaderOptions {
+    /// The GeoArrow coordinate type to use in the geometry arrays.
+    pub coord_type: CoordType,
+
+    /// The number of rows in each batch.
+    pub batch_size: usize,
+
+    /// The layer to read, defaults to [`GpxLayer::Waypoints`].
+    pub layer: GpxLayer,
+}
+
+impl GpxReaderOptions {
+    /// Set the GeoArrow coordinate type.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the layer to read.
+    pub fn with_layer(mut self, layer: GpxLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+}
+
+impl Default for GpxReaderOptions {
+    fn default() -> Self {
+        Self {
+            coord_type: Default::default(),
+            batch_size: 65_536,
+            layer: Default::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GpxParser {
+    layer: GpxLayer,
+    /// The index of the next track, for the track points layer.
+    track_index: usize,
+}
+
+impl GpxParser {
+    fn new(options: GpxReaderOptions) -> Self {
+        Self {
+            layer: options.layer,
+            track_index: 0,
+        }
+    }
+}
+
+/// The coordinate of a `<wpt>`, `<rtept>` or `<trkpt>`.
+fn point_coord(element: &Element) -> GeoArrowResult<Coord<f64>> {
+    let value = |name| {
+        element
+            .attribute(name)
+            .and_then(|value| value.trim().parse().ok())
+    };
+    let (Some(lon), Some(lat)) = (value("lon"), value("lat")) else {
+        return Err(GeoArrowError::Xml(format!(
+            "<{}> is missing a valid lat or lon attribute",
+            element.name
+        )));
+    };
+    let ele = element
+        .child_text("ele")
+        .and_then(|value| value.trim().parse().ok());
+    Ok(Coord {
+        x: lon,
+        y: lat,
+        z: Some(ele.unwrap_or(f64::NAN)),
+        m: None,
+    })
+}
+
+fn extensions(element: &Element, properties: &mut Vec<(String, String)>) {
+    for child in &element.children {
+        if !child.is_leaf() {
+            extensions(child, properties);
+        } else if !child.text.is_empty() {
+            properties.push((child.name.clone(), child.text.clone()));
+        }
+    }
+}
+
+/// The simple child elements and extensions of an element, except for `ele`.
+fn properties(element: &Element, properties: &mut Vec<(String, String)>) {
+    for child in &element.children {
+        if child.name == "extensions" {
+            extensions(child, properties);
+        } else if child.is_leaf() && child.name != "ele" && !child.text.is_empty() {
+            properties.push((child.name.clone(), child.text.clone()));
+        }
+    }
+}
+
+fn line_string<'a>(points: impl Iterator<Item = &'a Element>) -> GeoArrowResult<LineString<f64>> {
+    let coords = points.map(point_coord).collect::<GeoArrowResult<_>>()?;
+    Ok(LineString::new(coords, wkt::types::Dimension::XYZ))
+}
+
+impl FeatureParser for GpxParser {
+    fn is_feature(&self, ancestors: &[String], name: &str) -> bool {
+        let element = match self.layer {
+            GpxLayer::Waypoints => "wpt",
+            GpxLayer::Routes => "rte",
+            GpxLayer::Tracks | GpxLayer::TrackPoints => "trk",
+        };
+        name == element && ancestors.last().is_some_and(|parent| parent == "gpx")
+    }
+
+    fn parse(&mut self, element: &Element, features: &mut Vec<Feature>) -> GeoArrowResult<()> {
+        let xyz = wkt::types::Dimension::XYZ;
+        match self.layer {
+            GpxLayer::Waypoints => {
+                let mut props = vec![];
+                properties(element, &mut props);
+                features.push(Feature {
+                    geometry: Some(Wkt::Point(Point::new(Some(point_coord(element)?), xyz))),
+                    properties: props,
+                });
+            }
+            GpxLayer::Routes => {
+                let mut props = vec![];
+                properties(element, &mut props);
+                let line = line_string(element.children_named("rtept"))?;
+                features.push(Feature {
+                    geometry: Some(Wkt::LineString(line)),
+                    properties: props,
+                });
+            }
+            GpxLayer::Tracks => {
+                let mut props = vec![];
+                properties(element, &mut props);
+                let lines = element
+                    .children_named("trkseg")
+                    .map(|segment| line_string(segment.children_named("trkpt")))
+                    .collect::<GeoArrowResult<_>>()?;
+                features.push(Feature {
+                    geometry: Some(Wkt::MultiLineString(MultiLineString::new(lines, xyz))),
+                    properties: props,
+                });
+            }
+            GpxLayer::TrackPoints => {
+                let track_name = element.child_text("name");
+                for (segment_idx, segment) in element.children_named("trkseg").enumerate() {
+                    for (point_idx, point) in segment.children_named("trkpt").enumerate() {
+                        let mut props = vec![
+                            ("track_fid".to_string(), self.track_index.to_string()),
+                            ("track_seg_id".to_string(), segment_idx.to_string()),
+                            ("track_seg_point_id".to_string(), point_idx.to_string()),
+                        ];
+                        if let Some(name) = track_name {
+                            props.push(("track_name".to_string(), name.to_string()));
+                        }
+                        properties(point, &mut props);
+                        features.push(Feature {
+                            geometry: Some(Wkt::Point(Point::new(Some(point_coord(point)?), xyz))),
+                            properties: props,
+                        });
+                    }
+                }
+                self.track_index += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn geometry_type(&self) -> GeoArrowType {
+        let metadata = Default::default();
+        match self.layer {
+            GpxLayer::Waypoints | GpxLayer::TrackPoints => {
+                GeoArrowType::Point(PointType::new(Dimension::XYZ, metadata))
+            }
+            GpxLayer::Routes => {
+                GeoArrowType::LineString(LineStringType::new(Dimension::XYZ, metadata))
+            }
+            GpxLayer::Tracks => {
+                GeoArrowType::MultiLineString(MultiLineStringType::new(Dimension::XYZ, metadata))
+            }
+        }
+    }
+
+    fn data_type(&self, column: &str) -> DataType {
+        match column {
+            "time" => DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            "magvar" | "geoidheight" | "hdop" | "vdop" | "pdop" | "ageofdgpsdata" => {
+                DataType::Float64
+            }
+            "sat" | "dgpsid" | "number" | "track_fid" | "track_seg_id" | "track_seg_point_id" => {
+                DataType::Int64
+            }
+            _ => DataType::Utf8,
+        }
+    }
+
+    fn crs(&self) -> Option<Crs> {
+        Some(Crs::from_authority_code("EPSG:4326".to_string()))
+    }
+}
+
+feature_reader!(
+    /// A reader of one layer of a GPX document into record batches.
+    ///
+    /// `time` columns are read as UTC timestamps and the numeric fields of the GPX schema as
+    /// numbers. Other columns are read as strings.
+    GpxReader,
+    GpxParser,
+    GpxReaderOptions
+);
+
+/// Read all features of one layer of a GPX document.
+pub fn read_gpx<R: std::io::BufRead + std::io::Seek>(
+    reader: R,
+    options: GpxReaderOptions,
+) -> GeoArrowResult<Vec<arrow_array::RecordBatch>> {
+    Ok(GpxReader::try_new(reader, options)?.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// The child elements of `<wpt>` that can be written from columns, in schema order.
+const WAYPOINT_ELEMENTS: &[&str] = &[
+    "time",
+    "magvar",
+    "geoidheight",
+    "name",
+    "cmt",
+    "desc",
+    "src",
+    "sym",
+    "type",
+    "fix",
+    "sat",
+    "hdop",
+    "vdop",
+    "pdop",
+    "ageofdgpsdata",
+    "dgpsid",
+];
+
+/// The child elements of `<trk>` that can be written from columns, in schema order.
+const TRACK_ELEMENTS: &[&str] = &["name", "cmt", "desc", "src", "number", "type"];
+
+/// Write the columns of a row as GPX elements, in the order of `elements`, followed by any other
+/// columns as `<extensions>`.
+fn write_properties(out: &mut String, columns: &[Column], row: usize, elements: &[&str]) {
+    for element in elements {
+        let value = columns
+            .iter()
+            .find(|c| c.name == *element)
+            .and_then(|c| c.value(row));
+        if let Some(value) = value {
+            write!(out, "<{element}>{}</{element}>", escape(&value)).unwrap();
+        }
+    }
+
+    let extensions = columns
+        .iter()
+        .filter(|c| !elements.contains(&c.name))
+        .filter_map(|c| Some((c.name, c.value(row)?)))
+        .collect::<Vec<_>>();
+    if !extensions.is_empty() {
+        out.push_str("<extensions>");
+        for (name, value) in extensions {
+            write!(out, "<{name}>{}</{name}>", escape(&value)).unwrap();
+        }
+        out.push_str("</extensions>");
+    }
+}
+
+/// Write a point element such as `<wpt>`, with its elevation, and call `content` to write any
+/// other child elements.
+fn write_point(
+    out: &mut String,
+    element: &str,
+    coord: &impl CoordTrait<T = f64>,
+    content: impl FnOnce(&mut String),
+) {
+    write!(
+        out,
+        "<{element} lat=\"{}\" lon=\"{}\">",
+        coord.y(),
+        coord.x()
+    )
+    .unwrap();
+    if matches!(coord.dim(), Dimensions::Xyz | Dimensions::Xyzm) && !coord.nth_or_panic(2).is_nan()
+    {
+        write!(out, "<ele>{}</ele>", coord.nth_or_panic(2)).unwrap();
+    }
+    content(out);
+    write!(out, "</{element}>").unwrap();
+}
+
+fn write_waypoint(
+    out: &mut String,
+    point: &impl PointTrait<T = f64>,
+    columns: &[Column],
+    row: usize,
+) {
+    if let Some(coord) = point.coord() {
+        write_point(out, "wpt", &coord, |out| {
+            write_properties(out, columns, row, WAYPOINT_ELEMENTS)
+        });
+        out.push('\n');
+    }
+}
+
+fn write_segment(out: &mut String, line: &impl LineStringTrait<T = f64>) {
+    out.push_str("<trkseg>");
+    for coord in line.coords() {
+        write_point(out, "trkpt", &coord, |_| {});
+    }
+    out.push_str("</trkseg>");
+}
+
+/// Write record batches as a GPX document.
+///
+/// The first geometry column must contain longitude and latitude coordinates, with elevation as
+/// an optional z value. Each point is written as a waypoint, with each point of a multipoint
+/// sharing the row's values. Linestrings and multilinestrings are written as tracks, which are
+/// kept in memory until all waypoints have been written as GPX requires. Other geometry types
+/// are an error and null geometries are skipped.
+///
+/// Columns named after the child elements of waypoints or tracks, such as `name`, `desc` or
+/// `time`, are written as those elements. Other columns are written as `<extensions>`, and so
+/// should have names that are valid XML element names.
+pub fn write_gpx<W: Write>(reader: impl RecordBatchReader, mut writer: W) -> GeoArrowResult<()> {
+    let schema = reader.schema();
+    let geometry_idx = geometry_column(&schema)?;
+
+    writer.write_all(
+        b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<gpx version=\"1.1\" creator=\"geoarrow\" xmlns=\"http://www.topografix.com/GPX/1/1\">\n",
+    )?;
+
+    let format_options = FormatOptions::default();
+    let mut tracks = String::new();
+    for batch in reader {
+        let batch = batch?;
+        let geometries = geometry_wkb(&batch, geometry_idx)?;
+        let columns = schema
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .enumerate()
+            .filter(|(idx, _)| *idx != geometry_idx)
+            .map(|(_, (field, array))| Column::try_new(field.name(), array, &format_options))
+            .collect::<GeoArrowResult<Vec<_>>>()?;
+
+        let mut waypoints = String::new();
+        for (row, geometry) in geometries.iter().enumerate() {
+            let Some(geometry) = geometry.transpose()? else {
+                continue;
+            };
+            match geometry.as_type() {
+                GeometryType::Point(g) => write_waypoint(&mut waypoints, g, &columns, row),
+                GeometryType::MultiPoint(g) => g
+                    .points()
+                    .for_each(|p| write_waypoint(&mut waypoints, &p, &columns, row)),
+                GeometryType::LineString(g) => {
+                    tracks.push_str("<trk>");
+                    write_properties(&mut tracks, &columns, row, TRACK_ELEMENTS);
+                    write_segment(&mut tracks, g);
+                    tracks.push_str("</trk>\n");
+                }
+                GeometryType::MultiLineString(g) => {
+                    tracks.push_str("<trk>");
+                    write_properties(&mut tracks, &columns, row, TRACK_ELEMENTS);
+                    g.line_strings()
+                        .for_each(|l| write_segment(&mut tracks, &l));
+                    tracks.push_str("</trk>\n");
+                }
+                _ => {
+                    return Err(GeoArrowError::IncorrectGeometryType(
+                        "GPX can only hold points, linestrings and multilinestrings".to_string(),
+                    ));
+                }
+            }
+        }
+        writer.write_all(waypoints.as_bytes())?;
+    }
+
+    writer.write_all(tracks.as_bytes())?;
+    writer.write_all(b"</gpx>\n")?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, Int64Type, TimestampMillisecondType};
+    use arrow_array::{Array, RecordBatchIterator};
+
+    use super::*;
+    use crate::test::geometry_wkt;
+
+    const GPX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1"
+     xmlns:gpxtpx="http://www.garmin.com/xmlschemas/TrackPointExtension/v1">
+  <metadata><name>Field trip</name><time>2024-05-01T00:00:00Z</time></metadata>
+  <wpt lat="52.5" lon="13.4"><ele>34</ele><name>Camp</name><sym>Flag</sym></wpt>
+  <wpt lat="52.6" lon="13.5"><name>Spring</name><hdop>1.5</hdop></wpt>
+  <trk>
+    <name>Morning</name>
+    <trkseg>
+      <trkpt lat="52.50" lon="13.40"><ele>34.5</ele><time>2024-05-01T08:00:00Z</time>
+        <extensions><gpxtpx:TrackPointExtension><gpxtpx:hr>120</gpxtpx:hr></gpxtpx:TrackPointExtension></extensions>
+      </trkpt>
+      <trkpt lat="52.51" lon="13.41"><ele>36</ele><time>2024-05-01T08:00:10Z</time></trkpt>
+    </trkseg>
+    <trkseg>
+      <trkpt lat="52.52" lon="13.42"><time>2024-05-01T08:05:00.500Z</time></trkpt>
+    </trkseg>
+  </trk>
+</gpx>"#;
+
+    fn read(layer: GpxLayer) -> arrow_array::RecordBatch {
+        let options = GpxReaderOptions::default().with_layer(layer);
+        let mut batches = read_gpx(Cursor::new(GPX), options).unwrap();
+        assert_eq!(batches.len(), 1);
+        batches.remove(0)
+    }
+
+    #[test]
+    fn track_points() {
+        let batch = read(GpxLayer::TrackPoints);
+        assert_eq!(batch.num_rows(), 3);
+        let schema = batch.schema();
+        assert_eq!(
+            schema
+                .fields()
+                .iter()
+                .map(|f| f.name().as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "track_fid",
+                "track_seg_id",
+                "track_seg_point_id",
+                "track_name",
+                "time",
+                "hr",
+                "geometry"
+            ]
+        );
+        assert_eq!(
+            schema.field(6).extension_type_name(),
+            Some("geoarrow.point")
+        );
+
+        let segments = batch.column(1).as_primitive::<Int64Type>();
+        assert_eq!(segments.values().to_vec(), vec![0, 0, 1]);
+        let times = batch.column(4).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(times.value(0), 1_714_550_400_000);
+        assert_eq!(times.value(2), 1_714_550_700_500);
+        assert_eq!(batch.column(5).as_string::<i32>().value(0), "120");
+        assert!(batch.column(5).is_null(1));
+
+        let wkt = geometry_wkt(&batch);
+        assert_eq!(wkt[0].as_deref(), Some("POINT Z(13.4 52.5 34.5)"));
+        assert_eq!(wkt[2].as_deref(), Some("POINT Z(13.42 52.52 NaN)"));
+    }
+
+    #[test]
+    fn tracks_and_waypoints() {
+        let batch = read(GpxLayer::Tracks);
+        assert_eq!(
+            geometry_wkt(&batch),
+            vec![Some(
+                "MULTILINESTRING Z((13.4 52.5 34.5,13.41 52.51 36),(13.42 52.52 NaN))".to_string()
+            )]
+        );
+        assert_eq!(batch.column(0).as_string::<i32>().value(0), "Morning");
+
+        let batch = read(GpxLayer::Waypoints);
+        assert_eq!(batch.num_rows(), 2);
+        let hdop = batch
+            .column(batch.schema().index_of("hdop").unwrap())
+            .as_primitive::<Float64Type>();
+        assert!(hdop.is_null(0));
+        assert_eq!(hdop.value(1), 1.5);
+    }
+
+    #[test]
+    fn round_trip() {
+        let waypoints = read(GpxLayer::Waypoints);
+        let tracks = read(GpxLayer::Tracks);
+
+        let mut buf = vec![];
+        write_gpx(
+            RecordBatchIterator::new(vec![Ok(waypoints.clone())], waypoints.schema()),
+            &mut buf,
+        )
+        .unwrap();
+        let batches = read_gpx(Cursor::new(&buf), Default::default()).unwrap();
+        assert_eq!(batches[0], waypoints);
+
+        let mut buf = vec![];
+        write_gpx(
+            RecordBatchIterator::new(vec![Ok(tracks.clone())], tracks.schema()),
+            &mut buf,
+        )
+        .unwrap();
+        let options = GpxReaderOptions::default().with_layer(GpxLayer::Tracks);
+        let batches = read_gpx(Cursor::new(&buf), options).unwrap();
+        assert_eq!(geometry_wkt(&batches[0]), geometry_wkt(&tracks));
+    }
+}
@@ -0,0 +1,507 @@
+//! Read and write KML placemarks.
+//!
+//! Each `<Placemark>` becomes a row, with columns for its `<name>`, its `<description>` and each
+//! `<Data>` and `<SimpleData>` value of its `<ExtendedData>`. Coordinates are longitude, latitude
+//! and optionally altitude in WGS 84.
+
+use std::fmt::Write as _;
+use std::io::Write;
+
+use arrow_array::RecordBatchReader;
+use arrow_cast::display::FormatOptions;
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+use geoarrow_array::GeoArrowArrayAccessor;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{CoordType, Crs, GeoArrowType};
+use quick_xml::escape::escape;
+use wkt::Wkt;
+use wkt::types::{Dimension, LineString, Point, Polygon};
+
+use crate::geometry::{combine, parse_list, parse_tuples, tuples_have_z};
+use crate::reader::{Feature, FeatureParser, feature_reader};
+use crate::writer::{Column, geometry_column, geometry_wkb, write_coord};
+use crate::xml::Element;
+
+/// Options for the KML reader
+#[derive(Debug, Clone)]
+pub struct KmlReaderOptions {
+    /// The GeoArrow coordinate type to use in the geometry arrays.
+    pub coord_type: CoordType,
+
+    /// The number of rows in each batch.
+    pub batch_size: usize,
+}
+
+impl KmlReaderOptions {
+    /// Set the GeoArrow coordinate type.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+}
+
+impl Default for KmlReaderOptions {
+    fn default() -> Self {
+        Self {
+            coord_type: Default::default(),
+            batch_size: 65_536,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct KmlParser;
+
+impl KmlParser {
+    fn new(_options: KmlReaderOptions) -> Self {
+        Self
+    }
+}
+
+/// Whether any coordinate below a geometry element has an altitude.
+fn has_z(element: &Element) -> bool {
+    match element.name.as_str() {
+        "coordinates" => tuples_have_z(&element.text),
+        "coord" => element.text.split_whitespace().count() >= 3,
+        _ => element.children.iter().any(has_z),
+    }
+}
+
+fn coordinates(element: &Element, dim: Dimension) -> GeoArrowResult<LineString<f64>> {
+    let coords = match element.child_text("coordinates") {
+        Some(text) => parse_tuples(text, dim)?,
+        None => vec![],
+    };
+    Ok(LineString::new(coords, dim))
+}
+
+fn parse_geometry(element: &Element, dim: Dimension) -> GeoArrowResult<Option<Wkt<f64>>> {
+    let geometry = match element.name.as_str() {
+        "Point" => {
+            let (coords, _) = coordinates(element, dim)?.into_inner();
+            Wkt::Point(Point::new(coords.into_iter().next(), dim))
+        }
+        "LineString" | "LinearRing" => Wkt::LineString(coordinates(element, dim)?),
+        "Polygon" => {
+            let exterior = element
+                .children_named("outerBoundaryIs")
+                .flat_map(|boundary| boundary.children_named("LinearRing"));
+            let interiors = element
+                .children_named("innerBoundaryIs")
+                .flat_map(|boundary| boundary.children_named("LinearRing"));
+            let rings = exterior
+                .chain(interiors)
+                .map(|ring| coordinates(ring, dim))
+                .collect::<GeoArrowResult<_>>()?;
+            Wkt::Polygon(Polygon::new(rings, dim))
+        }
+        // A gx:Track, with one "longitude latitude altitude" gx:coord per timestamp
+        "Track" => {
+            let mut coords = vec![];
+            for c in element.children_named("coord") {
+                let size = c.text.split_whitespace().count();
+                coords.extend(parse_list(&c.text, size, dim)?);
+            }
+            Wkt::LineString(LineString::new(coords, dim))
+        }
+        "MultiGeometry" | "MultiTrack" => {
+            let mut members = vec![];
+            for child in &element.children {
+                members.extend(parse_geometry(child, dim)?);
+            }
+            combine(members, dim)
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(geometry))
+}
+
+fn extended_data(element: &Element, properties: &mut Vec<(String, String)>) {
+    for data in element.children_named("Data") {
+        if let (Some(name), Some(value)) = (data.attribute("name"), data.child_text("value")) {
+            properties.push((name.to_string(), value.to_string()));
+        }
+    }
+    for simple in element
+        .children_named("SchemaData")
+        .flat_map(|schema_data| schema_data.children_named("SimpleData"))
+    {
+        if let Some(name) = simple.attribute("name") {
+            properties.push((name.to_string(), simple.text.clone()));
+        }
+    }
+}
+
+impl FeatureParser for KmlParser {
+    fn is_feature(&self, _ancestors: &[String], name: &str) -> bool {
+        name == "Placemark"
+    }
+
+    fn parse(&mut self, element: &Element, features: &mut Vec<Feature>) -> GeoArrowResult<()> {
+        let mut properties = vec![];
+        for key in ["name", "description"] {
+            if let Some(value) = element.child_text(key) {
+                properties.push((key.to_string(), value.to_string()));
+            }
+        }
+        if let Some(data) = element.child("ExtendedData") {
+            extended_data(data, &mut properties);
+        }
+
+        let mut geometry = None;
+        for child in &element.children {
+            let dim = if has_z(child) {
+                Dimension::XYZ
+            } else {
+                Dimension::XY
+            };
+            geometry = parse_geometry(child, dim)?;
+            if geometry.is_some() {
+                break;
+            }
+        }
+
+        features.push(Feature {
+            geometry,
+            properties,
+        });
+        Ok(())
+    }
+
+    fn geometry_type(&self) -> GeoArrowType {
+        GeoArrowType::Geometry(geoarrow_schema::GeometryType::new(Default::default()))
+    }
+
+    fn crs(&self) -> Option<Crs> {
+        Some(Crs::from_authority_code("EPSG:4326".to_string()))
+    }
+}
+
+feature_reader!(
+    /// A reader of KML placemarks into record batches.
+    ///
+    /// Placemarks are found anywhere in the document, including inside nested folders. Columns are
+    /// read as strings.
+    KmlReader,
+    KmlParser,
+    KmlReaderOptions
+);
+
+/// Read all placemarks of a KML document.
+pub fn read_kml<R: std::io::BufRead + std::io::Seek>(
+    reader: R,
+    options: KmlReaderOptions,
+) -> GeoArrowResult<Vec<arrow_array::RecordBatch>> {
+    Ok(KmlReader::try_new(reader, options)?.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Options for the KML writer
+#[derive(Debug, Clone)]
+pub struct KmlWriterOptions {
+    /// The name of the document.
+    pub document_name: Option<String>,
+
+    /// The column written as each placemark's `<name>`, defaults to `"name"`.
+    pub name_column: String,
+
+    /// The column written as each placemark's `<description>`, defaults to `"description"`.
+    pub description_column: String,
+}
+
+impl KmlWriterOptions {
+    /// Set the name of the document.
+    pub fn with_document_name(mut self, document_name: impl Into<String>) -> Self {
+        self.document_name = Some(document_name.into());
+        self
+    }
+
+    /// Set the column written as each placemark's `<name>`.
+    pub fn with_name_column(mut self, name_column: impl Into<String>) -> Self {
+        self.name_column = name_column.into();
+        self
+    }
+
+    /// Set the column written as each placemark's `<description>`.
+    pub fn with_description_column(mut self, description_column: impl Into<String>) -> Self {
+        self.description_column = description_column.into();
+        self
+    }
+}
+
+impl Default for KmlWriterOptions {
+    fn default() -> Self {
+        Self {
+            document_name: None,
+            name_column: "name".to_string(),
+            description_column: "description".to_string(),
+        }
+    }
+}
+
+fn write_coordinates(out: &mut String, coords: impl Iterator<Item = impl CoordTrait<T = f64>>) {
+    out.push_str("<coordinates>");
+    for (i, coord) in coords.enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        write_coord(out, &coord, ',');
+    }
+    out.push_str("</coordinates>");
+}
+
+fn write_point(out: &mut String, point: &impl PointTrait<T = f64>) {
+    if let Some(coord) = point.coord() {
+        out.push_str("<Point>");
+        write_coordinates(out, std::iter::once(coord));
+        out.push_str("</Point>");
+    }
+}
+
+fn write_line_string(out: &mut String, line: &impl LineStringTrait<T = f64>) {
+    out.push_str("<LineString>");
+    write_coordinates(out, line.coords());
+    out.push_str("</LineString>");
+}
+
+fn write_polygon(out: &mut String, polygon: &impl PolygonTrait<T = f64>) {
+    out.push_str("<Polygon>");
+    if let Some(exterior) = polygon.exterior() {
+        out.push_str("<outerBoundaryIs><LinearRing>");
+        write_coordinates(out, exterior.coords());
+        out.push_str("</LinearRing></outerBoundaryIs>");
+    }
+    for interior in polygon.interiors() {
+        out.push_str("<innerBoundaryIs><LinearRing>");
+        write_coordinates(out, interior.coords());
+        out.push_str("</LinearRing></innerBoundaryIs>");
+    }
+    out.push_str("</Polygon>");
+}
+
+fn write_geometry(out: &mut String, geometry: &impl GeometryTrait<T = f64>) -> GeoArrowResult<()> {
+    match geometry.as_type() {
+        GeometryType::Point(g) => write_point(out, g),
+        GeometryType::LineString(g) => write_line_string(out, g),
+        GeometryType::Polygon(g) => write_polygon(out, g),
+        GeometryType::MultiPoint(g) => {
+            out.push_str("<MultiGeometry>");
+            g.points().for_each(|p| write_point(out, &p));
+            out.push_str("</MultiGeometry>");
+        }
+        GeometryType::MultiLineString(g) => {
+            out.push_str("<MultiGeometry>");
+            g.line_strings().for_each(|l| write_line_string(out, &l));
+            out.push_str("</MultiGeometry>");
+        }
+        GeometryType::MultiPolygon(g) => {
+            out.push_str("<MultiGeometry>");
+            g.polygons().for_each(|p| write_polygon(out, &p));
+            out.push_str("</MultiGeometry>");
+        }
+        GeometryType::GeometryCollection(g) => {
+            out.push_str("<MultiGeometry>");
+            for child in g.geometries() {
+                write_geometry(out, &child)?;
+            }
+            out.push_str("</MultiGeometry>");
+        }
+        _ => {
+            return Err(GeoArrowError::IncorrectGeometryType(
+                "KML can't represent rects, triangles or lines".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Write record batches as KML placemarks.
+///
+/// The first geometry column is written as each placemark's geometry, and must contain longitude
+/// and latitude coordinates. Columns other than the name and description columns are written as
+/// `<ExtendedData>`, with null values left out.
+pub fn write_kml<W: Write>(
+    reader: impl RecordBatchReader,
+    mut writer: W,
+    options: &KmlWriterOptions,
+) -> GeoArrowResult<()> {
+    let schema = reader.schema();
+    let geometry_idx = geometry_column(&schema)?;
+
+    writer.write_all(
+        b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n<Document>\n",
+    )?;
+    if let Some(name) = &options.document_name {
+        writeln!(writer, "<name>{}</name>", escape(name))?;
+    }
+
+    let format_options = FormatOptions::default();
+    for batch in reader {
+        let batch = batch?;
+        let geometries = geometry_wkb(&batch, geometry_idx)?;
+        let columns = schema
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .enumerate()
+            .filter(|(idx, _)| *idx != geometry_idx)
+            .map(|(_, (field, array))| Column::try_new(field.name(), array, &format_options))
+            .collect::<GeoArrowResult<Vec<_>>>()?;
+
+        let mut out = String::new();
+        for (row, geometry) in geometries.iter().enumerate() {
+            out.push_str("<Placemark>");
+            for (key, column) in [
+                ("name", &options.name_column),
+                ("description", &options.description_column),
+            ] {
+                let value = columns
+                    .iter()
+                    .find(|c| c.name == column.as_str())
+                    .and_then(|c| c.value(row));
+                if let Some(value) = value {
+                    write!(out, "<{key}>{}</{key}>", escape(&value)).unwrap();
+                }
+            }
+
+            let data = columns
+                .iter()
+                .filter(|c| c.name != options.name_column && c.name != options.description_column)
+                .filter_map(|c| Some((c.name, c.value(row)?)))
+                .collect::<Vec<_>>();
+            if !data.is_empty() {
+                out.push_str("<ExtendedData>");
+                for (name, value) in data {
+                    write!(
+                        out,
+                        "<Data name=\"{}\"><value>{}</value></Data>",
+                        escape(name),
+                        escape(&value)
+                    )
+                    .unwrap();
+                }
+                out.push_str("</ExtendedData>");
+            }
+
+            if let Some(geometry) = geometry.transpose()? {
+                write_geometry(&mut out, &geometry)?;
+            }
+            out.push_str("</Placemark>\n");
+        }
+        writer.write_all(out.as_bytes())?;
+    }
+
+    writer.write_all(b"</Document>\n</kml>\n")?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Array, RecordBatchIterator};
+
+    use super::*;
+    use crate::test::geometry_wkt;
+
+    const KML: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<kml xmlns="http://www.opengis.net/kml/2.2">
+<Document>
+  <Folder>
+    <Placemark>
+      <name>Office</name>
+      <description><![CDATA[<b>Head office</b>]]></description>
+      <ExtendedData><Data name="floors"><value>3</value></Data></ExtendedData>
+      <Point><coordinates>-122.08,37.42,10</coordinates></Point>
+    </Placemark>
+    <Placemark>
+      <name>Road</name>
+      <ExtendedData>
+        <SchemaData schemaUrl="#roads"><SimpleData name="lanes">2</SimpleData></SchemaData>
+      </ExtendedData>
+      <LineString><coordinates>0,0 1,1
+        2,0</coordinates></LineString>
+    </Placemark>
+  </Folder>
+  <Placemark>
+    <name>Islands</name>
+    <MultiGeometry>
+      <Polygon><outerBoundaryIs><LinearRing><coordinates>0,0 1,0 1,1 0,0</coordinates></LinearRing></outerBoundaryIs></Polygon>
+      <Polygon><outerBoundaryIs><LinearRing><coordinates>5,5 6,5 6,6 5,5</coordinates></LinearRing></outerBoundaryIs></Polygon>
+    </MultiGeometry>
+  </Placemark>
+  <Placemark><name>Nowhere</name></Placemark>
+</Document>
+</kml>"##;
+
+    #[test]
+    fn read_placemarks() {
+        let batches = read_kml(Cursor::new(KML), Default::default()).unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        let schema = batch.schema();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["name", "description", "floors", "lanes", "geometry"]
+        );
+
+        let description = batch.column(1).as_string::<i32>();
+        assert_eq!(description.value(0), "<b>Head office</b>");
+        assert!(description.is_null(1));
+        assert_eq!(batch.column(3).as_string::<i32>().value(1), "2");
+
+        assert_eq!(
+            geometry_wkt(batch),
+            vec![
+                Some("POINT Z(-122.08 37.42 10)".to_string()),
+                Some("LINESTRING(0 0,1 1,2 0)".to_string()),
+                Some("MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((5 5,6 5,6 6,5 5)))".to_string()),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn small_batches() {
+        let options = KmlReaderOptions::default().with_batch_size(3);
+        let batches = read_kml(Cursor::new(KML), options).unwrap();
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            vec![3, 1]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let batches = read_kml(Cursor::new(KML), Default::default()).unwrap();
+        let schema = batches[0].schema();
+        let mut buf = vec![];
+        write_kml(
+            RecordBatchIterator::new(batches.clone().into_iter().map(Ok), schema),
+            &mut buf,
+            &KmlWriterOptions::default().with_document_name("places"),
+        )
+        .unwrap();
+
+        let round_tripped = read_kml(Cursor::new(buf), Default::default()).unwrap();
+        assert_eq!(round_tripped[0].schema(), batches[0].schema());
+        assert_eq!(geometry_wkt(&round_tripped[0]), geometry_wkt(&batches[0]));
+        assert_eq!(round_tripped[0].column(0), batches[0].column(0));
+        assert_eq!(round_tripped[0].column(2), batches[0].column(2));
+    }
+}
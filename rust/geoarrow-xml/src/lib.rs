@@ -0,0 +1,19 @@
+//! Read KML, GML and GPX documents into GeoArrow record batches, and write KML and GPX.
+//!
+//! Documents are streamed, with one feature element at a time held in memory. Readers scan the
+//! whole input once up front to find the columns of its features, and so require [`Seek`].
+//!
+//! [`Seek`]: std::io::Seek
+
+#![warn(missing_docs)]
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
+
+mod geometry;
+pub mod gml;
+pub mod gpx;
+pub mod kml;
+mod reader;
+#[cfg(test)]
+mod test;
+mod writer;
+mod xml;
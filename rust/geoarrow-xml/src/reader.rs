@@ -0,0 +1,243 @@
+//! The record batch reader shared by the KML, GML and GPX readers.
+
+use std::collections::HashMap;
+use std::io::{BufRead, Seek};
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch, StringArray};
+use arrow_cast::cast;
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::builder::{
+    GeometryBuilder, LineStringBuilder, MultiLineStringBuilder, PointBuilder,
+};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{CoordType, Crs, GeoArrowType, Metadata};
+use wkt::Wkt;
+
+use crate::xml::{Element, ElementReader};
+
+/// A feature parsed from an XML element, with property values still in their text form.
+#[derive(Debug, Clone)]
+pub(crate) struct Feature {
+    pub(crate) geometry: Option<Wkt<f64>>,
+    pub(crate) properties: Vec<(String, String)>,
+}
+
+/// Parses the feature elements of one XML format.
+pub(crate) trait FeatureParser: Clone {
+    /// Whether an element is a feature, given the names of its ancestors.
+    fn is_feature(&self, ancestors: &[String], name: &str) -> bool;
+
+    /// Parse a feature element into zero or more features.
+    fn parse(&mut self, element: &Element, features: &mut Vec<Feature>) -> GeoArrowResult<()>;
+
+    /// The GeoArrow type of the geometry column, with default metadata and coordinate type.
+    fn geometry_type(&self) -> GeoArrowType;
+
+    /// The data type of a property column. Values are cast from strings to this type, with
+    /// unparseable values becoming null.
+    fn data_type(&self, _column: &str) -> DataType {
+        DataType::Utf8
+    }
+
+    /// The CRS of the geometries, after every feature has been parsed.
+    fn crs(&self) -> Option<Crs>;
+}
+
+/// Reads features from an XML document into record batches with one column per property and a
+/// trailing `geometry` column.
+pub(crate) struct FeatureReader<R, P> {
+    reader: ElementReader<R>,
+    parser: P,
+    schema: SchemaRef,
+    geometry_type: GeoArrowType,
+    column_index: HashMap<String, usize>,
+    batch_size: usize,
+    pending: Vec<Feature>,
+}
+
+impl<R: BufRead + Seek, P: FeatureParser> FeatureReader<R, P> {
+    /// Scan the whole document for property names, then rewind to the start of the reader.
+    pub(crate) fn try_new(
+        mut reader: R,
+        parser: P,
+        coord_type: CoordType,
+        batch_size: usize,
+    ) -> GeoArrowResult<Self> {
+        // Scan with a copy of the parser so that any state it keeps starts over when reading
+        let mut scan_parser = parser.clone();
+        let mut columns: Vec<String> = vec![];
+        let mut column_index = HashMap::new();
+        let mut elements = ElementReader::new(&mut reader);
+        let mut features = vec![];
+        while let Some(element) =
+            elements.next_element(|ancestors, name| scan_parser.is_feature(ancestors, name))?
+        {
+            scan_parser.parse(&element, &mut features)?;
+            for (key, _) in features.drain(..).flat_map(|feature| feature.properties) {
+                if !column_index.contains_key(&key) {
+                    column_index.insert(key.clone(), columns.len());
+                    columns.push(key);
+                }
+            }
+        }
+        reader.rewind()?;
+
+        let metadata = Arc::new(Metadata::new(scan_parser.crs().unwrap_or_default(), None));
+        let geometry_type = parser
+            .geometry_type()
+            .with_coord_type(coord_type)
+            .with_metadata(metadata);
+        let mut fields = columns
+            .iter()
+            .map(|name| Field::new(name, parser.data_type(name), true))
+            .collect::<Vec<_>>();
+        fields.push(geometry_type.to_field("geometry", true));
+
+        Ok(Self {
+            reader: ElementReader::new(reader),
+            parser,
+            schema: Arc::new(Schema::new(fields)),
+            geometry_type,
+            column_index,
+            batch_size,
+            pending: vec![],
+        })
+    }
+}
+
+impl<R: BufRead, P: FeatureParser> FeatureReader<R, P> {
+    pub(crate) fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    pub(crate) fn next_batch(&mut self) -> GeoArrowResult<Option<RecordBatch>> {
+        while self.pending.len() < self.batch_size {
+            let Some(element) = self
+                .reader
+                .next_element(|ancestors, name| self.parser.is_feature(ancestors, name))?
+            else {
+                break;
+            };
+            self.parser.parse(&element, &mut self.pending)?;
+        }
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+
+        let rest = self
+            .pending
+            .split_off(self.batch_size.min(self.pending.len()));
+        let features = std::mem::replace(&mut self.pending, rest);
+        self.build_batch(features).map(Some)
+    }
+
+    fn build_batch(&self, features: Vec<Feature>) -> GeoArrowResult<RecordBatch> {
+        let num_columns = self.column_index.len();
+        let mut values = vec![Vec::with_capacity(features.len()); num_columns];
+        let mut geometries = Vec::with_capacity(features.len());
+        for feature in features {
+            let mut row = vec![None; num_columns];
+            for (key, value) in feature.properties {
+                let idx = self.column_index.get(&key).ok_or_else(|| {
+                    GeoArrowError::Xml(format!("Property {key} was not found when scanning"))
+                })?;
+                row[*idx] = Some(value);
+            }
+            values.iter_mut().zip(row).for_each(|(col, v)| col.push(v));
+            geometries.push(feature.geometry);
+        }
+
+        let mut columns = values
+            .into_iter()
+            .zip(self.schema.fields())
+            .map(|(values, field)| {
+                let array: ArrayRef = Arc::new(StringArray::from(values));
+                match field.data_type() {
+                    DataType::Utf8 => Ok(array),
+                    data_type => cast(&array, data_type),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        columns.push(build_geometry(&geometries, &self.geometry_type)?);
+        Ok(RecordBatch::try_new(self.schema.clone(), columns)?)
+    }
+}
+
+fn build_geometry(geometries: &[Option<Wkt<f64>>], typ: &GeoArrowType) -> GeoArrowResult<ArrayRef> {
+    let array = match typ {
+        GeoArrowType::Point(typ) => {
+            PointBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        GeoArrowType::LineString(typ) => {
+            LineStringBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        GeoArrowType::MultiLineString(typ) => {
+            MultiLineStringBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        GeoArrowType::Geometry(typ) => {
+            GeometryBuilder::from_nullable_geometries(geometries, typ.clone())?
+                .finish()
+                .to_array_ref()
+        }
+        typ => {
+            return Err(GeoArrowError::IncorrectGeometryType(format!(
+                "Unsupported output type {typ:?}"
+            )));
+        }
+    };
+    Ok(array)
+}
+
+/// Define a public reader wrapping a [`FeatureReader`], with its constructors and the `Iterator`
+/// and `RecordBatchReader` impls.
+macro_rules! feature_reader {
+    ($(#[$attr:meta])* $name:ident, $parser:ty, $options:ty) => {
+        $(#[$attr])*
+        pub struct $name<R>(crate::reader::FeatureReader<R, $parser>);
+
+        impl<R: std::io::BufRead + std::io::Seek> $name<R> {
+            /// Create a new reader, scanning the whole input once to find its property columns.
+            pub fn try_new(
+                reader: R,
+                options: $options,
+            ) -> geoarrow_schema::error::GeoArrowResult<Self> {
+                let coord_type = options.coord_type;
+                let batch_size = options.batch_size;
+                let parser = <$parser>::new(options);
+                crate::reader::FeatureReader::try_new(reader, parser, coord_type, batch_size)
+                    .map(Self)
+            }
+        }
+
+        impl<R: std::io::BufRead> $name<R> {
+            /// The schema of the record batches produced by this reader.
+            pub fn schema(&self) -> arrow_schema::SchemaRef {
+                self.0.schema()
+            }
+        }
+
+        impl<R: std::io::BufRead> Iterator for $name<R> {
+            type Item = Result<arrow_array::RecordBatch, arrow_schema::ArrowError>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next_batch().map_err(Into::into).transpose()
+            }
+        }
+
+        impl<R: std::io::BufRead> arrow_array::RecordBatchReader for $name<R> {
+            fn schema(&self) -> arrow_schema::SchemaRef {
+                self.0.schema()
+            }
+        }
+    };
+}
+
+pub(crate) use feature_reader;
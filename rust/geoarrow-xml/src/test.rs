@@ -0,0 +1,17 @@
+use arrow_array::RecordBatch;
+use arrow_array::cast::AsArray;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::cast::to_wkt;
+
+/// The `geometry` column of a batch as WKT strings.
+pub(crate) fn geometry_wkt(batch: &RecordBatch) -> Vec<Option<String>> {
+    let schema = batch.schema();
+    let idx = schema.index_of("geometry").unwrap();
+    let geometry = from_arrow_array(batch.column(idx).as_ref(), schema.field(idx)).unwrap();
+    let wkt = to_wkt::<i32>(geometry.as_ref()).unwrap().to_array_ref();
+    wkt.as_string::<i32>()
+        .iter()
+        .map(|value| value.map(String::from))
+        .collect()
+}
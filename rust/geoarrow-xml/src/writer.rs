@@ -0,0 +1,69 @@
+//! Helpers shared by the KML and GPX writers.
+
+use std::fmt::Write;
+
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::Schema;
+use geo_traits::{CoordTrait, Dimensions};
+use geoarrow_array::array::{WkbArray, from_arrow_array};
+use geoarrow_array::cast::to_wkb;
+use geoarrow_schema::GeoArrowType;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+
+/// Find the first geometry column of a schema.
+pub(crate) fn geometry_column(schema: &Schema) -> GeoArrowResult<usize> {
+    schema
+        .fields()
+        .iter()
+        .position(|field| {
+            field.extension_type_name().is_some() && GeoArrowType::try_from(field.as_ref()).is_ok()
+        })
+        .ok_or_else(|| GeoArrowError::Xml("No geometry column found in record batch".to_string()))
+}
+
+/// The geometry column of a batch as WKB.
+pub(crate) fn geometry_wkb(batch: &RecordBatch, geometry_idx: usize) -> GeoArrowResult<WkbArray> {
+    let schema = batch.schema();
+    let geometry = from_arrow_array(
+        batch.column(geometry_idx).as_ref(),
+        schema.field(geometry_idx),
+    )?;
+    to_wkb(geometry.as_ref())
+}
+
+/// Formats the values of one column as text.
+pub(crate) struct Column<'a> {
+    pub(crate) name: &'a str,
+    array: &'a ArrayRef,
+    formatter: ArrayFormatter<'a>,
+}
+
+impl<'a> Column<'a> {
+    pub(crate) fn try_new(
+        name: &'a str,
+        array: &'a ArrayRef,
+        options: &FormatOptions<'a>,
+    ) -> GeoArrowResult<Self> {
+        Ok(Self {
+            name,
+            array,
+            formatter: ArrayFormatter::try_new(array.as_ref(), options)?,
+        })
+    }
+
+    /// The value at a row, or `None` if it is null.
+    pub(crate) fn value(&self, row: usize) -> Option<String> {
+        self.array
+            .is_valid(row)
+            .then(|| self.formatter.value(row).to_string())
+    }
+}
+
+/// Write a coordinate with its values separated by `sep`, including z for 3D coordinates.
+pub(crate) fn write_coord(out: &mut String, coord: &impl CoordTrait<T = f64>, sep: char) {
+    write!(out, "{}{sep}{}", coord.x(), coord.y()).unwrap();
+    if matches!(coord.dim(), Dimensions::Xyz | Dimensions::Xyzm) {
+        write!(out, "{sep}{}", coord.nth_or_panic(2)).unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+//! A streaming reader that materializes one feature element at a time as a small tree.
+
+use std::io::BufRead;
+
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+pub(crate) fn xml_error(err: impl ToString) -> GeoArrowError {
+    GeoArrowError::Xml(err.to_string())
+}
+
+/// An XML element with namespace prefixes stripped from element and attribute names.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Element {
+    pub(crate) name: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) children: Vec<Element>,
+    /// The concatenated, trimmed text and CDATA content directly inside this element.
+    pub(crate) text: String,
+}
+
+impl Element {
+    fn from_start(start: &BytesStart) -> GeoArrowResult<Self> {
+        let name = local_name(start.local_name().as_ref());
+        let attributes = start
+            .attributes()
+            .map(|attr| {
+                let attr = attr.map_err(xml_error)?;
+                let value = attr.unescape_value().map_err(xml_error)?;
+                Ok((
+                    local_name(attr.key.local_name().as_ref()),
+                    value.into_owned(),
+                ))
+            })
+            .collect::<GeoArrowResult<_>>()?;
+        Ok(Self {
+            name,
+            attributes,
+            ..Default::default()
+        })
+    }
+
+    /// The value of an attribute.
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The first child element with a name.
+    pub(crate) fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// All child elements with a name.
+    pub(crate) fn children_named<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// The text of the first child element with a name.
+    pub(crate) fn child_text(&self, name: &str) -> Option<&str> {
+        self.child(name).map(|child| child.text.as_str())
+    }
+
+    /// Whether this element has no child elements.
+    pub(crate) fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+fn local_name(name: &[u8]) -> String {
+    String::from_utf8_lossy(name).into_owned()
+}
+
+/// Reads an XML document, returning each element that matches a predicate as an [`Element`] tree
+/// without materializing the rest of the document.
+pub(crate) struct ElementReader<R> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+    /// The names of the currently open elements outside of any matched element.
+    ancestors: Vec<String>,
+}
+
+impl<R: BufRead> ElementReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        let mut reader = Reader::from_reader(reader);
+        let config = reader.config_mut();
+        config.trim_text(true);
+        config.expand_empty_elements = true;
+        Self {
+            reader,
+            buf: vec![],
+            ancestors: vec![],
+        }
+    }
+
+    /// Read the next element for which `is_match`, given the names of the element's ancestors and
+    /// the element itself, returns true.
+    pub(crate) fn next_element(
+        &mut self,
+        is_match: impl Fn(&[String], &str) -> bool,
+    ) -> GeoArrowResult<Option<Element>> {
+        loop {
+            self.buf.clear();
+            let element = match self
+                .reader
+                .read_event_into(&mut self.buf)
+                .map_err(xml_error)?
+            {
+                Event::Start(start) => Element::from_start(&start)?,
+                Event::End(_) => {
+                    self.ancestors.pop();
+                    continue;
+                }
+                Event::Eof => return Ok(None),
+                _ => continue,
+            };
+            if is_match(&self.ancestors, &element.name) {
+                return self.read_subtree(element).map(Some);
+            }
+            self.ancestors.push(element.name);
+        }
+    }
+
+    /// Read the content of an element whose start tag has just been read.
+    fn read_subtree(&mut self, root: Element) -> GeoArrowResult<Element> {
+        let mut stack = vec![root];
+        loop {
+            self.buf.clear();
+            match self
+                .reader
+                .read_event_into(&mut self.buf)
+                .map_err(xml_error)?
+            {
+                Event::Start(start) => stack.push(Element::from_start(&start)?),
+                Event::End(_) => {
+                    let element = stack.pop().unwrap();
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                Event::Text(text) => {
+                    let text = text.unescape().map_err(xml_error)?;
+                    stack.last_mut().unwrap().text.push_str(&text);
+                }
+                Event::CData(data) => {
+                    let text = data.decode().map_err(xml_error)?;
+                    stack.last_mut().unwrap().text.push_str(text.trim());
+                }
+                Event::Eof => {
+                    return Err(GeoArrowError::Xml(format!(
+                        "Document ended inside a <{}> element",
+                        stack[0].name
+                    )));
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -1,13 +1,13 @@
 use std::sync::Arc;
 
 use arrow_array::ArrayRef;
-use arrow_schema::DataType;
+use arrow_schema::{DataType, FieldRef};
 use datafusion::error::DataFusionError;
-use datafusion::logical_expr::{Signature, Volatility};
+use datafusion::logical_expr::{Signature, TypeSignature, Volatility};
 use geoarrow_array::GeoArrowArray;
 use geoarrow_array::array::{GeometryArray, PointArray, RectArray};
 use geoarrow_schema::{
-    BoxType, CoordType, Dimension, GeoArrowType, GeometryCollectionType, GeometryType,
+    BoxType, CoordType, Crs, Dimension, GeoArrowType, GeometryCollectionType, GeometryType,
     LineStringType, MultiLineStringType, MultiPointType, MultiPolygonType, PointType, PolygonType,
 };
 
@@ -45,6 +45,19 @@ pub(crate) fn GEOMETRY_TYPE() -> GeoArrowType {
 }
 
 pub(crate) fn any_single_geometry_type_input() -> Signature {
+    Signature::uniform(1, any_geometry_types(), Volatility::Immutable)
+}
+
+/// A geometry of any type, followed by an integer argument.
+pub(crate) fn any_geometry_and_integer_input() -> Signature {
+    let type_signatures = any_geometry_types()
+        .into_iter()
+        .map(|geometry_type| TypeSignature::Exact(vec![geometry_type, DataType::Int64]))
+        .collect();
+    Signature::one_of(type_signatures, Volatility::Immutable)
+}
+
+fn any_geometry_types() -> Vec<DataType> {
     let mut valid_types = vec![];
 
     for coord_type in [CoordType::Separated, CoordType::Interleaved] {
@@ -119,7 +132,7 @@ pub(crate) fn any_single_geometry_type_input() -> Signature {
     valid_types.push(DataType::LargeUtf8);
     valid_types.push(DataType::Utf8View);
 
-    Signature::uniform(1, valid_types, Volatility::Immutable)
+    valid_types
 }
 
 pub(crate) fn any_single_point_type_input() -> Signature {
@@ -149,6 +162,27 @@ pub(crate) fn any_single_point_type_input() -> Signature {
     Signature::uniform(1, valid_types, Volatility::Immutable)
 }
 
+/// A point of any dimension and coordinate type, followed by an integer argument.
+pub(crate) fn point_and_integer_input() -> Signature {
+    let mut type_signatures = vec![];
+
+    for coord_type in [CoordType::Separated, CoordType::Interleaved] {
+        for dim in [
+            Dimension::XY,
+            Dimension::XYZ,
+            Dimension::XYM,
+            Dimension::XYZM,
+        ] {
+            let point_type = PointType::new(dim, Default::default())
+                .with_coord_type(coord_type)
+                .data_type();
+            type_signatures.push(TypeSignature::Exact(vec![point_type, DataType::Int64]));
+        }
+    }
+
+    Signature::one_of(type_signatures, Volatility::Immutable)
+}
+
 /// This will not cast a PointArray to a GeometryArray
 pub(crate) fn parse_to_native_array(
     array: ArrayRef,
@@ -183,3 +217,53 @@ pub(crate) fn parse_to_native_array(
         Err(DataFusionError::Execution(format!("Unexpected input data type: {data_type}")).into())
     }
 }
+
+/// Whether two CRS describe the same coordinate reference system.
+///
+/// CRS with an SRID are compared by SRID, so that `EPSG:4326` matches an SRID of `4326`. Others
+/// must be structurally equal.
+pub(crate) fn same_crs(left: &Crs, right: &Crs) -> bool {
+    match (left.srid(), right.srid()) {
+        (Some(left), Some(right)) => left == right,
+        _ => left == right,
+    }
+}
+
+/// Check at planning time that the geometry arguments of a function share a CRS.
+///
+/// Arguments without a CRS are assumed to be in the CRS of the others.
+pub(crate) fn check_same_crs(
+    function_name: &str,
+    arg_fields: &[FieldRef],
+) -> datafusion::error::Result<()> {
+    let mut first_crs: Option<Crs> = None;
+    for field in arg_fields {
+        let Ok(data_type) = GeoArrowType::try_from(field.as_ref()) else {
+            continue;
+        };
+        let crs = data_type.metadata().crs();
+        if crs == &Crs::default() {
+            continue;
+        }
+        match &first_crs {
+            None => first_crs = Some(crs.clone()),
+            Some(first_crs) if !same_crs(first_crs, crs) => {
+                return Err(DataFusionError::Plan(format!(
+                    "{function_name} arguments have different CRS ({} and {}); use ST_Transform to reproject one of them",
+                    describe_crs(first_crs),
+                    describe_crs(crs)
+                )));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn describe_crs(crs: &Crs) -> String {
+    match (crs.srid(), crs.crs_value()) {
+        (Some(srid), _) => format!("SRID {srid}"),
+        (None, Some(value)) => value.to_string(),
+        (None, None) => "unknown".to_string(),
+    }
+}
@@ -21,6 +21,13 @@ pub(crate) enum GeoDataFusionError {
 
     #[error(transparent)]
     GeoHash(#[from] geohash::GeohashError),
+
+    #[cfg(feature = "geos")]
+    #[error(transparent)]
+    Geos(#[from] geos::Error),
+
+    #[error(transparent)]
+    GeoZero(#[from] geozero::error::GeozeroError),
 }
 
 /// Crate-specific result type.
@@ -33,6 +40,9 @@ impl From<GeoDataFusionError> for DataFusionError {
             GeoDataFusionError::DataFusion(err) => err,
             GeoDataFusionError::GeoArrow(err) => DataFusionError::External(Box::new(err)),
             GeoDataFusionError::GeoHash(err) => DataFusionError::External(Box::new(err)),
+            #[cfg(feature = "geos")]
+            GeoDataFusionError::Geos(err) => DataFusionError::External(Box::new(err)),
+            GeoDataFusionError::GeoZero(err) => DataFusionError::External(Box::new(err)),
         }
     }
 }
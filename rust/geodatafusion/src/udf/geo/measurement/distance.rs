@@ -1,14 +1,16 @@
 use std::any::Any;
 use std::sync::{Arc, OnceLock};
 
-use arrow_schema::DataType;
+use arrow_schema::{DataType, Field};
 use datafusion::error::Result;
 use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
 use datafusion::logical_expr::{
-    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
 };
 use geoarrow_array::array::from_arrow_array;
 
+use crate::data_types::check_same_crs;
 use crate::error::GeoDataFusionResult;
 
 #[derive(Debug)]
@@ -49,6 +51,11 @@ impl ScalarUDFImpl for Distance {
         Ok(DataType::Float64)
     }
 
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        check_same_crs(self.name(), args.arg_fields)?;
+        Ok(Field::new(self.name(), DataType::Float64, true).into())
+    }
+
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
         Ok(distance_impl(args)?)
     }
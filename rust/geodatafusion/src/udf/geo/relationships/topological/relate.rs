@@ -7,7 +7,8 @@ use arrow_schema::{DataType, Field};
 use datafusion::error::Result;
 use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
 use datafusion::logical_expr::{
-    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
 };
 use geo::relate::IntersectionMatrix;
 use geo::{PreparedGeometry, Relate};
@@ -16,6 +17,7 @@ use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_arr
 use geoarrow_geo::util::to_geo::geometry_to_geo;
 use geoarrow_schema::error::GeoArrowResult;
 
+use crate::data_types::check_same_crs;
 use crate::error::GeoDataFusionResult;
 
 macro_rules! impl_relate_udf {
@@ -58,6 +60,11 @@ macro_rules! impl_relate_udf {
                 Ok(DataType::Boolean)
             }
 
+            fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+                check_same_crs(self.name(), args.arg_fields)?;
+                Ok(Field::new(self.name(), DataType::Boolean, true).into())
+            }
+
             fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
                 let mut arrays = args.args.into_iter();
                 Ok(relate_impl(
@@ -266,6 +273,7 @@ mod test {
     use geoarrow_schema::{Dimension, PointType};
 
     use super::*;
+    use crate::udf::native::constructors::Point;
     use crate::udf::native::io::GeomFromText;
 
     #[tokio::test]
@@ -482,4 +490,24 @@ mod test {
                 .value(0)
         );
     }
+
+    #[tokio::test]
+    async fn test_intersects_mismatched_crs() {
+        let ctx = SessionContext::new();
+
+        ctx.register_udf(Intersects::new().into());
+        ctx.register_udf(Point::new(Default::default()).into());
+
+        let result = ctx
+            .sql("SELECT ST_Intersects(ST_Point(0.0, 0.0, 4326), ST_Point(0.0, 0.0, 3857));")
+            .await;
+        assert!(result.is_err());
+
+        let df = ctx
+            .sql("SELECT ST_Intersects(ST_Point(0.0, 0.0, 4326), ST_Point(0.0, 0.0, 4326));")
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        assert!(batch.column(0).as_boolean().value(0));
+    }
 }
@@ -0,0 +1,150 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geoarrow_array::array::{GeometryArray, from_arrow_array};
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor};
+use geoarrow_cast::cast::cast;
+use geoarrow_geos::export::to_geos_geometry;
+use geoarrow_geos::import::array::FromGEOS;
+use geoarrow_schema::{CoordType, GeoArrowType, GeometryType};
+use geos::Geom;
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+
+#[derive(Debug)]
+pub struct MakeValid {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl MakeValid {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for MakeValid {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for MakeValid {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_makevalid"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::GeoArrow)?;
+        let geom_type =
+            GeometryType::new(data_type.metadata().clone()).with_coord_type(self.coord_type);
+        Ok(geom_type
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(make_valid_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Attempts to create a valid representation of an invalid geometry without losing any of the input vertices. Already-valid geometries are returned unchanged.",
+                "ST_MakeValid(ST_GeomFromText('POLYGON((0 0, 1 1, 1 0, 0 1, 0 0))'))",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn make_valid_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+
+    // Go through a geometry array, so that boxes are converted to polygons before reaching GEOS
+    let geometries = cast(
+        geo_array.as_ref(),
+        &GeoArrowType::Geometry(GeometryType::new(Default::default())),
+    )?;
+    let valid = geometries
+        .as_geometry()
+        .iter()
+        .map(|geometry| {
+            geometry
+                .map(|geometry| Ok(to_geos_geometry(&geometry?)?.make_valid()?))
+                .transpose()
+        })
+        .collect::<GeoDataFusionResult<Vec<_>>>()?;
+
+    let typ = args.return_field.extension_type::<GeometryType>();
+    let result = GeometryArray::from_geos(valid, typ)?;
+    Ok(ColumnarValue::Array(result.into_array_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo::{Area, Geometry};
+    use geoarrow_geo::util::to_geo::array_to_geo;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_make_valid() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(MakeValid::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        // A bow-tie polygon, which becomes two triangles
+        let sql = "SELECT ST_MakeValid(ST_GeomFromText('POLYGON((0 0, 2 2, 2 0, 0 2, 0 0))'));";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = from_arrow_array(batch.column(0), &field).unwrap();
+        let geometries = array_to_geo(array.as_ref()).unwrap();
+        let Some(Geometry::MultiPolygon(multi_polygon)) = &geometries[0] else {
+            panic!("expected a MultiPolygon, got {:?}", geometries[0]);
+        };
+        assert_eq!(multi_polygon.0.len(), 2);
+        assert_eq!(multi_polygon.unsigned_area(), 2.0);
+    }
+}
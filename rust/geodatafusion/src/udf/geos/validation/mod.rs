@@ -0,0 +1,3 @@
+mod make_valid;
+
+pub use make_valid::MakeValid;
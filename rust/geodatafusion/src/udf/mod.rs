@@ -1,2 +1,4 @@
 pub mod geo;
+#[cfg(feature = "geos")]
+pub mod geos;
 pub mod native;
@@ -0,0 +1,210 @@
+//! Split a geometry into its parts
+
+use std::sync::Arc;
+
+use arrow_array::builder::{Int32Builder, ListBuilder};
+use arrow_array::{Array, RecordBatch};
+use arrow_schema::{Field, Schema};
+use datafusion::catalog::{TableFunctionImpl, TableProvider};
+use datafusion::datasource::MemTable;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::Expr;
+use geo_traits::{
+    GeometryCollectionTrait, GeometryTrait, MultiLineStringTrait, MultiPointTrait,
+    MultiPolygonTrait,
+};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, GeometryType};
+
+use crate::error::GeoDataFusionResult;
+
+/// `ST_Dump(geometry)`: a table function returning one row per part of a geometry.
+///
+/// Each row has a `path`, the 1-based indices of the part within nested collections, and a
+/// `geom` with the part itself. Atomic geometries are returned as a single row with an empty
+/// path. Like other table functions, the argument must be a constant expression, such as
+/// `SELECT * FROM ST_Dump(ST_GeomFromText('MULTIPOINT(0 0, 1 1)'))`.
+#[derive(Debug)]
+pub struct Dump {
+    coord_type: CoordType,
+}
+
+impl Dump {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+}
+
+impl Default for Dump {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl TableFunctionImpl for Dump {
+    fn call(&self, args: &[Expr]) -> Result<Arc<dyn TableProvider>> {
+        let [Expr::Literal(scalar, metadata)] = args else {
+            return Err(DataFusionError::Plan(
+                "ST_Dump expects a single constant geometry argument".to_string(),
+            ));
+        };
+        let field = Field::new("geom", scalar.data_type(), true);
+        let field = match metadata {
+            Some(metadata) => field.with_metadata(metadata.to_hashmap()),
+            None => field,
+        };
+        Ok(dump_impl(&scalar.to_array()?, &field, self.coord_type)?)
+    }
+}
+
+fn dump_impl(
+    array: &arrow_array::ArrayRef,
+    field: &Field,
+    coord_type: CoordType,
+) -> GeoDataFusionResult<Arc<dyn TableProvider>> {
+    let geo_array = from_arrow_array(array, field)?;
+    let geo_array = geo_array.as_ref();
+
+    let geom_type =
+        GeometryType::new(geo_array.data_type().metadata().clone()).with_coord_type(coord_type);
+    let mut parts = DumpBuilder {
+        paths: ListBuilder::new(Int32Builder::new()),
+        geometries: GeometryBuilder::new(geom_type.clone()),
+        path: vec![],
+    };
+    downcast_geoarrow_array!(geo_array, _dump_impl, &mut parts)?;
+
+    let paths = parts.paths.finish();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("path", paths.data_type().clone(), false),
+        geom_type.to_field("geom", true),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(paths), parts.geometries.finish().into_array_ref()],
+    )?;
+    Ok(Arc::new(MemTable::try_new(schema, vec![vec![batch]])?))
+}
+
+fn _dump_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    parts: &mut DumpBuilder,
+) -> GeoArrowResult<()> {
+    for geometry in array.iter().flatten() {
+        parts.push_geometry(&geometry?)?;
+    }
+    Ok(())
+}
+
+struct DumpBuilder {
+    paths: ListBuilder<Int32Builder>,
+    geometries: GeometryBuilder,
+    /// The path of the collection currently being dumped
+    path: Vec<i32>,
+}
+
+impl DumpBuilder {
+    fn push_geometry(&mut self, geometry: &impl GeometryTrait<T = f64>) -> GeoArrowResult<()> {
+        use geo_traits::GeometryType::*;
+
+        match geometry.as_type() {
+            MultiPoint(multi_point) => {
+                for (i, point) in multi_point.points().enumerate() {
+                    self.push_part(i, &point)?;
+                }
+            }
+            MultiLineString(multi_line_string) => {
+                for (i, line_string) in multi_line_string.line_strings().enumerate() {
+                    self.push_part(i, &line_string)?;
+                }
+            }
+            MultiPolygon(multi_polygon) => {
+                for (i, polygon) in multi_polygon.polygons().enumerate() {
+                    self.push_part(i, &polygon)?;
+                }
+            }
+            GeometryCollection(collection) => {
+                for (i, geometry) in collection.geometries().enumerate() {
+                    self.path.push(i as i32 + 1);
+                    self.push_geometry(&geometry)?;
+                    self.path.pop();
+                }
+            }
+            _ => self.push_row(geometry)?,
+        }
+        Ok(())
+    }
+
+    /// Push the `i`-th part of a multi geometry.
+    fn push_part(&mut self, i: usize, part: &impl GeometryTrait<T = f64>) -> GeoArrowResult<()> {
+        self.path.push(i as i32 + 1);
+        self.push_row(part)?;
+        self.path.pop();
+        Ok(())
+    }
+
+    fn push_row(&mut self, geometry: &impl GeometryTrait<T = f64>) -> GeoArrowResult<()> {
+        self.paths.values().append_slice(&self.path);
+        self.paths.append(true);
+        self.geometries.push_geometry(Some(geometry))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use datafusion::prelude::SessionContext;
+    use geo::{Geometry, line_string, point};
+    use geoarrow_geo::util::to_geo::array_to_geo;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_dump() {
+        let ctx = SessionContext::new();
+        ctx.register_udtf("st_dump", Arc::new(Dump::default()));
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let sql = "SELECT path, geom FROM ST_Dump(ST_GeomFromText(
+            'GEOMETRYCOLLECTION(POINT(0 0), MULTIPOINT(1 1, 2 2), LINESTRING(0 0, 1 1))'
+        ));";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let paths = batch
+            .column(0)
+            .as_list::<i32>()
+            .iter()
+            .map(|path| path.unwrap().as_primitive::<Int32Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec![vec![1], vec![2, 1], vec![2, 2], vec![3]]);
+
+        let field = batch.schema_ref().field(1).clone();
+        let array = from_arrow_array(batch.column(1), &field).unwrap();
+        assert_eq!(
+            array_to_geo(array.as_ref()).unwrap(),
+            vec![
+                Some(Geometry::Point(point!(x: 0.0, y: 0.0))),
+                Some(Geometry::Point(point!(x: 1.0, y: 1.0))),
+                Some(Geometry::Point(point!(x: 2.0, y: 2.0))),
+                Some(Geometry::LineString(line_string![
+                    (x: 0.0, y: 0.0),
+                    (x: 1.0, y: 1.0)
+                ])),
+            ]
+        );
+    }
+}
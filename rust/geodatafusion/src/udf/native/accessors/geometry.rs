@@ -0,0 +1,691 @@
+//! Accessors that apply to geometries of any type
+
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::{BooleanBuilder, Int32Builder, StringBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_array::{BooleanArray, Int32Array, Int64Array, StringArray};
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geo_traits::{
+    GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
+    MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+use geoarrow_array::array::{GeometryArray, LineStringArray, from_arrow_array};
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, GeoArrowType};
+
+use crate::data_types::{any_geometry_and_integer_input, any_single_geometry_type_input};
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+
+#[derive(Debug)]
+pub struct NPoints {
+    signature: Signature,
+}
+
+impl NPoints {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for NPoints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static NPOINTS_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for NPoints {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_npoints"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+        let geo_array =
+            from_arrow_array(array, &args.arg_fields[0]).map_err(GeoDataFusionError::GeoArrow)?;
+        let geo_array = geo_array.as_ref();
+        let result = match geo_array.data_type() {
+            GeoArrowType::LineString(_) => line_string_npoints(geo_array.as_line_string()),
+            _ => downcast_geoarrow_array!(geo_array, _npoints_impl)
+                .map_err(GeoDataFusionError::GeoArrow)?,
+        };
+        Ok(ColumnarValue::Array(Arc::new(result)))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(NPOINTS_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return the number of points (vertices) in a geometry. Works for all geometry types.",
+                "ST_NPoints(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+/// The number of points of each LineString, read from the offsets.
+fn line_string_npoints(array: &LineStringArray) -> Int32Array {
+    let lengths = array.geom_offsets().lengths().map(|length| length as i32);
+    Int32Array::new(lengths.collect(), array.logical_nulls())
+}
+
+fn _npoints_impl<'a>(array: &'a impl GeoArrowArrayAccessor<'a>) -> GeoArrowResult<Int32Array> {
+    let mut builder = Int32Builder::with_capacity(array.len());
+    for geometry in array.iter() {
+        builder.append_option(
+            geometry
+                .transpose()?
+                .map(|geometry| num_points(&geometry) as i32),
+        );
+    }
+    Ok(builder.finish())
+}
+
+fn num_points(geometry: &impl GeometryTrait<T = f64>) -> usize {
+    use geo_traits::GeometryType::*;
+
+    match geometry.as_type() {
+        Point(point) => usize::from(point.coord().is_some()),
+        LineString(line_string) => line_string.num_coords(),
+        Polygon(polygon) => polygon_num_points(polygon),
+        MultiPoint(multi_point) => multi_point
+            .points()
+            .filter(|point| point.coord().is_some())
+            .count(),
+        MultiLineString(multi_line_string) => multi_line_string
+            .line_strings()
+            .map(|line_string| line_string.num_coords())
+            .sum(),
+        MultiPolygon(multi_polygon) => multi_polygon
+            .polygons()
+            .map(|polygon| polygon_num_points(&polygon))
+            .sum(),
+        GeometryCollection(collection) => collection
+            .geometries()
+            .map(|geometry| num_points(&geometry))
+            .sum(),
+        // A closed ring of four corners
+        Rect(_) => 5,
+        Triangle(_) => 4,
+        Line(_) => 2,
+    }
+}
+
+fn polygon_num_points(polygon: &impl PolygonTrait<T = f64>) -> usize {
+    polygon
+        .exterior()
+        .map_or(0, |exterior| exterior.num_coords())
+        + polygon
+            .interiors()
+            .map(|interior| interior.num_coords())
+            .sum::<usize>()
+}
+
+#[derive(Debug)]
+pub struct NumGeometries {
+    signature: Signature,
+}
+
+impl NumGeometries {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for NumGeometries {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static NUM_GEOMETRIES_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for NumGeometries {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_numgeometries"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+        let geo_array =
+            from_arrow_array(array, &args.arg_fields[0]).map_err(GeoDataFusionError::GeoArrow)?;
+        let geo_array = geo_array.as_ref();
+        let result = downcast_geoarrow_array!(geo_array, _num_geometries_impl)
+            .map_err(GeoDataFusionError::GeoArrow)?;
+        Ok(ColumnarValue::Array(Arc::new(result)))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(NUM_GEOMETRIES_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the number of elements in a geometry collection (GEOMETRYCOLLECTION or MULTI*). For non-empty atomic geometries returns 1. For empty geometries returns 0.",
+                "ST_NumGeometries(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn _num_geometries_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+) -> GeoArrowResult<Int32Array> {
+    let mut builder = Int32Builder::with_capacity(array.len());
+    for geometry in array.iter() {
+        builder.append_option(
+            geometry
+                .transpose()?
+                .map(|geometry| num_geometries(&geometry) as i32),
+        );
+    }
+    Ok(builder.finish())
+}
+
+fn num_geometries(geometry: &impl GeometryTrait<T = f64>) -> usize {
+    use geo_traits::GeometryType::*;
+
+    match geometry.as_type() {
+        MultiPoint(multi_point) => multi_point.num_points(),
+        MultiLineString(multi_line_string) => multi_line_string.num_line_strings(),
+        MultiPolygon(multi_polygon) => multi_polygon.num_polygons(),
+        GeometryCollection(collection) => collection.num_geometries(),
+        _ => usize::from(!is_empty(geometry)),
+    }
+}
+
+#[derive(Debug)]
+pub struct GeometryN {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeometryN {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_geometry_and_integer_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeometryN {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOMETRY_N_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeometryN {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_geometryn"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::GeoArrow)?;
+        let typ = geoarrow_schema::GeometryType::new(data_type.metadata().clone())
+            .with_coord_type(self.coord_type);
+        Ok(typ.to_field(input_field.name(), true).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geometry_n_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOMETRY_N_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return the Nth element of a geometry collection (GEOMETRYCOLLECTION or MULTI*), counting from 1. For atomic geometries, N = 1 returns the geometry itself. Returns NULL if N is out of range.",
+                "ST_GeometryN(geometry, 1)",
+            )
+            .with_argument("geomA", "geometry")
+            .with_argument("n", "integer index, starting at 1")
+            .build()
+        }))
+    }
+}
+
+fn geometry_n_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let geo_array = geo_array.as_ref();
+    let n = arrays[1].as_primitive::<Int64Type>();
+    let typ = args
+        .return_field
+        .extension_type::<geoarrow_schema::GeometryType>();
+    let result = downcast_geoarrow_array!(geo_array, _geometry_n_impl, typ, n)?;
+    Ok(ColumnarValue::Array(result.into_array_ref()))
+}
+
+fn _geometry_n_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    typ: geoarrow_schema::GeometryType,
+    n: &Int64Array,
+) -> GeoArrowResult<GeometryArray> {
+    let mut builder = GeometryBuilder::new(typ);
+    for (i, geometry) in array.iter().enumerate() {
+        let geometry = geometry.transpose()?;
+        let index = n
+            .is_valid(i)
+            .then(|| usize::try_from(n.value(i) - 1).ok())
+            .flatten();
+        let (Some(geometry), Some(index)) = (geometry, index) else {
+            builder.push_null();
+            continue;
+        };
+
+        use geo_traits::GeometryType::*;
+        match geometry.as_type() {
+            MultiPoint(multi_point) => builder.push_geometry(multi_point.point(index).as_ref())?,
+            MultiLineString(multi_line_string) => {
+                builder.push_geometry(multi_line_string.line_string(index).as_ref())?
+            }
+            MultiPolygon(multi_polygon) => {
+                builder.push_geometry(multi_polygon.polygon(index).as_ref())?
+            }
+            GeometryCollection(collection) => {
+                builder.push_geometry(collection.geometry(index).as_ref())?
+            }
+            _ if index == 0 => builder.push_geometry(Some(&geometry))?,
+            _ => builder.push_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[derive(Debug)]
+pub struct GeometryType {
+    signature: Signature,
+}
+
+impl GeometryType {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for GeometryType {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static GEOMETRY_TYPE_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeometryType {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_geometrytype"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+        let geo_array =
+            from_arrow_array(array, &args.arg_fields[0]).map_err(GeoDataFusionError::GeoArrow)?;
+        let geo_array = geo_array.as_ref();
+        let result = downcast_geoarrow_array!(geo_array, _geometry_type_impl)
+            .map_err(GeoDataFusionError::GeoArrow)?;
+        Ok(ColumnarValue::Array(Arc::new(result)))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOMETRY_TYPE_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the type of the geometry as a string, such as 'ST_LineString' or 'ST_Polygon'.",
+                "ST_GeometryType(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn _geometry_type_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+) -> GeoArrowResult<StringArray> {
+    let mut builder = StringBuilder::with_capacity(array.len(), array.len() * 8);
+    for geometry in array.iter() {
+        builder.append_option(geometry.transpose()?.map(|geometry| {
+            use geo_traits::GeometryType::*;
+
+            match geometry.as_type() {
+                Point(_) => "ST_Point",
+                LineString(_) | Line(_) => "ST_LineString",
+                Polygon(_) | Rect(_) => "ST_Polygon",
+                MultiPoint(_) => "ST_MultiPoint",
+                MultiLineString(_) => "ST_MultiLineString",
+                MultiPolygon(_) => "ST_MultiPolygon",
+                GeometryCollection(_) => "ST_GeometryCollection",
+                Triangle(_) => "ST_Triangle",
+            }
+        }));
+    }
+    Ok(builder.finish())
+}
+
+#[derive(Debug)]
+pub struct IsEmpty {
+    signature: Signature,
+}
+
+impl IsEmpty {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for IsEmpty {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static IS_EMPTY_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for IsEmpty {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_isempty"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+        let geo_array =
+            from_arrow_array(array, &args.arg_fields[0]).map_err(GeoDataFusionError::GeoArrow)?;
+        let geo_array = geo_array.as_ref();
+        let result = downcast_geoarrow_array!(geo_array, _is_empty_impl)
+            .map_err(GeoDataFusionError::GeoArrow)?;
+        Ok(ColumnarValue::Array(Arc::new(result)))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(IS_EMPTY_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns true if this Geometry is an empty geometry. If true, then this Geometry represents an empty geometry collection, polygon, point etc.",
+                "ST_IsEmpty(geometry)",
+            )
+            .with_argument("geomA", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn _is_empty_impl<'a>(array: &'a impl GeoArrowArrayAccessor<'a>) -> GeoArrowResult<BooleanArray> {
+    let mut builder = BooleanBuilder::with_capacity(array.len());
+    for geometry in array.iter() {
+        builder.append_option(geometry.transpose()?.map(|geometry| is_empty(&geometry)));
+    }
+    Ok(builder.finish())
+}
+
+fn is_empty(geometry: &impl GeometryTrait<T = f64>) -> bool {
+    use geo_traits::GeometryType::*;
+
+    match geometry.as_type() {
+        Point(point) => point.coord().is_none(),
+        LineString(line_string) => line_string.num_coords() == 0,
+        Polygon(polygon) => polygon
+            .exterior()
+            .is_none_or(|exterior| exterior.num_coords() == 0),
+        MultiPoint(multi_point) => multi_point.num_points() == 0,
+        MultiLineString(multi_line_string) => multi_line_string.num_line_strings() == 0,
+        MultiPolygon(multi_polygon) => multi_polygon.num_polygons() == 0,
+        GeometryCollection(collection) => collection.num_geometries() == 0,
+        Rect(_) | Triangle(_) | Line(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::types::Int32Type;
+    use datafusion::prelude::SessionContext;
+    use geo::{Geometry, point};
+    use geoarrow_geo::util::to_geo::array_to_geo;
+
+    use super::*;
+    use crate::udf::native::constructors::MakeLine;
+    use crate::udf::native::io::GeomFromText;
+
+    const GEOMETRIES: &str = "FROM (VALUES
+        ('POINT(0 0)'),
+        ('LINESTRING(0 0, 1 1, 2 2)'),
+        ('POLYGON((0 0, 1 0, 1 1, 0 0))'),
+        ('MULTIPOINT(0 0, 1 1)'),
+        ('GEOMETRYCOLLECTION(POINT(0 0), LINESTRING(0 0, 1 1))'),
+        ('GEOMETRYCOLLECTION EMPTY'),
+        (NULL)
+    ) AS t(wkt)";
+
+    async fn column(ctx: &SessionContext, udf: &str) -> arrow_array::ArrayRef {
+        let sql = format!("SELECT {udf}(ST_GeomFromText(wkt)) {GEOMETRIES}");
+        let batch = ctx
+            .sql(&sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        batch.column(0).clone()
+    }
+
+    #[tokio::test]
+    async fn test_accessors() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(NPoints::new().into());
+        ctx.register_udf(NumGeometries::new().into());
+        ctx.register_udf(GeometryType::new().into());
+        ctx.register_udf(IsEmpty::new().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let npoints = column(&ctx, "ST_NPoints").await;
+        assert_eq!(
+            npoints
+                .as_primitive::<Int32Type>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(3), Some(4), Some(2), Some(3), Some(0), None]
+        );
+
+        // Native LineString arrays are read from their offsets
+        ctx.register_udf(MakeLine::default().into());
+        let batch = ctx
+            .sql("SELECT ST_NPoints(ST_MakeLine(ST_GeomFromText('POINT(0 0)'), ST_GeomFromText('LINESTRING(1 1, 2 2)')));")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_eq!(
+            batch
+                .column(0)
+                .as_primitive::<Int32Type>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(3)]
+        );
+
+        let num_geometries = column(&ctx, "ST_NumGeometries").await;
+        assert_eq!(
+            num_geometries
+                .as_primitive::<Int32Type>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(1), Some(1), Some(2), Some(2), Some(0), None]
+        );
+
+        let geometry_type = column(&ctx, "ST_GeometryType").await;
+        assert_eq!(
+            geometry_type.as_string::<i32>().iter().collect::<Vec<_>>(),
+            vec![
+                Some("ST_Point"),
+                Some("ST_LineString"),
+                Some("ST_Polygon"),
+                Some("ST_MultiPoint"),
+                Some("ST_GeometryCollection"),
+                Some("ST_GeometryCollection"),
+                None
+            ]
+        );
+
+        let is_empty = column(&ctx, "ST_IsEmpty").await;
+        assert_eq!(
+            is_empty.as_boolean().iter().collect::<Vec<_>>(),
+            vec![
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                None
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_geometry_n() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeometryN::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let sql = format!("SELECT ST_GeometryN(ST_GeomFromText(wkt), 2) {GEOMETRIES}");
+        let batch = ctx
+            .sql(&sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = from_arrow_array(batch.column(0), &field).unwrap();
+        let geometries = array_to_geo(array.as_ref()).unwrap();
+        assert_eq!(
+            geometries,
+            vec![
+                None,
+                None,
+                None,
+                Some(Geometry::Point(point!(x: 1.0, y: 1.0))),
+                Some(Geometry::LineString(geo::line_string![
+                    (x: 0.0, y: 0.0),
+                    (x: 1.0, y: 1.0)
+                ])),
+                None,
+                None
+            ]
+        );
+
+        let sql = "SELECT ST_GeometryN(ST_GeomFromText('POINT(1 2)'), 1);";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = from_arrow_array(batch.column(0), &field).unwrap();
+        assert_eq!(
+            array_to_geo(array.as_ref()).unwrap(),
+            vec![Some(Geometry::Point(point!(x: 1.0, y: 2.0)))]
+        );
+    }
+}
@@ -1,35 +1,51 @@
 //! Accessors from LineString geometries
 
 use std::any::Any;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
-use arrow_schema::DataType;
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
 use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
-use datafusion::logical_expr::{ColumnarValue, Documentation, ScalarUDFImpl, Signature};
-use geo_traits::LineStringTrait;
-use geoarrow::ArrayBase;
-use geoarrow::array::{AsNativeArray, PointBuilder};
-use geoarrow::error::GeoArrowError;
-use geoarrow::scalar::Geometry;
-use geoarrow::trait_::ArrayAccessor;
-use geoarrow_schema::{CoordType, Dimension};
-
-use crate::data_types::{POINT2D_TYPE, any_single_geometry_type_input, parse_to_native_array};
-use crate::error::GeoDataFusionResult;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait,
+};
+use geoarrow_array::array::{PointArray, from_arrow_array};
+use geoarrow_array::builder::{LineStringBuilder, PointBuilder};
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, Dimension, GeoArrowType, PointType};
+
+use crate::data_types::{any_geometry_and_integer_input, any_single_geometry_type_input};
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
 
 #[derive(Debug)]
-pub(super) struct StartPoint {
+pub struct StartPoint {
     signature: Signature,
+    coord_type: CoordType,
 }
 
 impl StartPoint {
-    pub fn new() -> Self {
+    pub fn new(coord_type: CoordType) -> Self {
         Self {
             signature: any_single_geometry_type_input(),
+            coord_type,
         }
     }
 }
 
+impl Default for StartPoint {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
 static START_POINT_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
 
 impl ScalarUDFImpl for StartPoint {
@@ -45,50 +61,463 @@ impl ScalarUDFImpl for StartPoint {
         &self.signature
     }
 
-    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
-        Ok(POINT2D_TYPE().into())
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
     }
 
-    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
-        Ok(start_point_impl(args)?)
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        point_return_field(&args, self.coord_type)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(point_n_impl(args, |_| Some(1))?)
     }
 
     fn documentation(&self) -> Option<&Documentation> {
         Some(START_POINT_DOCUMENTATION.get_or_init(|| {
-            Documentation::builder(DOC_SECTION_OTHER, "Returns the first point of a LINESTRING geometry as a POINT. Returns NULL if the input is not a LINESTRING", "ST_StartPoint(line_string)" )
-                .with_argument("g1", "geometry")
-                .build()
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the first point of a LINESTRING geometry as a POINT. Returns NULL if the input is not a LINESTRING",
+                "ST_StartPoint(line_string)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct EndPoint {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl EndPoint {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for EndPoint {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static END_POINT_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for EndPoint {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_endpoint"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        point_return_field(&args, self.coord_type)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(point_n_impl(args, |_| Some(-1))?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(END_POINT_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the last point of a LINESTRING geometry as a POINT. Returns NULL if the input is not a LINESTRING",
+                "ST_EndPoint(line_string)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct PointN {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl PointN {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_geometry_and_integer_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for PointN {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static POINT_N_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for PointN {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_pointn"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        point_return_field(&args, self.coord_type)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let n = ColumnarValue::values_to_arrays(&args.args[1..])?.remove(0);
+        let n = n.as_primitive::<Int64Type>().clone();
+        Ok(point_n_impl(args, |i| n.is_valid(i).then(|| n.value(i)))?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(POINT_N_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the Nth point of a LINESTRING geometry as a POINT, counting from 1. Negative values count backwards from the end, so that -1 is the last point. Returns NULL if the input is not a LINESTRING or N is out of range.",
+                "ST_PointN(line_string, 2)",
+            )
+            .with_argument("a_linestring", "geometry")
+            .with_argument("n", "integer index, starting at 1")
+            .build()
+        }))
+    }
+}
+
+/// The dimension of points and rings taken from geometries of the given type.
+///
+/// Arrays of mixed dimension produce 2D output.
+pub(super) fn output_dimension(data_type: &GeoArrowType) -> Dimension {
+    data_type.dimension().unwrap_or(Dimension::XY)
+}
+
+/// Push a coordinate to a point builder of the given dimension, dropping any Z and M values if
+/// the builder is 2D.
+pub(super) fn push_coord(
+    builder: &mut PointBuilder,
+    dim: Dimension,
+    coord: &impl CoordTrait<T = f64>,
+) {
+    if dim == Dimension::XY {
+        builder.push_coord(Some(&geo::coord! { x: coord.x(), y: coord.y() }));
+    } else {
+        builder.push_coord(Some(coord));
+    }
+}
+
+/// Push a LineString to a builder of the given dimension, dropping any Z and M values if the
+/// builder is 2D.
+pub(super) fn push_line_string(
+    builder: &mut LineStringBuilder,
+    dim: Dimension,
+    line_string: &impl LineStringTrait<T = f64>,
+) -> GeoArrowResult<()> {
+    if dim == Dimension::XY {
+        let line_string = geo::LineString::new(
+            line_string
+                .coords()
+                .map(|coord| geo::coord! { x: coord.x(), y: coord.y() })
+                .collect(),
+        );
+        builder.push_line_string(Some(&line_string))
+    } else {
+        builder.push_line_string(Some(line_string))
+    }
+}
+
+fn point_return_field(args: &ReturnFieldArgs, coord_type: CoordType) -> Result<Arc<Field>> {
+    let input_field = &args.arg_fields[0];
+    let data_type =
+        GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::GeoArrow)?;
+    let typ = PointType::new(output_dimension(&data_type), data_type.metadata().clone())
+        .with_coord_type(coord_type);
+    Ok(typ.to_field(input_field.name(), true).into())
+}
+
+/// Take the `n(i)`-th point of the LineString in row `i`, where `n` counts from 1 and negative
+/// values count from the end.
+fn point_n_impl(
+    args: ScalarFunctionArgs,
+    n: impl Fn(usize) -> Option<i64>,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+    let geo_array = geo_array.as_ref();
+    let typ = args.return_field.extension_type::<PointType>();
+    let result = downcast_geoarrow_array!(geo_array, _point_n_impl, typ, &n)?;
+    Ok(ColumnarValue::Array(result.into_array_ref()))
+}
+
+fn _point_n_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    typ: PointType,
+    n: &impl Fn(usize) -> Option<i64>,
+) -> GeoArrowResult<PointArray> {
+    let dim = typ.dimension();
+    let mut builder = PointBuilder::with_capacity(typ, array.len());
+    for (i, geometry) in array.iter().enumerate() {
+        let geometry = geometry.transpose()?;
+        let coord =
+            geometry
+                .as_ref()
+                .zip(n(i))
+                .and_then(|(geometry, n)| match geometry.as_type() {
+                    GeometryType::LineString(line_string) => {
+                        let num_coords = line_string.num_coords() as i64;
+                        let index = if n < 0 { num_coords + n } else { n - 1 };
+                        (0..num_coords)
+                            .contains(&index)
+                            .then(|| line_string.coord(index as usize))
+                            .flatten()
+                    }
+                    _ => None,
+                });
+        match coord {
+            Some(coord) => push_coord(&mut builder, dim, &coord),
+            None => builder.push_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[derive(Debug)]
+pub struct IsClosed {
+    signature: Signature,
+}
+
+impl IsClosed {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for IsClosed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static IS_CLOSED_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for IsClosed {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_isclosed"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(is_closed_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(IS_CLOSED_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns TRUE if the LINESTRING's start and end points are coincident. For MultiLineStrings and GeometryCollections, returns TRUE only if all of their parts are closed. Points and Polygons are always closed.",
+                "ST_IsClosed(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
         }))
     }
 }
 
-fn start_point_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
-    let array = ColumnarValue::values_to_arrays(args)?
-        .into_iter()
-        .next()
-        .unwrap();
-    let native_array = parse_to_native_array(array)?;
-    let native_array_ref = native_array.as_ref();
-    let geometry_array = native_array_ref
-        .as_geometry_opt()
-        .ok_or(GeoArrowError::General(
-            "Expected Geometry-typed array in ST_StartPoint".to_string(),
-        ))?;
-
-    let mut output_builder = PointBuilder::with_capacity_and_options(
-        Dimension::XY,
-        geometry_array.len(),
-        CoordType::Separated,
-        Default::default(),
-    );
-
-    for geom in geometry_array.iter() {
-        if let Some(Geometry::LineString(line_string)) = geom {
-            output_builder.push_coord(line_string.coord(0).as_ref());
-        } else {
-            output_builder.push_null();
+fn is_closed_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+    let geo_array = geo_array.as_ref();
+    let result = downcast_geoarrow_array!(geo_array, _is_closed_impl)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn _is_closed_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+) -> GeoArrowResult<arrow_array::BooleanArray> {
+    let mut builder = BooleanBuilder::with_capacity(array.len());
+    for geometry in array.iter() {
+        builder.append_option(geometry.transpose()?.map(|geometry| is_closed(&geometry)));
+    }
+    Ok(builder.finish())
+}
+
+fn is_closed(geometry: &impl GeometryTrait<T = f64>) -> bool {
+    match geometry.as_type() {
+        GeometryType::LineString(line_string) => line_string_is_closed(line_string),
+        GeometryType::MultiLineString(multi_line_string) => {
+            multi_line_string.num_line_strings() > 0
+                && multi_line_string
+                    .line_strings()
+                    .all(|line_string| line_string_is_closed(&line_string))
+        }
+        GeometryType::GeometryCollection(collection) => {
+            collection.num_geometries() > 0
+                && collection.geometries().all(|geometry| is_closed(&geometry))
         }
+        GeometryType::Line(_) => false,
+        _ => true,
+    }
+}
+
+fn line_string_is_closed(line_string: &impl LineStringTrait<T = f64>) -> bool {
+    let num_coords = line_string.num_coords();
+    match (
+        line_string.coord(0),
+        num_coords.checked_sub(1).and_then(|i| line_string.coord(i)),
+    ) {
+        (Some(first), Some(last)) => first.x_y() == last.x_y(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo_traits::PointTrait;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    async fn points(ctx: &SessionContext, sql: &str) -> Vec<Option<(f64, f64)>> {
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = PointArray::try_from((batch.column(0).as_ref(), &field)).unwrap();
+        array
+            .iter()
+            .map(|point| point.and_then(|point| point.unwrap().coord().map(|coord| coord.x_y())))
+            .collect()
     }
 
-    Ok(output_builder.finish().into_array_ref().into())
+    #[tokio::test]
+    async fn test_point_n() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(StartPoint::default().into());
+        ctx.register_udf(EndPoint::default().into());
+        ctx.register_udf(PointN::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let values = "FROM (VALUES
+            ('LINESTRING(0 0, 1 1, 2 0)'),
+            ('POINT(0 0)'),
+            (NULL)
+        ) AS t(wkt)";
+        assert_eq!(
+            points(
+                &ctx,
+                &format!("SELECT ST_StartPoint(ST_GeomFromText(wkt)) {values}")
+            )
+            .await,
+            vec![Some((0.0, 0.0)), None, None]
+        );
+        assert_eq!(
+            points(
+                &ctx,
+                &format!("SELECT ST_EndPoint(ST_GeomFromText(wkt)) {values}")
+            )
+            .await,
+            vec![Some((2.0, 0.0)), None, None]
+        );
+        assert_eq!(
+            points(
+                &ctx,
+                &format!("SELECT ST_PointN(ST_GeomFromText(wkt), 2) {values}")
+            )
+            .await,
+            vec![Some((1.0, 1.0)), None, None]
+        );
+        assert_eq!(
+            points(
+                &ctx,
+                &format!("SELECT ST_PointN(ST_GeomFromText(wkt), -3) {values}")
+            )
+            .await,
+            vec![Some((0.0, 0.0)), None, None]
+        );
+        assert_eq!(
+            points(
+                &ctx,
+                &format!("SELECT ST_PointN(ST_GeomFromText(wkt), 4) {values}")
+            )
+            .await,
+            vec![None, None, None]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_is_closed() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(IsClosed::new().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let sql = "SELECT ST_IsClosed(ST_GeomFromText(wkt)) FROM (VALUES
+            ('LINESTRING(0 0, 1 1, 0 0)'),
+            ('LINESTRING(0 0, 1 1)'),
+            ('MULTILINESTRING((0 0, 1 1, 0 0), (0 0, 1 1))'),
+            ('POINT(0 0)'),
+            (NULL)
+        ) AS t(wkt);";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let result = batch.column(0).as_boolean().iter().collect::<Vec<_>>();
+        assert_eq!(
+            result,
+            vec![Some(true), Some(false), Some(false), Some(true), None]
+        );
+    }
 }
@@ -1,5 +1,13 @@
 mod coord_dim;
+mod dump;
+mod geometry;
+mod line_string;
 mod point;
+mod polygon;
 
 pub use coord_dim::{CoordDim, NDims};
+pub use dump::Dump;
+pub use geometry::{GeometryN, GeometryType, IsEmpty, NPoints, NumGeometries};
+pub use line_string::{EndPoint, IsClosed, PointN, StartPoint};
 pub use point::{M, X, Y, Z};
+pub use polygon::{ExteriorRing, InteriorRingN, NumInteriorRings};
@@ -0,0 +1,420 @@
+//! Accessors from Polygon geometries
+
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::Int32Array;
+use arrow_array::builder::Int32Builder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geo_traits::{GeometryTrait, GeometryType, PolygonTrait};
+use geoarrow_array::array::{LineStringArray, PolygonArray, from_arrow_array};
+use geoarrow_array::builder::LineStringBuilder;
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, GeoArrowType, LineStringType};
+
+use crate::data_types::{any_geometry_and_integer_input, any_single_geometry_type_input};
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+use crate::udf::native::accessors::line_string::{output_dimension, push_line_string};
+
+#[derive(Debug)]
+pub struct ExteriorRing {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl ExteriorRing {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for ExteriorRing {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static EXTERIOR_RING_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for ExteriorRing {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_exteriorring"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        ring_return_field(&args, self.coord_type)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(ring_n_impl(args, |_| Some(0))?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(EXTERIOR_RING_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns a LINESTRING representing the exterior ring (shell) of a POLYGON. Returns NULL if the geometry is not a polygon.",
+                "ST_ExteriorRing(polygon)",
+            )
+            .with_argument("a_polygon", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct InteriorRingN {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl InteriorRingN {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_geometry_and_integer_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for InteriorRingN {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static INTERIOR_RING_N_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for InteriorRingN {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_interiorringn"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        ring_return_field(&args, self.coord_type)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let n = ColumnarValue::values_to_arrays(&args.args[1..])?.remove(0);
+        let n = n.as_primitive::<Int64Type>().clone();
+        Ok(ring_n_impl(args, |i| n.is_valid(i).then(|| n.value(i)))?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(INTERIOR_RING_N_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the Nth interior ring (hole) of a POLYGON geometry as a LINESTRING, counting from 1. Returns NULL if the geometry is not a polygon or the index is out of range.",
+                "ST_InteriorRingN(polygon, 1)",
+            )
+            .with_argument("a_polygon", "geometry")
+            .with_argument("n", "integer index, starting at 1")
+            .build()
+        }))
+    }
+}
+
+fn ring_return_field(args: &ReturnFieldArgs, coord_type: CoordType) -> Result<Arc<Field>> {
+    let input_field = &args.arg_fields[0];
+    let data_type =
+        GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::GeoArrow)?;
+    let typ = LineStringType::new(output_dimension(&data_type), data_type.metadata().clone())
+        .with_coord_type(coord_type);
+    Ok(typ.to_field(input_field.name(), true).into())
+}
+
+/// Take ring `n(i)` of the Polygon in row `i`, where ring 0 is the exterior ring and rings from 1
+/// are the interior rings.
+fn ring_n_impl(
+    args: ScalarFunctionArgs,
+    n: impl Fn(usize) -> Option<i64>,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+    let geo_array = geo_array.as_ref();
+    let typ = args.return_field.extension_type::<LineStringType>();
+    let result = downcast_geoarrow_array!(geo_array, _ring_n_impl, typ, &n)?;
+    Ok(ColumnarValue::Array(result.into_array_ref()))
+}
+
+fn _ring_n_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    typ: LineStringType,
+    n: &impl Fn(usize) -> Option<i64>,
+) -> GeoArrowResult<LineStringArray> {
+    let dim = typ.dimension();
+    let mut builder = LineStringBuilder::new(typ);
+    for (i, geometry) in array.iter().enumerate() {
+        let geometry = geometry.transpose()?;
+        let ring = geometry
+            .as_ref()
+            .zip(n(i))
+            .and_then(|(geometry, n)| match geometry.as_type() {
+                GeometryType::Polygon(polygon) => match n {
+                    0 => polygon.exterior(),
+                    n if n > 0 => polygon.interior(n as usize - 1),
+                    _ => None,
+                },
+                _ => None,
+            });
+        match ring {
+            Some(ring) => push_line_string(&mut builder, dim, &ring)?,
+            None => builder.push_line_string(None::<&geo::LineString>)?,
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[derive(Debug)]
+pub struct NumInteriorRings {
+    signature: Signature,
+}
+
+impl NumInteriorRings {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for NumInteriorRings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static NUM_INTERIOR_RINGS_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for NumInteriorRings {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_numinteriorrings"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(num_interior_rings_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(NUM_INTERIOR_RINGS_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return the number of interior rings of a polygon geometry. Return NULL if the geometry is not a polygon.",
+                "ST_NumInteriorRings(polygon)",
+            )
+            .with_argument("a_polygon", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn num_interior_rings_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+    let geo_array = geo_array.as_ref();
+    let result = match geo_array.data_type() {
+        GeoArrowType::Polygon(_) => polygon_num_interior_rings(geo_array.as_polygon()),
+        _ => downcast_geoarrow_array!(geo_array, _num_interior_rings_impl)?,
+    };
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+/// The number of interior rings of each Polygon, read from the offsets.
+fn polygon_num_interior_rings(array: &PolygonArray) -> Int32Array {
+    let num_interiors = array
+        .geom_offsets()
+        .lengths()
+        .map(|num_rings| num_rings.saturating_sub(1) as i32);
+    Int32Array::new(num_interiors.collect(), array.logical_nulls())
+}
+
+fn _num_interior_rings_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+) -> GeoArrowResult<Int32Array> {
+    let mut builder = Int32Builder::with_capacity(array.len());
+    for geometry in array.iter() {
+        let num_interiors = geometry
+            .transpose()?
+            .and_then(|geometry| match geometry.as_type() {
+                GeometryType::Polygon(polygon) => Some(polygon.num_interiors() as i32),
+                _ => None,
+            });
+        builder.append_option(num_interiors);
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo::LineString;
+    use geo_traits::to_geo::ToGeoLineString;
+
+    use super::*;
+    use crate::udf::native::constructors::MakePolygon;
+    use crate::udf::native::io::GeomFromText;
+
+    const POLYGONS: &str = "FROM (VALUES
+        ('POLYGON((0 0, 10 0, 10 10, 0 10, 0 0), (1 1, 2 1, 2 2, 1 1))'),
+        ('POINT(0 0)'),
+        (NULL)
+    ) AS t(wkt)";
+
+    async fn rings(ctx: &SessionContext, sql: &str) -> Vec<Option<LineString>> {
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = LineStringArray::try_from((batch.column(0).as_ref(), &field)).unwrap();
+        array
+            .iter()
+            .map(|ring| ring.map(|ring| ring.unwrap().to_line_string()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_rings() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(ExteriorRing::default().into());
+        ctx.register_udf(InteriorRingN::default().into());
+        ctx.register_udf(NumInteriorRings::new().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        assert_eq!(
+            rings(
+                &ctx,
+                &format!("SELECT ST_ExteriorRing(ST_GeomFromText(wkt)) {POLYGONS}")
+            )
+            .await,
+            vec![
+                Some(LineString::from(vec![
+                    (0.0, 0.0),
+                    (10.0, 0.0),
+                    (10.0, 10.0),
+                    (0.0, 10.0),
+                    (0.0, 0.0)
+                ])),
+                None,
+                None
+            ]
+        );
+        assert_eq!(
+            rings(
+                &ctx,
+                &format!("SELECT ST_InteriorRingN(ST_GeomFromText(wkt), 1) {POLYGONS}")
+            )
+            .await,
+            vec![
+                Some(LineString::from(vec![
+                    (1.0, 1.0),
+                    (2.0, 1.0),
+                    (2.0, 2.0),
+                    (1.0, 1.0)
+                ])),
+                None,
+                None
+            ]
+        );
+        assert_eq!(
+            rings(
+                &ctx,
+                &format!("SELECT ST_InteriorRingN(ST_GeomFromText(wkt), 2) {POLYGONS}")
+            )
+            .await,
+            vec![None, None, None]
+        );
+
+        let batch = ctx
+            .sql(&format!(
+                "SELECT ST_NumInteriorRings(ST_GeomFromText(wkt)) {POLYGONS}"
+            ))
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let result = batch
+            .column(0)
+            .as_primitive::<arrow_array::types::Int32Type>()
+            .iter()
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![Some(1), None, None]);
+
+        // Native polygon arrays are read from their offsets
+        ctx.register_udf(MakePolygon::default().into());
+        let batch = ctx
+            .sql("SELECT ST_NumInteriorRings(ST_MakePolygon(ST_GeomFromText('LINESTRING(0 0, 1 0, 1 1, 0 0)')));")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let result = batch
+            .column(0)
+            .as_primitive::<arrow_array::types::Int32Type>()
+            .iter()
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![Some(0)]);
+    }
+}
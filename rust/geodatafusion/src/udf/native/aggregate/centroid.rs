@@ -0,0 +1,320 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, UInt8Type};
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{DataType, Field, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::aggregate_doc_sections::DOC_SECTION_GENERAL;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Documentation, Signature};
+use datafusion::scalar::ScalarValue;
+use geo::dimensions::Dimensions;
+use geo::{Area, Centroid, CoordsIter, Geometry, HasDimensions, LinesIter, Point};
+use geoarrow_array::builder::PointBuilder;
+use geoarrow_schema::{CoordType, Dimension, GeoArrowType, PointType};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+use crate::udf::native::aggregate::state::{
+    geometry_scalar, input_field, input_geometries, output_metadata,
+};
+
+#[derive(Debug)]
+pub struct CentroidAgg {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl CentroidAgg {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for CentroidAgg {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl AggregateUDFImpl for CentroidAgg {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_centroid_agg"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field(&self, arg_fields: &[FieldRef]) -> Result<FieldRef> {
+        let input_field = &arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::from)?;
+        let point_type = PointType::new(Dimension::XY, data_type.metadata().clone())
+            .with_coord_type(self.coord_type);
+        Ok(Arc::new(point_type.to_field(input_field.name(), true)))
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let point_type = PointType::new(Dimension::XY, output_metadata(&acc_args)?)
+            .with_coord_type(self.coord_type);
+        Ok(Box::new(CentroidAccumulator {
+            input_field: input_field(&acc_args)?,
+            point_type,
+            centroid: None,
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![
+            Arc::new(Field::new(
+                format_state_name(args.name, "dimension"),
+                DataType::UInt8,
+                true,
+            )),
+            Arc::new(Field::new(
+                format_state_name(args.name, "weight"),
+                DataType::Float64,
+                true,
+            )),
+            Arc::new(Field::new(
+                format_state_name(args.name, "weighted_x"),
+                DataType::Float64,
+                true,
+            )),
+            Arc::new(Field::new(
+                format_state_name(args.name, "weighted_y"),
+                DataType::Float64,
+                true,
+            )),
+        ])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_GENERAL,
+                "Aggregate function that returns the center of mass of a set of geometries. As for the centroid of a GeometryCollection, only the geometries of the highest dimension contribute, weighted by their area, length or number of points.",
+                "ST_Centroid_Agg(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .with_related_udf("st_centroid")
+            .build()
+        }))
+    }
+}
+
+/// The weighted sum of the centroids of the geometries of the highest dimension seen so far.
+#[derive(Debug, Clone, Copy)]
+struct WeightedCentroid {
+    dimension: u8,
+    weight: f64,
+    weighted_x: f64,
+    weighted_y: f64,
+}
+
+impl WeightedCentroid {
+    /// The centroid of a single geometry, weighted by its area, length or number of points.
+    ///
+    /// Like [`Centroid`], a geometry without area or length is treated as a geometry of a lower
+    /// dimension.
+    fn try_new(geometry: &Geometry) -> Option<Self> {
+        let centroid = geometry.centroid()?;
+        let mut dimension = match geometry.dimensions() {
+            Dimensions::Empty => return None,
+            Dimensions::ZeroDimensional => 0,
+            Dimensions::OneDimensional => 1,
+            Dimensions::TwoDimensional => 2,
+        };
+        loop {
+            let weight = match dimension {
+                2 => geometry.unsigned_area(),
+                1 => geometry
+                    .lines_iter()
+                    .map(|line| line.dx().hypot(line.dy()))
+                    .sum(),
+                _ => geometry.coords_count() as f64,
+            };
+            if weight > 0.0 || dimension == 0 {
+                return Some(Self {
+                    dimension,
+                    weight,
+                    weighted_x: centroid.x() * weight,
+                    weighted_y: centroid.y() * weight,
+                });
+            }
+            dimension -= 1;
+        }
+    }
+
+    /// Combine with another weighted centroid, keeping only the highest dimension.
+    fn add(self, other: Self) -> Self {
+        if other.dimension > self.dimension {
+            other
+        } else if other.dimension < self.dimension {
+            self
+        } else {
+            Self {
+                dimension: self.dimension,
+                weight: self.weight + other.weight,
+                weighted_x: self.weighted_x + other.weighted_x,
+                weighted_y: self.weighted_y + other.weighted_y,
+            }
+        }
+    }
+
+    fn centroid(&self) -> Point {
+        Point::new(self.weighted_x / self.weight, self.weighted_y / self.weight)
+    }
+}
+
+#[derive(Debug)]
+struct CentroidAccumulator {
+    input_field: FieldRef,
+    point_type: PointType,
+    centroid: Option<WeightedCentroid>,
+}
+
+impl CentroidAccumulator {
+    fn add(&mut self, other: WeightedCentroid) {
+        self.centroid = Some(match self.centroid {
+            Some(centroid) => centroid.add(other),
+            None => other,
+        });
+    }
+
+    fn update_impl(&mut self, values: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        for geometry in input_geometries(&values[0], &self.input_field)?
+            .iter()
+            .flatten()
+        {
+            if let Some(centroid) = WeightedCentroid::try_new(geometry) {
+                self.add(centroid);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Accumulator for CentroidAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        Ok(self.update_impl(values)?)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let dimension = states[0].as_primitive::<UInt8Type>();
+        let [weight, weighted_x, weighted_y] =
+            [1, 2, 3].map(|i| states[i].as_primitive::<Float64Type>());
+        for i in 0..dimension.len() {
+            if dimension.is_valid(i) {
+                self.add(WeightedCentroid {
+                    dimension: dimension.value(i),
+                    weight: weight.value(i),
+                    weighted_x: weighted_x.value(i),
+                    weighted_y: weighted_y.value(i),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let centroid = self.centroid.as_ref();
+        Ok(vec![
+            ScalarValue::UInt8(centroid.map(|c| c.dimension)),
+            ScalarValue::Float64(centroid.map(|c| c.weight)),
+            ScalarValue::Float64(centroid.map(|c| c.weighted_x)),
+            ScalarValue::Float64(centroid.map(|c| c.weighted_y)),
+        ])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let centroid = self.centroid.map(|centroid| centroid.centroid());
+        let builder = PointBuilder::from_nullable_points(
+            [centroid.as_ref()].into_iter(),
+            self.point_type.clone(),
+        );
+        geometry_scalar(builder.finish())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo_traits::{CoordTrait, PointTrait};
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::PointArray;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    async fn centroids(sql: &str) -> Vec<Option<(f64, f64)>> {
+        let ctx = SessionContext::new();
+        ctx.register_udaf(CentroidAgg::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(1).clone();
+        let array = PointArray::try_from((batch.column(1).as_ref(), &field)).unwrap();
+        array
+            .iter()
+            .map(|point| {
+                point.map(|point| {
+                    let coord = point.unwrap().coord().unwrap();
+                    (coord.x(), coord.y())
+                })
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_centroid() {
+        let sql = "SELECT k, ST_Centroid_Agg(ST_GeomFromText(wkt)) FROM (VALUES
+            (1, 'POINT(0 0)'),
+            (1, 'POINT(2 4)'),
+            (2, 'POINT(100 100)'),
+            (2, 'LINESTRING(0 0, 4 0)'),
+            (2, 'LINESTRING(0 2, 0 4)'),
+            (3, 'POINT(100 100)'),
+            (3, 'POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'),
+            (4, NULL)
+        ) AS t(k, wkt) GROUP BY k ORDER BY k;";
+        assert_eq!(
+            centroids(sql).await,
+            vec![
+                Some((1.0, 2.0)),
+                Some((4.0 / 3.0, 1.0)),
+                Some((1.0, 1.0)),
+                None
+            ]
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::ArrayRef;
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::aggregate_doc_sections::DOC_SECTION_GENERAL;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Documentation, Signature};
+use datafusion::scalar::ScalarValue;
+use geo::{Geometry, GeometryCollection, MultiLineString, MultiPoint, MultiPolygon};
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_schema::{CoordType, GeoArrowType, GeometryType};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+use crate::udf::native::aggregate::state::{
+    geometry_scalar, input_field, input_geometries, output_metadata, wkb_state, wkb_state_field,
+    wkb_state_geometries,
+};
+
+#[derive(Debug)]
+pub struct Collect {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl Collect {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for Collect {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl AggregateUDFImpl for Collect {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_collect"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field(&self, arg_fields: &[FieldRef]) -> Result<FieldRef> {
+        let input_field = &arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::from)?;
+        let geometry_type =
+            GeometryType::new(data_type.metadata().clone()).with_coord_type(self.coord_type);
+        Ok(Arc::new(geometry_type.to_field(input_field.name(), true)))
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let geometry_type =
+            GeometryType::new(output_metadata(&acc_args)?).with_coord_type(self.coord_type);
+        Ok(Box::new(CollectAccumulator {
+            input_field: input_field(&acc_args)?,
+            geometry_type,
+            geometries: vec![],
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![wkb_state_field(args.name, "geometries")])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_GENERAL,
+                "Aggregate function that collects a set of geometries into a single geometry, without dissolving them. Points, LineStrings and Polygons are collected into a MultiPoint, MultiLineString or MultiPolygon when all inputs have the same type, and any other set of inputs into a GeometryCollection.",
+                "ST_Collect(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .with_related_udf("st_union")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct CollectAccumulator {
+    input_field: FieldRef,
+    geometry_type: GeometryType,
+    geometries: Vec<Geometry>,
+}
+
+impl CollectAccumulator {
+    fn update_impl(&mut self, values: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        let geometries = input_geometries(&values[0], &self.input_field)?;
+        self.geometries.extend(geometries.into_iter().flatten());
+        Ok(())
+    }
+
+    fn merge_impl(&mut self, states: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        for state in wkb_state_geometries(&states[0])?.into_iter().flatten() {
+            if let Geometry::GeometryCollection(collection) = state {
+                self.geometries.extend(collection);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Accumulator for CollectAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        Ok(self.update_impl(values)?)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        Ok(self.merge_impl(states)?)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let collection = GeometryCollection::new_from(self.geometries.clone());
+        Ok(vec![wkb_state(Some(&collection))?])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let collected = (!self.geometries.is_empty()).then(|| collect(self.geometries.clone()));
+        let builder =
+            GeometryBuilder::from_nullable_geometries(&[collected], self.geometry_type.clone())
+                .map_err(GeoDataFusionError::from)?;
+        geometry_scalar(builder.finish())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self) + self.geometries.capacity() * size_of::<Geometry>()
+    }
+}
+
+/// Collect geometries into the multi geometry of their type if they are all Points, all
+/// LineStrings or all Polygons, or into a GeometryCollection otherwise.
+fn collect(geometries: Vec<Geometry>) -> Geometry {
+    if geometries.iter().all(|g| matches!(g, Geometry::Point(_))) {
+        let points = geometries.into_iter().filter_map(|g| g.try_into().ok());
+        Geometry::MultiPoint(MultiPoint::from_iter(points))
+    } else if geometries
+        .iter()
+        .all(|g| matches!(g, Geometry::LineString(_)))
+    {
+        let line_strings = geometries.into_iter().filter_map(|g| g.try_into().ok());
+        Geometry::MultiLineString(MultiLineString::from_iter(line_strings))
+    } else if geometries.iter().all(|g| matches!(g, Geometry::Polygon(_))) {
+        let polygons = geometries.into_iter().filter_map(|g| g.try_into().ok());
+        Geometry::MultiPolygon(MultiPolygon::from_iter(polygons))
+    } else {
+        Geometry::GeometryCollection(GeometryCollection::new_from(geometries))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo_traits::{
+        GeometryCollectionTrait, GeometryTrait, GeometryType as GeometryTypeTrait, MultiPointTrait,
+    };
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::GeometryArray;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_collect() {
+        let ctx = SessionContext::new();
+        ctx.register_udaf(Collect::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let sql = "SELECT k, ST_Collect(ST_GeomFromText(wkt)) FROM (VALUES
+            (1, 'POINT(0 0)'),
+            (1, 'POINT(1 1)'),
+            (1, NULL),
+            (2, 'POINT(0 0)'),
+            (2, 'LINESTRING(0 0, 1 1)')
+        ) AS t(k, wkt) GROUP BY k ORDER BY k;";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(1).clone();
+        let array = GeometryArray::try_from((batch.column(1).as_ref(), &field)).unwrap();
+
+        match array.value(0).unwrap().as_type() {
+            GeometryTypeTrait::MultiPoint(multi_point) => {
+                assert_eq!(multi_point.num_points(), 2)
+            }
+            _ => panic!("Expected a MultiPoint"),
+        }
+        match array.value(1).unwrap().as_type() {
+            GeometryTypeTrait::GeometryCollection(collection) => {
+                assert_eq!(collection.num_geometries(), 2)
+            }
+            _ => panic!("Expected a GeometryCollection"),
+        }
+    }
+}
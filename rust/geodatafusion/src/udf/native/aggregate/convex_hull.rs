@@ -0,0 +1,202 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::ArrayRef;
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::aggregate_doc_sections::DOC_SECTION_GENERAL;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Documentation, Signature};
+use datafusion::scalar::ScalarValue;
+use geo::{ConvexHull, CoordsIter, Geometry, MultiPoint, Point, Polygon};
+use geoarrow_array::builder::PolygonBuilder;
+use geoarrow_schema::{CoordType, Dimension, GeoArrowType, PolygonType};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+use crate::udf::native::aggregate::state::{
+    geometry_scalar, input_field, input_geometries, output_metadata, wkb_state, wkb_state_field,
+    wkb_state_geometries,
+};
+
+#[derive(Debug)]
+pub struct ConvexHullAgg {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl ConvexHullAgg {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for ConvexHullAgg {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl AggregateUDFImpl for ConvexHullAgg {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_convexhull_agg"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field(&self, arg_fields: &[FieldRef]) -> Result<FieldRef> {
+        let input_field = &arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::from)?;
+        let polygon_type = PolygonType::new(Dimension::XY, data_type.metadata().clone())
+            .with_coord_type(self.coord_type);
+        Ok(Arc::new(polygon_type.to_field(input_field.name(), true)))
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let polygon_type = PolygonType::new(Dimension::XY, output_metadata(&acc_args)?)
+            .with_coord_type(self.coord_type);
+        Ok(Box::new(ConvexHullAccumulator {
+            input_field: input_field(&acc_args)?,
+            polygon_type,
+            hull: None,
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![wkb_state_field(args.name, "hull")])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_GENERAL,
+                "Aggregate function that returns the convex hull of a set of geometries, the smallest convex polygon containing all of them.",
+                "ST_ConvexHull_Agg(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct ConvexHullAccumulator {
+    input_field: FieldRef,
+    polygon_type: PolygonType,
+    hull: Option<Polygon>,
+}
+
+impl ConvexHullAccumulator {
+    /// Extend the hull so far to contain a batch of geometries.
+    ///
+    /// The hull of the union of two sets is the hull of their hulls, so only the vertices of the
+    /// hull are kept between batches.
+    fn add_geometries(&mut self, geometries: Vec<Option<Geometry>>) {
+        let mut points = geometries
+            .iter()
+            .flatten()
+            .flat_map(|geometry| geometry.coords_iter())
+            .map(Point::from)
+            .collect::<Vec<_>>();
+        if points.is_empty() {
+            return;
+        }
+        if let Some(hull) = &self.hull {
+            points.extend(hull.exterior_coords_iter().map(Point::from));
+        }
+        self.hull = Some(MultiPoint::new(points).convex_hull());
+    }
+
+    fn update_impl(&mut self, values: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        self.add_geometries(input_geometries(&values[0], &self.input_field)?);
+        Ok(())
+    }
+
+    fn merge_impl(&mut self, states: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        self.add_geometries(wkb_state_geometries(&states[0])?);
+        Ok(())
+    }
+}
+
+impl Accumulator for ConvexHullAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        Ok(self.update_impl(values)?)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        Ok(self.merge_impl(states)?)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![wkb_state(self.hull.as_ref())?])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let builder = PolygonBuilder::from_nullable_polygons(
+            &[self.hull.as_ref()],
+            self.polygon_type.clone(),
+        );
+        geometry_scalar(builder.finish())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo::Area;
+    use geo_traits::to_geo::ToGeoPolygon;
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::PolygonArray;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_convex_hull() {
+        let ctx = SessionContext::new();
+        ctx.register_udaf(ConvexHullAgg::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let sql = "SELECT ST_ConvexHull_Agg(ST_GeomFromText(wkt)) FROM (VALUES
+            ('POINT(0 0)'),
+            ('LINESTRING(2 0, 1 0.5)'),
+            ('POINT(0 2)'),
+            (NULL)
+        ) AS t(wkt);";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = PolygonArray::try_from((batch.column(0).as_ref(), &field)).unwrap();
+        let hull = array.value(0).unwrap().to_polygon();
+        assert_eq!(hull.unsigned_area(), 2.0);
+        assert_eq!(hull.exterior().0.len(), 4);
+    }
+}
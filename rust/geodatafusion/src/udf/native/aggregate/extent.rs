@@ -0,0 +1,261 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{DataType, Field, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::aggregate_doc_sections::DOC_SECTION_GENERAL;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Documentation, Signature};
+use datafusion::scalar::ScalarValue;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::RectBuilder;
+use geoarrow_geo::{BoundsAccumulator, SphericalBoundsAccumulator, total_bounds};
+use geoarrow_schema::{BoxType, Dimension, Edges, GeoArrowType};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+use crate::udf::native::aggregate::state::{geometry_scalar, input_field, output_metadata};
+
+#[derive(Debug)]
+pub struct Extent {
+    signature: Signature,
+}
+
+impl Extent {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for Extent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+const STATE_NAMES: [&str; 4] = ["min_x", "min_y", "max_x", "max_y"];
+
+impl AggregateUDFImpl for Extent {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_extent"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field(&self, arg_fields: &[FieldRef]) -> Result<FieldRef> {
+        let input_field = &arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::from)?;
+        let box_type = BoxType::new(Dimension::XY, data_type.metadata().clone());
+        Ok(Arc::new(box_type.to_field(input_field.name(), true)))
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let metadata = output_metadata(&acc_args)?;
+        Ok(Box::new(ExtentAccumulator {
+            input_field: input_field(&acc_args)?,
+            edges: metadata.edges(),
+            box_type: BoxType::new(Dimension::XY, metadata),
+            bounds: BoundsAccumulator::new(),
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(STATE_NAMES
+            .iter()
+            .map(|state| {
+                Arc::new(Field::new(
+                    format_state_name(args.name, state),
+                    DataType::Float64,
+                    true,
+                ))
+            })
+            .collect())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_GENERAL,
+                "Aggregate function that returns the 2D bounding box of a set of geometries. With spherical edges, the box accounts for great circle arcs and has a minimum x greater than its maximum x if it crosses the antimeridian.",
+                "ST_Extent(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct ExtentAccumulator {
+    input_field: FieldRef,
+    edges: Option<Edges>,
+    box_type: BoxType,
+    bounds: BoundsAccumulator,
+}
+
+impl ExtentAccumulator {
+    fn update_impl(&mut self, values: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        let geo_array = from_arrow_array(&values[0], &self.input_field)?;
+        let bounds = total_bounds(geo_array.as_ref(), self.edges)?;
+        self.add_bounds(&bounds);
+        Ok(())
+    }
+
+    /// Merge bounds into the running bounds, the same way GeoParquet merges column bounds.
+    fn add_bounds(&mut self, bounds: &BoundsAccumulator) {
+        if matches!(self.edges, Some(Edges::Spherical)) {
+            self.bounds = SphericalBoundsAccumulator::union(&self.bounds, bounds);
+        } else {
+            self.bounds.update(bounds);
+        }
+    }
+}
+
+impl Accumulator for ExtentAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        Ok(self.update_impl(values)?)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let [min_x, min_y, max_x, max_y] =
+            [0, 1, 2, 3].map(|i| states[i].as_primitive::<Float64Type>());
+        for i in 0..min_x.len() {
+            if min_x.is_valid(i) {
+                self.add_bounds(&BoundsAccumulator::from_xy(
+                    min_x.value(i),
+                    min_y.value(i),
+                    max_x.value(i),
+                    max_y.value(i),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let is_empty = self.bounds.is_empty();
+        Ok([
+            self.bounds.minx(),
+            self.bounds.miny(),
+            self.bounds.maxx(),
+            self.bounds.maxy(),
+        ]
+        .map(|value| ScalarValue::Float64((!is_empty).then_some(value)))
+        .to_vec())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let mut builder = RectBuilder::with_capacity(self.box_type.clone(), 1);
+        if self.bounds.is_empty() {
+            builder.push_null();
+        } else {
+            builder.push_rect(Some(&self.bounds));
+        }
+        geometry_scalar(builder.finish())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::RecordBatch;
+    use datafusion::prelude::SessionContext;
+    use geo::line_string;
+    use geo_traits::{CoordTrait, RectTrait};
+    use geoarrow_array::GeoArrowArray;
+    use geoarrow_array::array::RectArray;
+    use geoarrow_array::test::geometry::from_geoms;
+    use geoarrow_schema::{Crs, Metadata};
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    async fn extents(sql: &str) -> Vec<Option<[f64; 4]>> {
+        let ctx = SessionContext::new();
+        ctx.register_udaf(Extent::new().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+        let batches: Vec<RecordBatch> = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+        let batch = &batches[0];
+        let field = batch.schema_ref().field(1).clone();
+        let rects = RectArray::try_from((batch.column(1).as_ref(), &field)).unwrap();
+        rects
+            .iter()
+            .map(|rect| {
+                rect.map(|rect| {
+                    let rect = rect.unwrap();
+                    [
+                        rect.min().x(),
+                        rect.min().y(),
+                        rect.max().x(),
+                        rect.max().y(),
+                    ]
+                })
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_extent() {
+        let sql = "SELECT k, ST_Extent(ST_GeomFromText(wkt)) FROM (VALUES
+            (1, 'POINT(0 0)'),
+            (1, 'LINESTRING(1 1, 3 -2)'),
+            (2, 'POLYGON((10 10, 12 10, 12 13, 10 10))'),
+            (3, NULL)
+        ) AS t(k, wkt) GROUP BY k ORDER BY k;";
+        assert_eq!(
+            extents(sql).await,
+            vec![
+                Some([0.0, -2.0, 3.0, 1.0]),
+                Some([10.0, 10.0, 12.0, 13.0]),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spherical_extent() {
+        let metadata = Arc::new(Metadata::new(Crs::default(), Some(Edges::Spherical)));
+        let array = from_geoms(
+            &[Some(
+                line_string![(x: 170.0, y: 0.0), (x: -170.0, y: 0.0)].into(),
+            )],
+            metadata.clone(),
+        );
+        let mut accumulator = ExtentAccumulator {
+            input_field: Arc::new(array.data_type().to_field("geometry", true)),
+            edges: metadata.edges(),
+            box_type: BoxType::new(Dimension::XY, metadata),
+            bounds: BoundsAccumulator::new(),
+        };
+        accumulator.update_batch(&[array.to_array_ref()]).unwrap();
+
+        // The shorter great circle arc crosses the antimeridian
+        let [min_x, min_y, max_x, max_y] = accumulator.state().unwrap().try_into().unwrap();
+        assert_eq!(min_x, ScalarValue::Float64(Some(170.0)));
+        assert_eq!(max_x, ScalarValue::Float64(Some(-170.0)));
+        assert_eq!(min_y, ScalarValue::Float64(Some(0.0)));
+        assert_eq!(max_y, ScalarValue::Float64(Some(0.0)));
+    }
+}
@@ -0,0 +1,260 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::ArrayRef;
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::aggregate_doc_sections::DOC_SECTION_GENERAL;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Documentation, Signature};
+use datafusion::scalar::ScalarValue;
+use geo_traits::GeometryTrait;
+use geoarrow_array::array::{WkbArray, from_arrow_array};
+use geoarrow_array::builder::LineStringBuilder;
+use geoarrow_array::{GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, GeoArrowType, LineStringType, WkbType};
+use wkt::types::Coord;
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+use crate::udf::native::aggregate::state::{
+    geometry_scalar, input_field, output_metadata, wkb_state, wkb_state_field,
+};
+use crate::udf::native::constructors::{line_dimension, line_string, push_line_vertices};
+
+/// The aggregate form of `ST_MakeLine`, as in PostGIS.
+///
+/// DataFusion resolves a function name to a scalar function before an aggregate, so this is
+/// named `ST_MakeLine_Agg` to be registered alongside the scalar
+/// [`MakeLine`][crate::udf::native::constructors::MakeLine], like `ST_ConvexHull_Agg` and
+/// `ST_Centroid_Agg`.
+#[derive(Debug)]
+pub struct MakeLineAgg {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl MakeLineAgg {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for MakeLineAgg {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl AggregateUDFImpl for MakeLineAgg {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_makeline_agg"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field(&self, arg_fields: &[FieldRef]) -> Result<FieldRef> {
+        let input_field = &arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::from)?;
+        let line_string_type = LineStringType::new(
+            line_dimension(&[data_type.clone()]),
+            data_type.metadata().clone(),
+        )
+        .with_coord_type(self.coord_type);
+        Ok(Arc::new(
+            line_string_type.to_field(input_field.name(), true),
+        ))
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let input_field = input_field(&acc_args)?;
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::from)?;
+        let line_string_type =
+            LineStringType::new(line_dimension(&[data_type]), output_metadata(&acc_args)?)
+                .with_coord_type(self.coord_type);
+        Ok(Box::new(MakeLineAccumulator {
+            input_field,
+            line_string_type,
+            line: None,
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![wkb_state_field(args.name, "line")])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_GENERAL,
+                "Aggregate function that creates a LineString from the vertices of a set of Point, MultiPoint, or LineString geometries, in the order they are aggregated. Use ORDER BY within the aggregate to control the order of vertices. The LineString keeps the Z and M values of its inputs if their type has them.",
+                "ST_MakeLine_Agg(geom ORDER BY ts)",
+            )
+            .with_argument("geom", "geometry")
+            .with_related_udf("st_makeline")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct MakeLineAccumulator {
+    input_field: FieldRef,
+    line_string_type: LineStringType,
+    /// The vertices of the line so far, or `None` before the first non-null geometry.
+    line: Option<Vec<Coord>>,
+}
+
+impl MakeLineAccumulator {
+    /// Append the vertices of a geometry to the line so far.
+    fn add_geometry(&mut self, geometry: &impl GeometryTrait<T = f64>) -> GeoArrowResult<()> {
+        let dim = self.line_string_type.dimension();
+        push_line_vertices(self.line.get_or_insert_with(Vec::new), geometry, dim)
+    }
+
+    fn line_string(&self) -> Option<wkt::types::LineString> {
+        let dim = self.line_string_type.dimension();
+        self.line
+            .as_ref()
+            .map(|coords| line_string(coords.clone(), dim))
+    }
+
+    fn update_impl(&mut self, values: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        let geo_array = from_arrow_array(&values[0], &self.input_field)?;
+        let geo_array = geo_array.as_ref();
+        downcast_geoarrow_array!(geo_array, add_array, self)?;
+        Ok(())
+    }
+
+    fn merge_impl(&mut self, states: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        let wkb_array = WkbArray::try_from((states[0].as_ref(), WkbType::new(Default::default())))?;
+        add_array(&wkb_array, self)?;
+        Ok(())
+    }
+}
+
+/// Append the vertices of each geometry in an array, skipping nulls.
+fn add_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    accumulator: &mut MakeLineAccumulator,
+) -> GeoArrowResult<()> {
+    for geometry in array.iter().flatten() {
+        accumulator.add_geometry(&geometry?)?;
+    }
+    Ok(())
+}
+
+impl Accumulator for MakeLineAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        Ok(self.update_impl(values)?)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        Ok(self.merge_impl(states)?)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![wkb_state(self.line_string().as_ref())?])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let builder = LineStringBuilder::from_nullable_line_strings(
+            &[self.line_string()],
+            self.line_string_type.clone(),
+        );
+        geometry_scalar(builder.finish())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+            + self
+                .line
+                .as_ref()
+                .map_or(0, |line| line.capacity() * size_of::<Coord>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::RecordBatch;
+    use datafusion::prelude::SessionContext;
+    use geo::LineString;
+    use geo_traits::to_geo::ToGeoLineString;
+    use geo_traits::{CoordTrait, LineStringTrait};
+    use geoarrow_array::GeoArrowArray;
+    use geoarrow_array::array::LineStringArray;
+    use geoarrow_schema::Dimension;
+
+    use super::*;
+    use crate::udf::native::constructors::PointZ;
+    use crate::udf::native::io::GeomFromText;
+
+    async fn make_line(sql: &str) -> LineStringArray {
+        let ctx = SessionContext::new();
+        ctx.register_udaf(MakeLineAgg::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+        ctx.register_udf(PointZ::new(Default::default()).into());
+        let batch: RecordBatch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        LineStringArray::try_from((batch.column(0).as_ref(), &field)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_make_line_agg() {
+        let sql = "SELECT ST_MakeLine_Agg(ST_GeomFromText(wkt) ORDER BY ts) FROM (VALUES
+            (3, 'LINESTRING(2 0, 3 1)'),
+            (1, 'POINT(0 0)'),
+            (2, 'POINT(1 1)'),
+            (4, NULL)
+        ) AS t(ts, wkt);";
+        let array = make_line(sql).await;
+        assert_eq!(
+            array.value(0).unwrap().to_line_string(),
+            LineString::from(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0), (3.0, 1.0)])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_make_line_agg_keeps_z() {
+        let sql = "SELECT ST_MakeLine_Agg(ST_PointZ(x, y, z) ORDER BY x) FROM (VALUES
+            (1.0, 1.0, 20.0),
+            (0.0, 0.0, 10.0)
+        ) AS t(x, y, z);";
+        let array = make_line(sql).await;
+        assert_eq!(array.data_type().dimension(), Some(Dimension::XYZ));
+        let z = array
+            .value(0)
+            .unwrap()
+            .coords()
+            .map(|coord| coord.nth(2))
+            .collect::<Vec<_>>();
+        assert_eq!(z, vec![Some(10.0), Some(20.0)]);
+    }
+}
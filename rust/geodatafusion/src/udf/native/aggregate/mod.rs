@@ -0,0 +1,28 @@
+//! Aggregate functions over geometries
+
+mod centroid;
+mod collect;
+mod convex_hull;
+mod extent;
+mod make_line;
+mod state;
+mod union;
+
+pub use centroid::CentroidAgg;
+pub use collect::Collect;
+pub use convex_hull::ConvexHullAgg;
+pub use extent::Extent;
+pub use make_line::MakeLineAgg;
+pub use union::Union;
+
+// use datafusion::prelude::SessionContext;
+
+// /// Register all provided aggregate functions
+// pub fn register_udfs(ctx: &SessionContext) {
+//     ctx.register_udaf(centroid::CentroidAgg::default().into());
+//     ctx.register_udaf(collect::Collect::default().into());
+//     ctx.register_udaf(convex_hull::ConvexHullAgg::default().into());
+//     ctx.register_udaf(extent::Extent::new().into());
+//     ctx.register_udaf(make_line::MakeLineAgg::default().into());
+//     ctx.register_udaf(union::Union::default().into());
+// }
@@ -0,0 +1,68 @@
+//! Conversions between geometries and the inputs, states and results of aggregates.
+
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use arrow_schema::{DataType, Field, FieldRef};
+use datafusion::error::Result;
+use datafusion::logical_expr::function::AccumulatorArgs;
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::physical_expr::PhysicalExpr;
+use datafusion::scalar::ScalarValue;
+use geo::Geometry;
+use geo_traits::GeometryTrait;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::{WkbArray, from_arrow_array};
+use geoarrow_array::builder::WkbBuilder;
+use geoarrow_geo::util::to_geo::array_to_geo;
+use geoarrow_schema::{GeoArrowType, Metadata, WkbType};
+
+use crate::error::GeoDataFusionResult;
+
+/// The field of the geometry argument of an aggregate.
+pub(super) fn input_field(acc_args: &AccumulatorArgs) -> Result<FieldRef> {
+    acc_args.exprs[0].return_field(acc_args.schema)
+}
+
+/// The metadata of the geometry returned by an aggregate.
+pub(super) fn output_metadata(acc_args: &AccumulatorArgs) -> GeoDataFusionResult<Arc<Metadata>> {
+    Ok(GeoArrowType::try_from(acc_args.return_field.as_ref())?
+        .metadata()
+        .clone())
+}
+
+/// Convert a batch of the geometry argument of an aggregate to geo geometries, keeping nulls.
+pub(super) fn input_geometries(
+    array: &ArrayRef,
+    field: &Field,
+) -> GeoDataFusionResult<Vec<Option<Geometry>>> {
+    let geo_array = from_arrow_array(array, field)?;
+    Ok(array_to_geo(geo_array.as_ref())?)
+}
+
+/// The field of an intermediate state holding a geometry as WKB.
+pub(super) fn wkb_state_field(name: &str, state: &str) -> FieldRef {
+    Arc::new(Field::new(
+        format_state_name(name, state),
+        DataType::Binary,
+        true,
+    ))
+}
+
+/// Encode a geometry as the value of a WKB state.
+pub(super) fn wkb_state(geometry: Option<&impl GeometryTrait<T = f64>>) -> Result<ScalarValue> {
+    let mut builder = WkbBuilder::<i32>::new(WkbType::new(Default::default()));
+    builder.push_geometry(geometry);
+    ScalarValue::try_from_array(&builder.finish().into_array_ref(), 0)
+}
+
+/// Decode the geometries of a WKB state, keeping nulls.
+pub(super) fn wkb_state_geometries(array: &ArrayRef) -> GeoDataFusionResult<Vec<Option<Geometry>>> {
+    let wkb_array = WkbArray::try_from((array.as_ref(), WkbType::new(Default::default())))?;
+    Ok(array_to_geo(&wkb_array)?)
+}
+
+/// The value of a GeoArrow array with a single geometry, as the result of an aggregate.
+pub(super) fn geometry_scalar(array: impl GeoArrowArray) -> Result<ScalarValue> {
+    ScalarValue::try_from_array(&array.into_array_ref(), 0)
+}
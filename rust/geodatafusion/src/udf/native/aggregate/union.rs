@@ -0,0 +1,225 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::ArrayRef;
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::aggregate_doc_sections::DOC_SECTION_GENERAL;
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::{Accumulator, AggregateUDFImpl, Documentation, Signature};
+use datafusion::scalar::ScalarValue;
+use geo::{Geometry, MultiPolygon, Polygon, unary_union};
+use geoarrow_array::builder::MultiPolygonBuilder;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{CoordType, Dimension, GeoArrowType, MultiPolygonType};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+use crate::udf::native::aggregate::state::{
+    geometry_scalar, input_field, input_geometries, output_metadata, wkb_state, wkb_state_field,
+    wkb_state_geometries,
+};
+
+#[derive(Debug)]
+pub struct Union {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl Union {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for Union {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl AggregateUDFImpl for Union {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_union"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field(&self, arg_fields: &[FieldRef]) -> Result<FieldRef> {
+        let input_field = &arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::from)?;
+        let multi_polygon_type = MultiPolygonType::new(Dimension::XY, data_type.metadata().clone())
+            .with_coord_type(self.coord_type);
+        Ok(Arc::new(
+            multi_polygon_type.to_field(input_field.name(), true),
+        ))
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let multi_polygon_type = MultiPolygonType::new(Dimension::XY, output_metadata(&acc_args)?)
+            .with_coord_type(self.coord_type);
+        Ok(Box::new(UnionAccumulator {
+            input_field: input_field(&acc_args)?,
+            multi_polygon_type,
+            union: None,
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![wkb_state_field(args.name, "union")])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_GENERAL,
+                "Aggregate function that dissolves a set of polygonal geometries into a MultiPolygon covering the same area, with overlapping and adjacent polygons merged.",
+                "ST_Union(geom)",
+            )
+            .with_argument("geom", "polygonal geometry")
+            .with_related_udf("st_collect")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct UnionAccumulator {
+    input_field: FieldRef,
+    multi_polygon_type: MultiPolygonType,
+    union: Option<MultiPolygon>,
+}
+
+impl UnionAccumulator {
+    /// Dissolve a batch of geometries into the union so far.
+    fn add_geometries(&mut self, geometries: Vec<Option<Geometry>>) -> GeoArrowResult<()> {
+        let mut polygons = vec![];
+        for geometry in geometries.into_iter().flatten() {
+            add_polygons(geometry, &mut polygons)?;
+        }
+        if polygons.is_empty() {
+            return Ok(());
+        }
+        if let Some(union) = self.union.take() {
+            polygons.extend(union);
+        }
+        self.union = Some(unary_union(&polygons));
+        Ok(())
+    }
+
+    fn update_impl(&mut self, values: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        let geometries = input_geometries(&values[0], &self.input_field)?;
+        Ok(self.add_geometries(geometries)?)
+    }
+
+    fn merge_impl(&mut self, states: &[ArrayRef]) -> GeoDataFusionResult<()> {
+        let geometries = wkb_state_geometries(&states[0])?;
+        Ok(self.add_geometries(geometries)?)
+    }
+}
+
+impl Accumulator for UnionAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        Ok(self.update_impl(values)?)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        Ok(self.merge_impl(states)?)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![wkb_state(self.union.as_ref())?])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let builder = MultiPolygonBuilder::from_nullable_multi_polygons(
+            &[self.union.as_ref()],
+            self.multi_polygon_type.clone(),
+        );
+        geometry_scalar(builder.finish())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+}
+
+/// Add the polygons of a polygonal geometry.
+fn add_polygons(geometry: Geometry, polygons: &mut Vec<Polygon>) -> GeoArrowResult<()> {
+    match geometry {
+        Geometry::Polygon(polygon) => polygons.push(polygon),
+        Geometry::MultiPolygon(multi_polygon) => polygons.extend(multi_polygon),
+        Geometry::Rect(rect) => polygons.push(rect.to_polygon()),
+        Geometry::Triangle(triangle) => polygons.push(triangle.to_polygon()),
+        Geometry::GeometryCollection(collection) => {
+            for geometry in collection {
+                add_polygons(geometry, polygons)?;
+            }
+        }
+        _ => {
+            return Err(GeoArrowError::IncorrectGeometryType(
+                "ST_Union only supports polygonal geometries".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo::Area;
+    use geo_traits::to_geo::ToGeoMultiPolygon;
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::MultiPolygonArray;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_union() {
+        let ctx = SessionContext::new();
+        ctx.register_udaf(Union::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let sql = "SELECT ST_Union(ST_GeomFromText(wkt)) FROM (VALUES
+            ('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'),
+            ('POLYGON((1 1, 3 1, 3 3, 1 3, 1 1))'),
+            ('POLYGON((10 10, 11 10, 11 11, 10 11, 10 10))')
+        ) AS t(wkt);";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = MultiPolygonArray::try_from((batch.column(0).as_ref(), &field)).unwrap();
+        let union = array.value(0).unwrap().to_multi_polygon();
+        assert_eq!(union.0.len(), 2);
+        assert_eq!(union.unsigned_area(), 8.0);
+
+        let sql = "SELECT ST_Union(ST_GeomFromText('LINESTRING(0 0, 1 1)'));";
+        assert!(ctx.sql(sql).await.unwrap().collect().await.is_err());
+    }
+}
@@ -0,0 +1,272 @@
+//! LineString constructors
+
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryTrait, GeometryType, LineStringTrait, MultiPointTrait,
+    PointTrait,
+};
+use geoarrow_array::array::{LineStringArray, from_arrow_array};
+use geoarrow_array::builder::LineStringBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{CoordType, Dimension, GeoArrowType, LineStringType};
+use wkt::types::Coord;
+
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+
+#[derive(Debug)]
+pub struct MakeLine {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl MakeLine {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+impl Default for MakeLine {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static MAKE_LINE_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for MakeLine {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_makeline"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let types = args
+            .arg_fields
+            .iter()
+            .map(|field| GeoArrowType::try_from(field.as_ref()))
+            .collect::<GeoArrowResult<Vec<_>>>()
+            .map_err(GeoDataFusionError::GeoArrow)?;
+        let typ = LineStringType::new(line_dimension(&types), types[0].metadata().clone())
+            .with_coord_type(self.coord_type);
+        Ok(typ.to_field(args.arg_fields[0].name(), true).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(make_line_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(MAKE_LINE_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Creates a LineString containing the vertices of two Point, MultiPoint, or LineString geometries. The LineString keeps the Z and M values of its inputs if they share a dimension.",
+                "ST_MakeLine(ST_Point(0, 0), ST_Point(1, 1))",
+            )
+            .with_argument("geomA", "geometry")
+            .with_argument("geomB", "geometry")
+            .with_related_udf("st_makeline_agg")
+            .build()
+        }))
+    }
+}
+
+fn make_line_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let left_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let right_array = from_arrow_array(&arrays[1], &args.arg_fields[1])?;
+    let left = left_array.as_ref();
+    let typ = args.return_field.extension_type::<LineStringType>();
+    let lines = downcast_geoarrow_array!(left, make_lines, right_array.as_ref(), typ)?;
+    Ok(ColumnarValue::Array(lines.into_array_ref()))
+}
+
+fn make_lines<'a>(
+    left: &'a impl GeoArrowArrayAccessor<'a>,
+    right: &dyn GeoArrowArray,
+    typ: LineStringType,
+) -> GeoArrowResult<LineStringArray> {
+    downcast_geoarrow_array!(right, make_lines_impl, left, typ)
+}
+
+fn make_lines_impl<'a, 'b>(
+    right: &'b impl GeoArrowArrayAccessor<'b>,
+    left: &'a impl GeoArrowArrayAccessor<'a>,
+    typ: LineStringType,
+) -> GeoArrowResult<LineStringArray> {
+    let dim = typ.dimension();
+    let mut builder = LineStringBuilder::new(typ);
+    for (left, right) in left.iter().zip(right.iter()) {
+        match (left, right) {
+            (Some(left), Some(right)) => {
+                let mut coords = vec![];
+                push_line_vertices(&mut coords, &left?, dim)?;
+                push_line_vertices(&mut coords, &right?, dim)?;
+                builder.push_line_string(Some(&line_string(coords, dim)))?;
+            }
+            _ => builder.push_line_string(None::<&wkt::types::LineString>)?,
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// The dimension of a line made from geometries of the given types.
+///
+/// This is the dimension the types share, or XY if they differ or a type doesn't fix its
+/// dimension, as for WKB or mixed geometry arrays.
+pub(crate) fn line_dimension(types: &[GeoArrowType]) -> Dimension {
+    let mut dims = types.iter().map(|typ| typ.dimension());
+    match dims.next().flatten() {
+        Some(dim) if dims.all(|other| other == Some(dim)) => dim,
+        _ => Dimension::XY,
+    }
+}
+
+/// A line of dimension `dim` through the given vertices.
+pub(crate) fn line_string(coords: Vec<Coord>, dim: Dimension) -> wkt::types::LineString {
+    let dim = match dim {
+        Dimension::XY => wkt::types::Dimension::XY,
+        Dimension::XYZ => wkt::types::Dimension::XYZ,
+        Dimension::XYM => wkt::types::Dimension::XYM,
+        Dimension::XYZM => wkt::types::Dimension::XYZM,
+    };
+    wkt::types::LineString::new(coords, dim)
+}
+
+/// Append the vertices of a Point, MultiPoint or LineString to a line of dimension `dim` under
+/// construction.
+///
+/// As in PostGIS, a LineString that starts at the end of the line doesn't repeat that vertex.
+pub(crate) fn push_line_vertices(
+    coords: &mut Vec<Coord>,
+    geometry: &impl GeometryTrait<T = f64>,
+    dim: Dimension,
+) -> GeoArrowResult<()> {
+    match geometry.as_type() {
+        GeometryType::Point(point) => {
+            if let Some(coord) = point.coord() {
+                coords.push(line_vertex(&coord, dim)?);
+            }
+        }
+        GeometryType::MultiPoint(multi_point) => {
+            for point in multi_point.points() {
+                if let Some(coord) = point.coord() {
+                    coords.push(line_vertex(&coord, dim)?);
+                }
+            }
+        }
+        GeometryType::LineString(line_string) => {
+            for (i, coord) in line_string.coords().enumerate() {
+                let vertex = line_vertex(&coord, dim)?;
+                if i > 0 || coords.last() != Some(&vertex) {
+                    coords.push(vertex);
+                }
+            }
+        }
+        _ => {
+            return Err(GeoArrowError::IncorrectGeometryType(
+                "ST_MakeLine only supports Point, MultiPoint and LineString geometries".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// A coordinate as a vertex of a line of dimension `dim`, dropping any other dimensions.
+fn line_vertex(coord: &impl CoordTrait<T = f64>, dim: Dimension) -> GeoArrowResult<Coord> {
+    let (z, m) = match coord.dim() {
+        Dimensions::Xyz | Dimensions::Unknown(3) => (coord.nth(2), None),
+        Dimensions::Xym => (None, coord.nth(2)),
+        Dimensions::Xyzm | Dimensions::Unknown(4) => (coord.nth(2), coord.nth(3)),
+        _ => (None, None),
+    };
+    let has_z = matches!(dim, Dimension::XYZ | Dimension::XYZM);
+    let has_m = matches!(dim, Dimension::XYM | Dimension::XYZM);
+    if (has_z && z.is_none()) || (has_m && m.is_none()) {
+        return Err(GeoArrowError::IncorrectGeometryType(format!(
+            "ST_MakeLine can't add a {:?} vertex to a {dim:?} line",
+            coord.dim()
+        )));
+    }
+    Ok(Coord {
+        x: coord.x(),
+        y: coord.y(),
+        z: z.filter(|_| has_z),
+        m: m.filter(|_| has_m),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo::LineString;
+    use geo_traits::to_geo::ToGeoLineString;
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::LineStringArray;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_make_line() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(MakeLine::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let sql = "SELECT ST_MakeLine(ST_GeomFromText(a), ST_GeomFromText(b)) FROM (VALUES
+            ('POINT(0 0)', 'POINT(1 1)'),
+            ('LINESTRING(0 0, 1 1)', 'LINESTRING(1 1, 2 0)'),
+            ('MULTIPOINT(0 0, 1 0)', NULL)
+        ) AS t(a, b);";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = LineStringArray::try_from((batch.column(0).as_ref(), &field)).unwrap();
+        let lines = array
+            .iter()
+            .map(|line| line.map(|line| line.unwrap().to_line_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                Some(LineString::from(vec![(0.0, 0.0), (1.0, 1.0)])),
+                Some(LineString::from(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)])),
+                None,
+            ]
+        );
+
+        let sql = "SELECT ST_MakeLine(ST_GeomFromText('POLYGON((0 0, 1 0, 1 1, 0 0))'), ST_GeomFromText('POINT(0 0)'));";
+        assert!(ctx.sql(sql).await.unwrap().collect().await.is_err());
+    }
+}
@@ -1,3 +1,10 @@
+mod line;
+mod multi;
 mod point;
+mod polygon;
 
+pub use line::MakeLine;
+pub(crate) use line::{line_dimension, line_string, push_line_vertices};
+pub use multi::Multi;
 pub use point::{MakePoint, MakePointM, Point, PointM, PointZ, PointZM};
+pub use polygon::{MakeEnvelope, MakePolygon};
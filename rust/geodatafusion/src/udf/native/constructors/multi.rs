@@ -0,0 +1,193 @@
+//! Promote single geometries to their multi counterparts
+
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor};
+use geoarrow_cast::cast::cast;
+use geoarrow_schema::{
+    CoordType, GeoArrowType, GeometryType, MultiLineStringType, MultiPointType, MultiPolygonType,
+};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+
+#[derive(Debug)]
+pub struct Multi {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl Multi {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+
+    /// The type of the output for a given input type.
+    ///
+    /// Points, LineStrings and Polygons become their multi counterparts, multi geometries and
+    /// collections are kept as-is, and everything else becomes a mixed geometry array whose
+    /// single geometries are stored as multi geometries.
+    fn output_type(&self, input_type: GeoArrowType) -> GeoArrowType {
+        match input_type {
+            GeoArrowType::Point(typ) => GeoArrowType::MultiPoint(
+                MultiPointType::new(typ.dimension(), typ.metadata().clone())
+                    .with_coord_type(self.coord_type),
+            ),
+            GeoArrowType::LineString(typ) => GeoArrowType::MultiLineString(
+                MultiLineStringType::new(typ.dimension(), typ.metadata().clone())
+                    .with_coord_type(self.coord_type),
+            ),
+            GeoArrowType::Polygon(typ) => GeoArrowType::MultiPolygon(
+                MultiPolygonType::new(typ.dimension(), typ.metadata().clone())
+                    .with_coord_type(self.coord_type),
+            ),
+            typ @ (GeoArrowType::MultiPoint(_)
+            | GeoArrowType::MultiLineString(_)
+            | GeoArrowType::MultiPolygon(_)
+            | GeoArrowType::GeometryCollection(_)) => typ,
+            typ => GeoArrowType::Geometry(
+                GeometryType::new(typ.metadata().clone()).with_coord_type(self.coord_type),
+            ),
+        }
+    }
+}
+
+impl Default for Multi {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static MULTI_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Multi {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_multi"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::GeoArrow)?;
+        Ok(self
+            .output_type(data_type)
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(multi_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(MULTI_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the geometry as a MULTI* geometry collection. If the geometry is already a collection, it is returned unchanged.",
+                "ST_Multi(ST_GeomFromText('POLYGON((743238 2967416,743238 2967450,743265 2967450,743265.625 2967416,743238 2967416))'))",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn multi_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = ColumnarValue::values_to_arrays(&args.args)?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let to_type = GeoArrowType::try_from(args.return_field.as_ref())?;
+
+    let result = match &to_type {
+        GeoArrowType::Geometry(typ) => {
+            let geometry_type = GeometryType::new(Default::default());
+            let geometries = cast(geo_array.as_ref(), &GeoArrowType::Geometry(geometry_type))?;
+            let mut builder = GeometryBuilder::new(typ.clone()).with_prefer_multi(true);
+            for geometry in geometries.as_geometry().iter() {
+                builder.push_geometry(geometry.transpose()?.as_ref())?;
+            }
+            builder.finish().into_array_ref()
+        }
+        _ => cast(geo_array.as_ref(), &to_type)?.into_array_ref(),
+    };
+    Ok(ColumnarValue::Array(result))
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo::{Geometry, MultiLineString, MultiPoint, line_string, point};
+    use geoarrow_geo::util::to_geo::array_to_geo;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_multi() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Multi::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let sql = "SELECT ST_Multi(ST_GeomFromText(wkt)) FROM (VALUES
+            ('POINT(1 2)'),
+            ('LINESTRING(0 0, 1 1)'),
+            ('MULTIPOINT(0 0, 1 1)'),
+            (NULL)
+        ) AS t(wkt);";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = from_arrow_array(batch.column(0), &field).unwrap();
+        assert_eq!(
+            array_to_geo(array.as_ref()).unwrap(),
+            vec![
+                Some(Geometry::MultiPoint(MultiPoint::new(vec![
+                    point!(x: 1.0, y: 2.0)
+                ]))),
+                Some(Geometry::MultiLineString(MultiLineString::new(vec![
+                    line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)]
+                ]))),
+                Some(Geometry::MultiPoint(MultiPoint::new(vec![
+                    point!(x: 0.0, y: 0.0),
+                    point!(x: 1.0, y: 1.0)
+                ]))),
+                None,
+            ]
+        );
+    }
+}
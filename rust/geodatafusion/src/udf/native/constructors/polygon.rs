@@ -0,0 +1,312 @@
+//! Polygon constructors
+
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo::{Geometry, Polygon, polygon};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::PolygonBuilder;
+use geoarrow_geo::util::to_geo::array_to_geo;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{CoordType, Crs, Dimension, GeoArrowType, Metadata, PolygonType};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+
+#[derive(Debug)]
+pub struct MakePolygon {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl MakePolygon {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for MakePolygon {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static MAKE_POLYGON_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for MakePolygon {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_makepolygon"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        let data_type =
+            GeoArrowType::try_from(input_field.as_ref()).map_err(GeoDataFusionError::GeoArrow)?;
+        let typ = PolygonType::new(Dimension::XY, data_type.metadata().clone())
+            .with_coord_type(self.coord_type);
+        Ok(typ
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(make_polygon_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(MAKE_POLYGON_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Creates a Polygon formed by the given shell, which must be a closed LineString with at least 4 points.",
+                "ST_MakePolygon(ST_GeomFromText('LINESTRING(75 29, 77 29, 77 29, 75 29)'))",
+            )
+            .with_argument("linestring", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn make_polygon_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = ColumnarValue::values_to_arrays(&args.args)?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let polygons = array_to_geo(geo_array.as_ref())?
+        .into_iter()
+        .map(|geometry| geometry.map(make_polygon).transpose())
+        .collect::<GeoArrowResult<Vec<_>>>()?;
+
+    let typ = args.return_field.extension_type::<PolygonType>();
+    let builder = PolygonBuilder::from_nullable_polygons(&polygons, typ);
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+fn make_polygon(geometry: Geometry) -> GeoArrowResult<Polygon> {
+    let Geometry::LineString(shell) = geometry else {
+        return Err(GeoArrowError::IncorrectGeometryType(
+            "ST_MakePolygon shell must be a LineString".to_string(),
+        ));
+    };
+    if shell.0.len() < 4 || !shell.is_closed() {
+        return Err(GeoArrowError::IncorrectGeometryType(
+            "ST_MakePolygon shell must be a closed LineString with at least 4 points".to_string(),
+        ));
+    }
+    Ok(Polygon::new(shell, vec![]))
+}
+
+#[derive(Debug)]
+pub struct MakeEnvelope {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl MakeEnvelope {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    TypeSignature::Exact(vec![DataType::Float64; 4]),
+                    TypeSignature::Exact(vec![
+                        DataType::Float64,
+                        DataType::Float64,
+                        DataType::Float64,
+                        DataType::Float64,
+                        DataType::Int64,
+                    ]),
+                ],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for MakeEnvelope {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static MAKE_ENVELOPE_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for MakeEnvelope {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_makeenvelope"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let mut typ =
+            PolygonType::new(Dimension::XY, Default::default()).with_coord_type(self.coord_type);
+
+        if let Some(srid) = args.scalar_arguments.get(4) {
+            if let Some(ScalarValue::Int64(Some(srid))) = srid {
+                let crs = Crs::from_srid(srid.to_string());
+                typ = typ.with_metadata(Arc::new(Metadata::new(crs, None)));
+            } else {
+                return Err(DataFusionError::Internal(
+                    "ST_MakeEnvelope only supports SRID as a scalar integer".to_string(),
+                ));
+            }
+        };
+
+        Ok(typ.to_field("", true).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(make_envelope_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(MAKE_ENVELOPE_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Creates a rectangular Polygon from minimum and maximum values for X and Y.",
+                "ST_MakeEnvelope(10, 10, 11, 11, 4326)",
+            )
+            .with_argument("xmin", "minimum x value")
+            .with_argument("ymin", "minimum y value")
+            .with_argument("xmax", "maximum x value")
+            .with_argument("ymax", "maximum y value")
+            .with_argument("srid", "integer SRID value")
+            .build()
+        }))
+    }
+}
+
+fn make_envelope_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args[..4])?;
+    let [xmin, ymin, xmax, ymax] = [0, 1, 2, 3].map(|i| arrays[i].as_primitive::<Float64Type>());
+
+    let polygons = (0..xmin.len())
+        .map(|i| {
+            let (Some(xmin), Some(ymin), Some(xmax), Some(ymax)) = (
+                xmin.is_valid(i).then(|| xmin.value(i)),
+                ymin.is_valid(i).then(|| ymin.value(i)),
+                xmax.is_valid(i).then(|| xmax.value(i)),
+                ymax.is_valid(i).then(|| ymax.value(i)),
+            ) else {
+                return None;
+            };
+            Some(polygon![
+                (x: xmin, y: ymin),
+                (x: xmin, y: ymax),
+                (x: xmax, y: ymax),
+                (x: xmax, y: ymin),
+                (x: xmin, y: ymin),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let typ = args.return_field.extension_type::<PolygonType>();
+    let builder = PolygonBuilder::from_nullable_polygons(&polygons, typ);
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo::LineString;
+    use geo_traits::to_geo::ToGeoPolygon;
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::PolygonArray;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    async fn polygons(ctx: &SessionContext, sql: &str) -> (PolygonType, Vec<Option<Polygon>>) {
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let array = PolygonArray::try_from((batch.column(0).as_ref(), &field)).unwrap();
+        let polygons = array
+            .iter()
+            .map(|polygon| polygon.map(|polygon| polygon.unwrap().to_polygon()))
+            .collect();
+        (array.extension_type().clone(), polygons)
+    }
+
+    #[tokio::test]
+    async fn test_make_polygon() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(MakePolygon::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let (_, result) = polygons(
+            &ctx,
+            "SELECT ST_MakePolygon(ST_GeomFromText('LINESTRING(0 0, 1 0, 1 1, 0 0)'));",
+        )
+        .await;
+        let shell = LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]);
+        assert_eq!(result, vec![Some(Polygon::new(shell, vec![]))]);
+
+        let sql = "SELECT ST_MakePolygon(ST_GeomFromText('LINESTRING(0 0, 1 0, 1 1)'));";
+        assert!(ctx.sql(sql).await.unwrap().collect().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_make_envelope() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(MakeEnvelope::default().into());
+
+        let (typ, result) = polygons(&ctx, "SELECT ST_MakeEnvelope(10, 20, 11, 21, 4326);").await;
+        assert_eq!(typ.metadata().crs(), &Crs::from_srid("4326".to_string()));
+        assert_eq!(
+            result,
+            vec![Some(polygon![
+                (x: 10.0, y: 20.0),
+                (x: 10.0, y: 21.0),
+                (x: 11.0, y: 21.0),
+                (x: 11.0, y: 20.0),
+                (x: 10.0, y: 20.0),
+            ])]
+        );
+    }
+}
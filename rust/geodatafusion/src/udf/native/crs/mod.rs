@@ -0,0 +1,7 @@
+//! Coordinate reference system functions
+
+mod srid;
+mod transform;
+
+pub use srid::{SetSrid, Srid};
+pub use transform::{CoordTransform, Transform, TransformEngine, WebMercatorEngine};
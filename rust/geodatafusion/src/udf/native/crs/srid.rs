@@ -0,0 +1,204 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::Int32Array;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use datafusion::scalar::ScalarValue;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_schema::{Crs, GeoArrowType, Metadata};
+
+use crate::data_types::{any_geometry_and_integer_input, any_single_geometry_type_input};
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+
+#[derive(Debug)]
+pub struct Srid {
+    signature: Signature,
+}
+
+impl Srid {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for Srid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static SRID_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Srid {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_srid"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(srid_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(SRID_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the spatial reference identifier of the geometry, or 0 if the CRS of the geometry has no SRID.",
+                "ST_SRID(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .with_related_udf("st_setsrid")
+            .with_related_udf("st_transform")
+            .build()
+        }))
+    }
+}
+
+fn srid_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+    let srid = geo_array.data_type().metadata().crs().srid().unwrap_or(0);
+    let result = (0..geo_array.len())
+        .map(|i| (!geo_array.is_null(i)).then_some(srid))
+        .collect::<Int32Array>();
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+#[derive(Debug)]
+pub struct SetSrid {
+    signature: Signature,
+}
+
+impl SetSrid {
+    pub fn new() -> Self {
+        Self {
+            signature: any_geometry_and_integer_input(),
+        }
+    }
+}
+
+impl Default for SetSrid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static SET_SRID_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for SetSrid {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_setsrid"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let crs = srid_argument(&args, 1, "ST_SetSRID")?;
+        let input_field = &args.arg_fields[0];
+        Ok(with_crs(input_field, crs)?
+            .to_field(input_field.name(), true)
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        // Only the field metadata changes; the coordinates are passed through untouched.
+        Ok(args.args.into_iter().next().unwrap())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(SET_SRID_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Sets the SRID on a geometry to a particular integer value. The coordinates are not modified; use ST_Transform to reproject a geometry.",
+                "ST_SetSRID(ST_Point(-123.365556, 48.428611), 4326)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("srid", "integer SRID value")
+            .with_related_udf("st_srid")
+            .with_related_udf("st_transform")
+            .build()
+        }))
+    }
+}
+
+/// Read the SRID literal at position `index` of a function's arguments.
+pub(super) fn srid_argument(args: &ReturnFieldArgs, index: usize, name: &str) -> Result<Crs> {
+    match args.scalar_arguments.get(index) {
+        Some(Some(ScalarValue::Int64(Some(srid)))) => Ok(Crs::from_srid(srid.to_string())),
+        _ => Err(DataFusionError::Plan(format!(
+            "{name} only supports SRID as a scalar integer"
+        ))),
+    }
+}
+
+/// The GeoArrow type of `field`, with its CRS replaced by `crs`.
+pub(super) fn with_crs(field: &Field, crs: Crs) -> GeoDataFusionResult<GeoArrowType> {
+    let data_type = GeoArrowType::try_from(field).map_err(GeoDataFusionError::GeoArrow)?;
+    let metadata = Metadata::new(crs, data_type.metadata().edges());
+    Ok(data_type.with_metadata(Arc::new(metadata)))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+
+    #[tokio::test]
+    async fn test_srid() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Srid::default().into());
+        ctx.register_udf(SetSrid::default().into());
+        ctx.register_udf(Point::new(Default::default()).into());
+
+        let batch = ctx
+            .sql(
+                "SELECT ST_SRID(ST_Point(1.0, 2.0)), ST_SRID(ST_Point(1.0, 2.0, 4326)), ST_SRID(ST_SetSRID(ST_Point(1.0, 2.0, 4326), 3857));",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let srids = (0..3)
+            .map(|i| batch.column(i).as_primitive::<Int32Type>().value(0))
+            .collect::<Vec<_>>();
+        assert_eq!(srids, vec![0, 4326, 3857]);
+    }
+}
@@ -0,0 +1,277 @@
+use std::any::Any;
+use std::fmt::Debug;
+use std::sync::{Arc, OnceLock};
+
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geo::{Coord, MapCoords};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_cast::cast::cast;
+use geoarrow_geo::util::to_geo::array_to_geo;
+use geoarrow_geo::web_mercator::{lon_lat_to_web_mercator, web_mercator_to_lon_lat};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use geoarrow_schema::{CoordType, Crs, Dimension, GeoArrowType, GeometryType};
+
+use super::srid::{srid_argument, with_crs};
+use crate::data_types::{any_geometry_and_integer_input, same_crs};
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+
+/// A transformation of individual coordinates from one CRS to another.
+pub trait CoordTransform {
+    /// Transform a single coordinate.
+    fn transform(&self, coord: Coord) -> GeoArrowResult<Coord>;
+}
+
+impl<F: Fn(Coord) -> GeoArrowResult<Coord>> CoordTransform for F {
+    fn transform(&self, coord: Coord) -> GeoArrowResult<Coord> {
+        self(coord)
+    }
+}
+
+/// Creates coordinate transformations between pairs of CRS.
+///
+/// Implement this to plug a projection library such as PROJ into [`Transform`].
+pub trait TransformEngine: Debug + Send + Sync {
+    /// Create a transformation from the `from` CRS to the `to` CRS, or return an error if the pair
+    /// is not supported.
+    fn create(&self, from: &Crs, to: &Crs) -> GeoArrowResult<Box<dyn CoordTransform>>;
+}
+
+/// A [`TransformEngine`] with no external dependencies, supporting only longitude/latitude
+/// (EPSG:4326) and Web Mercator (EPSG:3857).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebMercatorEngine;
+
+/// The coordinate reference systems supported by [`WebMercatorEngine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Projection {
+    LonLat,
+    WebMercator,
+}
+
+impl WebMercatorEngine {
+    fn projection(crs: &Crs) -> GeoArrowResult<Projection> {
+        match crs.srid() {
+            Some(4326) => Ok(Projection::LonLat),
+            Some(3857) | Some(900913) => Ok(Projection::WebMercator),
+            _ => Err(GeoArrowError::Crs(format!(
+                "Unsupported CRS for Web Mercator transform: {crs:?}"
+            ))),
+        }
+    }
+}
+
+impl TransformEngine for WebMercatorEngine {
+    fn create(&self, from: &Crs, to: &Crs) -> GeoArrowResult<Box<dyn CoordTransform>> {
+        let project: fn(Coord) -> Coord = match (Self::projection(from)?, Self::projection(to)?) {
+            (Projection::LonLat, Projection::WebMercator) => lon_lat_to_web_mercator,
+            (Projection::WebMercator, Projection::LonLat) => web_mercator_to_lon_lat,
+            _ => |coord| coord,
+        };
+        Ok(Box::new(move |coord: Coord| Ok(project(coord))))
+    }
+}
+
+#[derive(Debug)]
+pub struct Transform {
+    signature: Signature,
+    coord_type: CoordType,
+    engine: Arc<dyn TransformEngine>,
+}
+
+impl Transform {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: any_geometry_and_integer_input(),
+            coord_type,
+            engine: Arc::new(WebMercatorEngine),
+        }
+    }
+
+    /// Reproject with the given engine instead of the default [`WebMercatorEngine`].
+    pub fn with_engine(self, engine: Arc<dyn TransformEngine>) -> Self {
+        Self { engine, ..self }
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static TRANSFORM_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Transform {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_transform"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let crs = srid_argument(&args, 1, "ST_Transform")?;
+        let input_field = &args.arg_fields[0];
+        let typ = with_crs(input_field, crs)?;
+        Ok(output_type(typ, self.coord_type)
+            .to_field(input_field.name(), true)
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(transform_impl(args, self.engine.as_ref())?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TRANSFORM_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns a new geometry with its coordinates transformed to a different spatial reference system. Only the first two dimensions are transformed.",
+                "ST_Transform(ST_Point(-71.104, 42.315, 4326), 3857)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("srid", "integer SRID value")
+            .with_related_udf("st_setsrid")
+            .with_related_udf("st_srid")
+            .build()
+        }))
+    }
+}
+
+/// Transformed coordinates are two-dimensional, so only 2D native types can be kept as-is.
+fn output_type(typ: GeoArrowType, coord_type: CoordType) -> GeoArrowType {
+    use GeoArrowType::*;
+    match typ {
+        Point(_)
+        | LineString(_)
+        | Polygon(_)
+        | MultiPoint(_)
+        | MultiLineString(_)
+        | MultiPolygon(_)
+        | GeometryCollection(_)
+            if typ.dimension() == Some(Dimension::XY) =>
+        {
+            typ
+        }
+        _ => GeoArrowType::Geometry(
+            GeometryType::new(typ.metadata().clone()).with_coord_type(coord_type),
+        ),
+    }
+}
+
+fn transform_impl(
+    args: ScalarFunctionArgs,
+    engine: &dyn TransformEngine,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+    let from = geo_array.data_type().metadata().crs().clone();
+    if from == Crs::default() {
+        return Err(DataFusionError::Execution(
+            "ST_Transform input has no CRS; use ST_SetSRID to assign one".to_string(),
+        )
+        .into());
+    }
+
+    let output_type =
+        GeoArrowType::try_from(args.return_field.as_ref()).map_err(GeoDataFusionError::GeoArrow)?;
+    let to = output_type.metadata().crs();
+
+    let mut geometries = array_to_geo(geo_array.as_ref())?;
+    if !same_crs(&from, to) {
+        let transform = engine.create(&from, to)?;
+        let transform = transform.as_ref();
+        geometries = geometries
+            .into_iter()
+            .map(|geometry| {
+                geometry
+                    .map(|geometry| geometry.try_map_coords(|coord| transform.transform(coord)))
+                    .transpose()
+            })
+            .collect::<GeoArrowResult<_>>()?;
+    }
+
+    let geometry_type = GeometryType::new(output_type.metadata().clone())
+        .with_coord_type(output_type.coord_type().unwrap_or_default());
+    let result = GeometryBuilder::from_nullable_geometries(&geometries, geometry_type)?.finish();
+    let result = match output_type {
+        GeoArrowType::Geometry(_) => result.into_array_ref(),
+        _ => cast(&result, &output_type)?.into_array_ref(),
+    };
+    Ok(ColumnarValue::Array(result))
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+    use datafusion::prelude::SessionContext;
+    use geo_traits::{CoordTrait, PointTrait};
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::PointArray;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+    use crate::udf::native::crs::Srid;
+
+    #[tokio::test]
+    async fn test_transform() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Transform::default().into());
+        ctx.register_udf(Srid::default().into());
+        ctx.register_udf(Point::new(Default::default()).into());
+
+        let batch = ctx
+            .sql("SELECT ST_Transform(ST_Point(lon, 0.0, 4326), 3857) FROM (VALUES (0.0), (180.0)) AS t(lon);")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let field = batch.schema_ref().field(0).clone();
+        let array = PointArray::try_from((batch.column(0).as_ref(), &field)).unwrap();
+        assert_eq!(array.data_type().metadata().crs().srid(), Some(3857));
+
+        let coords = array
+            .iter()
+            .map(|point| point.unwrap().unwrap().coord().unwrap().x_y())
+            .collect::<Vec<_>>();
+        assert_relative_eq!(coords[0].0, 0.0, epsilon = 1e-6);
+        assert_relative_eq!(coords[0].1, 0.0, epsilon = 1e-6);
+        assert_relative_eq!(coords[1].0, 20037508.342789244, epsilon = 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_transform_unsupported_crs() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Transform::default().into());
+        ctx.register_udf(Point::new(Default::default()).into());
+
+        let result = ctx
+            .sql("SELECT ST_Transform(ST_Point(0.0, 0.0, 4326), 2263);")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert!(result.is_err());
+    }
+}
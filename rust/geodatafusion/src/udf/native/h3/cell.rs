@@ -0,0 +1,358 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::{ListBuilder, UInt64Builder};
+use arrow_array::cast::AsArray;
+use arrow_array::types::UInt64Type;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_h3::{cell_to_boundary, latlng_to_cell, polygon_to_cells};
+use geoarrow_schema::{BoxType, CoordType, Dimension, GeometryType, MultiPolygonType, PolygonType};
+
+use crate::data_types::point_and_integer_input;
+use crate::error::GeoDataFusionResult;
+
+/// The H3 resolution argument, which must be a scalar integer.
+fn resolution_arg(value: &ColumnarValue) -> GeoDataFusionResult<u8> {
+    match value {
+        ColumnarValue::Scalar(ScalarValue::Int64(Some(res))) => u8::try_from(*res)
+            .map_err(|_| DataFusionError::Execution(format!("Invalid H3 resolution {res}")).into()),
+        _ => Err(
+            DataFusionError::Execution("H3 resolution must be a scalar integer".to_string()).into(),
+        ),
+    }
+}
+
+#[derive(Debug)]
+pub struct LatLngToCell {
+    signature: Signature,
+}
+
+impl LatLngToCell {
+    pub fn new() -> Self {
+        Self {
+            signature: point_and_integer_input(),
+        }
+    }
+}
+
+impl Default for LatLngToCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static LATLNG_TO_CELL_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for LatLngToCell {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "h3_latlng_to_cell"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(latlng_to_cell_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(LATLNG_TO_CELL_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the H3 cell containing a point in longitude and latitude degrees, at the given resolution between 0 and 15.",
+                "H3_LatLng_To_Cell(ST_Point(-122.0553238, 37.3615593), 7)",
+            )
+            .with_argument("point", "geometry")
+            .with_argument("resolution", "integer H3 resolution")
+            .with_related_udf("h3_cell_to_boundary")
+            .build()
+        }))
+    }
+}
+
+fn latlng_to_cell_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let resolution = resolution_arg(&args.args[1])?;
+    let array = ColumnarValue::values_to_arrays(&args.args[..1])?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(array.as_ref(), args.arg_fields[0].as_ref())?;
+    let result = latlng_to_cell(geo_array.as_point(), resolution)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+#[derive(Debug)]
+pub struct CellToBoundary {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl CellToBoundary {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::UInt64], Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+static CELL_TO_BOUNDARY_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for CellToBoundary {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "h3_cell_to_boundary"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, _args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let typ =
+            PolygonType::new(Dimension::XY, Default::default()).with_coord_type(self.coord_type);
+        Ok(typ.to_field("", true).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(self.invoke_with_args(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(CELL_TO_BOUNDARY_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the boundary of an H3 cell as a polygon in longitude and latitude degrees.",
+                "H3_Cell_To_Boundary(cell)",
+            )
+            .with_argument("cell", "unsigned 64-bit H3 cell index")
+            .with_related_udf("h3_latlng_to_cell")
+            .build()
+        }))
+    }
+}
+
+impl CellToBoundary {
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+        let array = ColumnarValue::values_to_arrays(&args.args)?
+            .into_iter()
+            .next()
+            .unwrap();
+        let result = cell_to_boundary(array.as_primitive::<UInt64Type>(), self.coord_type)?;
+        Ok(ColumnarValue::Array(result.to_array_ref()))
+    }
+}
+
+#[derive(Debug)]
+pub struct PolygonToCells {
+    signature: Signature,
+}
+
+impl PolygonToCells {
+    pub fn new() -> Self {
+        let mut valid_types = vec![];
+        for coord_type in [CoordType::Separated, CoordType::Interleaved] {
+            for dim in [
+                Dimension::XY,
+                Dimension::XYZ,
+                Dimension::XYM,
+                Dimension::XYZM,
+            ] {
+                valid_types.push(
+                    PolygonType::new(dim, Default::default())
+                        .with_coord_type(coord_type)
+                        .data_type(),
+                );
+                valid_types.push(
+                    MultiPolygonType::new(dim, Default::default())
+                        .with_coord_type(coord_type)
+                        .data_type(),
+                );
+            }
+            valid_types.push(
+                GeometryType::new(Default::default())
+                    .with_coord_type(coord_type)
+                    .data_type(),
+            );
+        }
+        for dim in [
+            Dimension::XY,
+            Dimension::XYZ,
+            Dimension::XYM,
+            Dimension::XYZM,
+        ] {
+            valid_types.push(BoxType::new(dim, Default::default()).data_type());
+        }
+
+        let type_signatures = valid_types
+            .into_iter()
+            .map(|typ| TypeSignature::Exact(vec![typ, DataType::Int64]))
+            .collect();
+        Self {
+            signature: Signature::one_of(type_signatures, Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for PolygonToCells {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static POLYGON_TO_CELLS_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for PolygonToCells {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "h3_polygon_to_cells"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::List(Arc::new(Field::new_list_field(
+            DataType::UInt64,
+            true,
+        ))))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(polygon_to_cells_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(POLYGON_TO_CELLS_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the list of H3 cells whose centers are within a polygon in longitude and latitude degrees, at the given resolution between 0 and 15. Use UNNEST to get one row per cell.",
+                "H3_Polygon_To_Cells(geom, 7)",
+            )
+            .with_argument("geom", "polygonal geometry")
+            .with_argument("resolution", "integer H3 resolution")
+            .with_related_udf("h3_cell_to_boundary")
+            .build()
+        }))
+    }
+}
+
+fn polygon_to_cells_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let resolution = resolution_arg(&args.args[1])?;
+    let array = ColumnarValue::values_to_arrays(&args.args[..1])?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(array.as_ref(), args.arg_fields[0].as_ref())?;
+    let (rows, cells) = polygon_to_cells(geo_array.as_ref(), resolution)?;
+
+    // Gather the exploded cells back into one list per row
+    let mut builder = ListBuilder::with_capacity(UInt64Builder::new(), geo_array.len());
+    let mut offset = 0;
+    for row in 0..geo_array.len() {
+        let start = offset;
+        while offset < rows.len() && rows.value(offset) as usize == row {
+            offset += 1;
+        }
+        builder
+            .values()
+            .append_slice(&cells.values()[start..offset]);
+        builder.append(geo_array.is_valid(row));
+    }
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+    use geo_traits::{LineStringTrait, PolygonTrait};
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::PolygonArray;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+    use crate::udf::native::io::GeomFromText;
+
+    fn context() -> SessionContext {
+        let ctx = SessionContext::new();
+        ctx.register_udf(LatLngToCell::new().into());
+        ctx.register_udf(CellToBoundary::new(Default::default()).into());
+        ctx.register_udf(PolygonToCells::new().into());
+        ctx.register_udf(Point::new(CoordType::Separated).into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+        ctx
+    }
+
+    #[tokio::test]
+    async fn test_latlng_to_cell() {
+        let ctx = context();
+        let df = ctx
+            .sql("SELECT h3_latlng_to_cell(ST_Point(-122.0553238, 37.3615593), 7);")
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        let cells = batch.column(0).as_primitive::<UInt64Type>();
+        assert_eq!(cells.value(0), 0x87283472bffffff);
+    }
+
+    #[tokio::test]
+    async fn test_cell_to_boundary() {
+        let ctx = context();
+        let df = ctx
+            .sql("SELECT h3_cell_to_boundary(h3_latlng_to_cell(ST_Point(-122.0553238, 37.3615593), 7));")
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let polygons = PolygonArray::try_from((batch.column(0).as_ref(), &field)).unwrap();
+        let polygon = polygons.value(0).unwrap();
+        assert_eq!(polygon.exterior().unwrap().num_coords(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_polygon_to_cells() {
+        let ctx = context();
+        let df = ctx
+            .sql(
+                "SELECT h3_polygon_to_cells(ST_GeomFromText('POLYGON((-122.5 37.5,-122 37.5,-122 38,-122.5 38,-122.5 37.5))'), 6);",
+            )
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        let lists = batch.column(0).as_list::<i32>();
+        assert_eq!(lists.len(), 1);
+        assert!(!lists.value(0).is_empty());
+    }
+}
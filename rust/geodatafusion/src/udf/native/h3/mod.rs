@@ -0,0 +1,14 @@
+//! H3 cell indexing
+
+mod cell;
+
+pub use cell::{CellToBoundary, LatLngToCell, PolygonToCells};
+
+// use datafusion::prelude::SessionContext;
+
+// /// Register all provided H3 functions
+// pub fn register_udfs(ctx: &SessionContext) {
+//     ctx.register_udf(cell::CellToBoundary::new(Default::default()).into());
+//     ctx.register_udf(cell::LatLngToCell::new().into());
+//     ctx.register_udf(cell::PolygonToCells::new().into());
+// }
@@ -0,0 +1,246 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, Field};
+use datafusion::arrow::compute::cast;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::cast::from_wkb;
+use geoarrow_array::ewkb::{
+    EwkbReadOptions, WkbFlavor, WkbWriteOptions, from_ewkb, to_wkb_with_options,
+};
+use geoarrow_schema::{CoordType, GeoArrowType, GeometryType, Metadata};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::{GeoDataFusionError, GeoDataFusionResult};
+
+#[derive(Debug)]
+pub struct AsEWKB {
+    signature: Signature,
+}
+
+impl AsEWKB {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for AsEWKB {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_EWKB_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsEWKB {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_asewkb"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    // EWKB is not valid GeoArrow WKB, so the output is plain binary without an extension type.
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Binary)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_ewkb_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_EWKB_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the Extended Well-Known Binary (EWKB) representation of the geometry, including its SRID when the CRS of the geometry has one.",
+                "ST_AsEWKB(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .with_related_udf("st_geomfromewkb")
+            .build()
+        }))
+    }
+}
+
+fn as_ewkb_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+    let options = WkbWriteOptions::default().with_flavor(WkbFlavor::Extended { srid: None });
+    let binary_array = to_wkb_with_options::<i32>(geo_array.as_ref(), options)?;
+    Ok(ColumnarValue::Array(Arc::new(binary_array)))
+}
+
+#[derive(Debug)]
+pub struct GeomFromEWKB {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeomFromEWKB {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![
+                    DataType::Binary,
+                    DataType::LargeBinary,
+                    DataType::BinaryView,
+                ],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeomFromEWKB {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOM_FROM_EWKB_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeomFromEWKB {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_geomfromewkb"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        // The SRID of a column is only known once it is read, so it can only be carried into the
+        // CRS of the output when the EWKB is a literal.
+        let metadata = match args.scalar_arguments.first() {
+            Some(Some(scalar)) => literal_metadata(scalar)?,
+            _ => Default::default(),
+        };
+        let geom_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
+        let input_field = &args.arg_fields[0];
+        Ok(geom_type
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geom_from_ewkb_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOM_FROM_EWKB_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Creates a geometry from Extended Well-Known Binary (EWKB). The SRID of a literal EWKB value is kept as the CRS of the output.",
+                "ST_GeomFromEWKB(ST_AsEWKB(geometry))",
+            )
+            .with_argument("ewkb", "EWKB buffers")
+            .with_related_udf("st_asewkb")
+            .build()
+        }))
+    }
+}
+
+/// The metadata of the geometry read from a literal EWKB value, including its SRID if any.
+fn literal_metadata(scalar: &ScalarValue) -> GeoDataFusionResult<Arc<Metadata>> {
+    let array = cast(&scalar.to_array()?, &DataType::Binary)?;
+    let wkb_array = from_ewkb(
+        array.as_binary::<i32>(),
+        Default::default(),
+        &EwkbReadOptions::default(),
+    )?;
+    Ok(wkb_array.data_type().metadata().clone())
+}
+
+fn geom_from_ewkb_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+    let array = cast(array, &DataType::Binary)?;
+    let to_type = GeoArrowType::try_from(args.return_field.as_ref())?;
+    let wkb_array = from_ewkb(
+        array.as_binary::<i32>(),
+        to_type.metadata().clone(),
+        &EwkbReadOptions::default(),
+    )
+    .map_err(GeoDataFusionError::GeoArrow)?;
+    let geom_array = from_wkb(&wkb_array, to_type)?;
+    Ok(ColumnarValue::Array(geom_array.to_array_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo::{Geometry, point};
+    use geoarrow_geo::util::to_geo::array_to_geo;
+    use geoarrow_schema::Crs;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+
+    #[tokio::test]
+    async fn test_ewkb_round_trip() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsEWKB::new().into());
+        ctx.register_udf(GeomFromEWKB::default().into());
+        ctx.register_udf(Point::new(Default::default()).into());
+
+        // Little-endian EWKB of SRID=4326;POINT(1 2)
+        let batch = ctx
+            .sql("SELECT ST_AsEWKB(ST_Point(1, 2, 4326));")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let ewkb = batch.column(0).as_binary::<i32>().value(0);
+        assert_eq!(&ewkb[1..9], &[1, 0, 0, 0x20, 0xE6, 0x10, 0, 0]);
+
+        let sql = "SELECT ST_GeomFromEWKB(X'0101000020E6100000000000000000F03F0000000000000040');";
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let typ = field.extension_type::<GeometryType>();
+        assert_eq!(typ.metadata().crs(), &Crs::from_srid("4326".to_string()));
+        let array = from_arrow_array(batch.column(0), &field).unwrap();
+        assert_eq!(
+            array_to_geo(array.as_ref()).unwrap(),
+            vec![Some(Geometry::Point(point!(x: 1.0, y: 2.0)))]
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::StringArray;
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, Field};
+use datafusion::arrow::compute::cast;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_geo::util::to_geo::array_to_geo;
+use geoarrow_schema::{CoordType, Crs, GeometryType, Metadata};
+use geozero::geojson::GeoJson;
+use geozero::{ToGeo, ToJson};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug)]
+pub struct AsGeoJSON {
+    signature: Signature,
+}
+
+impl AsGeoJSON {
+    pub fn new() -> Self {
+        Self {
+            signature: any_single_geometry_type_input(),
+        }
+    }
+}
+
+impl Default for AsGeoJSON {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_GEOJSON_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsGeoJSON {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_asgeojson"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_geojson_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_GEOJSON_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the geometry as a GeoJSON geometry object. Only the first two dimensions are written.",
+                "ST_AsGeoJSON(geometry)",
+            )
+            .with_argument("geom", "geometry")
+            .with_related_udf("st_geomfromgeojson")
+            .build()
+        }))
+    }
+}
+
+fn as_geojson_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+    let json = array_to_geo(geo_array.as_ref())?
+        .into_iter()
+        .map(|geometry| geometry.map(|geometry| geometry.to_json()).transpose())
+        .collect::<std::result::Result<StringArray, _>>()?;
+    Ok(ColumnarValue::Array(Arc::new(json)))
+}
+
+#[derive(Debug)]
+pub struct GeomFromGeoJSON {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeomFromGeoJSON {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeomFromGeoJSON {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOM_FROM_GEOJSON_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeomFromGeoJSON {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_geomfromgeojson"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        // GeoJSON coordinates are always WGS84 longitude and latitude (RFC 7946)
+        let metadata = Arc::new(Metadata::new(Crs::from_srid("4326".to_string()), None));
+        let geom_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
+        let input_field = &args.arg_fields[0];
+        Ok(geom_type
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geom_from_geojson_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOM_FROM_GEOJSON_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Takes as input a GeoJSON representation of a geometry and outputs a geometry in SRID 4326. Only the first two dimensions are read.",
+                "ST_GeomFromGeoJSON('{\"type\":\"Point\",\"coordinates\":[-48.23456,20.12345]}')",
+            )
+            .with_argument("geomjson", "GeoJSON geometry text")
+            .with_related_udf("st_asgeojson")
+            .build()
+        }))
+    }
+}
+
+fn geom_from_geojson_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+    let array = cast(array, &DataType::Utf8)?;
+    let geometries = array
+        .as_string::<i32>()
+        .iter()
+        .map(|json| json.map(|json| GeoJson(json).to_geo()).transpose())
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let typ = args.return_field.extension_type::<GeometryType>();
+    let builder = GeometryBuilder::from_nullable_geometries(&geometries, typ)?;
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo::{Geometry, line_string, point};
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_geojson_round_trip() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsGeoJSON::new().into());
+        ctx.register_udf(GeomFromGeoJSON::default().into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+
+        let batch = ctx
+            .sql("SELECT ST_AsGeoJSON(ST_GeomFromText('POINT(1 2)'));")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let json = batch.column(0).as_string::<i32>().value(0);
+        assert_eq!(
+            GeoJson(json).to_geo().unwrap(),
+            Geometry::Point(point!(x: 1.0, y: 2.0))
+        );
+
+        let sql = r#"SELECT ST_GeomFromGeoJSON('{"type": "LineString", "coordinates": [[0, 0], [1, 1]]}');"#;
+        let batch = ctx
+            .sql(sql)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let typ = field.extension_type::<GeometryType>();
+        assert_eq!(typ.metadata().crs(), &Crs::from_srid("4326".to_string()));
+        let array = from_arrow_array(batch.column(0), &field).unwrap();
+        assert_eq!(
+            array_to_geo(array.as_ref()).unwrap(),
+            vec![Some(Geometry::LineString(
+                line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)]
+            ))]
+        );
+    }
+}
@@ -1,9 +1,13 @@
 //! Geometry Input and Output
 
+mod ewkb;
 // mod geohash;
+mod geojson;
 mod wkb;
 mod wkt;
 
+pub use ewkb::{AsEWKB, GeomFromEWKB};
+pub use geojson::{AsGeoJSON, GeomFromGeoJSON};
 pub use wkb::{AsBinary, GeomFromWKB};
 pub use wkt::{AsText, GeomFromText};
 
@@ -11,9 +15,13 @@ pub use wkt::{AsText, GeomFromText};
 
 // /// Register all provided functions for geometry input and output
 // pub fn register_udfs(ctx: &SessionContext) {
+//     ctx.register_udf(ewkb::AsEWKB::new().into());
+//     ctx.register_udf(ewkb::GeomFromEWKB::default().into());
 //     ctx.register_udf(geohash::Box2DFromGeoHash::new().into());
 //     ctx.register_udf(geohash::GeoHash::new().into());
 //     ctx.register_udf(geohash::PointFromGeoHash::new().into());
+//     ctx.register_udf(geojson::AsGeoJSON::new().into());
+//     ctx.register_udf(geojson::GeomFromGeoJSON::default().into());
 //     ctx.register_udf(wkb::AsBinary::new().into());
 //     ctx.register_udf(wkb::GeomFromWKB::new().into());
 //     ctx.register_udf(wkt::AsText::new().into());
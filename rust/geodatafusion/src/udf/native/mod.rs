@@ -1,20 +1,28 @@
 //! User-defined functions that wrap native Rust implementations.
 
 pub mod accessors;
+pub mod aggregate;
 // mod bounding_box;
 pub mod constructors;
+pub mod crs;
+pub mod h3;
 pub mod io;
 pub mod measurement;
 // mod processing;
+pub mod tiling;
 
 // use datafusion::prelude::SessionContext;
 
 // /// Register all provided native-Rust functions
 // pub fn register_native(ctx: &SessionContext) {
 //     accessors::register_udfs(ctx);
+//     aggregate::register_udfs(ctx);
 //     bounding_box::register_udfs(ctx);
 //     constructors::register_udfs(ctx);
+//     crs::register_udfs(ctx);
+//     h3::register_udfs(ctx);
 //     io::register_udfs(ctx);
 //     measurement::register_udfs(ctx);
 //     processing::register_udfs(ctx);
+//     tiling::register_udfs(ctx);
 // }
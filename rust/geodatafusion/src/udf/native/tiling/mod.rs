@@ -0,0 +1,16 @@
+//! Web Mercator tiling
+
+mod tile;
+
+pub use tile::{TileEnvelope, TileQuadkey, TileX, TileY, TilesCovering};
+
+// use datafusion::prelude::SessionContext;
+
+// /// Register all provided tiling functions
+// pub fn register_udfs(ctx: &SessionContext) {
+//     ctx.register_udf(tile::TileEnvelope::new().into());
+//     ctx.register_udf(tile::TileQuadkey::new().into());
+//     ctx.register_udf(tile::TileX::new().into());
+//     ctx.register_udf(tile::TileY::new().into());
+//     ctx.register_udf(tile::TilesCovering::new().into());
+// }
@@ -0,0 +1,534 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Int64Type, UInt8Type, UInt32Type};
+use arrow_array::{
+    Array, ArrayRef, ArrowPrimitiveType, ListArray, PrimitiveArray, StructArray, UInt32Array,
+};
+use arrow_schema::{DataType, Field, Fields};
+use datafusion::arrow::buffer::{NullBuffer, OffsetBuffer};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::{PointArray, from_arrow_array};
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_geo::tiling::{
+    DEFAULT_MAX_TILES, SourceProjection, point_to_tile_x, point_to_tile_y, tile_envelope,
+    tile_quadkey, tiles_covering,
+};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{BoxType, Dimension, Metadata};
+
+use crate::data_types::{any_geometry_and_integer_input, point_and_integer_input};
+use crate::error::GeoDataFusionResult;
+
+/// The zoom level argument, which must be a scalar integer.
+fn zoom_arg(value: &ColumnarValue) -> GeoDataFusionResult<u8> {
+    match value {
+        ColumnarValue::Scalar(ScalarValue::Int64(Some(zoom))) => u8::try_from(*zoom)
+            .map_err(|_| DataFusionError::Execution(format!("Invalid zoom level {zoom}")).into()),
+        _ => Err(
+            DataFusionError::Execution("Zoom level must be a scalar integer".to_string()).into(),
+        ),
+    }
+}
+
+/// Convert an integer tile coordinate argument to the unsigned type of the tiling kernels.
+fn tile_arg<T>(array: &ArrayRef, name: &str) -> GeoDataFusionResult<PrimitiveArray<T>>
+where
+    T: ArrowPrimitiveType,
+    T::Native: TryFrom<i64>,
+{
+    Ok(array
+        .as_primitive::<Int64Type>()
+        .iter()
+        .map(|value| {
+            value
+                .map(|value| {
+                    T::Native::try_from(value).map_err(|_| {
+                        DataFusionError::Execution(format!("Invalid tile {name} {value}"))
+                    })
+                })
+                .transpose()
+        })
+        .collect::<Result<PrimitiveArray<T>>>()?)
+}
+
+fn tile_args(
+    args: &ScalarFunctionArgs,
+) -> GeoDataFusionResult<(PrimitiveArray<UInt8Type>, UInt32Array, UInt32Array)> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    Ok((
+        tile_arg(&arrays[0], "zoom")?,
+        tile_arg(&arrays[1], "x")?,
+        tile_arg(&arrays[2], "y")?,
+    ))
+}
+
+#[derive(Debug)]
+pub struct TileX {
+    signature: Signature,
+}
+
+impl TileX {
+    pub fn new() -> Self {
+        Self {
+            signature: point_and_integer_input(),
+        }
+    }
+}
+
+impl Default for TileX {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TILE_X_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for TileX {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "tile_x"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(point_to_tile_impl(args, point_to_tile_x)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TILE_X_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the column of the Web Mercator tile containing a point at the given zoom level. The point must be in EPSG:4326 or EPSG:3857, and is assumed to be in longitude and latitude degrees if it has no CRS.",
+                "Tile_X(ST_Point(-122.4194, 37.7749), 10)",
+            )
+            .with_argument("point", "geometry")
+            .with_argument("zoom", "integer zoom level")
+            .with_related_udf("tile_y")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct TileY {
+    signature: Signature,
+}
+
+impl TileY {
+    pub fn new() -> Self {
+        Self {
+            signature: point_and_integer_input(),
+        }
+    }
+}
+
+impl Default for TileY {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TILE_Y_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for TileY {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "tile_y"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(point_to_tile_impl(args, point_to_tile_y)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TILE_Y_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the row, from north to south, of the Web Mercator tile containing a point at the given zoom level. The point must be in EPSG:4326 or EPSG:3857, and is assumed to be in longitude and latitude degrees if it has no CRS.",
+                "Tile_Y(ST_Point(-122.4194, 37.7749), 10)",
+            )
+            .with_argument("point", "geometry")
+            .with_argument("zoom", "integer zoom level")
+            .with_related_udf("tile_x")
+            .build()
+        }))
+    }
+}
+
+/// The geometry argument and the projection given by its CRS.
+fn geometry_arg(
+    args: &ScalarFunctionArgs,
+) -> GeoDataFusionResult<(Arc<dyn GeoArrowArray>, SourceProjection)> {
+    let array = ColumnarValue::values_to_arrays(&args.args[..1])?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(array.as_ref(), args.arg_fields[0].as_ref())?;
+    let projection = SourceProjection::from_crs(geo_array.data_type().metadata().crs())?;
+    Ok((geo_array, projection))
+}
+
+fn point_to_tile_impl(
+    args: ScalarFunctionArgs,
+    kernel: impl Fn(&PointArray, u8, SourceProjection) -> GeoArrowResult<UInt32Array>,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let zoom = zoom_arg(&args.args[1])?;
+    let (geo_array, projection) = geometry_arg(&args)?;
+    let tiles = kernel(geo_array.as_point(), zoom, projection)?;
+    Ok(ColumnarValue::Array(Arc::new(tiles)))
+}
+
+#[derive(Debug)]
+pub struct TileQuadkey {
+    signature: Signature,
+}
+
+impl TileQuadkey {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(
+                vec![DataType::Int64, DataType::Int64, DataType::Int64],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for TileQuadkey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TILE_QUADKEY_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for TileQuadkey {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "tile_quadkey"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(tile_quadkey_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TILE_QUADKEY_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the quadkey of a tile, with one digit per zoom level.",
+                "Tile_Quadkey(3, 3, 5)",
+            )
+            .with_argument("zoom", "integer zoom level")
+            .with_argument("x", "integer tile column")
+            .with_argument("y", "integer tile row")
+            .build()
+        }))
+    }
+}
+
+fn tile_quadkey_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let (z, x, y) = tile_args(&args)?;
+    Ok(ColumnarValue::Array(Arc::new(tile_quadkey(&z, &x, &y)?)))
+}
+
+#[derive(Debug)]
+pub struct TileEnvelope {
+    signature: Signature,
+}
+
+impl TileEnvelope {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(
+                vec![DataType::Int64, DataType::Int64, DataType::Int64],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for TileEnvelope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TILE_ENVELOPE_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for TileEnvelope {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_tileenvelope"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, _args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let metadata = Arc::new(Metadata::new(SourceProjection::WebMercator.crs(), None));
+        Ok(BoxType::new(Dimension::XY, metadata)
+            .to_field("", true)
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(tile_envelope_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TILE_ENVELOPE_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the bounds of a tile in the XYZ tiling scheme as a box in Web Mercator (EPSG:3857).",
+                "ST_TileEnvelope(2, 1, 1)",
+            )
+            .with_argument("zoom", "integer zoom level")
+            .with_argument("x", "integer tile column")
+            .with_argument("y", "integer tile row")
+            .build()
+        }))
+    }
+}
+
+fn tile_envelope_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let (z, x, y) = tile_args(&args)?;
+    let envelopes = tile_envelope(&z, &x, &y, SourceProjection::WebMercator)?;
+    Ok(ColumnarValue::Array(envelopes.to_array_ref()))
+}
+
+#[derive(Debug)]
+pub struct TilesCovering {
+    signature: Signature,
+}
+
+impl TilesCovering {
+    pub fn new() -> Self {
+        Self {
+            signature: any_geometry_and_integer_input(),
+        }
+    }
+}
+
+impl Default for TilesCovering {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TILES_COVERING_DOC: OnceLock<Documentation> = OnceLock::new();
+
+fn tile_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("x", DataType::UInt32, false),
+        Field::new("y", DataType::UInt32, false),
+    ])
+}
+
+impl ScalarUDFImpl for TilesCovering {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "tiles_covering"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::List(Arc::new(Field::new_list_field(
+            DataType::Struct(tile_fields()),
+            false,
+        ))))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(tiles_covering_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TILES_COVERING_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the list of Web Mercator tiles at the given zoom level that intersect a geometry, as structs of the tile column x and row y. Use UNNEST to get one row per tile. The geometry must be in EPSG:4326 or EPSG:3857, and is assumed to be in longitude and latitude degrees if it has no CRS. A geometry covering more than 1,048,576 tiles is an error.",
+                "Tiles_Covering(geom, 10)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("zoom", "integer zoom level")
+            .with_related_udf("st_tileenvelope")
+            .build()
+        }))
+    }
+}
+
+fn tiles_covering_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let zoom = zoom_arg(&args.args[1])?;
+    let (geo_array, projection) = geometry_arg(&args)?;
+    let (rows, x, y) = tiles_covering(geo_array.as_ref(), zoom, projection, DEFAULT_MAX_TILES)?;
+
+    // Gather the exploded tiles back into one list per row
+    let mut lengths = vec![0; geo_array.len()];
+    for row in rows.values() {
+        lengths[*row as usize] += 1;
+    }
+    let tiles = StructArray::new(tile_fields(), vec![Arc::new(x), Arc::new(y)], None);
+    let validity = (0..geo_array.len())
+        .map(|row| geo_array.is_valid(row))
+        .collect::<NullBuffer>();
+    let list = ListArray::try_new(
+        Arc::new(Field::new_list_field(tiles.data_type().clone(), false)),
+        OffsetBuffer::from_lengths(lengths),
+        Arc::new(tiles),
+        Some(validity),
+    )?;
+    Ok(ColumnarValue::Array(Arc::new(list)))
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::SessionContext;
+    use geo_traits::{CoordTrait, RectTrait};
+    use geoarrow_array::GeoArrowArrayAccessor;
+    use geoarrow_array::array::RectArray;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+    use crate::udf::native::io::GeomFromText;
+
+    fn context() -> SessionContext {
+        let ctx = SessionContext::new();
+        ctx.register_udf(TileX::new().into());
+        ctx.register_udf(TileY::new().into());
+        ctx.register_udf(TileQuadkey::new().into());
+        ctx.register_udf(TileEnvelope::new().into());
+        ctx.register_udf(TilesCovering::new().into());
+        ctx.register_udf(Point::new(Default::default()).into());
+        ctx.register_udf(GeomFromText::new(Default::default()).into());
+        ctx
+    }
+
+    #[tokio::test]
+    async fn test_point_to_tile() {
+        let ctx = context();
+        let df = ctx
+            .sql("SELECT tile_x(ST_Point(-122.4194, 37.7749), 10), tile_y(ST_Point(-122.4194, 37.7749), 10), tile_quadkey(3, 3, 5);")
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        assert_eq!(batch.column(0).as_primitive::<UInt32Type>().value(0), 163);
+        assert_eq!(batch.column(1).as_primitive::<UInt32Type>().value(0), 395);
+        assert_eq!(batch.column(2).as_string::<i32>().value(0), "213");
+    }
+
+    #[tokio::test]
+    async fn test_point_to_tile_crs() {
+        let ctx = context();
+        let df = ctx
+            .sql("SELECT tile_x(ST_Point(-13627665.27, 4547675.35, 3857), 10), tile_y(ST_Point(-13627665.27, 4547675.35, 3857), 10);")
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        assert_eq!(batch.column(0).as_primitive::<UInt32Type>().value(0), 163);
+        assert_eq!(batch.column(1).as_primitive::<UInt32Type>().value(0), 395);
+
+        assert!(
+            ctx.sql("SELECT tile_x(ST_Point(530000.0, 180000.0, 27700), 10);")
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tile_envelope() {
+        let ctx = context();
+        let df = ctx.sql("SELECT ST_TileEnvelope(1, 1, 0);").await.unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        let field = batch.schema_ref().field(0).clone();
+        let rects = RectArray::try_from((batch.column(0).as_ref(), &field)).unwrap();
+        let rect = rects.value(0).unwrap();
+        assert_eq!(rect.min().x(), 0.0);
+        assert_eq!(rect.min().y(), 0.0);
+        assert!((rect.max().x() - 20_037_508.342_789_244).abs() < 1e-6);
+
+        assert!(
+            ctx.sql("SELECT ST_TileEnvelope(1, 2, 0);")
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tiles_covering() {
+        let ctx = context();
+        let df = ctx
+            .sql("SELECT tiles_covering(ST_GeomFromText('LINESTRING(-10 -20, 10 10)'), 1);")
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        let lists = batch.column(0).as_list::<i32>();
+        let tiles = lists.value(0);
+        let tiles = tiles.as_struct();
+        let x = tiles.column(0).as_primitive::<UInt32Type>();
+        let y = tiles.column(1).as_primitive::<UInt32Type>();
+        assert_eq!(x.values().to_vec(), vec![0, 1, 1]);
+        assert_eq!(y.values().to_vec(), vec![1, 0, 1]);
+    }
+}
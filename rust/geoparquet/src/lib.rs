@@ -6,5 +6,4 @@ pub mod metadata;
 pub mod reader;
 #[cfg(test)]
 mod test;
-mod total_bounds;
 pub mod writer;
@@ -96,7 +96,7 @@ impl Display for GeoParquetColumnEncoding {
 /// Note that this only defines the geometry type, not the dimension. The dimension is tracked
 /// separately, and stored together in [`GeoParquetGeometryTypeAndDimension`]. On that type the
 /// serde serialize and deserialize traits are implemented.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum GeoParquetGeometryType {
     /// Point geometry type
     Point,
@@ -255,6 +255,31 @@ impl GeoParquetGeometryTypeAndDimension {
     }
 }
 
+/// Geometry types are ordered first by geometry type, then by dimension (`XY`, `XYZ`, `XYM`,
+/// `XYZM`), so that they serialize in a deterministic order.
+impl Ord for GeoParquetGeometryTypeAndDimension {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        fn dimension_rank(dim: Dimension) -> u8 {
+            match dim {
+                Dimension::XY => 0,
+                Dimension::XYZ => 1,
+                Dimension::XYM => 2,
+                Dimension::XYZM => 3,
+            }
+        }
+
+        self.geometry_type
+            .cmp(&other.geometry_type)
+            .then_with(|| dimension_rank(self.dimension).cmp(&dimension_rank(other.dimension)))
+    }
+}
+
+impl PartialOrd for GeoParquetGeometryTypeAndDimension {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl FromStr for GeoParquetGeometryTypeAndDimension {
     type Err = GeoArrowError;
 
@@ -691,6 +716,9 @@ pub struct GeoParquetColumnMetadata {
     /// and multipolygons, it is not sufficient to specify `["MultiPolygon"]`, but it is expected
     /// to specify `["Polygon", "MultiPolygon"]`. Or if having 3D points, it is not sufficient to
     /// specify `["Point"]`, but it is expected to list `["Point Z"]`.
+    ///
+    /// Geometry types are always serialized in sorted order.
+    #[serde(serialize_with = "serialize_sorted_geometry_types")]
     pub geometry_types: HashSet<GeoParquetGeometryTypeAndDimension>,
 
     /// [PROJJSON](https://proj.org/specifications/projjson.html) object representing the
@@ -732,6 +760,15 @@ pub struct GeoParquetColumnMetadata {
     pub covering: Option<GeoParquetCovering>,
 }
 
+fn serialize_sorted_geometry_types<S: serde::Serializer>(
+    geometry_types: &HashSet<GeoParquetGeometryTypeAndDimension>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut geometry_types = geometry_types.iter().collect::<Vec<_>>();
+    geometry_types.sort();
+    serializer.collect_seq(geometry_types)
+}
+
 impl GeoParquetColumnMetadata {
     /// Get the bounding box covering for this geometry column.
     ///
@@ -751,22 +788,21 @@ impl GeoParquetColumnMetadata {
     }
 }
 
-impl From<GeoParquetColumnMetadata> for Metadata {
-    fn from(value: GeoParquetColumnMetadata) -> Self {
-        let edges = if let Some(edges) = value.edges {
-            if edges.as_str() == "spherical" {
-                Some(Edges::Spherical)
-            } else {
-                None
+impl TryFrom<GeoParquetColumnMetadata> for Metadata {
+    type Error = GeoArrowError;
+
+    fn try_from(value: GeoParquetColumnMetadata) -> Result<Self, Self::Error> {
+        let edges = match value.edges.as_deref() {
+            None | Some("planar") => None,
+            Some("spherical") => Some(Edges::Spherical),
+            Some(edges) => {
+                return Err(GeoArrowError::GeoParquet(format!(
+                    "Unknown edges value: {edges:?}. Expected \"planar\" or \"spherical\"."
+                )));
             }
-        } else {
-            None
         };
-        if let Some(crs) = value.crs {
-            Metadata::new(Crs::from_projjson(crs), edges)
-        } else {
-            Metadata::default()
-        }
+        let crs = value.crs.map(Crs::from_projjson).unwrap_or_default();
+        Ok(Metadata::new(crs, edges))
     }
 }
 
@@ -882,4 +918,38 @@ mod test {
 
         dbg!(&meta);
     }
+
+    #[test]
+    fn geometry_types_serialize_sorted() {
+        let s = r#"{
+            "encoding": "WKB",
+            "geometry_types": ["MultiPolygon", "Point Z", "Polygon", "Point"]
+        }"#;
+        let meta: GeoParquetColumnMetadata = serde_json::from_str(s).unwrap();
+        let value = serde_json::to_value(&meta).unwrap();
+        assert_eq!(
+            value["geometry_types"],
+            serde_json::json!(["Point", "Point Z", "Polygon", "MultiPolygon"])
+        );
+    }
+
+    #[test]
+    fn edges_to_geoarrow_metadata() {
+        let s = r#"{
+            "encoding": "WKB",
+            "geometry_types": [],
+            "edges": "spherical"
+        }"#;
+        let meta: GeoParquetColumnMetadata = serde_json::from_str(s).unwrap();
+        let geoarrow_meta = Metadata::try_from(meta).unwrap();
+        assert_eq!(geoarrow_meta.edges(), Some(Edges::Spherical));
+
+        let s = r#"{
+            "encoding": "WKB",
+            "geometry_types": [],
+            "edges": "geodesic"
+        }"#;
+        let meta: GeoParquetColumnMetadata = serde_json::from_str(s).unwrap();
+        assert!(Metadata::try_from(meta).is_err());
+    }
 }
@@ -1,12 +1,14 @@
 use arrow_schema::SchemaRef;
-use geo_types::Rect;
+use geo_traits::RectTrait;
 use geoarrow_schema::CoordType;
 use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
-use parquet::arrow::arrow_reader::{ArrowPredicate, ArrowReaderBuilder, RowFilter};
+use parquet::arrow::arrow_reader::{ArrowPredicate, ArrowReaderBuilder, RowFilter, RowSelection};
 
 use crate::metadata::GeoParquetMetadata;
 use crate::reader::parse::infer_geoarrow_schema;
-use crate::reader::spatial_filter::{ParquetBboxStatistics, bbox_arrow_predicate, bbox_row_groups};
+use crate::reader::spatial_filter::{
+    ParquetBboxStatistics, bbox_arrow_predicate, bbox_row_groups, bbox_row_selection, query_bounds,
+};
 
 /// A trait that extends the [`ArrowReaderBuilder`] with methods for reading GeoParquet files.
 ///
@@ -52,12 +54,13 @@ pub trait GeoParquetReaderBuilder: Sized {
     /// [`RowFilter`] to [`ArrowReaderBuilder::with_row_filter`].
     ///
     /// Note that the `bbox` must be in the same coordinate system as the geometries in the
-    /// designated geometry column.
+    /// designated geometry column. A `bbox` whose min x is greater than its max x is interpreted
+    /// as wrapping across the antimeridian.
     ///
     /// If `column_name` is `None`, the primary geometry column will be used.
     fn intersecting_arrow_predicate(
         &self,
-        bbox: Rect,
+        bbox: impl RectTrait<T = f64>,
         geo_metadata: &GeoParquetMetadata,
         column_name: Option<&str>,
     ) -> GeoArrowResult<Box<dyn ArrowPredicate>>;
@@ -72,7 +75,7 @@ pub trait GeoParquetReaderBuilder: Sized {
     /// If `column_name` is `None`, the primary geometry column will be used.
     fn with_intersecting_row_filter(
         self,
-        bbox: Rect,
+        bbox: impl RectTrait<T = f64>,
         geo_metadata: &GeoParquetMetadata,
         column_name: Option<&str>,
     ) -> GeoArrowResult<Self>;
@@ -80,12 +83,18 @@ pub trait GeoParquetReaderBuilder: Sized {
     /// Find the row groups that intersect with the bounding box.
     ///
     /// Note that the `bbox` must be in the same coordinate system as the geometries in the
-    /// designated geometry column.
+    /// designated geometry column. A `bbox` whose min x is greater than its max x is interpreted
+    /// as wrapping across the antimeridian. For columns with spherical edges, row groups whose
+    /// geometries may cross the antimeridian are handled conservatively.
+    ///
+    /// Native line and polygon encodings with spherical edges are never pruned: the great circle
+    /// arcs between coordinates can extend beyond the coordinates' statistics. The row filter of
+    /// [`Self::with_intersecting_row_filter`] still applies the spherical bounds of each row.
     ///
     /// If `column_name` is `None`, the primary geometry column will be used.
     fn intersecting_row_groups(
         &self,
-        bbox: Rect,
+        bbox: impl RectTrait<T = f64>,
         geo_metadata: &GeoParquetMetadata,
         column_name: Option<&str>,
     ) -> GeoArrowResult<Vec<usize>>;
@@ -98,7 +107,41 @@ pub trait GeoParquetReaderBuilder: Sized {
     /// If `column_name` is `None`, the primary geometry column will be used.
     fn with_intersecting_row_groups(
         self,
-        bbox: Rect,
+        bbox: impl RectTrait<T = f64>,
+        geo_metadata: &GeoParquetMetadata,
+        column_name: Option<&str>,
+    ) -> GeoArrowResult<Self>;
+
+    /// Find the rows within `row_groups` whose data pages may intersect the bounding box.
+    ///
+    /// This uses the Parquet page index (column index and offset index) of the bounding box
+    /// covering columns, or of the coordinate columns of a native encoding. The page index is only
+    /// available when the metadata was loaded with
+    /// [`ArrowReaderOptions::with_page_index`][parquet::arrow::arrow_reader::ArrowReaderOptions::with_page_index];
+    /// otherwise `None` is returned. As for [`Self::intersecting_row_groups`], pages of native
+    /// line and polygon encodings with spherical edges are never pruned.
+    ///
+    /// The returned [`RowSelection`] is relative to the rows of `row_groups`, so it must be
+    /// applied together with the same row group selection.
+    ///
+    /// If `column_name` is `None`, the primary geometry column will be used.
+    fn intersecting_row_selection(
+        &self,
+        bbox: impl RectTrait<T = f64>,
+        row_groups: &[usize],
+        geo_metadata: &GeoParquetMetadata,
+        column_name: Option<&str>,
+    ) -> GeoArrowResult<Option<RowSelection>>;
+
+    /// Select row groups and, if the page index was loaded, pages to read based on the bounding
+    /// box.
+    ///
+    /// Note that this will **replace** any existing row group and row selection.
+    ///
+    /// If `column_name` is `None`, the primary geometry column will be used.
+    fn with_intersecting_pages(
+        self,
+        bbox: impl RectTrait<T = f64>,
         geo_metadata: &GeoParquetMetadata,
         column_name: Option<&str>,
     ) -> GeoArrowResult<Self>;
@@ -120,7 +163,7 @@ impl<T> GeoParquetReaderBuilder for ArrowReaderBuilder<T> {
 
     fn intersecting_arrow_predicate(
         &self,
-        bbox: Rect,
+        bbox: impl RectTrait<T = f64>,
         geo_metadata: &GeoParquetMetadata,
         column_name: Option<&str>,
     ) -> GeoArrowResult<Box<dyn ArrowPredicate>> {
@@ -132,14 +175,15 @@ impl<T> GeoParquetReaderBuilder for ArrowReaderBuilder<T> {
                     "No covering metadata found for column: {column_name}",
                 )))?;
 
-        let bbox_cols = ParquetBboxStatistics::try_new(self.parquet_schema(), &bbox_covering)?;
+        let bbox_cols =
+            ParquetBboxStatistics::try_new(self.parquet_schema(), &bbox_covering, column_meta)?;
 
-        bbox_arrow_predicate(self.parquet_schema(), bbox_cols, bbox)
+        bbox_arrow_predicate(self.parquet_schema(), bbox_cols, &bbox)
     }
 
     fn with_intersecting_row_filter(
         self,
-        bbox: Rect,
+        bbox: impl RectTrait<T = f64>,
         geo_metadata: &GeoParquetMetadata,
         column_name: Option<&str>,
     ) -> GeoArrowResult<Self> {
@@ -149,7 +193,7 @@ impl<T> GeoParquetReaderBuilder for ArrowReaderBuilder<T> {
 
     fn intersecting_row_groups(
         &self,
-        bbox: Rect,
+        bbox: impl RectTrait<T = f64>,
         geo_metadata: &GeoParquetMetadata,
         column_name: Option<&str>,
     ) -> GeoArrowResult<Vec<usize>> {
@@ -161,18 +205,63 @@ impl<T> GeoParquetReaderBuilder for ArrowReaderBuilder<T> {
                     "No covering metadata found for column: {column_name}",
                 )))?;
 
-        let bbox_cols = ParquetBboxStatistics::try_new(self.parquet_schema(), &bbox_covering)?;
+        let bbox_cols =
+            ParquetBboxStatistics::try_new(self.parquet_schema(), &bbox_covering, column_meta)?;
 
-        bbox_row_groups(self.metadata().row_groups(), &bbox_cols, bbox)
+        bbox_row_groups(self.metadata().row_groups(), &bbox_cols, &bbox)
     }
 
     fn with_intersecting_row_groups(
         self,
-        bbox: Rect,
+        bbox: impl RectTrait<T = f64>,
         geo_metadata: &GeoParquetMetadata,
         column_name: Option<&str>,
     ) -> GeoArrowResult<Self> {
         let row_groups = self.intersecting_row_groups(bbox, geo_metadata, column_name)?;
         Ok(self.with_row_groups(row_groups))
     }
+
+    fn intersecting_row_selection(
+        &self,
+        bbox: impl RectTrait<T = f64>,
+        row_groups: &[usize],
+        geo_metadata: &GeoParquetMetadata,
+        column_name: Option<&str>,
+    ) -> GeoArrowResult<Option<RowSelection>> {
+        let (column_name, column_meta) = geo_metadata.geometry_column(column_name)?;
+        let bbox_covering =
+            column_meta
+                .bbox_covering(column_name)
+                .ok_or(GeoArrowError::GeoParquet(format!(
+                    "No covering metadata found for column: {column_name}",
+                )))?;
+
+        let bbox_cols =
+            ParquetBboxStatistics::try_new(self.parquet_schema(), &bbox_covering, column_meta)?;
+
+        Ok(bbox_row_selection(
+            self.metadata(),
+            row_groups,
+            &bbox_cols,
+            &bbox,
+        ))
+    }
+
+    fn with_intersecting_pages(
+        self,
+        bbox: impl RectTrait<T = f64>,
+        geo_metadata: &GeoParquetMetadata,
+        column_name: Option<&str>,
+    ) -> GeoArrowResult<Self> {
+        let bbox = query_bounds(&bbox);
+        let row_groups = self.intersecting_row_groups(bbox, geo_metadata, column_name)?;
+        let selection =
+            self.intersecting_row_selection(bbox, &row_groups, geo_metadata, column_name)?;
+        let builder = self.with_row_groups(row_groups);
+        if let Some(selection) = selection {
+            Ok(builder.with_row_selection(selection))
+        } else {
+            Ok(builder)
+        }
+    }
 }
@@ -146,6 +146,10 @@ impl GeoParquetReaderMetadata {
     ///
     /// As of GeoParquet 1.1 you won't need to pass in these column names, as they'll be specified
     /// in the metadata.
+    ///
+    /// Bounds that wrap across the antimeridian are widened to span all longitudes. Use
+    /// [`Self::row_groups_bounds`] to get wrapping bounds with `xmin > xmax`. `None` is returned
+    /// if the row group doesn't have statistics for the covering columns.
     pub fn row_group_bounds(
         &self,
         row_group_idx: usize,
@@ -158,10 +162,25 @@ impl GeoParquetReaderMetadata {
                 .ok_or(GeoArrowError::GeoParquet(format!(
                     "No covering metadata found for column: {column_name}",
                 )))?;
-        let geo_statistics =
-            ParquetBboxStatistics::try_new(self.meta.parquet_schema(), &bbox_covering)?;
+        let geo_statistics = ParquetBboxStatistics::try_new(
+            self.meta.parquet_schema(),
+            &bbox_covering,
+            column_meta,
+        )?;
         let row_group_meta = self.meta.metadata().row_group(row_group_idx);
-        Ok(Some(geo_statistics.get_bbox(row_group_meta)?))
+        let Some(bbox) = geo_statistics.get_bbox(row_group_meta)? else {
+            return Ok(None);
+        };
+        // A [`geo_types::Rect`] can't represent bounds that wrap across the antimeridian
+        let (minx, maxx) = if bbox.minx() > bbox.maxx() {
+            (-180.0, 180.0)
+        } else {
+            (bbox.minx(), bbox.maxx())
+        };
+        Ok(Some(geo_types::Rect::new(
+            geo_types::coord! { x: minx, y: bbox.miny() },
+            geo_types::coord! { x: maxx, y: bbox.maxy() },
+        )))
     }
 
     /// Get the bounds of all row groups.
@@ -177,11 +196,14 @@ impl GeoParquetReaderMetadata {
                     "No covering metadata found for column: {column_name}",
                 )))?;
 
-        let geo_statistics =
-            ParquetBboxStatistics::try_new(self.meta.parquet_schema(), &bbox_covering)?;
+        let geo_statistics = ParquetBboxStatistics::try_new(
+            self.meta.parquet_schema(),
+            &bbox_covering,
+            column_meta,
+        )?;
         geo_statistics.get_bboxes(
             self.meta.metadata().row_groups(),
-            Arc::new(column_meta.clone().into()),
+            Arc::new(column_meta.clone().try_into()?),
         )
     }
 
@@ -202,7 +224,7 @@ impl GeoParquetReaderMetadata {
     /// Access the GeoArrow [`Metadata`] from the provided geometry column.
     pub fn geoarrow_metadata(&self, column_name: Option<&str>) -> GeoArrowResult<Metadata> {
         let (_, column_meta) = self.geo_meta.geometry_column(column_name)?;
-        Ok(column_meta.clone().into())
+        column_meta.clone().try_into()
     }
 
     /// Access the Coordinate Reference System (CRS) of the given column
@@ -326,7 +348,7 @@ impl GeoParquetDatasetMetadata {
     /// Access the GeoArrow [`Metadata`] from the provided geometry column.
     pub fn geoarrow_metadata(&self, column_name: Option<&str>) -> GeoArrowResult<Metadata> {
         let (_, column_meta) = self.geo_meta.geometry_column(column_name)?;
-        Ok(column_meta.clone().into())
+        column_meta.clone().try_into()
     }
 
     /// Access the Coordinate Reference System (CRS) of the given column
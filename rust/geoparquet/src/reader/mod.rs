@@ -4,6 +4,8 @@
 mod r#async;
 mod geo_ext;
 mod metadata;
+#[cfg(feature = "object_store")]
+mod object_store_reader;
 mod parse;
 mod spatial_filter;
 mod sync;
@@ -12,4 +14,6 @@ mod sync;
 pub use r#async::GeoParquetRecordBatchStream;
 pub use geo_ext::GeoParquetReaderBuilder;
 pub use metadata::{GeoParquetDatasetMetadata, GeoParquetReaderMetadata};
+#[cfg(feature = "object_store")]
+pub use object_store_reader::{GeoParquetObjectStoreReader, GeoParquetObjectStoreReaderBuilder};
 pub use sync::GeoParquetRecordBatchReader;
@@ -0,0 +1,502 @@
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::stream::{self, BoxStream};
+use futures::{StreamExt, TryStreamExt};
+use geo_traits::RectTrait;
+use geoarrow_geo::BoundsAccumulator;
+use geoarrow_schema::CoordType;
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use indexmap::IndexMap;
+use object_store::path::Path;
+use object_store::{ObjectMeta, ObjectStore};
+use parquet::arrow::arrow_reader::ArrowReaderMetadata;
+use parquet::arrow::async_reader::{ParquetObjectReader, ParquetRecordBatchStreamBuilder};
+
+use crate::metadata::GeoParquetMetadata;
+use crate::reader::spatial_filter::{
+    ParquetBboxStatistics, bbox_row_groups, query_bounds, rect_intersects,
+};
+use crate::reader::{
+    GeoParquetDatasetMetadata, GeoParquetReaderBuilder, GeoParquetRecordBatchReader,
+    GeoParquetRecordBatchStream,
+};
+
+/// The default number of footers or row groups fetched at once.
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// An asynchronous reader for a GeoParquet dataset, i.e. a collection of GeoParquet files with
+/// the same schema, stored in an [`ObjectStore`].
+///
+/// Footers of all files are fetched concurrently when the reader is created, to build a
+/// [`GeoParquetDatasetMetadata`]. When reading, files and row groups that don't intersect the
+/// query bounding box (if any) are skipped, and the remaining row groups are fetched and decoded
+/// with bounded parallelism.
+pub struct GeoParquetObjectStoreReader {
+    store: Arc<dyn ObjectStore>,
+    metadata: GeoParquetDatasetMetadata,
+    /// The size in bytes of each file, if known, keyed by path.
+    file_sizes: IndexMap<String, Option<u64>>,
+    bbox: Option<BoundsAccumulator>,
+    bbox_column: Option<String>,
+    batch_size: Option<usize>,
+    concurrency: usize,
+    parse_to_native: bool,
+    coord_type: CoordType,
+}
+
+/// A builder for [`GeoParquetObjectStoreReader`], configuring how the dataset is discovered and
+/// how many requests are made at once.
+pub struct GeoParquetObjectStoreReaderBuilder {
+    store: Arc<dyn ObjectStore>,
+    concurrency: usize,
+}
+
+impl GeoParquetObjectStoreReaderBuilder {
+    /// Create a new builder reading from the given store.
+    pub fn new(store: Arc<dyn ObjectStore>) -> Self {
+        Self {
+            store,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Set the maximum number of footers, and later row groups, to fetch at once.
+    ///
+    /// Defaults to 8.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Create a reader for the GeoParquet files at the given paths.
+    pub async fn build_from_paths(
+        self,
+        paths: impl IntoIterator<Item = Path>,
+    ) -> GeoArrowResult<GeoParquetObjectStoreReader> {
+        let files = paths.into_iter().map(|path| (path, None)).collect();
+        GeoParquetObjectStoreReader::try_new(self.store, files, self.concurrency).await
+    }
+
+    /// Create a reader for all GeoParquet files under the given prefix.
+    ///
+    /// The prefix is listed recursively, so this can be used with partitioned datasets. Only
+    /// files with a `.parquet` extension are included.
+    pub async fn build_from_prefix(
+        self,
+        prefix: Option<&Path>,
+    ) -> GeoArrowResult<GeoParquetObjectStoreReader> {
+        let mut objects: Vec<ObjectMeta> = self
+            .store
+            .list(prefix)
+            .try_filter(|object| {
+                futures::future::ready(object.location.extension() == Some("parquet"))
+            })
+            .try_collect()
+            .await
+            .map_err(|err| GeoArrowError::External(Box::new(err)))?;
+        objects.sort_by(|a, b| a.location.cmp(&b.location));
+
+        let files = objects
+            .into_iter()
+            .map(|object| (object.location, Some(object.size)))
+            .collect();
+        GeoParquetObjectStoreReader::try_new(self.store, files, self.concurrency).await
+    }
+}
+
+impl std::fmt::Debug for GeoParquetObjectStoreReaderBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeoParquetObjectStoreReaderBuilder")
+            .field("store", &self.store)
+            .field("concurrency", &self.concurrency)
+            .finish()
+    }
+}
+
+impl GeoParquetObjectStoreReader {
+    /// Create a new reader for the GeoParquet files at the given paths, with the default
+    /// concurrency.
+    ///
+    /// Use [`GeoParquetObjectStoreReaderBuilder`] to configure the concurrency.
+    pub async fn try_new_from_paths(
+        store: Arc<dyn ObjectStore>,
+        paths: impl IntoIterator<Item = Path>,
+    ) -> GeoArrowResult<Self> {
+        GeoParquetObjectStoreReaderBuilder::new(store)
+            .build_from_paths(paths)
+            .await
+    }
+
+    /// Create a new reader for all GeoParquet files under the given prefix, with the default
+    /// concurrency.
+    ///
+    /// Use [`GeoParquetObjectStoreReaderBuilder`] to configure the concurrency.
+    pub async fn try_new_from_prefix(
+        store: Arc<dyn ObjectStore>,
+        prefix: Option<&Path>,
+    ) -> GeoArrowResult<Self> {
+        GeoParquetObjectStoreReaderBuilder::new(store)
+            .build_from_prefix(prefix)
+            .await
+    }
+
+    async fn try_new(
+        store: Arc<dyn ObjectStore>,
+        files: Vec<(Path, Option<u64>)>,
+        concurrency: usize,
+    ) -> GeoArrowResult<Self> {
+        let metas: Vec<(String, Option<u64>, ArrowReaderMetadata)> = stream::iter(files)
+            .map(|(path, file_size)| {
+                let store = store.clone();
+                async move {
+                    let mut reader = object_reader(store, path.clone(), file_size);
+                    let meta = ArrowReaderMetadata::load_async(&mut reader, Default::default())
+                        .await
+                        .map_err(|err| GeoArrowError::External(Box::new(err)))?;
+                    Ok::<_, GeoArrowError>((path.to_string(), file_size, meta))
+                }
+            })
+            .buffered(concurrency)
+            .try_collect()
+            .await?;
+
+        let mut file_sizes = IndexMap::with_capacity(metas.len());
+        let mut file_metas = IndexMap::with_capacity(metas.len());
+        for (path, file_size, meta) in metas {
+            file_sizes.insert(path.clone(), file_size);
+            file_metas.insert(path, meta);
+        }
+
+        Ok(Self {
+            store,
+            metadata: GeoParquetDatasetMetadata::from_files(file_metas)?,
+            file_sizes,
+            bbox: None,
+            bbox_column: None,
+            batch_size: None,
+            concurrency,
+            parse_to_native: true,
+            coord_type: CoordType::default(),
+        })
+    }
+
+    /// Access the metadata of the dataset.
+    pub fn metadata(&self) -> &GeoParquetDatasetMetadata {
+        &self.metadata
+    }
+
+    /// Only read files, row groups, and rows that intersect with the bounding box.
+    ///
+    /// Rows are only filtered individually when the geometry column has a bounding box covering
+    /// or a native encoding. A `bbox` whose min x is greater than its max x is interpreted as
+    /// wrapping across the antimeridian.
+    ///
+    /// If `column_name` is `None`, the primary geometry column will be used.
+    pub fn with_bbox(mut self, bbox: impl RectTrait<T = f64>, column_name: Option<&str>) -> Self {
+        self.bbox = Some(query_bounds(&bbox));
+        self.bbox_column = column_name.map(|name| name.to_string());
+        self
+    }
+
+    /// Set the size of [`RecordBatch`]es to produce.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Set whether geometries should be parsed to their GeoArrow-native representation, and the
+    /// coordinate type to use if so.
+    ///
+    /// Defaults to parsing to native geometries with the default [`CoordType`].
+    pub fn with_parse_to_native(mut self, parse_to_native: bool, coord_type: CoordType) -> Self {
+        self.parse_to_native = parse_to_native;
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// The GeoArrow schema of the [`RecordBatch`]es produced by this reader.
+    pub fn schema(&self) -> GeoArrowResult<SchemaRef> {
+        self.metadata
+            .geoarrow_schema(self.parse_to_native, self.coord_type)
+    }
+
+    /// The row groups of each file that intersect the bounding box, in file order.
+    ///
+    /// Files whose bounding box doesn't intersect are omitted. If no bounding box was set, every
+    /// row group of every file is returned.
+    pub fn intersecting_row_groups(&self) -> GeoArrowResult<Vec<(String, Vec<usize>)>> {
+        let mut output = vec![];
+        for (path, meta) in self.metadata.files() {
+            let all_row_groups = (0..meta.metadata().num_row_groups()).collect();
+            let geo_meta = GeoParquetMetadata::from_parquet_meta(meta.metadata().file_metadata())
+                .transpose()?;
+            let row_groups = match (&self.bbox, geo_meta) {
+                (Some(bbox), Some(geo_meta)) => {
+                    let Some(row_groups) =
+                        file_row_groups(meta, &geo_meta, bbox, self.bbox_column())?
+                    else {
+                        continue;
+                    };
+                    row_groups
+                }
+                _ => all_row_groups,
+            };
+            if !row_groups.is_empty() {
+                output.push((path.clone(), row_groups));
+            }
+        }
+        Ok(output)
+    }
+
+    /// Read the dataset as a stream of [`RecordBatch`]es.
+    ///
+    /// Up to `concurrency` row groups are fetched at once. The batches of each row group are
+    /// decoded and emitted as the stream is polled, rather than after the whole row group has
+    /// been decoded. Batches are emitted in file and row group order.
+    pub fn read(self) -> GeoArrowResult<BoxStream<'static, Result<RecordBatch, ArrowError>>> {
+        let target_schema = self.schema()?;
+        let row_groups = self.intersecting_row_groups()?;
+
+        let mut tasks = vec![];
+        for (path, row_groups) in row_groups {
+            let meta = self.metadata.files()[&path].clone();
+            let file_size = self.file_sizes[&path];
+            for row_group in row_groups {
+                tasks.push(RowGroupTask {
+                    store: self.store.clone(),
+                    path: Path::from(path.as_str()),
+                    file_size,
+                    meta: meta.clone(),
+                    row_group,
+                    target_schema: target_schema.clone(),
+                    bbox: self.bbox,
+                    bbox_column: self.bbox_column.clone(),
+                    batch_size: self.batch_size,
+                });
+            }
+        }
+
+        let stream = stream::iter(tasks)
+            .map(RowGroupTask::read)
+            .buffered(self.concurrency)
+            .map_err(ArrowError::from)
+            .map_ok(|reader| stream::iter(reader.into_iter().flatten()))
+            .try_flatten();
+        Ok(stream.boxed())
+    }
+
+    fn bbox_column(&self) -> Option<&str> {
+        self.bbox_column.as_deref()
+    }
+}
+
+/// The work of reading a single row group.
+struct RowGroupTask {
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+    file_size: Option<u64>,
+    meta: ArrowReaderMetadata,
+    row_group: usize,
+    target_schema: SchemaRef,
+    bbox: Option<BoundsAccumulator>,
+    bbox_column: Option<String>,
+    batch_size: Option<usize>,
+}
+
+impl RowGroupTask {
+    /// Fetch the row group's data, returning a reader that decodes it lazily.
+    async fn read(self) -> GeoArrowResult<Option<GeoParquetRecordBatchReader>> {
+        let reader = object_reader(self.store, self.path, self.file_size);
+        let mut builder = ParquetRecordBatchStreamBuilder::new_with_metadata(reader, self.meta)
+            .with_row_groups(vec![self.row_group]);
+        if let Some(batch_size) = self.batch_size {
+            builder = builder.with_batch_size(batch_size);
+        }
+
+        if let Some(bbox) = self.bbox {
+            if let Some(geo_meta) = builder.geoparquet_metadata().transpose()? {
+                let (column_name, column_meta) =
+                    geo_meta.geometry_column(self.bbox_column.as_deref())?;
+                if column_meta.bbox_covering(column_name).is_some() {
+                    builder = builder.with_intersecting_row_filter(
+                        bbox,
+                        &geo_meta,
+                        self.bbox_column.as_deref(),
+                    )?;
+                }
+            }
+        }
+
+        let stream = builder
+            .build()
+            .map_err(|err| GeoArrowError::External(Box::new(err)))?;
+        let mut stream = GeoParquetRecordBatchStream::try_new(stream, self.target_schema)?;
+        stream.next_row_group().await
+    }
+}
+
+fn object_reader(
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+    file_size: Option<u64>,
+) -> ParquetObjectReader {
+    let reader = ParquetObjectReader::new(store, path);
+    if let Some(file_size) = file_size {
+        reader.with_file_size(file_size)
+    } else {
+        reader
+    }
+}
+
+/// The row groups of a single file that intersect the bounding box, or `None` if the file's
+/// bounding box doesn't intersect it at all.
+fn file_row_groups(
+    meta: &ArrowReaderMetadata,
+    geo_meta: &GeoParquetMetadata,
+    bbox: &BoundsAccumulator,
+    column_name: Option<&str>,
+) -> GeoArrowResult<Option<Vec<usize>>> {
+    let (column_name, column_meta) = geo_meta.geometry_column(column_name)?;
+
+    if let Some(file_bbox) = column_meta.bbox.as_ref().filter(|b| b.len() >= 4) {
+        let half = file_bbox.len() / 2;
+        let file_bbox = BoundsAccumulator::from_xy(
+            file_bbox[0],
+            file_bbox[1],
+            file_bbox[half],
+            file_bbox[half + 1],
+        );
+        if !rect_intersects(&file_bbox, bbox) {
+            return Ok(None);
+        }
+    }
+
+    let Some(bbox_covering) = column_meta.bbox_covering(column_name) else {
+        return Ok(Some((0..meta.metadata().num_row_groups()).collect()));
+    };
+    let bbox_cols =
+        ParquetBboxStatistics::try_new(meta.parquet_schema(), &bbox_covering, column_meta)?;
+    Ok(Some(bbox_row_groups(
+        meta.metadata().row_groups(),
+        &bbox_cols,
+        bbox,
+    )?))
+}
+
+impl std::fmt::Debug for GeoParquetObjectStoreReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeoParquetObjectStoreReader")
+            .field("store", &self.store)
+            .field("files", &self.file_sizes.keys().collect::<Vec<_>>())
+            .field("bbox", &self.bbox)
+            .field("bbox_column", &self.bbox_column)
+            .field("batch_size", &self.batch_size)
+            .field("concurrency", &self.concurrency)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use geo_types::{Rect, coord, point};
+    use geoarrow_array::GeoArrowArray;
+    use geoarrow_array::builder::PointBuilder;
+    use geoarrow_schema::{Dimension, PointType};
+    use object_store::PutPayload;
+    use object_store::memory::InMemory;
+    use parquet::file::properties::WriterProperties;
+
+    use super::*;
+    use crate::writer::{GeoParquetWriter, GeoParquetWriterOptionsBuilder};
+
+    /// Write a GeoParquet file of points along the x axis, starting at `x_offset`, with two
+    /// points per row group.
+    fn write_points(x_offset: f64) -> Vec<u8> {
+        let points = (0..4)
+            .map(|i| point!(x: x_offset + i as f64, y: 0.0))
+            .collect::<Vec<_>>();
+        let typ = PointType::new(Dimension::XY, Default::default());
+        let array = PointBuilder::from_points(points.iter(), typ).finish();
+        let schema = Arc::new(Schema::new(vec![
+            array.data_type().to_field("geometry", true),
+        ]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![array.to_array_ref()]).unwrap();
+
+        let options = GeoParquetWriterOptionsBuilder::default()
+            .set_generate_covering(true)
+            .build();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .build();
+
+        let mut buf = vec![];
+        let mut writer =
+            GeoParquetWriter::try_new(&mut buf, &schema, &options, Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        buf
+    }
+
+    async fn partitioned_store() -> Arc<dyn ObjectStore> {
+        let store = Arc::new(InMemory::new());
+        for (partition, x_offset) in [(0, 0.0), (1, 100.0)] {
+            let path = Path::from(format!("dataset/part={partition}/data.parquet"));
+            store
+                .put(&path, PutPayload::from(write_points(x_offset)))
+                .await
+                .unwrap();
+        }
+        store
+            .put(
+                &Path::from("dataset/_SUCCESS"),
+                PutPayload::from_static(b""),
+            )
+            .await
+            .unwrap();
+        store
+    }
+
+    #[tokio::test]
+    async fn read_partitioned_dataset() {
+        let store = partitioned_store().await;
+        let reader =
+            GeoParquetObjectStoreReader::try_new_from_prefix(store, Some(&Path::from("dataset")))
+                .await
+                .unwrap();
+        assert_eq!(reader.metadata().files().len(), 2);
+        assert_eq!(reader.metadata().num_rows(), 8);
+
+        let batches: Vec<RecordBatch> = reader.read().unwrap().try_collect().await.unwrap();
+        let num_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(num_rows, 8);
+    }
+
+    #[tokio::test]
+    async fn read_with_bbox() {
+        let store = partitioned_store().await;
+        let paths = ["dataset/part=0/data.parquet", "dataset/part=1/data.parquet"]
+            .into_iter()
+            .map(Path::from);
+        let bbox = Rect::new(coord! { x: 100.5, y: -1.0 }, coord! { x: 101.5, y: 1.0 });
+        let reader = GeoParquetObjectStoreReaderBuilder::new(store)
+            .with_concurrency(2)
+            .build_from_paths(paths)
+            .await
+            .unwrap()
+            .with_bbox(bbox, None);
+
+        let row_groups = reader.intersecting_row_groups().unwrap();
+        assert_eq!(
+            row_groups,
+            vec![("dataset/part=1/data.parquet".to_string(), vec![0])]
+        );
+
+        let batches: Vec<RecordBatch> = reader.read().unwrap().try_collect().await.unwrap();
+        let num_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(num_rows, 1);
+    }
+}
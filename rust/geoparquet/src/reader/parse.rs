@@ -61,7 +61,7 @@ fn infer_target_field(
     parse_to_native: bool,
     coord_type: CoordType,
 ) -> GeoArrowResult<FieldRef> {
-    let metadata = Arc::new(Metadata::from(column_meta.clone()));
+    let metadata = Arc::new(Metadata::try_from(column_meta.clone())?);
 
     let target_geo_data_type: GeoArrowType = match column_meta.encoding {
         GeoParquetColumnEncoding::WKB => {
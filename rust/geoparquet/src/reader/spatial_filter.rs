@@ -1,27 +1,42 @@
 use std::collections::HashSet;
 use std::fmt::Debug;
+use std::ops::Range;
 use std::sync::Arc;
 
+use arrow_arith::boolean::{and, or};
 use arrow_array::cast::AsArray;
 use arrow_array::types::{Float32Type, Float64Type};
-use arrow_array::{Array, Float32Array, Float64Array, Scalar};
+use arrow_array::{Array, BooleanArray, Float32Array, Float64Array, Scalar, StructArray};
 use arrow_buffer::ScalarBuffer;
-use arrow_ord::cmp::{gt_eq, lt_eq};
+use arrow_ord::cmp::{gt, gt_eq, lt_eq};
+use arrow_schema::ArrowError;
 use geo_traits::{CoordTrait, RectTrait};
-use geo_types::{CoordNum, Rect, coord};
-use geoarrow_array::GeoArrowArrayAccessor;
-use geoarrow_array::array::{RectArray, from_arrow_array};
+use geo_types::{Coord, LineString, Point, Polygon};
+use geoarrow_array::array::RectArray;
 use geoarrow_array::builder::RectBuilder;
+use geoarrow_geo::{BoundsAccumulator, SphericalBoundsAccumulator};
 use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 use geoarrow_schema::{BoxType, Dimension, Metadata};
 use parquet::arrow::ProjectionMask;
-use parquet::arrow::arrow_reader::{ArrowPredicate, ArrowPredicateFn};
-use parquet::file::metadata::{ColumnChunkMetaData, RowGroupMetaData};
+use parquet::arrow::arrow_reader::{ArrowPredicate, ArrowPredicateFn, RowSelection, RowSelector};
+use parquet::file::metadata::{ColumnChunkMetaData, ParquetMetaData, RowGroupMetaData};
+use parquet::file::page_index::index::Index;
+use parquet::file::page_index::offset_index::OffsetIndexMetaData;
 use parquet::file::statistics::Statistics;
 use parquet::schema::types::{ColumnPath, SchemaDescriptor};
 
-use crate::metadata::GeoParquetBboxCovering;
-use crate::total_bounds::bounding_rect;
+use crate::metadata::{GeoParquetBboxCovering, GeoParquetColumnEncoding, GeoParquetColumnMetadata};
+
+/// How the coordinates in the innermost lists of a native encoding are connected by edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NativeParts {
+    /// Unconnected points
+    Points,
+    /// Line strings
+    Lines,
+    /// Polygon rings
+    Rings,
+}
 
 /// A helper for interpreting bounding box row group statistics from GeoParquet files
 ///
@@ -52,6 +67,15 @@ pub(crate) struct ParquetBboxStatistics<'a> {
 
     /// The index of the Parquet column that contains the ymax
     maxy_col: usize,
+
+    /// Whether the geometry column has spherical edges
+    spherical: bool,
+
+    /// How the coordinates of a native encoding are connected
+    native_parts: NativeParts,
+
+    /// Whether any row's bounding box may wrap across the antimeridian, i.e. have `xmin > xmax`.
+    may_wrap: bool,
 }
 
 impl<'a> ParquetBboxStatistics<'a> {
@@ -59,12 +83,26 @@ impl<'a> ParquetBboxStatistics<'a> {
     pub(crate) fn try_new(
         parquet_schema: &SchemaDescriptor,
         bbox_covering: &'a GeoParquetBboxCovering,
+        column_meta: &GeoParquetColumnMetadata,
     ) -> GeoArrowResult<Self> {
         let mut minx_col: Option<usize> = None;
         let mut miny_col: Option<usize> = None;
         let mut maxx_col: Option<usize> = None;
         let mut maxy_col: Option<usize> = None;
 
+        // For native encodings, the covering paths are inferred using the conventional list field
+        // names. But writers may name intermediate list fields differently (e.g. after the GeoArrow
+        // field names), so we only compare the path length and its first and last parts.
+        let native =
+            bbox_covering.xmin == bbox_covering.xmax && bbox_covering.ymin == bbox_covering.ymax;
+        let matches_path = |a: &[String], b: &ColumnPath| {
+            if native {
+                native_path_equals(a, b)
+            } else {
+                path_equals(a, b)
+            }
+        };
+
         for (column_idx, column_meta) in parquet_schema.columns().iter().enumerate() {
             // If all column paths have been found, break from loop
             if minx_col.is_some() && miny_col.is_some() && maxx_col.is_some() && maxy_col.is_some()
@@ -75,19 +113,19 @@ impl<'a> ParquetBboxStatistics<'a> {
             // NOTE: we **don't** want to `continue` out of the loop after matching one of these
             // paths because in the native encoding case the same column can be _both_ the minx and
             // maxx column paths.
-            if minx_col.is_none() && path_equals(bbox_covering.xmin.as_ref(), column_meta.path()) {
+            if minx_col.is_none() && matches_path(&bbox_covering.xmin, column_meta.path()) {
                 minx_col = Some(column_idx);
             }
 
-            if miny_col.is_none() && path_equals(bbox_covering.ymin.as_ref(), column_meta.path()) {
+            if miny_col.is_none() && matches_path(&bbox_covering.ymin, column_meta.path()) {
                 miny_col = Some(column_idx);
             }
 
-            if maxx_col.is_none() && path_equals(bbox_covering.xmax.as_ref(), column_meta.path()) {
+            if maxx_col.is_none() && matches_path(&bbox_covering.xmax, column_meta.path()) {
                 maxx_col = Some(column_idx);
             }
 
-            if maxy_col.is_none() && path_equals(bbox_covering.ymax.as_ref(), column_meta.path()) {
+            if maxy_col.is_none() && matches_path(&bbox_covering.ymax, column_meta.path()) {
                 maxy_col = Some(column_idx);
             }
         }
@@ -120,6 +158,28 @@ impl<'a> ParquetBboxStatistics<'a> {
             )));
         }
 
+        // Spherical geometries that cross the antimeridian have wrapping bounding boxes. If the
+        // file-level bbox neither wraps nor spans every longitude, then no row can wrap.
+        let spherical = column_meta.edges.as_deref() == Some("spherical");
+        let native_parts = match column_meta.encoding {
+            GeoParquetColumnEncoding::LineString | GeoParquetColumnEncoding::MultiLineString => {
+                NativeParts::Lines
+            }
+            GeoParquetColumnEncoding::Polygon | GeoParquetColumnEncoding::MultiPolygon => {
+                NativeParts::Rings
+            }
+            _ => NativeParts::Points,
+        };
+        // Points don't have edges, so a native point encoding never wraps
+        let may_wrap = spherical
+            && !(native && native_parts == NativeParts::Points)
+            && !column_meta.bbox.as_ref().is_some_and(|bbox| {
+                bbox.len() >= 4 && {
+                    let (xmin, xmax) = (bbox[0], bbox[bbox.len() / 2]);
+                    xmin <= xmax && (xmin > -180.0 || xmax < 180.0)
+                }
+            });
+
         Ok(Self {
             minx_col_path: bbox_covering.xmin.as_slice(),
             miny_col_path: bbox_covering.ymin.as_slice(),
@@ -129,26 +189,71 @@ impl<'a> ParquetBboxStatistics<'a> {
             miny_col: miny_col.unwrap(),
             maxx_col: maxx_col.unwrap(),
             maxy_col: maxy_col.unwrap(),
+            spherical,
+            native_parts,
+            may_wrap,
         })
     }
 
+    /// Whether the bounding box columns are the geometry's own coordinates
+    fn is_native(&self) -> bool {
+        self.minx_col == self.maxx_col && self.miny_col == self.maxy_col
+    }
+
+    /// Whether the bounding box columns are native coordinates connected by great circle arcs.
+    ///
+    /// These arcs can bulge past the coordinates' own extents, so the coordinate statistics
+    /// don't bound the geometries.
+    fn has_spherical_edges(&self) -> bool {
+        self.spherical && self.is_native() && self.native_parts != NativeParts::Points
+    }
+
     /// Extract the bounding box from a given row group's metadata.
     ///
-    /// This uses the column statistics contained in the row group metadata.
-    pub(crate) fn get_bbox(&self, rg_meta: &RowGroupMetaData) -> GeoArrowResult<Rect> {
-        let (minx, _) = parse_statistics_f64(rg_meta.column(self.minx_col))?;
-        let (miny, _) = parse_statistics_f64(rg_meta.column(self.miny_col))?;
-        let (_, maxx) = parse_statistics_f64(rg_meta.column(self.maxx_col))?;
-        let (_, maxy) = parse_statistics_f64(rg_meta.column(self.maxy_col))?;
-        Ok(Rect::new(
-            coord! { x: minx, y: miny },
-            coord! {x: maxx, y: maxy},
-        ))
+    /// This uses the column statistics contained in the row group metadata. The returned box has
+    /// `minx > maxx` if it wraps across the antimeridian. `None` is returned if the row group
+    /// doesn't have statistics for all bounding box columns, or if the statistics don't bound the
+    /// geometries because they are native lines or polygons with spherical edges.
+    pub(crate) fn get_bbox(
+        &self,
+        rg_meta: &RowGroupMetaData,
+    ) -> GeoArrowResult<Option<BoundsAccumulator>> {
+        if self.has_spherical_edges() {
+            return Ok(None);
+        }
+
+        let (
+            Some((minx_lower, minx_upper)),
+            Some((miny, _)),
+            Some((maxx_lower, maxx_upper)),
+            Some((_, maxy)),
+        ) = (
+            parse_statistics_f64(rg_meta.column(self.minx_col))?,
+            parse_statistics_f64(rg_meta.column(self.miny_col))?,
+            parse_statistics_f64(rg_meta.column(self.maxx_col))?,
+            parse_statistics_f64(rg_meta.column(self.maxy_col))?,
+        )
+        else {
+            return Ok(None);
+        };
+
+        // If no row wraps, or if every row wraps, the extremes of xmin and xmax bound all rows.
+        // Otherwise wrapping and non-wrapping rows may be mixed, so any longitude may be covered.
+        let no_row_wraps = !self.may_wrap || minx_upper <= maxx_lower;
+        let every_row_wraps = minx_lower > maxx_upper;
+        if no_row_wraps || every_row_wraps {
+            Ok(Some(BoundsAccumulator::from_xy(
+                minx_lower, miny, maxx_upper, maxy,
+            )))
+        } else {
+            Ok(Some(BoundsAccumulator::from_xy(-180.0, miny, 180.0, maxy)))
+        }
     }
 
     /// Extract the bounding boxes for a sequence of row groups
     ///
-    /// If `metadata` is provided, it will be assigned onto the generated `RectArray`.
+    /// If `metadata` is provided, it will be assigned onto the generated `RectArray`. Row groups
+    /// without statistics are null.
     pub(crate) fn get_bboxes(
         &self,
         row_groups: &[RowGroupMetaData],
@@ -158,21 +263,70 @@ impl<'a> ParquetBboxStatistics<'a> {
 
         let mut builder = RectBuilder::with_capacity(rect_type, row_groups.len());
         for rg_meta in row_groups.iter() {
-            builder.push_rect(Some(&self.get_bbox(rg_meta)?));
+            builder.push_rect(self.get_bbox(rg_meta)?.as_ref());
         }
         Ok(builder.finish())
     }
+
+    /// Select the rows of a row group whose pages may intersect the query, using the page index.
+    ///
+    /// Each bounding box comparison is evaluated against the min and max of every page of the
+    /// relevant column, and the resulting selections are combined. Returns `None` if the page
+    /// index is not available for all bounding box columns of this row group.
+    fn get_page_selection(
+        &self,
+        column_index: &[Index],
+        offset_index: &[OffsetIndexMetaData],
+        num_rows: usize,
+        bbox_query: &BoundsAccumulator,
+    ) -> Option<RowSelection> {
+        if self.has_spherical_edges() {
+            return None;
+        }
+
+        let select_pages = |col_idx: usize, predicate: &dyn Fn(f64, f64) -> bool| {
+            page_selection(
+                column_index.get(col_idx)?,
+                offset_index.get(col_idx)?,
+                num_rows,
+                predicate,
+            )
+        };
+
+        let miny_cmp = select_pages(self.maxy_col, &|_, max| max >= bbox_query.miny())?;
+        let maxy_cmp = select_pages(self.miny_col, &|min, _| min <= bbox_query.maxy())?;
+        let selection = miny_cmp.intersection(&maxy_cmp);
+
+        // Rows that wrap across the antimeridian can't be pruned by their x extent from the pages
+        // of a single column
+        if self.may_wrap {
+            return Some(selection);
+        }
+
+        let minx_cmp = select_pages(self.maxx_col, &|_, max| max >= bbox_query.minx())?;
+        let maxx_cmp = select_pages(self.minx_col, &|min, _| min <= bbox_query.maxx())?;
+        let x_selection = if bbox_query.minx() > bbox_query.maxx() {
+            minx_cmp.union(&maxx_cmp)
+        } else {
+            minx_cmp.intersection(&maxx_cmp)
+        };
+        Some(selection.intersection(&x_selection))
+    }
 }
 
 pub(crate) fn bbox_row_groups(
     row_groups: &[RowGroupMetaData],
     bbox_cols: &ParquetBboxStatistics,
-    bbox_query: Rect,
+    bbox_query: &impl RectTrait<T = f64>,
 ) -> GeoArrowResult<Vec<usize>> {
-    let row_groups_bounds = bbox_cols.get_bboxes(row_groups, Default::default())?;
+    let bbox_query = query_bounds(bbox_query);
     let mut intersects_row_groups_idxs = vec![];
-    for (row_group_idx, row_group_bounds) in row_groups_bounds.iter_values().enumerate() {
-        if rect_intersects(&row_group_bounds?, &bbox_query) {
+    for (row_group_idx, rg_meta) in row_groups.iter().enumerate() {
+        // Row groups without statistics can't be pruned
+        let intersects = bbox_cols
+            .get_bbox(rg_meta)?
+            .is_none_or(|row_group_bounds| rect_intersects(&row_group_bounds, &bbox_query));
+        if intersects {
             intersects_row_groups_idxs.push(row_group_idx);
         }
     }
@@ -180,13 +334,48 @@ pub(crate) fn bbox_row_groups(
     Ok(intersects_row_groups_idxs)
 }
 
+/// Select the rows within `row_groups` whose data pages may intersect the query, using the
+/// Parquet page index.
+///
+/// The returned [`RowSelection`] is relative to the rows of `row_groups`, in order, and so must
+/// be used together with the same row group selection. Returns `None` if the file metadata was
+/// loaded without the page index.
+pub(crate) fn bbox_row_selection(
+    metadata: &ParquetMetaData,
+    row_groups: &[usize],
+    bbox_cols: &ParquetBboxStatistics,
+    bbox_query: &impl RectTrait<T = f64>,
+) -> Option<RowSelection> {
+    let bbox_query = query_bounds(bbox_query);
+    let column_index = metadata.column_index()?;
+    let offset_index = metadata.offset_index()?;
+
+    let mut selectors: Vec<RowSelector> = vec![];
+    for row_group_idx in row_groups {
+        let num_rows = metadata.row_group(*row_group_idx).num_rows() as usize;
+        let selection = column_index
+            .get(*row_group_idx)
+            .zip(offset_index.get(*row_group_idx))
+            .and_then(|(column_index, offset_index)| {
+                bbox_cols.get_page_selection(column_index, offset_index, num_rows, &bbox_query)
+            });
+        match selection {
+            Some(selection) => selectors.extend(selection.iter().copied()),
+            None => selectors.push(RowSelector::select(num_rows)),
+        }
+    }
+
+    Some(selectors.into())
+}
+
 pub(crate) fn bbox_arrow_predicate(
     parquet_schema: &SchemaDescriptor,
     bbox_cols: ParquetBboxStatistics,
-    bbox_query: Rect,
+    bbox_query: &impl RectTrait<T = f64>,
 ) -> GeoArrowResult<Box<dyn ArrowPredicate>> {
+    let bbox_query = query_bounds(bbox_query);
     // If the min and max columns are the same, then it's a native column
-    if bbox_cols.minx_col == bbox_cols.maxx_col && bbox_cols.miny_col == bbox_cols.maxy_col {
+    if bbox_cols.is_native() {
         construct_native_predicate(parquet_schema, bbox_cols, bbox_query)
     } else {
         construct_bbox_columns_predicate(parquet_schema, bbox_cols, bbox_query)
@@ -194,6 +383,17 @@ pub(crate) fn bbox_arrow_predicate(
     // Ok(RowFilter::new(vec![predicate]))
 }
 
+/// Copy the user-provided query box, keeping `minx > maxx` for queries that wrap across the
+/// antimeridian.
+pub(crate) fn query_bounds(bbox_query: &impl RectTrait<T = f64>) -> BoundsAccumulator {
+    BoundsAccumulator::from_xy(
+        bbox_query.min().x(),
+        bbox_query.min().y(),
+        bbox_query.max().x(),
+        bbox_query.max().y(),
+    )
+}
+
 /// Upcast a Float32Array to a Float64Array
 fn upcast_float_array(array: &Float32Array) -> Float64Array {
     let nulls = array.nulls().cloned();
@@ -206,7 +406,7 @@ fn upcast_float_array(array: &Float32Array) -> Float64Array {
 fn construct_native_predicate(
     parquet_schema: &SchemaDescriptor,
     bbox_cols: ParquetBboxStatistics,
-    bbox_query: Rect,
+    bbox_query: BoundsAccumulator,
 ) -> GeoArrowResult<Box<dyn ArrowPredicate>> {
     let mask = ProjectionMask::leaves(
         parquet_schema,
@@ -218,47 +418,180 @@ fn construct_native_predicate(
         ],
     );
 
+    let spherical = bbox_cols.spherical;
+    let native_parts = bbox_cols.native_parts;
     let predicate = ArrowPredicateFn::new(mask, move |batch| {
         let array = batch.column(0);
-        let field = batch.schema_ref().field(0);
-        let nulls = array.nulls();
-        let geo_arr = from_arrow_array(array, field)?;
-        let rect_arr = bounding_rect(geo_arr.as_ref())?;
-
-        let xmin_col = Float64Array::new(rect_arr.lower().raw_buffers()[0].clone(), nulls.cloned());
-        let ymin_col = Float64Array::new(rect_arr.lower().raw_buffers()[1].clone(), nulls.cloned());
-        let xmax_col = Float64Array::new(rect_arr.upper().raw_buffers()[0].clone(), nulls.cloned());
-        let ymax_col = Float64Array::new(rect_arr.upper().raw_buffers()[1].clone(), nulls.cloned());
-
-        // Construct the bounding box from user input
-        let minx_scalar = Scalar::new(Float64Array::from(vec![bbox_query.min().x()]));
-        let miny_scalar = Scalar::new(Float64Array::from(vec![bbox_query.min().y()]));
-        let maxx_scalar = Scalar::new(Float64Array::from(vec![bbox_query.max().x()]));
-        let maxy_scalar = Scalar::new(Float64Array::from(vec![bbox_query.max().y()]));
-
-        // Perform bbox comparison
-        // TODO: do this in one pass instead of four?
-        let minx_cmp = gt_eq(&xmax_col, &minx_scalar).unwrap();
-        let miny_cmp = gt_eq(&ymax_col, &miny_scalar).unwrap();
-        let maxx_cmp = lt_eq(&xmin_col, &maxx_scalar).unwrap();
-        let maxy_cmp = lt_eq(&ymin_col, &maxy_scalar).unwrap();
-
-        // AND together the results
-        let first = arrow_arith::boolean::and(&minx_cmp, &miny_cmp).unwrap();
-        let second = arrow_arith::boolean::and(&first, &maxx_cmp).unwrap();
-        let third = arrow_arith::boolean::and(&second, &maxy_cmp).unwrap();
-
-        Ok(third)
+        let [xmin_col, ymin_col, xmax_col, ymax_col] = if spherical {
+            // Spherical bounds need to account for the arcs between coordinates
+            spherical_native_bounds(array, native_parts)?
+        } else {
+            native_bounds(array)?
+        };
+
+        bbox_columns_intersect(&xmin_col, &ymin_col, &xmax_col, &ymax_col, &bbox_query)
     });
     Ok(Box::new(predicate))
 }
 
+/// The coordinates of a natively-encoded geometry column.
+struct NativeCoords<'a> {
+    coords: &'a StructArray,
+    x: &'a Float64Array,
+    y: &'a Float64Array,
+    /// The range of coordinates of each row
+    rows: Vec<Range<usize>>,
+    /// The range of innermost lists of each row. Empty for the point encoding, which has no lists.
+    parts: Vec<Range<usize>>,
+    /// The offsets of the innermost lists into the coordinates
+    part_offsets: Vec<usize>,
+}
+
+impl NativeCoords<'_> {
+    fn coord(&self, coord_idx: usize) -> Option<Coord> {
+        self.coords.is_valid(coord_idx).then(|| Coord {
+            x: self.x.value(coord_idx),
+            y: self.y.value(coord_idx),
+        })
+    }
+
+    /// The coordinates of the innermost list `part_idx`.
+    fn part(&self, part_idx: usize) -> impl Iterator<Item = Coord> + '_ {
+        (self.part_offsets[part_idx]..self.part_offsets[part_idx + 1])
+            .filter_map(|coord_idx| self.coord(coord_idx))
+    }
+}
+
+/// Walk the nested list offsets of a natively-encoded geometry column down to its coordinate
+/// struct.
+///
+/// This works for every native encoding and dimension, even when only the `x` and `y` leaves were
+/// projected, and doesn't need any GeoArrow extension metadata on the column.
+fn native_coords(array: &dyn Array) -> Result<NativeCoords<'_>, ArrowError> {
+    let mut rows: Vec<Range<usize>> = (0..array.len()).map(|i| i..i + 1).collect();
+    let mut parts = vec![];
+    let mut part_offsets = vec![];
+    let mut current = array;
+    loop {
+        let (offsets, values): (Vec<usize>, &dyn Array) =
+            if let Some(list) = current.as_list_opt::<i32>() {
+                let offsets = list.value_offsets().iter().map(|o| *o as usize);
+                (offsets.collect(), list.values().as_ref())
+            } else if let Some(list) = current.as_list_opt::<i64>() {
+                let offsets = list.value_offsets().iter().map(|o| *o as usize);
+                (offsets.collect(), list.values().as_ref())
+            } else {
+                break;
+            };
+        parts = rows.clone();
+        rows.iter_mut()
+            .for_each(|r| *r = offsets[r.start]..offsets[r.end]);
+        part_offsets = offsets;
+        current = values;
+    }
+
+    let coords = current
+        .as_struct_opt()
+        .ok_or(ArrowError::SchemaError(format!(
+            "Expected a struct of native coordinates, got {}",
+            current.data_type()
+        )))?;
+    let coord_column = |name: &str| {
+        coords
+            .column_by_name(name)
+            .and_then(|col| col.as_primitive_opt::<Float64Type>())
+            .ok_or(ArrowError::SchemaError(format!(
+                "Expected a Float64 coordinate field named {name}"
+            )))
+    };
+    Ok(NativeCoords {
+        coords,
+        x: coord_column("x")?,
+        y: coord_column("y")?,
+        rows,
+        parts,
+        part_offsets,
+    })
+}
+
+/// Compute the 2D bounds of each row of a natively-encoded geometry column.
+///
+/// Null rows and rows without any coordinates are null.
+fn native_bounds(array: &dyn Array) -> Result<[Float64Array; 4], ArrowError> {
+    let native = native_coords(array)?;
+    let mut bounds = [const { Vec::new() }; 4];
+    for (row_idx, range) in native.rows.iter().enumerate() {
+        let mut rect = BoundsAccumulator::new();
+        if array.is_valid(row_idx) {
+            for coord in range
+                .clone()
+                .filter_map(|coord_idx| native.coord(coord_idx))
+            {
+                rect.add_coord(&coord);
+            }
+        }
+        push_bounds(&mut bounds, &rect);
+    }
+
+    Ok(bounds.map(Float64Array::from))
+}
+
+/// Compute the bounds of each row of a natively-encoded geometry column with spherical edges.
+///
+/// The coordinates of each innermost list are connected by great circle arcs, unless they are
+/// `points`. Bounds that cross the antimeridian have `xmin > xmax`. Null rows and rows without any
+/// coordinates are null.
+fn spherical_native_bounds(
+    array: &dyn Array,
+    native_parts: NativeParts,
+) -> Result<[Float64Array; 4], ArrowError> {
+    let native = native_coords(array)?;
+    let mut bounds = [const { Vec::new() }; 4];
+    for row_idx in 0..array.len() {
+        let mut rect = SphericalBoundsAccumulator::new();
+        if array.is_valid(row_idx) {
+            match native_parts {
+                NativeParts::Points => {
+                    for coord_idx in native.rows[row_idx].clone() {
+                        if let Some(coord) = native.coord(coord_idx) {
+                            rect.add_geometry(&Point(coord));
+                        }
+                    }
+                }
+                NativeParts::Lines => {
+                    for part_idx in native.parts[row_idx].clone() {
+                        rect.add_geometry(&LineString(native.part(part_idx).collect()));
+                    }
+                }
+                NativeParts::Rings => {
+                    for part_idx in native.parts[row_idx].clone() {
+                        let ring = LineString(native.part(part_idx).collect());
+                        rect.add_geometry(&Polygon::new(ring, vec![]));
+                    }
+                }
+            }
+        }
+        push_bounds(&mut bounds, &rect.finish());
+    }
+
+    Ok(bounds.map(Float64Array::from))
+}
+
+/// Append the extents of a row's bounds, or nulls if the row has no (non-NaN) coordinates.
+fn push_bounds(bounds: &mut [Vec<Option<f64>>; 4], rect: &BoundsAccumulator) {
+    let valid = !rect.is_empty() && rect.miny() <= rect.maxy();
+    bounds[0].push(valid.then_some(rect.minx()));
+    bounds[1].push(valid.then_some(rect.miny()));
+    bounds[2].push(valid.then_some(rect.maxx()));
+    bounds[3].push(valid.then_some(rect.maxy()));
+}
+
 /// Construct an [ArrowPredicate] used for spatial filtering when the input is a struct column of 4
 /// floats or doubles, as described in GeoParquet 1.1 bounding box columns.
 fn construct_bbox_columns_predicate(
     parquet_schema: &SchemaDescriptor,
     bbox_cols: ParquetBboxStatistics,
-    bbox_query: Rect,
+    bbox_query: BoundsAccumulator,
 ) -> GeoArrowResult<Box<dyn ArrowPredicate>> {
     let mask = ProjectionMask::leaves(
         parquet_schema,
@@ -350,30 +683,56 @@ fn construct_bbox_columns_predicate(
             _ => unreachable!(),
         };
 
-        // Construct the bounding box from user input
-        let minx_scalar = Scalar::new(Float64Array::from(vec![bbox_query.min().x()]));
-        let miny_scalar = Scalar::new(Float64Array::from(vec![bbox_query.min().y()]));
-        let maxx_scalar = Scalar::new(Float64Array::from(vec![bbox_query.max().x()]));
-        let maxy_scalar = Scalar::new(Float64Array::from(vec![bbox_query.max().y()]));
-
-        // Perform bbox comparison
-        // TODO: do this in one pass instead of four?
-        let minx_cmp = gt_eq(&xmax_col, &minx_scalar).unwrap();
-        let miny_cmp = gt_eq(&ymax_col, &miny_scalar).unwrap();
-        let maxx_cmp = lt_eq(&xmin_col, &maxx_scalar).unwrap();
-        let maxy_cmp = lt_eq(&ymin_col, &maxy_scalar).unwrap();
-
-        // AND together the results
-        let first = arrow_arith::boolean::and(&minx_cmp, &miny_cmp).unwrap();
-        let second = arrow_arith::boolean::and(&first, &maxx_cmp).unwrap();
-        let third = arrow_arith::boolean::and(&second, &maxy_cmp).unwrap();
-
-        Ok(third)
+        bbox_columns_intersect(xmin_col, ymin_col, xmax_col, ymax_col, &bbox_query)
     });
 
     Ok(Box::new(predicate))
 }
 
+/// Compare per-row bounding box columns against the query box.
+///
+/// Either the rows or the query may wrap across the antimeridian (i.e. have `xmin > xmax`).
+fn bbox_columns_intersect(
+    xmin_col: &Float64Array,
+    ymin_col: &Float64Array,
+    xmax_col: &Float64Array,
+    ymax_col: &Float64Array,
+    bbox_query: &BoundsAccumulator,
+) -> Result<BooleanArray, ArrowError> {
+    // Construct the bounding box from user input
+    let minx_scalar = Scalar::new(Float64Array::from(vec![bbox_query.minx()]));
+    let miny_scalar = Scalar::new(Float64Array::from(vec![bbox_query.miny()]));
+    let maxx_scalar = Scalar::new(Float64Array::from(vec![bbox_query.maxx()]));
+    let maxy_scalar = Scalar::new(Float64Array::from(vec![bbox_query.maxy()]));
+
+    // Perform bbox comparison
+    let minx_cmp = gt_eq(xmax_col, &minx_scalar)?;
+    let miny_cmp = gt_eq(ymax_col, &miny_scalar)?;
+    let maxx_cmp = lt_eq(xmin_col, &maxx_scalar)?;
+    let maxy_cmp = lt_eq(ymin_col, &maxy_scalar)?;
+
+    // A wrapping interval is the union of [xmin, 180] and [-180, xmax], so it intersects a
+    // non-wrapping interval if _either_ comparison holds. Two wrapping intervals always intersect.
+    let row_wraps = gt(xmin_col, xmax_col)?;
+    let either_x = or(&minx_cmp, &maxx_cmp)?;
+    let x_cmp = if bbox_query.minx() > bbox_query.maxx() {
+        or(&row_wraps, &either_x)?
+    } else {
+        or(&and(&minx_cmp, &maxx_cmp)?, &and(&row_wraps, &either_x)?)?
+    };
+
+    and(&x_cmp, &and(&miny_cmp, &maxy_cmp)?)
+}
+
+/// Check whether a native covering path matches a column path, ignoring the names of
+/// intermediate list fields.
+fn native_path_equals<T: AsRef<str> + Debug>(a: &[T], b: &ColumnPath) -> bool {
+    let parts = b.parts();
+    a.len() == parts.len()
+        && a.first().map(|x| x.as_ref()) == parts.first().map(|x| x.as_str())
+        && a.last().map(|x| x.as_ref()) == parts.last().map(|x| x.as_str())
+}
+
 /// Check whether two paths are equal
 fn path_equals<T: AsRef<str> + Debug>(a: &[T], b: &ColumnPath) -> bool {
     if a.len() != b.parts().len() {
@@ -391,46 +750,302 @@ fn path_equals<T: AsRef<str> + Debug>(a: &[T], b: &ColumnPath) -> bool {
 
 /// Parse Parquet statistics as f64
 ///
-/// When statistics are stored as f32, this will upcast to f64.
-fn parse_statistics_f64(column_meta: &ColumnChunkMetaData) -> GeoArrowResult<(f64, f64)> {
-    let stats = column_meta
-        .statistics()
-        .ok_or(GeoArrowError::GeoParquet(format!(
-            "No statistics for column {}",
-            column_meta.column_path()
-        )))?;
+/// When statistics are stored as f32, this will upcast to f64. Returns `None` if the column chunk
+/// has no min and max statistics, e.g. when statistics were not written or all values are null.
+fn parse_statistics_f64(column_meta: &ColumnChunkMetaData) -> GeoArrowResult<Option<(f64, f64)>> {
+    let Some(stats) = column_meta.statistics() else {
+        return Ok(None);
+    };
     match stats {
-        Statistics::Double(typed_stats) => Ok((
-            *typed_stats.min_opt().unwrap(),
-            *typed_stats.max_opt().unwrap(),
-        )),
-        Statistics::Float(typed_stats) => Ok((
-            *typed_stats.min_opt().unwrap() as f64,
-            *typed_stats.max_opt().unwrap() as f64,
-        )),
+        Statistics::Double(typed_stats) => Ok(typed_stats
+            .min_opt()
+            .zip(typed_stats.max_opt())
+            .map(|(min, max)| (*min, *max))),
+        Statistics::Float(typed_stats) => Ok(typed_stats
+            .min_opt()
+            .zip(typed_stats.max_opt())
+            .map(|(min, max)| (*min as f64, *max as f64))),
         st => Err(GeoArrowError::GeoParquet(format!(
             "Unexpected statistics type: {st:?}",
         ))),
     }
 }
 
+/// Select the rows of the pages of a single column chunk whose min and max satisfy `predicate`.
+///
+/// Pages without a min and max (e.g. all null) are skipped, as null bounding boxes never
+/// intersect the query. Returns `None` if the column index is not of a floating point type.
+fn page_selection(
+    column_index: &Index,
+    offset_index: &OffsetIndexMetaData,
+    num_rows: usize,
+    predicate: &dyn Fn(f64, f64) -> bool,
+) -> Option<RowSelection> {
+    let page_bounds: Vec<Option<(f64, f64)>> = match column_index {
+        Index::DOUBLE(index) => index
+            .indexes
+            .iter()
+            .map(|page| page.min().zip(page.max()).map(|(min, max)| (*min, *max)))
+            .collect(),
+        Index::FLOAT(index) => index
+            .indexes
+            .iter()
+            .map(|page| {
+                page.min()
+                    .zip(page.max())
+                    .map(|(min, max)| (*min as f64, *max as f64))
+            })
+            .collect(),
+        _ => return None,
+    };
+
+    let page_locations = offset_index.page_locations();
+    if page_locations.len() != page_bounds.len() {
+        return None;
+    }
+
+    let ranges = page_locations
+        .iter()
+        .enumerate()
+        .filter(|(page_idx, _)| {
+            page_bounds[*page_idx].is_some_and(|(min, max)| predicate(min, max))
+        })
+        .map(|(page_idx, location)| {
+            let start = location.first_row_index as usize;
+            let end = page_locations
+                .get(page_idx + 1)
+                .map_or(num_rows, |next| next.first_row_index as usize);
+            start..end
+        });
+    Some(RowSelection::from_consecutive_ranges(ranges, num_rows))
+}
+
 /// Check whether two [RectTrait] intersect.
-fn rect_intersects<T: CoordNum>(a: &impl RectTrait<T = T>, b: &impl RectTrait<T = T>) -> bool {
-    if a.max().x() < b.min().x() {
+///
+/// Either rect may wrap across the antimeridian, i.e. have `min().x() > max().x()`.
+pub(crate) fn rect_intersects(a: &impl RectTrait<T = f64>, b: &impl RectTrait<T = f64>) -> bool {
+    if a.max().y() < b.min().y() || a.min().y() > b.max().y() {
         return false;
     }
 
-    if a.max().y() < b.min().y() {
-        return false;
+    let a_wraps = a.min().x() > a.max().x();
+    let b_wraps = b.min().x() > b.max().x();
+    match (a_wraps, b_wraps) {
+        // Both contain the antimeridian
+        (true, true) => true,
+        (true, false) => b.max().x() >= a.min().x() || b.min().x() <= a.max().x(),
+        (false, true) => a.max().x() >= b.min().x() || a.min().x() <= b.max().x(),
+        (false, false) => a.max().x() >= b.min().x() && a.min().x() <= b.max().x(),
     }
+}
 
-    if a.min().x() > b.max().x() {
-        return false;
+#[cfg(test)]
+mod test {
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use bytes::Bytes;
+    use geo_types::{Rect, coord, line_string};
+    use geoarrow_array::GeoArrowArray;
+    use geoarrow_array::builder::LineStringBuilder;
+    use geoarrow_schema::{Edges, LineStringType};
+    use parquet::arrow::ArrowWriter;
+    use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    use parquet::arrow::arrow_writer::ArrowWriterOptions;
+    use parquet::file::properties::WriterProperties;
+
+    use super::*;
+    use crate::reader::GeoParquetReaderBuilder;
+    use crate::writer::{
+        GeoParquetRecordBatchEncoder, GeoParquetWriter, GeoParquetWriterEncoding,
+        GeoParquetWriterOptionsBuilder,
+    };
+
+    /// Write six line strings, spaced 10 units apart along the x axis, as a native-encoded file
+    /// with two rows per row group and one row per page.
+    fn native_line_string_file() -> Bytes {
+        let line_strings = (0..6)
+            .map(|i| {
+                let x = i as f64 * 10.0;
+                line_string![(x: x, y: 0.0), (x: x + 1.0, y: 1.0)]
+            })
+            .collect::<Vec<_>>();
+        let typ = LineStringType::new(Dimension::XY, Default::default());
+        let array = LineStringBuilder::from_line_strings(&line_strings, typ).finish();
+        let schema = Arc::new(Schema::new(vec![
+            array.data_type().to_field("geometry", true),
+        ]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![array.to_array_ref()]).unwrap();
+
+        let options = GeoParquetWriterOptionsBuilder::default()
+            .set_encoding(GeoParquetWriterEncoding::GeoArrow)
+            .build();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .set_write_batch_size(1)
+            .set_data_page_row_count_limit(1)
+            .build();
+
+        let mut buf = vec![];
+        let mut writer =
+            GeoParquetWriter::try_new(&mut buf, &schema, &options, Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buf)
     }
 
-    if a.min().y() > b.max().y() {
-        return false;
+    #[test]
+    fn native_line_string_pruning() {
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            native_line_string_file(),
+            options,
+        )
+        .unwrap();
+        let geo_meta = builder.geoparquet_metadata().unwrap().unwrap();
+        let bbox = Rect::new(coord! { x: 9.0, y: -1.0 }, coord! { x: 22.0, y: 2.0 });
+
+        let row_groups = builder
+            .intersecting_row_groups(bbox, &geo_meta, None)
+            .unwrap();
+        assert_eq!(row_groups, vec![0, 1]);
+
+        let selection = builder
+            .intersecting_row_selection(bbox, &row_groups, &geo_meta, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(selection.row_count(), 2);
+
+        let reader = builder
+            .with_intersecting_pages(bbox, &geo_meta, None)
+            .unwrap()
+            .with_intersecting_row_filter(bbox, &geo_meta, None)
+            .unwrap()
+            .build()
+            .unwrap();
+        let num_rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+        assert_eq!(num_rows, 2);
     }
 
-    true
+    /// Write two line strings with spherical edges as a native-encoded file with one row per row
+    /// group, optionally without the `ARROW:schema` metadata.
+    ///
+    /// The great circle arc of the first line passes over the north pole, while the second stays
+    /// near the equator.
+    fn spherical_line_string_file(dim: Dimension, skip_arrow_metadata: bool) -> Bytes {
+        let (z, wkt_dim) = match dim {
+            Dimension::XYZ => (Some(1.0), wkt::types::Dimension::XYZ),
+            _ => (None, wkt::types::Dimension::XY),
+        };
+        let coord = |x, y| wkt::types::Coord { x, y, z, m: None };
+        let line_strings = [
+            wkt::types::LineString::new(vec![coord(-90.0, 45.0), coord(90.0, 45.0)], wkt_dim),
+            wkt::types::LineString::new(vec![coord(100.0, -10.0), coord(110.0, -10.0)], wkt_dim),
+        ];
+        let metadata = Arc::new(Metadata::new(Default::default(), Some(Edges::Spherical)));
+        let typ = LineStringType::new(dim, metadata);
+        let array = LineStringBuilder::from_line_strings(&line_strings, typ).finish();
+        let schema = Arc::new(Schema::new(vec![
+            array.data_type().to_field("geometry", true),
+        ]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![array.to_array_ref()]).unwrap();
+
+        let options = GeoParquetWriterOptionsBuilder::default()
+            .set_encoding(GeoParquetWriterEncoding::GeoArrow)
+            .build();
+        let mut encoder = GeoParquetRecordBatchEncoder::try_new(&schema, &options).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(1)
+            .build();
+        let writer_options = ArrowWriterOptions::new()
+            .with_properties(encoder.target_writer_properties(Some(props)).unwrap())
+            .with_skip_arrow_metadata(skip_arrow_metadata);
+
+        let mut buf = vec![];
+        let mut writer =
+            ArrowWriter::try_new_with_options(&mut buf, encoder.target_schema(), writer_options)
+                .unwrap();
+        writer
+            .write(&encoder.encode_record_batch(&batch).unwrap())
+            .unwrap();
+        writer.append_key_value_metadata(encoder.into_keyvalue().unwrap());
+        writer.close().unwrap();
+        Bytes::from(buf)
+    }
+
+    #[test]
+    fn spherical_native_row_filter() {
+        let bbox = Rect::new(coord! { x: -10.0, y: 80.0 }, coord! { x: 10.0, y: 90.0 });
+        for dim in [Dimension::XY, Dimension::XYZ] {
+            for skip_arrow_metadata in [false, true] {
+                let file = spherical_line_string_file(dim, skip_arrow_metadata);
+                let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+                let geo_meta = builder.geoparquet_metadata().unwrap().unwrap();
+
+                // The coordinate statistics don't bound the arcs, so no row group is pruned
+                let row_groups = builder
+                    .intersecting_row_groups(bbox, &geo_meta, None)
+                    .unwrap();
+                assert_eq!(row_groups, vec![0, 1]);
+
+                let reader = builder
+                    .with_intersecting_row_filter(bbox, &geo_meta, None)
+                    .unwrap()
+                    .build()
+                    .unwrap();
+                let num_rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+                assert_eq!(
+                    num_rows, 1,
+                    "{dim:?}, skip_arrow_metadata: {skip_arrow_metadata}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn native_bounds_of_nested_lists() {
+        let line_strings = vec![
+            line_string![(x: 0.0, y: 5.0), (x: 2.0, y: -1.0)],
+            line_string![(x: -3.0, y: 1.0), (x: 1.0, y: 4.0), (x: 0.0, y: 0.0)],
+        ];
+        let typ = LineStringType::new(Dimension::XY, Default::default())
+            .with_coord_type(geoarrow_schema::CoordType::Separated);
+        let array = LineStringBuilder::from_line_strings(&line_strings, typ).finish();
+
+        let [xmin, ymin, xmax, ymax] = native_bounds(array.to_array_ref().as_ref()).unwrap();
+        assert_eq!(xmin, Float64Array::from(vec![0.0, -3.0]));
+        assert_eq!(ymin, Float64Array::from(vec![-1.0, 0.0]));
+        assert_eq!(xmax, Float64Array::from(vec![2.0, 1.0]));
+        assert_eq!(ymax, Float64Array::from(vec![5.0, 4.0]));
+    }
+
+    #[test]
+    fn rect_intersects_across_antimeridian() {
+        let pacific = BoundsAccumulator::from_xy(170.0, -10.0, -170.0, 10.0);
+        let fiji = BoundsAccumulator::from_xy(177.0, -19.0, 179.0, -16.0);
+        let samoa = BoundsAccumulator::from_xy(-173.0, -14.0, -171.0, -13.0);
+        let atlantic = BoundsAccumulator::from_xy(-40.0, -10.0, -20.0, 10.0);
+
+        assert!(rect_intersects(&pacific, &fiji));
+        assert!(rect_intersects(&samoa, &pacific));
+        assert!(!rect_intersects(&pacific, &atlantic));
+        assert!(rect_intersects(&pacific, &pacific));
+        assert!(!rect_intersects(&fiji, &samoa));
+    }
+
+    #[test]
+    fn bbox_columns_intersect_wrapping_rows() {
+        // Rows: non-wrapping near Fiji, wrapping across the antimeridian, and in the Atlantic
+        let xmin = Float64Array::from(vec![177.0, 175.0, -40.0]);
+        let ymin = Float64Array::from(vec![-19.0, -5.0, -10.0]);
+        let xmax = Float64Array::from(vec![179.0, -175.0, -20.0]);
+        let ymax = Float64Array::from(vec![-16.0, 5.0, 10.0]);
+
+        let query = BoundsAccumulator::from_xy(-178.0, -20.0, -170.0, 20.0);
+        let result = bbox_columns_intersect(&xmin, &ymin, &xmax, &ymax, &query).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false, true, false]));
+
+        let wrapped_query = BoundsAccumulator::from_xy(178.0, -20.0, -170.0, 20.0);
+        let result = bbox_columns_intersect(&xmin, &ymin, &xmax, &ymax, &wrapped_query).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, true, false]));
+    }
 }
@@ -0,0 +1,75 @@
+use arrow_array::RecordBatch;
+use arrow_schema::{Schema, SchemaRef};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use parquet::arrow::AsyncArrowWriter;
+use parquet::arrow::async_writer::AsyncFileWriter;
+use parquet::file::properties::WriterProperties;
+use parquet::format::FileMetaData;
+
+use crate::writer::{GeoParquetRecordBatchEncoder, GeoParquetWriterOptions};
+
+/// An asynchronous writer for GeoParquet files.
+///
+/// This is the async counterpart to [`GeoParquetWriter`][crate::writer::GeoParquetWriter],
+/// wrapping a [`GeoParquetRecordBatchEncoder`] and an upstream [`AsyncArrowWriter`].
+pub struct AsyncGeoParquetWriter<W: AsyncFileWriter> {
+    writer: AsyncArrowWriter<W>,
+    encoder: GeoParquetRecordBatchEncoder,
+}
+
+impl<W: AsyncFileWriter> AsyncGeoParquetWriter<W> {
+    /// Create a new writer with the given schema and options.
+    ///
+    /// All record batches written must have this same [`Schema`]. If `props` is `None`, default
+    /// [`WriterProperties`] are used. In either case, column chunk statistics are always enabled
+    /// for bounding box covering columns and native geometry coordinates so that readers can
+    /// prune row groups spatially.
+    pub fn try_new(
+        writer: W,
+        schema: &Schema,
+        options: &GeoParquetWriterOptions,
+        props: Option<WriterProperties>,
+    ) -> GeoArrowResult<Self> {
+        let encoder = GeoParquetRecordBatchEncoder::try_new(schema, options)?;
+        let props = encoder.target_writer_properties(props)?;
+        let writer = AsyncArrowWriter::try_new(writer, encoder.target_schema(), Some(props))
+            .map_err(|err| GeoArrowError::External(Box::new(err)))?;
+        Ok(Self { writer, encoder })
+    }
+
+    /// The Arrow schema of the data as written to the Parquet file.
+    pub fn target_schema(&self) -> SchemaRef {
+        self.encoder.target_schema()
+    }
+
+    /// Encode and write a [`RecordBatch`].
+    ///
+    /// This [`RecordBatch`] must have the same schema as the [`Schema`] passed into
+    /// [`AsyncGeoParquetWriter::try_new`].
+    pub async fn write(&mut self, batch: &RecordBatch) -> GeoArrowResult<()> {
+        let encoded_batch = self.encoder.encode_record_batch(batch)?;
+        self.writer
+            .write(&encoded_batch)
+            .await
+            .map_err(|err| GeoArrowError::External(Box::new(err)))
+    }
+
+    /// Flush any buffered data into a new row group.
+    pub async fn flush(&mut self) -> GeoArrowResult<()> {
+        self.writer
+            .flush()
+            .await
+            .map_err(|err| GeoArrowError::External(Box::new(err)))
+    }
+
+    /// Append the GeoParquet metadata and close the file, returning the written
+    /// [`FileMetaData`].
+    pub async fn close(mut self) -> GeoArrowResult<FileMetaData> {
+        self.writer
+            .append_key_value_metadata(self.encoder.into_keyvalue()?);
+        self.writer
+            .close()
+            .await
+            .map_err(|err| GeoArrowError::External(Box::new(err)))
+    }
+}
@@ -3,12 +3,14 @@ use arrow_schema::{Field, Schema, SchemaRef};
 use geoarrow_array::GeoArrowArray;
 use geoarrow_array::array::from_arrow_array;
 use geoarrow_array::cast::{AsGeoArrowArray, to_wkb};
+use geoarrow_geo::{BoundsAccumulator, bounding_rect, orient_polygons, total_bounds};
 use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 use geoarrow_schema::{CoordType, GeoArrowType};
+use parquet::arrow::ArrowSchemaConverter;
+use parquet::file::properties::{EnabledStatistics, WriterProperties};
 use parquet::format::KeyValue;
 
 use crate::metadata::{GeoParquetColumnEncoding, GeoParquetMetadata};
-use crate::total_bounds::{BoundingRect, bounding_rect, total_bounds};
 use crate::writer::GeoParquetWriterOptions;
 use crate::writer::metadata::{ColumnInfo, GeoParquetMetadataBuilder};
 
@@ -41,6 +43,51 @@ impl GeoParquetRecordBatchEncoder {
         self.metadata_builder.output_schema.clone()
     }
 
+    /// Construct the [`WriterProperties`] to use for the upstream Parquet writer.
+    ///
+    /// Spatial filtering on read relies on the per-row-group min/max statistics of the bounding
+    /// box covering columns and of the coordinate columns of native-encoded geometries. This
+    /// ensures that at least column chunk statistics are enabled for those columns, even if the
+    /// provided properties have disabled statistics.
+    pub(crate) fn target_writer_properties(
+        &self,
+        props: Option<WriterProperties>,
+    ) -> GeoArrowResult<WriterProperties> {
+        let props = props.unwrap_or_default();
+        let parquet_schema = ArrowSchemaConverter::new()
+            .convert(&self.metadata_builder.output_schema)
+            .map_err(|err| GeoArrowError::GeoParquet(err.to_string()))?;
+
+        let mut bbox_root_indices = vec![];
+        for (column_idx, column_info) in self.metadata_builder.columns.iter() {
+            if column_info.encoding != GeoParquetColumnEncoding::WKB {
+                bbox_root_indices.push(*column_idx);
+            }
+            if let Some(covering_field_idx) = column_info.covering_field_idx {
+                bbox_root_indices.push(covering_field_idx);
+            }
+        }
+
+        let mut builder = None;
+        for (leaf_idx, column) in parquet_schema.columns().iter().enumerate() {
+            if !bbox_root_indices.contains(&parquet_schema.get_column_root_idx(leaf_idx)) {
+                continue;
+            }
+            if props.statistics_enabled(column.path()) == EnabledStatistics::None {
+                builder = Some(
+                    builder
+                        .unwrap_or_else(|| props.clone().into_builder())
+                        .set_column_statistics_enabled(
+                            column.path().clone(),
+                            EnabledStatistics::Chunk,
+                        ),
+                );
+            }
+        }
+
+        Ok(builder.map(|builder| builder.build()).unwrap_or(props))
+    }
+
     /// Encode a record batch into a GeoParquet-compatible format.
     ///
     /// This also updates the internal bounding box tracking
@@ -101,7 +148,8 @@ pub(super) fn encode_record_batch(
         output_columns[*column_idx] = Some(encoded_column);
 
         if let Some(covering_field_idx) = column_info.covering_field_idx {
-            let covering = bounding_rect(from_arrow_array(array, field)?.as_ref())?;
+            let covering =
+                bounding_rect(from_arrow_array(array, field)?.as_ref(), column_info.edges)?;
             output_columns[covering_field_idx] = Some(covering.into_array_ref());
         }
 
@@ -121,9 +169,12 @@ fn encode_column(
     array: &dyn Array,
     field: &Field,
     column_info: &mut ColumnInfo,
-) -> GeoArrowResult<(ArrayRef, BoundingRect)> {
-    let geo_arr = from_arrow_array(array, field)?;
-    let array_bounds = total_bounds(geo_arr.as_ref())?;
+) -> GeoArrowResult<(ArrayRef, BoundsAccumulator)> {
+    let mut geo_arr = from_arrow_array(array, field)?;
+    if column_info.orient_polygons {
+        geo_arr = orient_polygons(geo_arr.as_ref())?;
+    }
+    let array_bounds = total_bounds(geo_arr.as_ref(), column_info.edges)?;
     let encoded_array = match column_info.encoding {
         GeoParquetColumnEncoding::WKB => encode_wkb_column(geo_arr.as_ref())?,
         _ => encode_native_column(geo_arr.as_ref()),
@@ -8,6 +8,7 @@ use geo_traits::GeometryTrait;
 use geoarrow_array::GeoArrowArrayAccessor;
 use geoarrow_array::array::from_arrow_array;
 use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_geo::{BoundsAccumulator, SphericalBoundsAccumulator};
 use geoarrow_schema::crs::{CrsTransform, DefaultCrsTransform};
 use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
 use geoarrow_schema::{CoordType, Dimension, Edges, GeoArrowType, Metadata, WkbType};
@@ -17,7 +18,6 @@ use crate::metadata::{
     GeoParquetBboxCovering, GeoParquetColumnEncoding, GeoParquetColumnMetadata, GeoParquetCovering,
     GeoParquetGeometryType, GeoParquetGeometryTypeAndDimension, GeoParquetMetadata,
 };
-use crate::total_bounds::BoundingRect;
 use crate::writer::options::{GeoParquetWriterEncoding, GeoParquetWriterOptions};
 
 // https://github.com/geoarrow/geoarrow-rs/pull/1159#issuecomment-2904610370
@@ -35,7 +35,7 @@ pub(crate) struct ColumnInfo {
     pub(crate) geometry_types: HashSet<GeoParquetGeometryTypeAndDimension>,
 
     /// The bounding box of this column.
-    pub(crate) bbox: Option<BoundingRect>,
+    pub(crate) bbox: Option<BoundsAccumulator>,
 
     /// The PROJJSON CRS for this geometry column.
     pub(crate) crs: Option<Value>,
@@ -50,6 +50,10 @@ pub(crate) struct ColumnInfo {
 
     /// This gets set in `create_output_schema`
     pub(crate) covering_field_idx: Option<usize>,
+
+    /// Whether polygons are rewound to counterclockwise exterior and clockwise interior rings
+    /// while encoding.
+    pub(crate) orient_polygons: bool,
 }
 
 impl ColumnInfo {
@@ -60,6 +64,7 @@ impl ColumnInfo {
         metadata: &Metadata,
         crs_transform: Option<&dyn CrsTransform>,
         covering_name: Option<String>,
+        orient_polygons: bool,
     ) -> GeoArrowResult<Self> {
         let encoding = GeoParquetColumnEncoding::try_new(writer_encoding, data_type)?;
         let geometry_types = get_geometry_types(data_type);
@@ -80,12 +85,18 @@ impl ColumnInfo {
             edges,
             covering_name,
             covering_field_idx: None,
+            orient_polygons,
         })
     }
 
-    pub(crate) fn update_bbox(&mut self, new_bounds: &BoundingRect) {
+    pub(crate) fn update_bbox(&mut self, new_bounds: &BoundsAccumulator) {
         if let Some(existing_bounds) = self.bbox.as_mut() {
-            existing_bounds.update(new_bounds)
+            // Spherical bounds may wrap across the antimeridian, so can't be merged by min/max
+            if matches!(self.edges, Some(Edges::Spherical)) {
+                *existing_bounds = SphericalBoundsAccumulator::union(existing_bounds, new_bounds)
+            } else {
+                existing_bounds.update(new_bounds)
+            }
         } else {
             self.bbox = Some(*new_bounds);
         }
@@ -263,7 +274,7 @@ impl ColumnInfo {
             crs: self.crs,
             bbox,
             edges,
-            orientation: None,
+            orientation: self.orient_polygons.then(|| "counterclockwise".to_string()),
             epoch: None,
             covering,
         };
@@ -349,6 +360,14 @@ impl GeoParquetMetadataBuilder {
                     None
                 };
 
+                let orient_polygons = options
+                    .column_properties
+                    .get(&column_name)
+                    .map_or(options.default_column_properties.orient_polygons, |props| {
+                        props.orient_polygons
+                    })
+                    .unwrap_or(false);
+
                 let column_info = ColumnInfo::try_new(
                     column_name,
                     column_encoding,
@@ -356,6 +375,7 @@ impl GeoParquetMetadataBuilder {
                     geo_data_type.metadata(),
                     options.crs_transform.as_deref(),
                     covering_name,
+                    orient_polygons,
                 )?;
 
                 columns.insert(col_idx, column_info);
@@ -407,7 +427,7 @@ impl GeoParquetMetadataBuilder {
     }
 
     #[allow(dead_code)]
-    fn update_bounds(&mut self, bounds: &HashMap<usize, BoundingRect>) {
+    fn update_bounds(&mut self, bounds: &HashMap<usize, BoundsAccumulator>) {
         for (column_idx, column_bounds) in bounds.iter() {
             self.columns
                 .get_mut(column_idx)
@@ -1,10 +1,16 @@
 #![doc = include_str!("README.md")]
 
+#[cfg(feature = "async")]
+mod r#async;
 mod encode;
 mod metadata;
 mod options;
+mod sync;
 
+#[cfg(feature = "async")]
+pub use r#async::AsyncGeoParquetWriter;
 pub use encode::GeoParquetRecordBatchEncoder;
 pub use options::{
     GeoParquetWriterEncoding, GeoParquetWriterOptions, GeoParquetWriterOptionsBuilder,
 };
+pub use sync::GeoParquetWriter;
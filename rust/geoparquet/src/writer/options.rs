@@ -26,6 +26,7 @@ pub(crate) struct ColumnOptions {
     pub(crate) encoding: Option<GeoParquetWriterEncoding>,
     pub(crate) generate_covering: Option<bool>,
     pub(crate) covering_name: Option<String>,
+    pub(crate) orient_polygons: Option<bool>,
 }
 
 impl ColumnOptions {
@@ -40,6 +41,10 @@ impl ColumnOptions {
     fn set_covering_name(&mut self, value: String) {
         self.covering_name = Some(value);
     }
+
+    fn set_orient_polygons(&mut self, value: bool) {
+        self.orient_polygons = Some(value);
+    }
 }
 
 /// Builder for [`GeoParquetWriterOptions`]
@@ -112,6 +117,22 @@ impl GeoParquetWriterOptionsBuilder {
         self
     }
 
+    /// Set the default status for whether polygons in all geometry columns should be rewound
+    /// while writing.
+    ///
+    /// If `true`, exterior rings are rewound to be counterclockwise and interior rings to be
+    /// clockwise, and the column metadata records `"orientation": "counterclockwise"`.
+    pub fn set_orient_polygons(mut self, value: bool) -> Self {
+        self.default_column_properties.set_orient_polygons(value);
+        self
+    }
+
+    /// Set whether polygons in a specific geometry column should be rewound while writing.
+    pub fn set_column_orient_polygons(mut self, col: String, value: bool) -> Self {
+        self.get_mut_props(col).set_orient_polygons(value);
+        self
+    }
+
     /// Finalizes the configuration and returns immutable writer options struct.
     pub fn build(self) -> GeoParquetWriterOptions {
         GeoParquetWriterOptions {
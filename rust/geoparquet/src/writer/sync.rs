@@ -0,0 +1,177 @@
+use std::io::Write;
+
+use arrow_array::RecordBatch;
+use arrow_schema::{Schema, SchemaRef};
+use geoarrow_schema::error::{GeoArrowError, GeoArrowResult};
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+use parquet::format::FileMetaData;
+
+use crate::writer::{GeoParquetRecordBatchEncoder, GeoParquetWriterOptions};
+
+/// A synchronous writer for GeoParquet files.
+///
+/// This wraps a [`GeoParquetRecordBatchEncoder`] and an upstream [`ArrowWriter`], encoding each
+/// batch before it is written and attaching the GeoParquet `"geo"` metadata when the file is
+/// closed.
+///
+/// Batches are buffered into row groups by the upstream [`ArrowWriter`] according to the
+/// provided [`WriterProperties`].
+pub struct GeoParquetWriter<W: Write + Send> {
+    writer: ArrowWriter<W>,
+    encoder: GeoParquetRecordBatchEncoder,
+}
+
+impl<W: Write + Send> GeoParquetWriter<W> {
+    /// Create a new writer with the given schema and options.
+    ///
+    /// All record batches written must have this same [`Schema`]. If `props` is `None`, default
+    /// [`WriterProperties`] are used. In either case, column chunk statistics are always enabled
+    /// for bounding box covering columns and native geometry coordinates so that readers can
+    /// prune row groups spatially.
+    pub fn try_new(
+        writer: W,
+        schema: &Schema,
+        options: &GeoParquetWriterOptions,
+        props: Option<WriterProperties>,
+    ) -> GeoArrowResult<Self> {
+        let encoder = GeoParquetRecordBatchEncoder::try_new(schema, options)?;
+        let props = encoder.target_writer_properties(props)?;
+        let writer = ArrowWriter::try_new(writer, encoder.target_schema(), Some(props))
+            .map_err(|err| GeoArrowError::External(Box::new(err)))?;
+        Ok(Self { writer, encoder })
+    }
+
+    /// The Arrow schema of the data as written to the Parquet file.
+    pub fn target_schema(&self) -> SchemaRef {
+        self.encoder.target_schema()
+    }
+
+    /// Encode and write a [`RecordBatch`].
+    ///
+    /// This [`RecordBatch`] must have the same schema as the [`Schema`] passed into
+    /// [`GeoParquetWriter::try_new`].
+    pub fn write(&mut self, batch: &RecordBatch) -> GeoArrowResult<()> {
+        let encoded_batch = self.encoder.encode_record_batch(batch)?;
+        self.writer
+            .write(&encoded_batch)
+            .map_err(|err| GeoArrowError::External(Box::new(err)))
+    }
+
+    /// Flush any buffered data into a new row group.
+    pub fn flush(&mut self) -> GeoArrowResult<()> {
+        self.writer
+            .flush()
+            .map_err(|err| GeoArrowError::External(Box::new(err)))
+    }
+
+    /// Append the GeoParquet metadata and close the file, returning the written
+    /// [`FileMetaData`].
+    pub fn close(mut self) -> GeoArrowResult<FileMetaData> {
+        self.writer
+            .append_key_value_metadata(self.encoder.into_keyvalue()?);
+        self.writer
+            .close()
+            .map_err(|err| GeoArrowError::External(Box::new(err)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use bytes::Bytes;
+    use geo_traits::to_geo::ToGeoPolygon;
+    use geo_types::polygon;
+    use geoarrow_array::array::from_arrow_array;
+    use geoarrow_array::builder::PolygonBuilder;
+    use geoarrow_array::cast::AsGeoArrowArray;
+    use geoarrow_array::test::point;
+    use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor};
+    use geoarrow_schema::{CoordType, Dimension, PolygonType};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::file::properties::{EnabledStatistics, WriterProperties};
+
+    use super::*;
+    use crate::reader::{GeoParquetReaderBuilder, GeoParquetRecordBatchReader};
+    use crate::writer::GeoParquetWriterOptionsBuilder;
+
+    #[test]
+    fn round_trip_with_covering() {
+        let point_arr = point::array(CoordType::Separated, Dimension::XY);
+        let field = point_arr.data_type().to_field("geometry", true);
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![point_arr.to_array_ref()]).unwrap();
+
+        let options = GeoParquetWriterOptionsBuilder::default()
+            .set_generate_covering(true)
+            .build();
+        let props = WriterProperties::builder()
+            .set_statistics_enabled(EnabledStatistics::None)
+            .set_max_row_group_size(2)
+            .build();
+
+        let mut buf = vec![];
+        let mut writer =
+            GeoParquetWriter::try_new(&mut buf, &schema, &options, Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let geo_meta = builder.geoparquet_metadata().unwrap().unwrap();
+        let column_meta = geo_meta.columns.get("geometry").unwrap();
+        assert!(column_meta.bbox.is_some());
+        assert!(column_meta.covering.is_some());
+
+        let parquet_meta = builder.metadata();
+        assert!(parquet_meta.num_row_groups() > 1);
+        for row_group in parquet_meta.row_groups() {
+            for column in row_group.columns() {
+                if column.column_path().parts()[0] == "bbox" {
+                    assert!(column.statistics().is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn orient_polygons_counterclockwise() {
+        let clockwise = polygon![
+            (x: 0., y: 0.), (x: 0., y: 10.), (x: 10., y: 10.), (x: 10., y: 0.), (x: 0., y: 0.)
+        ];
+        let typ = PolygonType::new(Dimension::XY, Default::default());
+        let polygon_arr = PolygonBuilder::from_polygons(&[clockwise], typ).finish();
+        let field = polygon_arr.data_type().to_field("geometry", true);
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![polygon_arr.to_array_ref()]).unwrap();
+
+        let options = GeoParquetWriterOptionsBuilder::default()
+            .set_orient_polygons(true)
+            .build();
+        let mut buf = vec![];
+        let mut writer = GeoParquetWriter::try_new(&mut buf, &schema, &options, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let geo_meta = builder.geoparquet_metadata().unwrap().unwrap();
+        let column_meta = geo_meta.columns.get("geometry").unwrap();
+        assert_eq!(column_meta.orientation.as_deref(), Some("counterclockwise"));
+
+        let geoarrow_schema = builder
+            .geoarrow_schema(&geo_meta, true, CoordType::Separated)
+            .unwrap();
+        let reader =
+            GeoParquetRecordBatchReader::try_new(builder.build().unwrap(), geoarrow_schema.clone())
+                .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let array = from_arrow_array(batches[0].column(0), geoarrow_schema.field(0)).unwrap();
+        let oriented = array.as_polygon().value(0).unwrap().to_polygon();
+        let expected = polygon![
+            (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)
+        ];
+        assert_eq!(oriented, expected);
+    }
+}